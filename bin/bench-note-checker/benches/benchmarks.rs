@@ -1,7 +1,11 @@
 use std::hint::black_box;
 use std::time::Duration;
 
-use bench_note_checker::benchmark_names::{BENCH_GROUP, BENCH_MIXED_NOTES};
+use bench_note_checker::benchmark_names::{
+    BENCH_GROUP,
+    BENCH_MANY_SUCCESSFUL_NOTES,
+    BENCH_MIXED_NOTES,
+};
 use bench_note_checker::{MixedNotesConfig, run_mixed_notes_check, setup_mixed_notes_benchmark};
 use criterion::{Criterion, SamplingMode, criterion_group, criterion_main};
 use miden_tx::MAX_NUM_CHECKER_NOTES;
@@ -15,18 +19,38 @@ fn note_checker_benchmarks(c: &mut Criterion) {
         .warm_up_time(Duration::from_millis(500))
         .measurement_time(Duration::from_secs(10));
 
-    // Benchmark with different numbers of failing notes.
-    for failing_count in [1, 10, MAX_NUM_CHECKER_NOTES] {
+    // Benchmark with different numbers of failing notes and two successful notes.
+    for failing_count in [1, 10, MAX_NUM_CHECKER_NOTES - 2] {
         group.bench_function(format!("{BENCH_MIXED_NOTES}_{failing_count}_failing"), |b| {
-            let setup =
-                setup_mixed_notes_benchmark(MixedNotesConfig { failing_note_count: failing_count })
-                    .expect("failed to set up mixed notes benchmark");
+            let setup = setup_mixed_notes_benchmark(MixedNotesConfig {
+                failing_note_count: failing_count,
+                ..Default::default()
+            })
+            .expect("failed to set up mixed notes benchmark");
 
             b.to_async(tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap())
                 .iter(|| async { black_box(run_mixed_notes_check(&setup).await) });
         });
     }
 
+    // Benchmark with as many successful notes as the note checker allows next to 10 failing notes,
+    // which is closer to the notes found when scanning a wallet.
+    let successful_count = MAX_NUM_CHECKER_NOTES - 10;
+    group.bench_function(
+        format!("{BENCH_MANY_SUCCESSFUL_NOTES}_{successful_count}_successful_10_failing"),
+        |b| {
+            let setup = setup_mixed_notes_benchmark(MixedNotesConfig {
+                successful_note_count: successful_count,
+                failing_note_count: 10,
+                ..Default::default()
+            })
+            .expect("failed to set up many successful notes benchmark");
+
+            b.to_async(tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap())
+                .iter(|| async { black_box(run_mixed_notes_check(&setup).await) });
+        },
+    );
+
     group.finish();
 }
 
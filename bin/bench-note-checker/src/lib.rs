@@ -1,55 +1,249 @@
+use std::collections::BTreeMap;
+use std::fs::write;
+use std::path::Path;
+
+use anyhow::Context;
 use miden_protocol::account::AccountId;
-use miden_protocol::asset::FungibleAsset;
+use miden_protocol::asset::{Asset, FungibleAsset};
 use miden_protocol::crypto::rand::RpoRandomCoin;
-use miden_protocol::note::{Note, NoteType};
+use miden_protocol::note::{Note, NoteAttachment, NoteType};
 use miden_protocol::testing::account_id::{
+    ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
     ACCOUNT_ID_SENDER,
 };
+use miden_standards::note::P2idNote;
 use miden_standards::testing::note::NoteBuilder;
 use miden_testing::{Auth, MockChain, TxContextInput};
 use miden_tx::auth::UnreachableAuth;
-use miden_tx::{NoteConsumptionChecker, TransactionExecutor};
+use miden_tx::{
+    MAX_NUM_CHECKER_NOTES,
+    NoteConsumptionChecker,
+    NoteConsumptionInfo,
+    TransactionExecutor,
+};
 use serde::{Deserialize, Serialize};
 
 pub mod benchmark_names {
     pub const BENCH_GROUP: &str = "note_checker";
     pub const BENCH_MIXED_NOTES: &str = "mixed_successful_and_failing_notes";
+    pub const BENCH_MANY_SUCCESSFUL_NOTES: &str = "many_successful_and_failing_notes";
 }
 
 /// Benchmark result measurements for note checker performance.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NoteCheckerMeasurements {
+    pub successful_note_count: usize,
     pub failing_note_count: usize,
+    pub failure_kind: FailureKind,
+    pub arrangement: NoteArrangement,
     pub successful_notes_found: usize,
     pub failed_notes_count: usize,
+    /// The number of iterations of the note checker's elimination loop, see
+    /// [`elimination_iterations`].
     pub total_iterations: usize,
+    /// The number of times the note check was repeated to measure the execution time.
+    pub num_repetitions: usize,
+    /// The mean execution time of a single note check.
     pub execution_time_ms: f64,
 }
 
 impl NoteCheckerMeasurements {
     pub fn new(
-        failing_note_count: usize,
+        config: &MixedNotesConfig,
         successful_notes_found: usize,
         failed_notes_count: usize,
         total_iterations: usize,
+        num_repetitions: usize,
         execution_time_ms: f64,
     ) -> Self {
         Self {
-            failing_note_count,
+            successful_note_count: config.successful_note_count,
+            failing_note_count: config.failing_note_count,
+            failure_kind: config.failure_kind,
+            arrangement: config.arrangement,
             successful_notes_found,
             failed_notes_count,
             total_iterations,
+            num_repetitions,
             execution_time_ms,
         }
     }
 }
 
+/// Execution time statistics of the benchmark runs with the same number of failing notes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionTimeStats {
+    pub num_runs: usize,
+    pub min_execution_time_ms: f64,
+    pub max_execution_time_ms: f64,
+    pub mean_execution_time_ms: f64,
+}
+
+/// Note checker measurements aggregated across benchmark runs.
+///
+/// The execution times are aggregated by the number of failing notes, since that determines the
+/// number of iterations required by the note checker and so gives a stable signal for tracking
+/// trends across CI runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedMeasurements {
+    pub by_failing_note_count: BTreeMap<usize, ExecutionTimeStats>,
+}
+
+/// Aggregates the execution times of the provided runs by their number of failing notes.
+pub fn aggregate(runs: &[NoteCheckerMeasurements]) -> AggregatedMeasurements {
+    let mut execution_times = BTreeMap::<usize, Vec<f64>>::new();
+    for run in runs {
+        execution_times
+            .entry(run.failing_note_count)
+            .or_default()
+            .push(run.execution_time_ms);
+    }
+
+    let by_failing_note_count = execution_times
+        .into_iter()
+        .map(|(failing_note_count, times)| {
+            let stats = ExecutionTimeStats {
+                num_runs: times.len(),
+                min_execution_time_ms: times.iter().copied().fold(f64::INFINITY, f64::min),
+                max_execution_time_ms: times.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                mean_execution_time_ms: times.iter().sum::<f64>() / times.len() as f64,
+            };
+            (failing_note_count, stats)
+        })
+        .collect();
+
+    AggregatedMeasurements { by_failing_note_count }
+}
+
+/// The benchmark results written by [`write_bench_results_to_json`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteCheckerBenchResults {
+    pub runs: Vec<NoteCheckerMeasurements>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregate: Option<AggregatedMeasurements>,
+}
+
+impl NoteCheckerBenchResults {
+    /// Creates the benchmark results from the provided runs, optionally including their
+    /// aggregate.
+    pub fn new(runs: &[NoteCheckerMeasurements], include_aggregate: bool) -> Self {
+        Self {
+            runs: runs.to_vec(),
+            aggregate: include_aggregate.then(|| aggregate(runs)),
+        }
+    }
+}
+
+/// Writes the provided benchmark runs to the JSON file at the provided path.
+///
+/// If `include_aggregate` is set, the aggregate of the runs (see [`aggregate`]) is written in an
+/// additional `aggregate` block.
+pub fn write_bench_results_to_json(
+    path: &Path,
+    runs: &[NoteCheckerMeasurements],
+    include_aggregate: bool,
+) -> anyhow::Result<()> {
+    let results = NoteCheckerBenchResults::new(runs, include_aggregate);
+    let results_json = serde_json::to_string_pretty(&results)
+        .context("failed to convert benchmark results to json")?;
+
+    write(path, results_json).context("failed to write benchmark results to file")?;
+
+    Ok(())
+}
+
+/// Describes how the failing notes of a benchmark fail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureKind {
+    /// The note script fails on its first operation (division by zero).
+    #[default]
+    EarlyAbort,
+    /// The note script executes the given number of operations before failing an assertion.
+    DeepAssert { num_ops: usize },
+    /// The note is a P2ID note whose asset cannot be added to the account vault because the
+    /// resulting balance would exceed the maximum amount of a fungible asset.
+    AssetLimitViolation,
+}
+
+/// Describes how the failing notes of a benchmark are arranged relative to the successful notes.
+///
+/// The cost of the elimination strategy of the note checker depends on where the failures are
+/// located, which allows measuring its best and worst cases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteArrangement {
+    /// All failing notes are placed before the successful notes.
+    FailingFirst,
+    /// All failing notes are placed after the successful notes.
+    FailingLast,
+    /// The successful notes are spread out evenly between the failing notes.
+    Interleaved,
+    /// All failing notes are placed between the two halves of the successful notes.
+    #[default]
+    FailingMiddle,
+}
+
+impl NoteArrangement {
+    /// All note arrangements.
+    pub const ALL: [Self; 4] =
+        [Self::FailingFirst, Self::FailingLast, Self::Interleaved, Self::FailingMiddle];
+
+    /// Arranges the successful notes and the failing notes.
+    fn arrange(self, successful_notes: Vec<Note>, failing_notes: Vec<Note>) -> Vec<Note> {
+        match self {
+            // [failing_notes..., successful_notes...]
+            Self::FailingFirst => failing_notes.into_iter().chain(successful_notes).collect(),
+            // [successful_notes..., failing_notes...]
+            Self::FailingLast => successful_notes.into_iter().chain(failing_notes).collect(),
+            // [failing_a..., successful_1, failing_b..., successful_2, ..., failing_z...]
+            Self::Interleaved => {
+                let chunk_size = failing_notes.len() / (successful_notes.len() + 1);
+                let mut all_notes =
+                    Vec::with_capacity(failing_notes.len() + successful_notes.len());
+                let mut failing_notes = failing_notes.into_iter();
+                for successful_note in successful_notes {
+                    all_notes.extend(failing_notes.by_ref().take(chunk_size));
+                    all_notes.push(successful_note);
+                }
+                all_notes.extend(failing_notes);
+                all_notes
+            },
+            // [successful_notes_a..., failing_notes..., successful_notes_b...]
+            Self::FailingMiddle => {
+                let mut successful_notes = successful_notes;
+                let successful_notes_b = successful_notes.split_off(successful_notes.len() / 2);
+                successful_notes
+                    .into_iter()
+                    .chain(failing_notes)
+                    .chain(successful_notes_b)
+                    .collect()
+            },
+        }
+    }
+}
+
 /// Benchmark configuration for mixed note scenarios.
 #[derive(Clone, Debug)]
 pub struct MixedNotesConfig {
-    /// Number of failing notes to insert between successful notes.
+    /// Number of successful notes in the benchmark.
+    pub successful_note_count: usize,
+    /// Number of failing notes in the benchmark.
     pub failing_note_count: usize,
+    /// How the failing notes fail.
+    pub failure_kind: FailureKind,
+    /// How the failing notes are arranged relative to the successful notes.
+    pub arrangement: NoteArrangement,
+}
+
+impl Default for MixedNotesConfig {
+    fn default() -> Self {
+        Self {
+            successful_note_count: 2,
+            failing_note_count: 0,
+            failure_kind: FailureKind::default(),
+            arrangement: NoteArrangement::default(),
+        }
+    }
 }
 
 /// Setup data for the mixed notes benchmark.
@@ -58,67 +252,103 @@ pub struct MixedNotesSetup {
     pub notes: Vec<Note>,
     pub target_account_id: AccountId,
     pub expected_successful_count: usize,
+    pub expected_failed_count: usize,
 }
 
-/// Creates a benchmark setup with one successful note, N failing notes, and one more successful
-/// note. This tests the iterative elimination strategy of `check_notes_consumability`.
+/// Creates a benchmark setup with M successful notes and N failing notes arranged according to
+/// the provided config (see [`NoteArrangement`]). This tests the iterative elimination strategy of
+/// `check_notes_consumability`.
+///
+/// # Errors
+///
+/// Returns an error if the total number of notes exceeds [`MAX_NUM_CHECKER_NOTES`], since the note
+/// checker would reject such a set of notes.
 pub fn setup_mixed_notes_benchmark(config: MixedNotesConfig) -> anyhow::Result<MixedNotesSetup> {
-    // Create a mock chain with an account.
+    let num_notes = config.successful_note_count + config.failing_note_count;
+    anyhow::ensure!(
+        num_notes <= MAX_NUM_CHECKER_NOTES,
+        "benchmark has {num_notes} notes but at most {MAX_NUM_CHECKER_NOTES} are allowed"
+    );
+
+    // Create a mock chain with an account. For asset limit violations, the account already holds
+    // the maximum amount of the asset carried by the failing notes.
     let mut builder = MockChain::builder();
-    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let limit_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1)?;
+    let account = match config.failure_kind {
+        FailureKind::AssetLimitViolation => builder.add_existing_wallet_with_assets(
+            Auth::IncrNonce,
+            [Asset::from(FungibleAsset::new(limit_faucet_id, FungibleAsset::MAX_AMOUNT)?)],
+        )?,
+        FailureKind::EarlyAbort | FailureKind::DeepAssert { .. } => {
+            builder.add_existing_wallet(Auth::IncrNonce)?
+        },
+    };
     let target_account_id = account.id();
 
-    // Create the first successful note (P2ID note that the account can consume).
-    let successful_note_1 = builder.add_p2id_note(
-        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?,
-        account.id(),
-        &[FungibleAsset::mock(100)],
-        NoteType::Public,
-    )?;
+    // Create the successful notes (P2ID notes that the account can consume).
+    let mut successful_notes = Vec::with_capacity(config.successful_note_count);
+    for i in 0..config.successful_note_count {
+        let successful_note = builder.add_p2id_note(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?,
+            account.id(),
+            &[FungibleAsset::mock(100 * (i as u64 + 1))],
+            NoteType::Public,
+        )?;
+        successful_notes.push(successful_note);
+    }
 
-    // Create many failing notes (division by zero error).
+    // Create many failing notes.
     let sender = AccountId::try_from(ACCOUNT_ID_SENDER)?;
     let mut failing_notes = Vec::with_capacity(config.failing_note_count);
 
     for i in 0..config.failing_note_count {
-        let mut seed = [0u8; 32];
-        seed[0] = i as u8;
         let mut rng = RpoRandomCoin::new([i as u32, 0, 0, 0].into());
-        let failing_note = NoteBuilder::new(sender, &mut rng)
-            .code("begin push.0 div end") // Division by zero - will fail.
-            .build()?;
+        let failing_note = match config.failure_kind {
+            FailureKind::EarlyAbort => NoteBuilder::new(sender, &mut rng)
+                .code("begin push.0 div end") // Division by zero - will fail.
+                .build()?,
+            FailureKind::DeepAssert { num_ops } => NoteBuilder::new(sender, &mut rng)
+                .code(format!("begin repeat.{num_ops} push.1 drop end push.0 assert end"))
+                .build()?,
+            FailureKind::AssetLimitViolation => P2idNote::create(
+                sender,
+                target_account_id,
+                vec![FungibleAsset::new(limit_faucet_id, 1)?.into()],
+                NoteType::Public,
+                NoteAttachment::default(),
+                &mut rng,
+            )?,
+        };
         failing_notes.push(failing_note);
     }
 
-    // Create the second successful note.
-    let successful_note_2 = builder.add_p2id_note(
-        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?,
-        account.id(),
-        &[FungibleAsset::mock(200)],
-        NoteType::Public,
-    )?;
-
     // Build the mock chain.
     let mock_chain = builder.build()?;
 
-    // Arrange notes: [successful_1, failing_notes..., successful_2].
-    let mut all_notes = vec![successful_note_1.clone()];
-    all_notes.extend(failing_notes);
-    all_notes.push(successful_note_2.clone());
-
-    // We expect exactly 2 successful notes.
-    let expected_successful_count = 2;
+    // Arrange the notes according to the configured arrangement.
+    let all_notes = config.arrangement.arrange(successful_notes, failing_notes);
 
     Ok(MixedNotesSetup {
         mock_chain,
         notes: all_notes,
         target_account_id,
-        expected_successful_count,
+        expected_successful_count: config.successful_note_count,
+        expected_failed_count: config.failing_note_count,
     })
 }
 
-/// Runs the note consumability check and validates the results.
-pub async fn run_mixed_notes_check(setup: &MixedNotesSetup) -> anyhow::Result<()> {
+/// Returns the number of iterations of the elimination loop the note checker needed to produce
+/// `result`.
+///
+/// Every iteration either eliminates one failing note or finds that all remaining candidate notes
+/// succeed, so the checker runs one iteration per failed note plus a final one if any note
+/// succeeded.
+pub fn elimination_iterations(result: &NoteConsumptionInfo) -> usize {
+    result.failed.len() + usize::from(!result.successful.is_empty())
+}
+
+/// Runs the note consumability check, validates the results and returns them.
+pub async fn run_mixed_notes_check(setup: &MixedNotesSetup) -> anyhow::Result<NoteConsumptionInfo> {
     // Create transaction context with the setup data.
     let tx_context = setup
         .mock_chain
@@ -145,8 +375,150 @@ pub async fn run_mixed_notes_check(setup: &MixedNotesSetup) -> anyhow::Result<()
         result.successful.len()
     );
 
-    // Validate that we have some failed notes (all the failing ones).
-    assert!(!result.failed.is_empty(), "Expected some failed notes");
+    // Validate that all failing notes failed.
+    assert_eq!(
+        setup.expected_failed_count,
+        result.failed.len(),
+        "Expected {} failed notes, got {}",
+        setup.expected_failed_count,
+        result.failed.len()
+    );
 
-    Ok(())
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurements(failing_note_count: usize, execution_time_ms: f64) -> NoteCheckerMeasurements {
+        let config = MixedNotesConfig { failing_note_count, ..Default::default() };
+        NoteCheckerMeasurements::new(
+            &config,
+            2,
+            failing_note_count,
+            failing_note_count + 1,
+            1,
+            execution_time_ms,
+        )
+    }
+
+    /// Checks that both successful notes are found for the provided arrangement and that the
+    /// notes are laid out as expected, where `true` marks a successful note.
+    async fn assert_arrangement(
+        arrangement: NoteArrangement,
+        expected_layout: [bool; 8],
+    ) -> anyhow::Result<()> {
+        let setup = setup_mixed_notes_benchmark(MixedNotesConfig {
+            failing_note_count: 6,
+            arrangement,
+            ..Default::default()
+        })?;
+
+        let successful_ids: Vec<_> = setup
+            .notes
+            .iter()
+            .zip(expected_layout)
+            .filter(|(_, is_successful)| *is_successful)
+            .map(|(note, _)| note.id())
+            .collect();
+        assert_eq!(setup.notes.len(), expected_layout.len());
+        assert_eq!(successful_ids.len(), setup.expected_successful_count);
+
+        let result = run_mixed_notes_check(&setup).await?;
+        let mut found_ids: Vec<_> = result.successful.iter().map(|note| note.id()).collect();
+        found_ids.sort();
+        let mut expected_ids = successful_ids;
+        expected_ids.sort();
+        assert_eq!(found_ids, expected_ids);
+        assert_eq!(result.failed.len(), 6);
+        assert_eq!(elimination_iterations(&result), 7);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn arrangement_failing_first() -> anyhow::Result<()> {
+        let layout = [false, false, false, false, false, false, true, true];
+        assert_arrangement(NoteArrangement::FailingFirst, layout).await
+    }
+
+    #[tokio::test]
+    async fn arrangement_failing_last() -> anyhow::Result<()> {
+        let layout = [true, true, false, false, false, false, false, false];
+        assert_arrangement(NoteArrangement::FailingLast, layout).await
+    }
+
+    #[tokio::test]
+    async fn arrangement_interleaved() -> anyhow::Result<()> {
+        let layout = [false, false, true, false, false, true, false, false];
+        assert_arrangement(NoteArrangement::Interleaved, layout).await
+    }
+
+    #[tokio::test]
+    async fn arrangement_failing_middle() -> anyhow::Result<()> {
+        let layout = [true, false, false, false, false, false, false, true];
+        assert_arrangement(NoteArrangement::FailingMiddle, layout).await
+    }
+
+    #[tokio::test]
+    async fn many_successful_notes() -> anyhow::Result<()> {
+        let setup = setup_mixed_notes_benchmark(MixedNotesConfig {
+            successful_note_count: MAX_NUM_CHECKER_NOTES - 10,
+            failing_note_count: 10,
+            arrangement: NoteArrangement::Interleaved,
+            ..Default::default()
+        })?;
+        assert_eq!(setup.notes.len(), MAX_NUM_CHECKER_NOTES);
+        assert_eq!(setup.expected_successful_count, MAX_NUM_CHECKER_NOTES - 10);
+        assert_eq!(setup.expected_failed_count, 10);
+
+        // validates the number of successful and failed notes
+        run_mixed_notes_check(&setup).await?;
+
+        // the note checker does not accept more notes, so neither does the setup
+        let error = setup_mixed_notes_benchmark(MixedNotesConfig {
+            successful_note_count: MAX_NUM_CHECKER_NOTES - 9,
+            failing_note_count: 10,
+            ..Default::default()
+        })
+        .map(|_| ())
+        .unwrap_err();
+        assert!(error.to_string().contains("are allowed"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn aggregate_by_failing_note_count() {
+        let runs = [
+            measurements(10, 2.0),
+            measurements(20, 5.0),
+            measurements(10, 1.0),
+            measurements(10, 6.0),
+        ];
+
+        let aggregated = aggregate(&runs);
+        assert_eq!(aggregated.by_failing_note_count.len(), 2);
+        assert_eq!(
+            aggregated.by_failing_note_count[&10],
+            ExecutionTimeStats {
+                num_runs: 3,
+                min_execution_time_ms: 1.0,
+                max_execution_time_ms: 6.0,
+                mean_execution_time_ms: 3.0,
+            }
+        );
+        assert_eq!(aggregated.by_failing_note_count[&20].mean_execution_time_ms, 5.0);
+
+        // the aggregate block is only emitted if requested
+        let results = serde_json::to_value(NoteCheckerBenchResults::new(&runs, true)).unwrap();
+        assert_eq!(
+            results["aggregate"]["by_failing_note_count"]["10"]["mean_execution_time_ms"],
+            3.0
+        );
+        let results = serde_json::to_value(NoteCheckerBenchResults::new(&runs, false)).unwrap();
+        assert!(results.get("aggregate").is_none());
+        assert_eq!(results["runs"].as_array().unwrap().len(), runs.len());
+    }
 }
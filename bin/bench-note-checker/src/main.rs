@@ -1,7 +1,78 @@
+use std::path::Path;
+use std::time::Instant;
+
 use anyhow::Result;
+use bench_note_checker::{
+    FailureKind,
+    MixedNotesConfig,
+    NoteArrangement,
+    NoteCheckerMeasurements,
+    elimination_iterations,
+    run_mixed_notes_check,
+    setup_mixed_notes_benchmark,
+    write_bench_results_to_json,
+};
+use miden_tx::NoteConsumptionInfo;
+
+/// The number of successful notes used in each configuration of the sweep.
+const SUCCESSFUL_NOTE_COUNT: usize = 2;
+
+/// The number of failing notes used in each configuration of the sweep.
+const FAILING_NOTE_COUNT: usize = 10;
+
+/// The number of times the note check is repeated for each configuration of the sweep to measure
+/// its mean execution time.
+const NUM_REPETITIONS: usize = 3;
+
+/// Runs the note checker for every combination of failure kind and note arrangement and prints
+/// the resulting measurements as JSON.
+///
+/// If an output path is passed as the first argument, the measurements are additionally written to
+/// that file together with their aggregate.
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<()> {
+    let failure_kinds = [
+        FailureKind::EarlyAbort,
+        FailureKind::DeepAssert { num_ops: 1000 },
+        FailureKind::AssetLimitViolation,
+    ];
+    let mut measurements = Vec::new();
+    for failure_kind in failure_kinds {
+        for arrangement in NoteArrangement::ALL {
+            let config = MixedNotesConfig {
+                successful_note_count: SUCCESSFUL_NOTE_COUNT,
+                failing_note_count: FAILING_NOTE_COUNT,
+                failure_kind,
+                arrangement,
+            };
+            let setup = setup_mixed_notes_benchmark(config.clone())?;
+
+            let start = Instant::now();
+            let mut result = NoteConsumptionInfo::default();
+            for _ in 0..NUM_REPETITIONS {
+                result = run_mixed_notes_check(&setup).await?;
+            }
+            let execution_time_ms = start.elapsed().as_secs_f64() * 1000.0 / NUM_REPETITIONS as f64;
+
+            measurements.push(NoteCheckerMeasurements::new(
+                &config,
+                result.successful.len(),
+                result.failed.len(),
+                elimination_iterations(&result),
+                NUM_REPETITIONS,
+                execution_time_ms,
+            ));
+        }
+    }
+
+    println!("{}", serde_json::to_string_pretty(&measurements)?);
+
+    if let Some(output_path) = std::env::args().nth(1) {
+        write_bench_results_to_json(Path::new(&output_path), &measurements, true)?;
+        println!("Benchmark results written to {output_path}");
+    }
 
-fn main() -> Result<()> {
-    println!("Use either of the following commands to run the benchmarks:");
+    println!("Use either of the following commands to run the criterion benchmarks:");
     println!("make bench-note-checker");
     println!("cargo bench --bin bench-note-checker --bench benches");
     Ok(())
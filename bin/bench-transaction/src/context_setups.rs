@@ -1,11 +1,22 @@
-use anyhow::Result;
-use miden_protocol::Word;
-use miden_protocol::asset::{Asset, FungibleAsset};
+use anyhow::{Result, bail};
+use miden_protocol::account::auth::AuthSecretKey;
+use miden_protocol::account::{
+    AccountBuilder,
+    AccountComponent,
+    AccountStorage,
+    AccountStorageMode,
+};
+use miden_protocol::asset::{Asset, FungibleAsset, NonFungibleAsset};
 use miden_protocol::note::NoteType;
 use miden_protocol::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_protocol::testing::storage::STORAGE_LEAVES_2;
 use miden_protocol::transaction::OutputNote;
+use miden_protocol::{Felt, Word};
 use miden_standards::code_builder::CodeBuilder;
-use miden_testing::{Auth, MockChain, TransactionContext};
+use miden_testing::{AccountState, Auth, MockChain, TransactionContext};
+use miden_tx::TransactionExecutorError;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 
 /// Returns the transaction context which could be used to run the transaction which creates a
 /// single P2ID note.
@@ -123,3 +134,197 @@ pub fn tx_consume_two_p2id_notes() -> Result<TransactionContext> {
         .disable_debug_mode()
         .build()
 }
+
+/// Returns the transaction context which could be used to run the transaction in which a native
+/// account reads one value slot and one map item of a foreign account using foreign procedure
+/// invocation.
+pub fn tx_fpi_storage_read() -> Result<TransactionContext> {
+    let mock_value_slot = AccountStorage::mock_value_slot0();
+    let mock_map_slot = AccountStorage::mock_map_slot();
+
+    let foreign_account_code_source = "
+        use miden::protocol::active_account
+
+        pub proc get_item_foreign
+            exec.active_account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 drop drop
+        end
+
+        pub proc get_map_item_foreign
+            exec.active_account::get_map_item
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::new(
+        CodeBuilder::default()
+            .compile_component_code("foreign_account", foreign_account_code_source)?,
+        vec![mock_value_slot.clone(), mock_map_slot.clone()],
+    )?
+    .with_supports_all_types();
+
+    let mut builder = MockChain::builder();
+    let native_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let foreign_account = builder.add_account_from_builder(
+        Auth::IncrNonce,
+        AccountBuilder::new([7; 32])
+            .with_component(foreign_account_component.clone())
+            .storage_mode(AccountStorageMode::Public),
+        AccountState::Exists,
+    )?;
+
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    let tx_fpi_script_src = format!(
+        r#"
+        use miden::core::sys
+        use miden::protocol::tx
+
+        const MOCK_VALUE_SLOT = word("{mock_value_slot}")
+        const MOCK_MAP_SLOT = word("{mock_map_slot}")
+
+        begin
+            # read the value slot of the foreign account
+            padw padw
+            push.MOCK_VALUE_SLOT[0..2]
+            procref.::foreign_account::get_item_foreign
+            push.{foreign_suffix} push.{foreign_prefix}
+            exec.tx::execute_foreign_procedure
+            dropw
+            # => []
+
+            # read the map item of the foreign account
+            padw
+            push.{map_key}
+            push.MOCK_MAP_SLOT[0..2]
+            procref.::foreign_account::get_map_item_foreign
+            push.{foreign_suffix} push.{foreign_prefix}
+            exec.tx::execute_foreign_procedure
+            dropw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        "#,
+        mock_value_slot = mock_value_slot.name(),
+        mock_map_slot = mock_map_slot.name(),
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        map_key = STORAGE_LEAVES_2[0].0,
+    );
+
+    let tx_script = CodeBuilder::default()
+        .with_dynamically_linked_library(foreign_account_component.component_code())?
+        .compile_tx_script(tx_fpi_script_src)?;
+
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+
+    // construct the transaction context
+    mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .foreign_accounts([foreign_account_inputs])
+        .tx_script(tx_script)
+        .disable_debug_mode()
+        .build()
+}
+
+/// Returns the transaction context which could be used to run the transaction which consumes a
+/// SWAP note, receiving the offered asset and creating a private payback note with the requested
+/// asset.
+pub fn tx_consume_swap_note() -> Result<TransactionContext> {
+    let offered_asset: Asset = FungibleAsset::mock(100);
+    let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+
+    let mut builder = MockChain::builder();
+    let sender_account =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [offered_asset])?;
+    let target_account =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [requested_asset])?;
+
+    let (swap_note, _payback_note) = builder.add_swap_note(
+        sender_account.id(),
+        offered_asset,
+        requested_asset,
+        NoteType::Private,
+    )?;
+
+    let mock_chain = builder.build()?;
+
+    // construct the transaction context
+    mock_chain
+        .build_tx_context(target_account.id(), &[swap_note.id()], &[])?
+        .disable_debug_mode()
+        .build()
+}
+
+/// Returns the transaction context which could be used to run the transaction in which a 2-of-3
+/// multisig wallet transfers an asset into a P2ID note.
+///
+/// The transaction is executed once without signatures to obtain the transaction summary, which
+/// is then signed by two of the three approvers. The returned context contains both signatures.
+pub async fn tx_multisig_2_of_3_transfer() -> Result<TransactionContext> {
+    let secret_keys: Vec<AuthSecretKey> = (0..3u8)
+        .map(|seed| {
+            let mut rng = ChaCha20Rng::from_seed([seed; 32]);
+            AuthSecretKey::new_falcon512_rpo_with_rng(&mut rng)
+        })
+        .collect();
+    let approvers: Vec<Word> =
+        secret_keys.iter().map(|key| key.public_key().to_commitment().into()).collect();
+
+    let mut builder = MockChain::builder();
+    let multisig_account = builder.add_existing_wallet_with_assets(
+        Auth::Multisig {
+            threshold: 2,
+            approvers,
+            proc_threshold_map: vec![],
+        },
+        [FungibleAsset::mock(100)],
+    )?;
+
+    let output_note = builder.add_p2id_note(
+        multisig_account.id(),
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+    let spawn_note = builder.add_spawn_note([&output_note])?;
+
+    let mock_chain = builder.build()?;
+
+    let salt = Word::from([Felt::new(1); 4]);
+
+    // execute the transaction without signatures to obtain the transaction summary
+    let tx_summary = match mock_chain
+        .build_tx_context(multisig_account.id(), &[spawn_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(output_note.clone())])
+        .auth_args(salt)
+        .build()?
+        .execute()
+        .await
+    {
+        Err(TransactionExecutorError::Unauthorized(tx_summary)) => tx_summary,
+        Err(err) => return Err(err.into()),
+        Ok(_) => bail!("transaction without signatures should be unauthorized"),
+    };
+
+    // construct the transaction context with the signatures of the first two approvers
+    let msg = tx_summary.to_commitment();
+    let mut tx_context_builder = mock_chain
+        .build_tx_context(multisig_account.id(), &[spawn_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(output_note)])
+        .auth_args(salt)
+        .disable_debug_mode();
+    for secret_key in secret_keys.iter().take(2) {
+        tx_context_builder = tx_context_builder.add_signature(
+            secret_key.public_key().to_commitment(),
+            msg,
+            secret_key.sign(msg),
+        );
+    }
+
+    tx_context_builder.build()
+}
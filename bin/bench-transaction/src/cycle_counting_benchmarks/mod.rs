@@ -7,6 +7,9 @@ pub enum ExecutionBenchmark {
     ConsumeSingleP2ID,
     ConsumeTwoP2ID,
     CreateSingleP2ID,
+    FpiStorageRead,
+    ConsumeSwap,
+    Multisig2Of3Transfer,
 }
 
 impl fmt::Display for ExecutionBenchmark {
@@ -15,6 +18,11 @@ impl fmt::Display for ExecutionBenchmark {
             ExecutionBenchmark::ConsumeSingleP2ID => write!(f, "consume single P2ID note"),
             ExecutionBenchmark::ConsumeTwoP2ID => write!(f, "consume two P2ID notes"),
             ExecutionBenchmark::CreateSingleP2ID => write!(f, "create single P2ID note"),
+            ExecutionBenchmark::FpiStorageRead => write!(f, "read foreign account storage"),
+            ExecutionBenchmark::ConsumeSwap => write!(f, "consume SWAP note"),
+            ExecutionBenchmark::Multisig2Of3Transfer => {
+                write!(f, "transfer asset from 2-of-3 multisig account")
+            },
         }
     }
 }
@@ -8,8 +8,11 @@ use miden_protocol::transaction::TransactionMeasurements;
 mod context_setups;
 use context_setups::{
     tx_consume_single_p2id_note,
+    tx_consume_swap_note,
     tx_consume_two_p2id_notes,
     tx_create_single_p2id_note,
+    tx_fpi_storage_read,
+    tx_multisig_2_of_3_transfer,
 };
 
 mod cycle_counting_benchmarks;
@@ -49,6 +52,31 @@ async fn main() -> Result<()> {
                 .map(TransactionMeasurements::from)?
                 .into(),
         ),
+        (
+            ExecutionBenchmark::FpiStorageRead,
+            tx_fpi_storage_read()?
+                .execute()
+                .await
+                .map(TransactionMeasurements::from)?
+                .into(),
+        ),
+        (
+            ExecutionBenchmark::ConsumeSwap,
+            tx_consume_swap_note()?
+                .execute()
+                .await
+                .map(TransactionMeasurements::from)?
+                .into(),
+        ),
+        (
+            ExecutionBenchmark::Multisig2Of3Transfer,
+            tx_multisig_2_of_3_transfer()
+                .await?
+                .execute()
+                .await
+                .map(TransactionMeasurements::from)?
+                .into(),
+        ),
     ];
 
     // store benchmark results in the JSON file
@@ -0,0 +1,27 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use miden_protocol::testing::vectors::TestVectors;
+
+/// The path of the canonical test vectors, relative to the workspace root.
+const DEFAULT_OUTPUT_PATH: &str = "crates/miden-protocol/src/testing/vectors/test_vectors.json";
+
+/// Generates the golden test vectors and writes them as JSON to the path provided as the first
+/// argument, or to the canonical location if no path is provided.
+fn main() -> Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT_PATH));
+
+    let vectors = TestVectors::generate();
+    let mut json =
+        serde_json::to_string_pretty(&vectors).context("failed to serialize test vectors")?;
+    json.push('\n');
+
+    fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))?;
+    println!("wrote test vectors to {}", path.display());
+
+    Ok(())
+}
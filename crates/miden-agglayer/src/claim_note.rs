@@ -0,0 +1,254 @@
+use alloc::format;
+
+use miden_core::{Felt, Word};
+use miden_protocol::account::AccountId;
+use miden_protocol::errors::NoteError;
+use miden_protocol::note::{Note, NoteTag};
+
+use crate::utils::{felts_to_bytes32, felts_to_ethereum_address};
+use crate::{EthAddressFormat, claim_script};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of felts of each of the SMT proofs of a CLAIM note.
+const SMT_PROOF_LEN: usize = 256;
+
+const SMT_PROOF_LOCAL_EXIT_ROOT_IDX: usize = 0;
+const SMT_PROOF_ROLLUP_EXIT_ROOT_IDX: usize = SMT_PROOF_LOCAL_EXIT_ROOT_IDX + SMT_PROOF_LEN;
+const GLOBAL_INDEX_IDX: usize = SMT_PROOF_ROLLUP_EXIT_ROOT_IDX + SMT_PROOF_LEN;
+const MAINNET_EXIT_ROOT_IDX: usize = GLOBAL_INDEX_IDX + 8;
+const ROLLUP_EXIT_ROOT_IDX: usize = MAINNET_EXIT_ROOT_IDX + 8;
+const ORIGIN_NETWORK_IDX: usize = ROLLUP_EXIT_ROOT_IDX + 8;
+const ORIGIN_TOKEN_ADDRESS_IDX: usize = ORIGIN_NETWORK_IDX + 1;
+const DESTINATION_NETWORK_IDX: usize = ORIGIN_TOKEN_ADDRESS_IDX + 5;
+const DESTINATION_ADDRESS_IDX: usize = DESTINATION_NETWORK_IDX + 1;
+const AMOUNT_IDX: usize = DESTINATION_ADDRESS_IDX + 5;
+const METADATA_IDX: usize = AMOUNT_IDX + 8;
+const PADDING_IDX: usize = METADATA_IDX + 8;
+const P2ID_SERIAL_NUMBER_IDX: usize = PADDING_IDX + 4;
+const FAUCET_ACCOUNT_ID_IDX: usize = P2ID_SERIAL_NUMBER_IDX + 4;
+const OUTPUT_NOTE_TAG_IDX: usize = FAUCET_ACCOUNT_ID_IDX + 2;
+
+/// The number of storage items of a CLAIM note.
+const NUM_STORAGE_ITEMS: usize = OUTPUT_NOTE_TAG_IDX + 1;
+
+// CLAIM NOTE
+// ================================================================================================
+
+/// A CLAIM note together with the claim data parsed from its storage.
+///
+/// A [`ClaimNote`] can be obtained from any [`Note`] using the CLAIM note script, e.g. one created
+/// via [`create_claim_note`](crate::create_claim_note), which allows reading the claim data off
+/// consumed notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimNote {
+    note: Note,
+    global_index: [Felt; 8],
+    mainnet_exit_root: [u8; 32],
+    rollup_exit_root: [u8; 32],
+    origin_network: u32,
+    origin_token_address: EthAddressFormat,
+    destination_network: u32,
+    destination_account_id: AccountId,
+    amount: [Felt; 8],
+    metadata: [Felt; 8],
+    p2id_serial_number: Word,
+    agglayer_faucet_account_id: AccountId,
+    output_note_tag: NoteTag,
+}
+
+impl ClaimNote {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the SMT proof for the local exit root (256 felts).
+    pub fn smt_proof_local_exit_root(&self) -> &[Felt] {
+        &self.storage_items()[SMT_PROOF_LOCAL_EXIT_ROOT_IDX..SMT_PROOF_ROLLUP_EXIT_ROOT_IDX]
+    }
+
+    /// Returns the SMT proof for the rollup exit root (256 felts).
+    pub fn smt_proof_rollup_exit_root(&self) -> &[Felt] {
+        &self.storage_items()[SMT_PROOF_ROLLUP_EXIT_ROOT_IDX..GLOBAL_INDEX_IDX]
+    }
+
+    /// Returns the global index (uint256 as 8 u32 felts).
+    pub fn global_index(&self) -> [Felt; 8] {
+        self.global_index
+    }
+
+    /// Returns the mainnet exit root.
+    pub fn mainnet_exit_root(&self) -> &[u8; 32] {
+        &self.mainnet_exit_root
+    }
+
+    /// Returns the rollup exit root.
+    pub fn rollup_exit_root(&self) -> &[u8; 32] {
+        &self.rollup_exit_root
+    }
+
+    /// Returns the origin network identifier.
+    pub fn origin_network(&self) -> u32 {
+        self.origin_network
+    }
+
+    /// Returns the address of the token on the origin network.
+    ///
+    /// The note only stores the address as encoded by [`EthAddressFormat::to_elements`], which
+    /// does not include its most-significant 4 bytes, so these are always zero.
+    pub fn origin_token_address(&self) -> EthAddressFormat {
+        self.origin_token_address
+    }
+
+    /// Returns the destination network identifier.
+    pub fn destination_network(&self) -> u32 {
+        self.destination_network
+    }
+
+    /// Returns the ID of the account receiving the claimed assets.
+    pub fn destination_account_id(&self) -> AccountId {
+        self.destination_account_id
+    }
+
+    /// Returns the claimed amount (uint256 as 8 u32 felts).
+    pub fn amount(&self) -> [Felt; 8] {
+        self.amount
+    }
+
+    /// Returns the ABI encoded metadata (8 felts).
+    pub fn metadata(&self) -> [Felt; 8] {
+        self.metadata
+    }
+
+    /// Returns the serial number of the P2ID note created when the claim is processed.
+    pub fn p2id_serial_number(&self) -> Word {
+        self.p2id_serial_number
+    }
+
+    /// Returns the ID of the agglayer faucet which processes the claim.
+    pub fn agglayer_faucet_account_id(&self) -> AccountId {
+        self.agglayer_faucet_account_id
+    }
+
+    /// Returns the tag of the P2ID note created when the claim is processed.
+    pub fn output_note_tag(&self) -> NoteTag {
+        self.output_note_tag
+    }
+
+    /// Consumes self and returns the underlying note.
+    pub fn into_note(self) -> Note {
+        self.note
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the storage items of the underlying note.
+    fn storage_items(&self) -> &[Felt] {
+        self.note.recipient().storage().items()
+    }
+}
+
+impl TryFrom<Note> for ClaimNote {
+    type Error = NoteError;
+
+    /// Parses the claim data from the storage of the provided note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note does not use the CLAIM note script.
+    /// - The note storage does not have the layout of a CLAIM note, i.e. it does not contain the
+    ///   expected number of items or any of the items is not a valid value for its field.
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        if note.script().root() != claim_script().root() {
+            return Err(NoteError::other("note does not use the CLAIM note script"));
+        }
+
+        let items = note.recipient().storage().items();
+        if items.len() != NUM_STORAGE_ITEMS {
+            return Err(NoteError::other(format!(
+                "CLAIM note must have exactly {NUM_STORAGE_ITEMS} storage items, got {}",
+                items.len()
+            )));
+        }
+
+        let mainnet_exit_root = felts_to_bytes32(&read_array(items, MAINNET_EXIT_ROOT_IDX))
+            .map_err(|err| NoteError::other(format!("invalid mainnet exit root: {err}")))?;
+        let rollup_exit_root = felts_to_bytes32(&read_array(items, ROLLUP_EXIT_ROOT_IDX))
+            .map_err(|err| NoteError::other(format!("invalid rollup exit root: {err}")))?;
+        let origin_token_address =
+            felts_to_ethereum_address(&read_array(items, ORIGIN_TOKEN_ADDRESS_IDX))
+                .map(EthAddressFormat::new)
+                .map_err(|err| NoteError::other(format!("invalid origin token address: {err}")))?;
+
+        let origin_network = read_u32(items, ORIGIN_NETWORK_IDX, "origin network")?;
+        let destination_network = read_u32(items, DESTINATION_NETWORK_IDX, "destination network")?;
+
+        // the destination address is stored as [prefix, suffix, 0, 0, 0]
+        let destination_address: [Felt; 5] = read_array(items, DESTINATION_ADDRESS_IDX);
+        if destination_address[2..].iter().any(|felt| *felt != Felt::new(0)) {
+            return Err(NoteError::other("destination address has non-zero padding"));
+        }
+        let destination_account_id =
+            read_account_id(items, DESTINATION_ADDRESS_IDX, "destination account")?;
+
+        let agglayer_faucet_account_id =
+            read_account_id(items, FAUCET_ACCOUNT_ID_IDX, "agglayer faucet account")?;
+        let output_note_tag =
+            NoteTag::new(read_u32(items, OUTPUT_NOTE_TAG_IDX, "output note tag")?);
+
+        let global_index = read_array(items, GLOBAL_INDEX_IDX);
+        let amount = read_array(items, AMOUNT_IDX);
+        let metadata = read_array(items, METADATA_IDX);
+        let p2id_serial_number = Word::from(read_array::<4>(items, P2ID_SERIAL_NUMBER_IDX));
+
+        Ok(Self {
+            note,
+            global_index,
+            mainnet_exit_root,
+            rollup_exit_root,
+            origin_network,
+            origin_token_address,
+            destination_network,
+            destination_account_id,
+            amount,
+            metadata,
+            p2id_serial_number,
+            agglayer_faucet_account_id,
+            output_note_tag,
+        })
+    }
+}
+
+impl From<ClaimNote> for Note {
+    fn from(claim_note: ClaimNote) -> Self {
+        claim_note.note
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads `N` storage items starting at the provided index.
+///
+/// The caller must ensure that the storage contains enough items.
+fn read_array<const N: usize>(items: &[Felt], idx: usize) -> [Felt; N] {
+    items[idx..idx + N].try_into().expect("storage should contain enough items")
+}
+
+/// Reads the storage item at the provided index as a `u32`.
+fn read_u32(items: &[Felt], idx: usize, field: &str) -> Result<u32, NoteError> {
+    u32::try_from(items[idx])
+        .map_err(|_| NoteError::other(format!("{field} should be a u32 value")))
+}
+
+/// Reads the account ID stored as `[prefix, suffix]` at the provided index.
+fn read_account_id(items: &[Felt], idx: usize, field: &str) -> Result<AccountId, NoteError> {
+    AccountId::try_from([items[idx], items[idx + 1]])
+        .map_err(|err| NoteError::other(format!("invalid {field} ID: {err}")))
+}
@@ -2,9 +2,10 @@
 
 extern crate alloc;
 
+use alloc::collections::BTreeSet;
 use alloc::string::ToString;
-use alloc::vec;
 use alloc::vec::Vec;
+use alloc::{format, vec};
 
 use miden_assembly::Library;
 use miden_assembly::utils::Deserializable;
@@ -17,11 +18,10 @@ use miden_protocol::account::{
     AccountStorageMode,
     AccountType,
     StorageSlot,
-    StorageSlotName,
 };
 use miden_protocol::asset::TokenSymbol;
 use miden_protocol::crypto::rand::FeltRng;
-use miden_protocol::errors::NoteError;
+use miden_protocol::errors::{AccountError, NoteError};
 use miden_protocol::note::{
     Note,
     NoteAssets,
@@ -33,16 +33,21 @@ use miden_protocol::note::{
     NoteTag,
     NoteType,
 };
+use miden_protocol::slot_name;
 use miden_standards::account::auth::NoAuth;
 use miden_standards::account::faucets::NetworkFungibleFaucet;
 use miden_standards::note::NetworkAccountTarget;
 use miden_utils_sync::LazyLock;
 
+pub mod claim_note;
 pub mod errors;
 pub mod eth_address;
+pub mod local_exit_tree;
 pub mod utils;
 
+pub use claim_note::ClaimNote;
 pub use eth_address::EthAddressFormat;
+pub use local_exit_tree::LocalExitTree;
 use utils::bytes32_to_felts;
 
 // AGGLAYER NOTE SCRIPTS
@@ -181,6 +186,35 @@ pub fn bridge_out_with_local_exit_tree_component(
     ]
 }
 
+/// Creates a single component which provides the procedures of both the bridge_out and the
+/// local_exit_tree modules.
+///
+/// Unlike [`bridge_out_with_local_exit_tree_component`], the returned component is a single
+/// component with the provided storage slots, so it can be added to an account builder at once
+/// without having to keep the storage layouts of the individual components in sync. The
+/// local_exit_tree module does not use any storage slots of its own.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided storage slots contain duplicate slot names.
+/// - The merged component has more than 255 storage slots.
+pub fn bridge_out_with_local_exit_tree_merged(
+    storage_slots: Vec<StorageSlot>,
+) -> Result<AccountComponent, AccountError> {
+    let mut slot_names = BTreeSet::new();
+    for slot in &storage_slots {
+        if !slot_names.insert(slot.name().clone()) {
+            return Err(AccountError::DuplicateStorageSlotName(slot.name().clone()));
+        }
+    }
+
+    // both modules are compiled into the unified agglayer library, so its procedures are the
+    // procedures of both components
+    let library = agglayer_library();
+
+    Ok(AccountComponent::new(library, storage_slots)?.with_supports_all_types())
+}
+
 /// Creates an Asset Conversion component with the specified storage slots.
 ///
 /// This component uses the agglayer library (which includes asset_conversion) and can be added to
@@ -204,8 +238,7 @@ pub fn asset_conversion_component(storage_slots: Vec<StorageSlot>) -> AccountCom
 /// # Returns
 /// Returns an [`AccountComponent`] configured for bridge operations with MMR validation.
 pub fn create_bridge_account_component() -> AccountComponent {
-    let bridge_storage_slot_name = StorageSlotName::new("miden::agglayer::bridge")
-        .expect("Bridge storage slot name should be valid");
+    let bridge_storage_slot_name = slot_name!("miden::agglayer::bridge");
     let bridge_storage_slots = vec![StorageSlot::with_empty_map(bridge_storage_slot_name)];
     bridge_out_component(bridge_storage_slots)
 }
@@ -235,27 +268,64 @@ pub fn create_agglayer_faucet_component(
 ) -> AccountComponent {
     // Create network faucet metadata slot: [max_supply, decimals, token_symbol, 0]
     let token_symbol = TokenSymbol::new(token_symbol).expect("Token symbol should be valid");
-    let metadata_word =
-        Word::new([max_supply, Felt::from(decimals), token_symbol.into(), FieldElement::ZERO]);
-    let metadata_slot =
-        StorageSlot::with_value(NetworkFungibleFaucet::metadata_slot().clone(), metadata_word);
+    let metadata_slot = StorageSlot::with_token_metadata(
+        NetworkFungibleFaucet::metadata_slot().clone(),
+        token_symbol,
+        decimals,
+        max_supply,
+    );
 
-    // Create agglayer-specific bridge storage slot
-    let bridge_account_id_word = Word::new([
-        Felt::new(0),
-        Felt::new(0),
-        bridge_account_id.suffix(),
-        bridge_account_id.prefix().as_felt(),
-    ]);
-    let agglayer_storage_slot_name = StorageSlotName::new("miden::agglayer::faucet")
-        .expect("Agglayer faucet storage slot name should be valid");
-    let bridge_slot = StorageSlot::with_value(agglayer_storage_slot_name, bridge_account_id_word);
+    // Create agglayer-specific bridge storage slot: [0, 0, suffix, prefix]
+    let agglayer_storage_slot_name = slot_name!("miden::agglayer::faucet");
+    let bridge_slot = StorageSlot::with_account_id(agglayer_storage_slot_name, bridge_account_id);
 
     // Combine all storage slots for the agglayer faucet component
     let agglayer_storage_slots = vec![metadata_slot, bridge_slot];
     agglayer_faucet_component(agglayer_storage_slots)
 }
 
+/// Creates an agglayer faucet account component like [`create_agglayer_faucet_component`], but
+/// additionally validates that the provided bridge account can be referenced by the faucet.
+///
+/// The faucet invokes the bridge account via FPI when processing CLAIM notes, so a misconfigured
+/// bridge reference would only surface once the first claim fails. This function catches such
+/// misconfigurations before the faucet is deployed.
+///
+/// # Errors
+/// Returns an error if:
+/// - The bridge account does not have public state, and so cannot be loaded as a foreign account.
+/// - The bridge account does not contain the bridge storage slot, i.e. it is not a bridge account.
+///
+/// # Panics
+/// Panics if the token symbol is invalid or storage slot names are malformed.
+pub fn agglayer_faucet_component_checked(
+    token_symbol: &str,
+    decimals: u8,
+    max_supply: Felt,
+    bridge_account: &Account,
+) -> Result<AccountComponent, AccountError> {
+    let bridge_account_id = bridge_account.id();
+    if !bridge_account_id.has_public_state() {
+        return Err(AccountError::other(format!(
+            "bridge account {bridge_account_id} has {} storage mode which does not support FPI",
+            bridge_account_id.storage_mode()
+        )));
+    }
+
+    if bridge_account.storage().get(&slot_name!("miden::agglayer::bridge")).is_none() {
+        return Err(AccountError::other(format!(
+            "account {bridge_account_id} does not contain the bridge storage slot"
+        )));
+    }
+
+    Ok(create_agglayer_faucet_component(
+        token_symbol,
+        decimals,
+        max_supply,
+        bridge_account_id,
+    ))
+}
+
 /// Creates a complete bridge account builder with the standard configuration.
 pub fn create_bridge_account_builder(seed: Word) -> AccountBuilder {
     let bridge_component = create_bridge_account_component();
@@ -377,6 +447,9 @@ pub struct ClaimNoteParams<'a, R: FeltRng> {
     pub p2id_serial_number: Word,
     /// TODO: remove and use destination_address: [u8; 20]
     pub destination_account_id: AccountId,
+    /// Whether the origin and destination networks are allowed to be identical, which is almost
+    /// always a bug for a bridge note.
+    pub allow_same_network: bool,
     /// RNG for creating CLAIM note serial number
     pub rng: &'a mut R,
 }
@@ -387,7 +460,12 @@ pub struct ClaimNoteParams<'a, R: FeltRng> {
 /// - `params`: The parameters for creating the CLAIM note (including RNG)
 ///
 /// # Errors
-/// Returns an error if note creation fails.
+/// Returns an error if:
+/// - the origin or destination network is not a valid u32 value.
+/// - the destination network is zero, i.e. Ethereum mainnet, which cannot be the destination of
+///   assets claimed on Miden.
+/// - the origin and destination networks are identical and `allow_same_network` is not set.
+/// - note creation fails.
 pub fn create_claim_note<R: FeltRng>(params: ClaimNoteParams<'_, R>) -> Result<Note, NoteError> {
     // Validate SMT proof lengths - each should be 256 felts (32 bytes32 values * 8 u32 per bytes32)
     if params.smt_proof_local_exit_root.len() != 256 {
@@ -402,6 +480,13 @@ pub fn create_claim_note<R: FeltRng>(params: ClaimNoteParams<'_, R>) -> Result<N
             params.smt_proof_rollup_exit_root.len()
         )));
     }
+
+    validate_claim_networks(
+        params.origin_network,
+        params.destination_network,
+        params.allow_same_network,
+    )?;
+
     // Create claim inputs matching exactly the agglayer claimAsset function parameters
     let mut claim_storage_items = vec![];
 
@@ -490,6 +575,34 @@ pub fn create_claim_note<R: FeltRng>(params: ClaimNoteParams<'_, R>) -> Result<N
     Ok(Note::new(assets, metadata, recipient))
 }
 
+/// Validates the origin and destination networks of a CLAIM note.
+fn validate_claim_networks(
+    origin_network: Felt,
+    destination_network: Felt,
+    allow_same_network: bool,
+) -> Result<(), NoteError> {
+    let origin_network = u32::try_from(origin_network).map_err(|_| {
+        NoteError::other(alloc::format!("origin network {origin_network} is not a valid u32"))
+    })?;
+    let destination_network = u32::try_from(destination_network).map_err(|_| {
+        NoteError::other(alloc::format!(
+            "destination network {destination_network} is not a valid u32"
+        ))
+    })?;
+
+    if destination_network == 0 {
+        return Err(NoteError::other("destination network of a CLAIM note must not be zero"));
+    }
+
+    if origin_network == destination_network && !allow_same_network {
+        return Err(NoteError::other(alloc::format!(
+            "origin and destination network of a CLAIM note are both {origin_network}"
+        )));
+    }
+
+    Ok(())
+}
+
 // TESTING HELPERS
 // ================================================================================================
 
@@ -0,0 +1,103 @@
+use miden_protocol::crypto::hash::keccak::{Keccak256, Keccak256Digest};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The depth of the local exit tree.
+pub const LOCAL_EXIT_TREE_DEPTH: usize = 32;
+
+/// The maximum number of leaves of the local exit tree.
+///
+/// As in the AggLayer deposit contract, one leaf of the full tree is left unused so that the
+/// number of leaves always fits into a `u32`.
+pub const MAX_LOCAL_EXIT_TREE_LEAVES: u32 = u32::MAX;
+
+// LOCAL EXIT TREE
+// ================================================================================================
+
+/// An off-chain mirror of the local exit tree maintained by the
+/// [`local_exit_tree_component`](crate::local_exit_tree_component).
+///
+/// The local exit tree is an append-only Keccak256 Merkle tree of depth
+/// [`LOCAL_EXIT_TREE_DEPTH`], where the leaves are the messages bridged out of Miden. Like the
+/// AggLayer deposit contract, only the frontier of the tree is stored, i.e. the last left sibling
+/// on every level, which suffices to append leaves and to compute the root.
+///
+/// This allows predicting the root of the local exit tree before submitting a bridge-out
+/// transaction.
+///
+/// Note: the frontier update in the MASM component is currently stubbed, so the on-chain root only
+/// matches this tree once the component implements the same Keccak256 frontier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalExitTree {
+    frontier: [[u8; 32]; LOCAL_EXIT_TREE_DEPTH],
+    num_leaves: u32,
+}
+
+impl LocalExitTree {
+    /// Returns a new empty local exit tree.
+    pub fn new() -> Self {
+        Self {
+            frontier: [[0; 32]; LOCAL_EXIT_TREE_DEPTH],
+            num_leaves: 0,
+        }
+    }
+
+    /// Returns the number of leaves inserted into the tree.
+    pub fn num_leaves(&self) -> u32 {
+        self.num_leaves
+    }
+
+    /// Appends the provided leaf to the tree and returns its index.
+    ///
+    /// # Panics
+    /// Panics if the tree already contains [`MAX_LOCAL_EXIT_TREE_LEAVES`] leaves.
+    pub fn insert_leaf(&mut self, leaf: [u8; 32]) -> u32 {
+        let index = self.num_leaves;
+        assert!(index < MAX_LOCAL_EXIT_TREE_LEAVES, "local exit tree is full");
+        self.num_leaves += 1;
+
+        // the new leaf is stored on the lowest level at which the new number of leaves has a set
+        // bit, after being merged with the left siblings of all levels below
+        let mut node = leaf;
+        for height in 0..LOCAL_EXIT_TREE_DEPTH {
+            if (self.num_leaves >> height) & 1 == 1 {
+                self.frontier[height] = node;
+                break;
+            }
+            node = merge(self.frontier[height], node);
+        }
+
+        index
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> [u8; 32] {
+        let mut node = [0; 32];
+        let mut zero_hash = [0; 32];
+        for height in 0..LOCAL_EXIT_TREE_DEPTH {
+            node = if (self.num_leaves >> height) & 1 == 1 {
+                merge(self.frontier[height], node)
+            } else {
+                merge(node, zero_hash)
+            };
+            zero_hash = merge(zero_hash, zero_hash);
+        }
+
+        node
+    }
+}
+
+impl Default for LocalExitTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the Keccak256 hash of the concatenation of the provided nodes.
+fn merge(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    Keccak256::merge(&[Keccak256Digest::from(left), Keccak256Digest::from(right)]).into()
+}
@@ -1,3 +1,5 @@
+use core::fmt;
+
 use miden_core::FieldElement;
 use miden_protocol::Felt;
 
@@ -16,6 +18,42 @@ pub fn bytes32_to_felts(bytes32: &[u8; 32]) -> [Felt; 8] {
     result
 }
 
+/// Converts an array of 8 Felt values back into a bytes32 value (32 bytes).
+///
+/// This is the inverse of [`bytes32_to_felts`]: each felt is interpreted as a big-endian `u32`
+/// chunk of the bytes32 value.
+///
+/// # Errors
+///
+/// Returns an error if any of the felts is not a valid `u32` value.
+pub fn felts_to_bytes32(felts: &[Felt; 8]) -> Result<[u8; 32], FeltConversionError> {
+    let mut result = [0u8; 32];
+    for (i, (chunk, felt)) in result.chunks_mut(4).zip(felts).enumerate() {
+        chunk.copy_from_slice(&felt_to_u32(i, *felt)?.to_be_bytes());
+    }
+    Ok(result)
+}
+
+/// Converts the MASM `address[5]` representation of an Ethereum address back into its 20 raw
+/// bytes.
+///
+/// This is the inverse of [`EthAddressFormat::to_elements`](crate::EthAddressFormat::to_elements)
+/// for addresses in the embedded [`AccountId`](miden_protocol::account::AccountId) format, whose
+/// most-significant 4 bytes are zero: the felts are u32 limbs in little-endian limb order, each
+/// interpreted as a big-endian `u32`.
+///
+/// # Errors
+///
+/// Returns an error if any of the felts is not a valid `u32` value.
+pub fn felts_to_ethereum_address(felts: &[Felt; 5]) -> Result<[u8; 20], FeltConversionError> {
+    let mut result = [0u8; 20];
+    // i=0 -> bytes[16..20], i=4 -> bytes[0..4]
+    for (i, (chunk, felt)) in result.chunks_mut(4).rev().zip(felts).enumerate() {
+        chunk.copy_from_slice(&felt_to_u32(i, *felt)?.to_be_bytes());
+    }
+    Ok(result)
+}
+
 /// Convert 8 Felt values (u32 limbs in little-endian order) to U256 bytes in little-endian format.
 pub fn felts_to_u256_bytes(limbs: [Felt; 8]) -> [u8; 32] {
     let mut bytes = [0u8; 32];
@@ -26,3 +64,31 @@ pub fn felts_to_u256_bytes(limbs: [Felt; 8]) -> [u8; 32] {
     }
     bytes
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the felt at the provided index into a `u32`, returning an error if it is out of range.
+fn felt_to_u32(index: usize, felt: Felt) -> Result<u32, FeltConversionError> {
+    u32::try_from(felt.as_int())
+        .map_err(|_| FeltConversionError::NotU32 { index, value: felt.as_int() })
+}
+
+// ================================================================================================
+// FELT CONVERSION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeltConversionError {
+    NotU32 { index: usize, value: u64 },
+}
+
+impl fmt::Display for FeltConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeltConversionError::NotU32 { index, value } => {
+                write!(f, "felt {value} at index {index} is not a valid u32 value")
+            },
+        }
+    }
+}
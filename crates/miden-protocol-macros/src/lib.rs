@@ -9,10 +9,16 @@
 //!
 //! A derive macro for tuple structs wrapping a `Word` type. Automatically generates
 //! accessor methods and `From` trait implementations.
+//!
+//! ### `slot_name!`
+//!
+//! A function-like macro constructing a `StorageSlotName` from a string literal which is validated
+//! at compile time.
 
+use miden_core::utils::hash_string_to_word;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Type, parse_macro_input};
+use syn::{Data, DeriveInput, Fields, LitStr, Type, parse_macro_input};
 
 /// Generates accessor methods for tuple structs wrapping a `Word` type.
 ///
@@ -169,3 +175,78 @@ pub fn word_wrapper_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Constructs a `StorageSlotName` from a string literal which is validated at compile time.
+///
+/// This is equivalent to calling `StorageSlotName::new` and unwrapping the result, except that an
+/// invalid name results in a compilation error rather than a panic at runtime. The ID of the slot
+/// is computed during macro expansion, so the macro can be used in `const` contexts.
+///
+/// The macro is re-exported as `miden_protocol::slot_name`.
+///
+/// # Examples
+///
+/// ```
+/// # use miden_protocol::account::StorageSlotName;
+/// # use miden_protocol::slot_name;
+/// const SLOT_NAME: StorageSlotName = slot_name!("miden::standards::fungible_faucets::metadata");
+/// assert_eq!(SLOT_NAME.as_str(), "miden::standards::fungible_faucets::metadata");
+/// ```
+///
+/// Slot names containing invalid characters are rejected:
+///
+/// ```compile_fail
+/// # use miden_protocol::slot_name;
+/// let slot_name = slot_name!("miden::standards::fungible-faucets");
+/// ```
+///
+/// Slot name components must not start with an underscore:
+///
+/// ```compile_fail
+/// # use miden_protocol::slot_name;
+/// let slot_name = slot_name!("miden::_standards::metadata");
+/// ```
+///
+/// Slot names must not be longer than 255 characters:
+///
+/// ```compile_fail
+/// # use miden_protocol::slot_name;
+/// let slot_name = slot_name!(
+///     "miden::aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+/// );
+/// ```
+///
+/// Only string literals are accepted:
+///
+/// ```compile_fail
+/// # use miden_protocol::slot_name;
+/// const NAME: &str = "miden::standards::metadata";
+/// let slot_name = slot_name!(NAME);
+/// ```
+#[proc_macro]
+pub fn slot_name(input: TokenStream) -> TokenStream {
+    let name = parse_macro_input!(input as LitStr);
+
+    // The slot ID is the first two elements of the hashed slot name, see `StorageSlotId`.
+    let hashed_name = hash_string_to_word(name.value().as_str());
+    let suffix = hashed_name[0].as_int();
+    let prefix = hashed_name[1].as_int();
+
+    let expanded = quote! {
+        {
+            const _: () = ::core::assert!(
+                ::miden_protocol::account::StorageSlotName::is_valid(#name),
+                "storage slot name is invalid"
+            );
+            ::miden_protocol::account::StorageSlotName::from_static_unchecked(
+                #name,
+                ::miden_protocol::account::StorageSlotId::new(
+                    ::miden_protocol::Felt::new(#suffix),
+                    ::miden_protocol::Felt::new(#prefix),
+                ),
+            )
+        }
+    };
+
+    TokenStream::from(expanded)
+}
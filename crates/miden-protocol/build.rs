@@ -28,6 +28,11 @@ const KERNEL_PROCEDURES_RS_FILE: &str = "src/transaction/kernel/procedures.rs";
 
 const PROTOCOL_LIB_NAMESPACE: &str = "miden::protocol";
 
+#[cfg(feature = "proto-codegen")]
+const PROTO_DIR: &str = "proto";
+#[cfg(feature = "proto-codegen")]
+const PROTO_GENERATED_DIR: &str = "src/proto/generated";
+
 const TX_KERNEL_ERRORS_FILE: &str = "src/errors/tx_kernel.rs";
 const PROTOCOL_LIB_ERRORS_FILE: &str = "src/errors/protocol.rs";
 
@@ -91,6 +96,9 @@ fn main() -> Result<()> {
 
     generate_event_constants(&source_dir, &target_dir)?;
 
+    #[cfg(feature = "proto-codegen")]
+    generate_proto_messages()?;
+
     Ok(())
 }
 
@@ -414,6 +422,46 @@ fn validate_tx_kernel_category(errors: &[shared::NamedError]) -> Result<()> {
     Ok(())
 }
 
+// PROTO MESSAGES
+// ================================================================================================
+
+/// Generates the Rust types of the protobuf messages defined in `./proto` and writes them to
+/// `./src/proto/generated`.
+///
+/// The `.proto` files are compiled with `protox`, so that no `protoc` installation is required,
+/// and the Rust types are generated from the resulting file descriptors by `prost-build`.
+///
+/// Because the generated file will be written to ./src/proto, this should be a no-op if ./src is
+/// read-only. To enable writing to ./src, set the `BUILD_GENERATED_FILES_IN_SRC` environment
+/// variable.
+#[cfg(feature = "proto-codegen")]
+fn generate_proto_messages() -> Result<()> {
+    println!("cargo::rerun-if-changed={PROTO_DIR}/");
+
+    if !BUILD_GENERATED_FILES_IN_SRC {
+        return Ok(());
+    }
+
+    let mut proto_files = Vec::new();
+    for entry in fs::read_dir(PROTO_DIR).into_diagnostic()? {
+        let path = entry.into_diagnostic()?.path();
+        if path.extension().is_some_and(|extension| extension == "proto") {
+            proto_files.push(path);
+        }
+    }
+    proto_files.sort();
+
+    let file_descriptors = protox::compile(&proto_files, [PROTO_DIR])
+        .into_diagnostic()
+        .wrap_err("failed to compile proto files")?;
+
+    prost_build::Config::new()
+        .out_dir(PROTO_GENERATED_DIR)
+        .compile_fds(file_descriptors)
+        .into_diagnostic()
+        .wrap_err("failed to generate proto messages")
+}
+
 // EVENT CONSTANTS FILE GENERATION
 // ================================================================================================
 
@@ -28,6 +28,16 @@ impl AccountType {
         matches!(self, Self::FungibleFaucet | Self::NonFungibleFaucet)
     }
 
+    /// Returns `true` if the account is a fungible faucet.
+    pub fn is_fungible_faucet(&self) -> bool {
+        matches!(self, Self::FungibleFaucet)
+    }
+
+    /// Returns `true` if the account is a non-fungible faucet.
+    pub fn is_non_fungible_faucet(&self) -> bool {
+        matches!(self, Self::NonFungibleFaucet)
+    }
+
     /// Returns `true` if the account is a regular account.
     pub fn is_regular_account(&self) -> bool {
         matches!(self, Self::RegularAccountImmutableCode | Self::RegularAccountUpdatableCode)
@@ -143,4 +153,29 @@ mod tests {
         assert_eq!((REGULAR_ACCOUNT_IMMUTABLE_CODE) & ACCOUNT_IS_FAUCET_MASK, 0);
         assert_eq!((REGULAR_ACCOUNT_UPDATABLE_CODE) & ACCOUNT_IS_FAUCET_MASK, 0);
     }
+
+    #[test]
+    fn test_account_type_predicates() {
+        let fungible_faucet = AccountType::FungibleFaucet;
+        assert!(fungible_faucet.is_faucet());
+        assert!(fungible_faucet.is_fungible_faucet());
+        assert!(!fungible_faucet.is_non_fungible_faucet());
+        assert!(!fungible_faucet.is_regular_account());
+
+        let non_fungible_faucet = AccountType::NonFungibleFaucet;
+        assert!(non_fungible_faucet.is_faucet());
+        assert!(!non_fungible_faucet.is_fungible_faucet());
+        assert!(non_fungible_faucet.is_non_fungible_faucet());
+        assert!(!non_fungible_faucet.is_regular_account());
+
+        for regular_account in [
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+        ] {
+            assert!(!regular_account.is_faucet());
+            assert!(!regular_account.is_fungible_faucet());
+            assert!(!regular_account.is_non_fungible_faucet());
+            assert!(regular_account.is_regular_account());
+        }
+    }
 }
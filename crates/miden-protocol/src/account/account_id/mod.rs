@@ -5,6 +5,7 @@ mod id_prefix;
 pub use id_prefix::AccountIdPrefix;
 
 mod seed;
+pub use seed::AccountIdSeed;
 
 mod account_type;
 pub use account_type::AccountType;
@@ -2,10 +2,120 @@ use alloc::vec::Vec;
 
 use crate::account::account_id::AccountIdVersion;
 use crate::account::account_id::v0::{compute_digest, validate_prefix};
-use crate::account::{AccountStorageMode, AccountType};
-use crate::errors::AccountError;
+use crate::account::{AccountId, AccountStorageMode, AccountType};
+use crate::errors::{AccountError, AccountIdError};
 use crate::{Felt, Word};
 
+// ACCOUNT ID SEED
+// ================================================================================================
+
+/// Functions for deriving [`AccountId`]s from seeds and for grinding seeds, e.g. for external
+/// tooling that wants to know which ID a seed yields without building an account.
+///
+/// [`AccountBuilder`](crate::account::AccountBuilder) derives account IDs through these functions,
+/// so IDs computed here are always equal to the IDs of accounts built with the same seed, code
+/// commitment and storage commitment.
+pub struct AccountIdSeed;
+
+impl AccountIdSeed {
+    /// Computes the [`AccountId`] that the given `seed` yields for an account with the given
+    /// `code_commitment` and `storage_commitment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the resulting ID does not meet the [constraints](AccountId#constraints) of an account ID.
+    /// - the resulting ID does not have the given `account_type` or `storage_mode`.
+    pub fn compute(
+        seed: Word,
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Word,
+        storage_commitment: Word,
+    ) -> Result<AccountId, AccountIdError> {
+        let account_id = AccountId::new(seed, version, code_commitment, storage_commitment)?;
+
+        if account_id.account_type() != account_type || account_id.storage_mode() != storage_mode {
+            return Err(AccountIdError::SeedDoesNotMatchAccountTypeOrStorageMode {
+                expected_type: account_type,
+                expected_storage_mode: storage_mode,
+                actual_type: account_id.account_type(),
+                actual_storage_mode: account_id.storage_mode(),
+            });
+        }
+
+        Ok(account_id)
+    }
+
+    /// Grinds a seed, starting from `init_seed`, until it yields an [`AccountId`] with the given
+    /// `account_type`, `storage_mode` and `version` for which `predicate` returns `true`, and
+    /// returns the seed together with that ID.
+    ///
+    /// The `predicate` can be used to search for IDs with additional properties, e.g. a vanity
+    /// prefix. Note that every additional bit the predicate constrains doubles the expected
+    /// grinding time.
+    ///
+    /// The `init_seed` should be a random seed generated from a cryptographically secure source.
+    pub fn grind(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Word,
+        storage_commitment: Word,
+        mut predicate: impl FnMut(&AccountId) -> bool,
+    ) -> Result<(Word, AccountId), AccountError> {
+        let init_seed: Vec<[u8; 8]> =
+            init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+        let mut current_seed: Word = Word::from([
+            Felt::new(u64::from_le_bytes(init_seed[0])),
+            Felt::new(u64::from_le_bytes(init_seed[1])),
+            Felt::new(u64::from_le_bytes(init_seed[2])),
+            Felt::new(u64::from_le_bytes(init_seed[3])),
+        ]);
+        let mut current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
+
+        // loop until we have a seed that satisfies the specified account type and the predicate.
+        loop {
+            // Check if the seed satisfies the specified type, storage mode and version.
+            // Additionally, the most significant bit of the suffix must be zero to ensure felt
+            // validity.
+            let prefix = current_digest.as_elements()[0];
+            let suffix = current_digest.as_elements()[1];
+            let is_suffix_msb_zero = suffix.as_int() >> 63 == 0;
+
+            if let Ok((computed_account_type, computed_storage_mode, computed_version)) =
+                validate_prefix(prefix)
+                && computed_account_type == account_type
+                && computed_storage_mode == storage_mode
+                && computed_version == version
+                && is_suffix_msb_zero
+            {
+                let account_id = Self::compute(
+                    current_seed,
+                    account_type,
+                    storage_mode,
+                    version,
+                    code_commitment,
+                    storage_commitment,
+                )
+                .expect("seed should yield a valid account ID of the requested type");
+
+                if predicate(&account_id) {
+                    return Ok((current_seed, account_id));
+                }
+            };
+
+            current_seed = current_digest;
+            current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
+        }
+    }
+}
+
+// SEED COMPUTATION
+// ================================================================================================
+
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
 /// using the provided initial seed as a starting point.
 ///
@@ -38,35 +148,14 @@ fn compute_account_seed_single(
     code_commitment: Word,
     storage_commitment: Word,
 ) -> Result<Word, AccountError> {
-    let init_seed: Vec<[u8; 8]> =
-        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
-    let mut current_seed: Word = Word::from([
-        Felt::new(u64::from_le_bytes(init_seed[0])),
-        Felt::new(u64::from_le_bytes(init_seed[1])),
-        Felt::new(u64::from_le_bytes(init_seed[2])),
-        Felt::new(u64::from_le_bytes(init_seed[3])),
-    ]);
-    let mut current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
-
-    // loop until we have a seed that satisfies the specified account type.
-    loop {
-        // Check if the seed satisfies the specified type, storage mode and version. Additionally,
-        // the most significant bit of the suffix must be zero to ensure felt validity.
-        let prefix = current_digest.as_elements()[0];
-        let suffix = current_digest.as_elements()[1];
-        let is_suffix_msb_zero = suffix.as_int() >> 63 == 0;
-
-        if let Ok((computed_account_type, computed_storage_mode, computed_version)) =
-            validate_prefix(prefix)
-            && computed_account_type == account_type
-            && computed_storage_mode == storage_mode
-            && computed_version == version
-            && is_suffix_msb_zero
-        {
-            return Ok(current_seed);
-        };
-
-        current_seed = current_digest;
-        current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
-    }
+    AccountIdSeed::grind(
+        init_seed,
+        account_type,
+        storage_mode,
+        version,
+        code_commitment,
+        storage_commitment,
+        |_| true,
+    )
+    .map(|(seed, _)| seed)
 }
@@ -48,6 +48,15 @@ impl AccountStorageMode {
     pub fn is_private(&self) -> bool {
         matches!(self, Self::Private)
     }
+
+    /// Returns `true` if accounts with this storage mode can be accessed via foreign procedure
+    /// invocation, `false` otherwise.
+    ///
+    /// FPI requires the full state of the foreign account to be available on chain, so this is the
+    /// case for [`Self::Public`] and [`Self::Network`] accounts.
+    pub fn supports_fpi(&self) -> bool {
+        self.has_public_state()
+    }
 }
 
 impl fmt::Display for AccountStorageMode {
@@ -101,3 +110,35 @@ impl rand::distr::Distribution<AccountStorageMode> for rand::distr::StandardUnif
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_mode_predicates() {
+        let public = AccountStorageMode::Public;
+        assert!(public.is_public());
+        assert!(!public.is_network());
+        assert!(!public.is_private());
+        assert!(public.has_public_state());
+        assert!(public.supports_fpi());
+
+        let network = AccountStorageMode::Network;
+        assert!(!network.is_public());
+        assert!(network.is_network());
+        assert!(!network.is_private());
+        assert!(network.has_public_state());
+        assert!(network.supports_fpi());
+
+        let private = AccountStorageMode::Private;
+        assert!(!private.is_public());
+        assert!(!private.is_network());
+        assert!(private.is_private());
+        assert!(!private.has_public_state());
+        assert!(!private.supports_fpi());
+    }
+}
@@ -189,7 +189,7 @@ impl AccountIdV0 {
 
     /// See [`AccountId::is_public`](super::AccountId::is_public) for details.
     pub fn is_public(&self) -> bool {
-        self.storage_mode() == AccountStorageMode::Public
+        self.storage_mode().is_public()
     }
 
     /// See [`AccountId::version`](super::AccountId::version) for details.
@@ -9,7 +9,7 @@ use crate::account::{
     AccountCode,
     AccountComponent,
     AccountId,
-    AccountIdV0,
+    AccountIdSeed,
     AccountIdVersion,
     AccountStorage,
     AccountStorageMode,
@@ -167,27 +167,27 @@ impl AccountBuilder {
         Ok((vault, code, storage))
     }
 
-    /// Grinds a new [`AccountId`] using the `init_seed` as a starting point.
+    /// Grinds a new [`AccountId`] using the `init_seed` as a starting point and returns it
+    /// together with the seed it was derived from.
     fn grind_account_id(
         &self,
         init_seed: [u8; 32],
         version: AccountIdVersion,
         code_commitment: Word,
         storage_commitment: Word,
-    ) -> Result<Word, AccountError> {
-        let seed = AccountIdV0::compute_account_seed(
+    ) -> Result<(Word, AccountId), AccountError> {
+        AccountIdSeed::grind(
             init_seed,
             self.account_type,
             self.storage_mode,
             version,
             code_commitment,
             storage_commitment,
+            |_| true,
         )
         .map_err(|err| {
             AccountError::BuildError("account seed generation failed".into(), Some(Box::new(err)))
-        })?;
-
-        Ok(seed)
+        })
     }
 
     /// Builds an [`Account`] out of the configured builder.
@@ -217,21 +217,13 @@ impl AccountBuilder {
             ));
         }
 
-        let seed = self.grind_account_id(
+        let (seed, account_id) = self.grind_account_id(
             self.init_seed,
             self.id_version,
             code.commitment(),
             storage.to_commitment(),
         )?;
 
-        let account_id = AccountId::new(
-            seed,
-            AccountIdVersion::Version0,
-            code.commitment(),
-            storage.to_commitment(),
-        )
-        .expect("get_account_seed should provide a suitable seed");
-
         debug_assert_eq!(account_id.account_type(), self.account_type);
         debug_assert_eq!(account_id.storage_mode(), self.storage_mode);
 
@@ -304,6 +296,7 @@ mod tests {
 
     use super::*;
     use crate::account::{AccountProcedureRoot, StorageSlot, StorageSlotName};
+    use crate::errors::AccountIdError;
     use crate::testing::noop_auth_component::NoopAuthComponent;
 
     const CUSTOM_CODE1: &str = "
@@ -450,5 +443,88 @@ mod tests {
         assert_matches!(build_error, AccountError::BuildError(msg, _) if msg == "account asset vault must be empty on new accounts")
     }
 
+    /// Tests that the account ID seed functions derive the same seed and ID as the builder.
+    #[test]
+    fn account_id_seed_matches_builder() -> anyhow::Result<()> {
+        let account = Account::builder([7; 32])
+            .account_type(AccountType::RegularAccountImmutableCode)
+            .storage_mode(AccountStorageMode::Public)
+            .with_auth_component(NoopAuthComponent)
+            .with_component(CustomComponent1 { slot0: 3 })
+            .build()?;
+        let seed = account.seed().unwrap();
+
+        let computed_id = AccountIdSeed::compute(
+            seed,
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageMode::Public,
+            AccountIdVersion::Version0,
+            account.code().commitment(),
+            account.storage().to_commitment(),
+        )?;
+        assert_eq!(computed_id, account.id());
+
+        let (ground_seed, ground_id) = AccountIdSeed::grind(
+            [7; 32],
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageMode::Public,
+            AccountIdVersion::Version0,
+            account.code().commitment(),
+            account.storage().to_commitment(),
+            |_| true,
+        )?;
+        assert_eq!(ground_seed, seed);
+        assert_eq!(ground_id, account.id());
+
+        // The seed does not yield a faucet ID.
+        let err = AccountIdSeed::compute(
+            seed,
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Public,
+            AccountIdVersion::Version0,
+            account.code().commitment(),
+            account.storage().to_commitment(),
+        )
+        .unwrap_err();
+        assert_matches!(
+            err,
+            AccountIdError::SeedDoesNotMatchAccountTypeOrStorageMode {
+                expected_type: AccountType::FungibleFaucet,
+                actual_type: AccountType::RegularAccountImmutableCode,
+                ..
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Tests that grinding with a predicate yields an ID matching the predicate whose seed
+    /// is accepted when constructing the account.
+    #[test]
+    fn account_id_seed_grind_with_predicate() -> anyhow::Result<()> {
+        let account = Account::builder([7; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component(CustomComponent1 { slot0: 3 })
+            .build()?;
+        let (_, vault, storage, code, nonce, _) = account.into_parts();
+
+        // Grind for an ID whose most significant four bits are 0xa.
+        let (seed, account_id) = AccountIdSeed::grind(
+            [7; 32],
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Private,
+            AccountIdVersion::Version0,
+            code.commitment(),
+            storage.to_commitment(),
+            |id| id.prefix().as_u64() >> 60 == 0xa,
+        )?;
+        assert_eq!(account_id.prefix().as_u64() >> 60, 0xa);
+
+        let account = Account::new(account_id, vault, storage, code, nonce, Some(seed))?;
+        assert_eq!(account.id(), account_id);
+
+        Ok(())
+    }
+
     // TODO: Test that a BlockHeader with a number which is not a multiple of 2^16 returns an error.
 }
@@ -1,3 +1,4 @@
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
@@ -34,6 +35,10 @@ use procedure::{AccountProcedureRoot, PrintableProcedure};
 /// the authentication procedure of the account, which is always at index 0. This procedure is
 /// automatically called at the end of a transaction to validate an account's state transition.
 ///
+/// The procedures of the [`AccountComponent`]s the code is built from are merged into a single
+/// interface. The code does not retain which component a procedure came from, nor how many
+/// components it was built from.
+///
 /// The code commits to the entire account interface by building a sequential hash of all procedure
 /// MAST roots. Specifically, each procedure contributes exactly 4 field elements to the sequence of
 /// elements to be hashed. Each procedure is represented by its MAST root:
@@ -93,6 +98,59 @@ impl AccountCode {
     pub(super) fn from_components_unchecked(
         components: &[AccountComponent],
     ) -> Result<Self, AccountError> {
+        let (merged_mast_forest, procedures) = Self::merge_components(components)?;
+
+        Ok(Self {
+            commitment: build_procedure_commitment(&procedures),
+            procedures,
+            mast: Arc::new(merged_mast_forest),
+        })
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks whether the provided components can be combined into an [`AccountCode`], without
+    /// building it.
+    ///
+    /// This allows tooling to validate a combination of components before building an account
+    /// from it. The components are validated exactly as when building the account code, but
+    /// without checking whether they support a specific account type.
+    ///
+    /// Returns the MAST roots of procedures exported by more than one component. Building the
+    /// account code deduplicates these procedures, so they do not prevent the components from
+    /// being combined, but the components cannot provide distinct implementations of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if building the account code from the provided components would fail, see
+    /// [`AccountCode::from_components`].
+    pub fn would_merge_cleanly(components: &[AccountComponent]) -> Result<Vec<Word>, AccountError> {
+        Self::merge_components(components)?;
+
+        let mut exported_roots = BTreeSet::new();
+        let mut colliding_roots = BTreeSet::new();
+        for component in components {
+            // A component may export multiple procedures with the same MAST root, which is not a
+            // collision between components.
+            let component_roots: BTreeSet<Word> =
+                component.get_procedures().into_iter().map(|(root, _)| root).collect();
+
+            for root in component_roots {
+                if !exported_roots.insert(root) {
+                    colliding_roots.insert(root);
+                }
+            }
+        }
+
+        Ok(colliding_roots.into_iter().collect())
+    }
+
+    /// Merges the MAST forests of the provided components and collects their procedures, which
+    /// validates the combination of the components.
+    fn merge_components(
+        components: &[AccountComponent],
+    ) -> Result<(MastForest, Vec<AccountProcedureRoot>), AccountError> {
         let (merged_mast_forest, _) =
             MastForest::merge(components.iter().map(|component| component.mast_forest()))
                 .map_err(AccountError::AccountComponentMastForestMergeError)?;
@@ -108,15 +166,12 @@ impl AccountCode {
             builder.add_component(component)?;
         }
 
-        let procedures = builder.build()?;
-
-        Ok(Self {
-            commitment: build_procedure_commitment(&procedures),
-            procedures,
-            mast: Arc::new(merged_mast_forest),
-        })
+        Ok((merged_mast_forest, builder.build()?))
     }
 
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
     /// Returns a new [AccountCode] deserialized from the provided bytes.
     ///
     /// # Errors
@@ -172,6 +227,10 @@ impl AccountCode {
     }
 
     /// Returns true if a procedure with the specified MAST root is defined in this account code.
+    ///
+    /// This is the counterpart of the `account_has_procedure` kernel procedure and can be used to
+    /// check that procedure roots referenced by a component, e.g. the trigger procedures of an ACL
+    /// auth component, are part of the account's code.
     pub fn has_procedure(&self, mast_root: Word) -> bool {
         self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
     }
@@ -406,10 +465,13 @@ pub(crate) fn procedures_as_elements(procedures: &[AccountProcedureRoot]) -> Vec
 #[cfg(test)]
 mod tests {
 
+    use alloc::vec::Vec;
+
     use assert_matches::assert_matches;
     use miden_assembly::Assembler;
 
     use super::{AccountCode, Deserializable, Serializable};
+    use crate::Word;
     use crate::account::code::build_procedure_commitment;
     use crate::account::{AccountComponent, AccountType};
     use crate::errors::AccountError;
@@ -431,6 +493,28 @@ mod tests {
         assert_eq!(procedure_root, code.commitment())
     }
 
+    #[test]
+    fn test_account_code_num_procedures() {
+        // the mock code consists of the noop auth procedure and the `foo` and `bar` procedures
+        let code = AccountCode::mock();
+        assert_eq!(code.num_procedures(), 3);
+
+        let roots: Vec<_> = code.procedure_roots().collect();
+        assert_eq!(roots.len(), code.num_procedures());
+        assert!(roots.iter().all(|root| code.has_procedure(*root)));
+    }
+
+    #[test]
+    fn test_account_code_has_procedure() {
+        let code = AccountCode::mock();
+
+        let present_root = *code.get(1).unwrap().mast_root();
+        assert!(code.has_procedure(present_root));
+
+        let absent_root = Word::from([1, 2, 3, 4u32]);
+        assert!(!code.has_procedure(absent_root));
+    }
+
     #[test]
     fn test_account_code_only_auth_component() {
         let err = AccountCode::from_components(
@@ -488,4 +572,48 @@ mod tests {
 
         assert_matches!(err, AccountError::AccountComponentMultipleAuthProcedures);
     }
+
+    #[test]
+    fn test_account_code_would_merge_cleanly() {
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let component = AccountComponent::new(library, vec![]).unwrap().with_supports_all_types();
+        let collisions =
+            AccountCode::would_merge_cleanly(&[NoopAuthComponent.into(), component]).unwrap();
+        assert!(collisions.is_empty());
+
+        let shared_proc_code_1 = "
+            pub proc foo
+                push.1 drop
+            end
+        ";
+        let shared_proc_code_2 = "
+            pub proc bar
+                push.1 drop
+            end
+
+            pub proc baz
+                push.2 drop
+            end
+        ";
+        let mut components = vec![NoopAuthComponent.into()];
+        components.extend([shared_proc_code_1, shared_proc_code_2].map(|code| {
+            let library = Assembler::default().assemble_library([code]).unwrap();
+            AccountComponent::new(library, vec![]).unwrap().with_supports_all_types()
+        }));
+        let shared_root = components[1].get_procedures()[0].0;
+
+        // colliding procedures are reported, but building the account code deduplicates them
+        let collisions = AccountCode::would_merge_cleanly(&components).unwrap();
+        assert_eq!(collisions, vec![shared_root]);
+        AccountCode::from_components(&components, AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+
+        // components which cannot be combined are rejected like when building the account code
+        let err = AccountCode::would_merge_cleanly(&components[1..]).unwrap_err();
+        assert_matches!(err, AccountError::AccountCodeNoAuthComponent);
+        let err =
+            AccountCode::would_merge_cleanly(&[NoopAuthComponent.into(), NoopAuthComponent.into()])
+                .unwrap_err();
+        assert_matches!(err, AccountError::AccountCodeMultipleAuthComponents);
+    }
 }
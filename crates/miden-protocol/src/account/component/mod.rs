@@ -334,6 +334,27 @@ mod tests {
         assert!(error_msg.contains("package does not contain account component metadata"));
     }
 
+    #[test]
+    fn test_supported_types() {
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let component = AccountComponent::new(library, vec![]).unwrap();
+
+        let all_types_component = component.clone().with_supports_all_types();
+        assert_eq!(all_types_component.supported_types().len(), 4);
+        assert!(all_types_component.supports_type(AccountType::FungibleFaucet));
+        assert!(all_types_component.supports_type(AccountType::NonFungibleFaucet));
+        assert!(all_types_component.supports_type(AccountType::RegularAccountImmutableCode));
+        assert!(all_types_component.supports_type(AccountType::RegularAccountUpdatableCode));
+
+        let faucet_component = component.with_supported_type(AccountType::FungibleFaucet);
+        assert_eq!(
+            faucet_component.supported_types(),
+            &BTreeSet::from_iter([AccountType::FungibleFaucet])
+        );
+        assert!(faucet_component.supports_type(AccountType::FungibleFaucet));
+        assert!(!faucet_component.supports_type(AccountType::RegularAccountUpdatableCode));
+    }
+
     #[test]
     fn test_from_library_with_init_data() {
         // Create a simple library for testing
@@ -0,0 +1,129 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::account::StorageSlotName;
+use crate::asset::Asset;
+use crate::{Felt, Word};
+
+// APPLIED CHANGES
+// ================================================================================================
+
+/// A report of the changes made to an account by applying an [`AccountDelta`](super::AccountDelta)
+/// to it.
+///
+/// The report is computed while the delta is applied (see
+/// [`Account::apply_delta_with_changes`](crate::account::Account::apply_delta_with_changes)) and
+/// contains:
+/// - the assets added to and removed from the account vault. Fungible assets carry the amount by
+///   which the balance changed.
+/// - the value slots whose value changed, with their old and new values.
+/// - the storage map entries whose value changed, with their old and new values.
+/// - the nonce of the account after the delta was applied.
+///
+/// Storage values and map entries which were included in the delta but set to the value they
+/// already had are not reported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedChanges {
+    added_assets: Vec<Asset>,
+    removed_assets: Vec<Asset>,
+    updated_values: BTreeMap<StorageSlotName, (Word, Word)>,
+    updated_map_entries: BTreeMap<StorageSlotName, BTreeMap<Word, (Word, Word)>>,
+    new_nonce: Felt,
+}
+
+impl AppliedChanges {
+    /// Returns a new, empty [`AppliedChanges`] report for an account with the provided nonce.
+    pub(crate) fn new(nonce: Felt) -> Self {
+        Self {
+            added_assets: Vec::new(),
+            removed_assets: Vec::new(),
+            updated_values: BTreeMap::new(),
+            updated_map_entries: BTreeMap::new(),
+            new_nonce: nonce,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the assets which were added to the account vault.
+    pub fn added_assets(&self) -> &[Asset] {
+        &self.added_assets
+    }
+
+    /// Returns the assets which were removed from the account vault.
+    pub fn removed_assets(&self) -> &[Asset] {
+        &self.removed_assets
+    }
+
+    /// Returns the value slots whose value changed, mapped to their `(old, new)` values.
+    pub fn updated_values(&self) -> &BTreeMap<StorageSlotName, (Word, Word)> {
+        &self.updated_values
+    }
+
+    /// Returns the map slots which had at least one entry changed, mapped to the changed keys and
+    /// their `(old, new)` values.
+    pub fn updated_map_entries(&self) -> &BTreeMap<StorageSlotName, BTreeMap<Word, (Word, Word)>> {
+        &self.updated_map_entries
+    }
+
+    /// Returns the nonce of the account after the delta was applied.
+    pub fn new_nonce(&self) -> Felt {
+        self.new_nonce
+    }
+
+    /// Returns `true` if applying the delta changed neither the vault nor the storage of the
+    /// account.
+    pub fn is_empty(&self) -> bool {
+        self.added_assets.is_empty()
+            && self.removed_assets.is_empty()
+            && self.updated_values.is_empty()
+            && self.updated_map_entries.is_empty()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records that the provided asset was added to the account vault.
+    pub(crate) fn record_added_asset(&mut self, asset: Asset) {
+        self.added_assets.push(asset);
+    }
+
+    /// Records that the provided asset was removed from the account vault.
+    pub(crate) fn record_removed_asset(&mut self, asset: Asset) {
+        self.removed_assets.push(asset);
+    }
+
+    /// Records the update of a value slot, unless the value did not change.
+    pub(crate) fn record_value_update(
+        &mut self,
+        slot_name: &StorageSlotName,
+        old_value: Word,
+        new_value: Word,
+    ) {
+        if old_value != new_value {
+            self.updated_values.insert(slot_name.clone(), (old_value, new_value));
+        }
+    }
+
+    /// Records the update of a storage map entry, unless the value did not change.
+    pub(crate) fn record_map_entry_update(
+        &mut self,
+        slot_name: &StorageSlotName,
+        raw_key: Word,
+        old_value: Word,
+        new_value: Word,
+    ) {
+        if old_value != new_value {
+            self.updated_map_entries
+                .entry(slot_name.clone())
+                .or_default()
+                .insert(raw_key, (old_value, new_value));
+        }
+    }
+
+    /// Sets the nonce of the account after the delta was applied.
+    pub(crate) fn set_new_nonce(&mut self, nonce: Felt) {
+        self.new_nonce = nonce;
+    }
+}
@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+
+use crate::crypto::DoubleWordHasher;
+use crate::{Felt, Word};
+
+// DELTA ELEMENT SINK
+// ================================================================================================
+
+/// A sink for the double words from which the commitment to an
+/// [`AccountDelta`](super::AccountDelta) is computed.
+///
+/// The delta is always reduced to a sequence of double words, so it can either be collected into
+/// a vector of elements or absorbed directly into a [`DoubleWordHasher`].
+pub(super) trait DeltaElementSink {
+    /// Appends the double word consisting of `first` and `second` to the sink.
+    fn append_double_word(&mut self, first: Word, second: Word);
+}
+
+impl DeltaElementSink for Vec<Felt> {
+    fn append_double_word(&mut self, first: Word, second: Word) {
+        self.extend_from_slice(first.as_elements());
+        self.extend_from_slice(second.as_elements());
+    }
+}
+
+impl DeltaElementSink for DoubleWordHasher {
+    fn append_double_word(&mut self, first: Word, second: Word) {
+        self.absorb(first, second);
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::boxed::Box;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::fmt::Display;
 
 use crate::account::{
     Account,
@@ -10,10 +12,19 @@ use crate::account::{
     StorageSlotType,
 };
 use crate::asset::AssetVault;
-use crate::crypto::SequentialCommit;
+use crate::crypto::{DoubleWordHasher, SequentialCommit};
 use crate::errors::{AccountDeltaError, AccountError};
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
-use crate::{Felt, Word, ZERO};
+use crate::{Felt, PrettyPrint, Word, ZERO};
+
+mod applied;
+pub use applied::AppliedChanges;
+
+mod commitment;
+use commitment::DeltaElementSink;
+
+mod size;
+pub use size::AccountDeltaSizeBreakdown;
 
 mod storage;
 pub use storage::{AccountStorageDelta, StorageMapDelta, StorageSlotDelta};
@@ -21,6 +32,7 @@ pub use storage::{AccountStorageDelta, StorageMapDelta, StorageSlotDelta};
 mod vault;
 pub use vault::{
     AccountVaultDelta,
+    AssetChange,
     FungibleAssetDelta,
     NonFungibleAssetDelta,
     NonFungibleDeltaAction,
@@ -176,6 +188,69 @@ impl AccountDelta {
         self.code.as_ref()
     }
 
+    /// Returns a delta containing only the storage updates of this delta.
+    ///
+    /// The vault updates are dropped while the nonce delta is preserved, which is valid even if
+    /// the storage delta is empty. The returned delta is always a partial state delta, i.e. the
+    /// account code of a full state delta is not included.
+    pub fn storage_only(&self) -> AccountDelta {
+        Self {
+            account_id: self.account_id,
+            storage: self.storage.clone(),
+            vault: AccountVaultDelta::default(),
+            code: None,
+            nonce_delta: self.nonce_delta,
+        }
+    }
+
+    /// Returns a delta containing only the vault updates of this delta.
+    ///
+    /// The storage updates are dropped while the nonce delta is preserved, which is valid even if
+    /// the vault delta is empty. The returned delta is always a partial state delta, i.e. the
+    /// account code of a full state delta is not included.
+    pub fn vault_only(&self) -> AccountDelta {
+        Self {
+            account_id: self.account_id,
+            storage: AccountStorageDelta::new(),
+            vault: self.vault.clone(),
+            code: None,
+            nonce_delta: self.nonce_delta,
+        }
+    }
+
+    /// Returns the size of this delta in bytes when encoded.
+    ///
+    /// Note that the limit enforced on proven transactions
+    /// ([`ACCOUNT_UPDATE_MAX_SIZE`](crate::ACCOUNT_UPDATE_MAX_SIZE)) applies to the
+    /// [`AccountUpdateDetails`] which wrap this delta and add a single byte to this size.
+    pub fn encoded_size(&self) -> usize {
+        self.get_size_hint()
+    }
+
+    /// Returns a breakdown of the encoded size of this delta into its vault, storage and storage
+    /// map parts.
+    pub fn size_breakdown(&self) -> AccountDeltaSizeBreakdown {
+        AccountDeltaSizeBreakdown::new(self.encoded_size(), &self.storage, &self.vault)
+    }
+
+    /// Checks that the encoded size of this delta does not exceed the provided limit in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoded size of this delta is greater than `limit`. The error
+    /// contains a [`AccountDeltaSizeBreakdown`] of the delta.
+    pub fn check_size_limit(&self, limit: usize) -> Result<(), AccountDeltaError> {
+        if self.encoded_size() > limit {
+            return Err(AccountDeltaError::SizeLimitExceeded {
+                account_id: self.account_id,
+                limit,
+                breakdown: Box::new(self.size_breakdown()),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Converts this storage delta into individual delta components.
     pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<AccountCode>, Felt) {
         (self.storage, self.vault, self.code, self.nonce_delta)
@@ -327,6 +402,30 @@ impl AccountDelta {
     pub fn to_commitment(&self) -> Word {
         <Self as SequentialCommit>::to_commitment(self)
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the delta as a sequence of double words to the given `elements` from which the
+    /// delta commitment will be computed.
+    fn append_delta_elements(&self, elements: &mut impl DeltaElementSink) {
+        // ID and Nonce
+        elements.append_double_word(
+            Word::from([
+                self.nonce_delta,
+                ZERO,
+                self.account_id.suffix(),
+                self.account_id.prefix().as_felt(),
+            ]),
+            Word::empty(),
+        );
+
+        // Vault Delta
+        self.vault.append_delta_elements(elements);
+
+        // Storage Delta
+        self.storage.append_delta_elements(elements);
+    }
 }
 
 impl TryFrom<&AccountDelta> for Account {
@@ -369,7 +468,8 @@ impl TryFrom<&AccountDelta> for Account {
         }
         let mut storage = AccountStorage::new(empty_storage_slots)
             .expect("storage delta should contain a valid number of slots");
-        storage.apply_delta(delta.storage())?;
+        // The change report is not needed since the account is built from scratch.
+        storage.apply_delta(delta.storage(), &mut AppliedChanges::new(ZERO))?;
 
         // The nonce of the account is the initial nonce of 0 plus the nonce_delta, so the
         // nonce_delta itself.
@@ -393,21 +493,7 @@ impl SequentialCommit for AccountDelta {
 
         // Minor optimization: At least 24 elements are always added.
         let mut elements = Vec::with_capacity(24);
-
-        // ID and Nonce
-        elements.extend_from_slice(&[
-            self.nonce_delta,
-            ZERO,
-            self.account_id.suffix(),
-            self.account_id.prefix().as_felt(),
-        ]);
-        elements.extend_from_slice(Word::empty().as_elements());
-
-        // Vault Delta
-        self.vault.append_delta_elements(&mut elements);
-
-        // Storage Delta
-        self.storage.append_delta_elements(&mut elements);
+        self.append_delta_elements(&mut elements);
 
         debug_assert!(
             elements.len() % (2 * crate::WORD_SIZE) == 0,
@@ -417,6 +503,21 @@ impl SequentialCommit for AccountDelta {
 
         elements
     }
+
+    /// Computes the commitment to the delta by absorbing its elements directly into the hasher,
+    /// which avoids allocating the sequence returned by [`SequentialCommit::to_elements`].
+    ///
+    /// See [AccountDelta::to_commitment()] for more details.
+    fn to_commitment(&self) -> Word {
+        // The commitment to an empty delta is defined as the empty word.
+        if self.is_empty() {
+            return Word::empty();
+        }
+
+        let mut hasher = DoubleWordHasher::new();
+        self.append_delta_elements(&mut hasher);
+        hasher.digest()
+    }
 }
 
 // ACCOUNT UPDATE DETAILS
@@ -559,6 +660,76 @@ impl Deserializable for AccountUpdateDetails {
     }
 }
 
+// PRETTY-PRINTING
+// ================================================================================================
+
+impl PrettyPrint for AccountDelta {
+    fn render(&self) -> miden_core::prettier::Document {
+        use miden_core::prettier::*;
+
+        let mut fields = nl()
+            + text(format!("account id: {}", self.account_id))
+            + nl()
+            + text(format!("nonce delta: {}", self.nonce_delta));
+
+        if let Some(code) = &self.code {
+            fields += nl()
+                + text(format!(
+                    "code: {} procedures, commitment {}",
+                    code.num_procedures(),
+                    code.commitment()
+                ));
+        }
+
+        if !self.storage.is_empty() {
+            let mut slots = Document::Empty;
+            for (slot_name, slot_delta) in self.storage.slots() {
+                match slot_delta {
+                    StorageSlotDelta::Value(value) => {
+                        slots += nl() + text(format!("value {slot_name}: {value}"));
+                    },
+                    StorageSlotDelta::Map(map_delta) => {
+                        let mut entries = Document::Empty;
+                        for (key, value) in map_delta.entries() {
+                            entries += nl() + text(format!("{}: {value}", key.inner()));
+                        }
+                        slots += nl() + text(format!("map {slot_name}")) + indent(4, entries);
+                    },
+                }
+            }
+            fields += nl() + const_text("storage") + indent(4, slots);
+        }
+
+        if !self.vault.is_empty() {
+            let mut assets = Document::Empty;
+            for (faucet_id, amount) in self.vault.fungible().iter() {
+                assets += nl() + text(format!("fungible {faucet_id}: {amount:+}"));
+            }
+            for (asset, action) in self.vault.non_fungible().iter() {
+                let action = match action {
+                    NonFungibleDeltaAction::Add => "added",
+                    NonFungibleDeltaAction::Remove => "removed",
+                };
+                assets += nl()
+                    + text(format!(
+                        "non-fungible {}: {} {action}",
+                        asset.faucet_id_prefix(),
+                        asset.vault_key()
+                    ));
+            }
+            fields += nl() + const_text("vault") + indent(4, assets);
+        }
+
+        const_text("account delta") + indent(4, fields)
+    }
+}
+
+impl Display for AccountDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.pretty_print(f)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -585,10 +756,15 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
 
     use assert_matches::assert_matches;
-    use miden_core::utils::Serializable;
+    use miden_core::utils::{Deserializable, Serializable};
     use miden_core::{Felt, FieldElement};
+    use proptest::prelude::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
 
     use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
     use crate::account::delta::AccountUpdateDetails;
@@ -609,13 +785,21 @@ mod tests {
         NonFungibleAsset,
         NonFungibleAssetDetails,
     };
+    use crate::crypto::SequentialCommit;
     use crate::errors::AccountDeltaError;
     use crate::testing::account_id::{
         ACCOUNT_ID_PRIVATE_SENDER,
         ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
         AccountIdBuilder,
     };
-    use crate::{ONE, Word, ZERO};
+    use crate::testing::strategies::{
+        MAX_GENERATED_DELTA_SLOTS,
+        any_account_delta,
+        any_account_delta_for,
+        any_account_id,
+        storage_for_deltas,
+    };
+    use crate::{Hasher, ONE, Word, ZERO};
 
     #[test]
     fn account_delta_nonce_validation() {
@@ -638,6 +822,55 @@ mod tests {
         AccountDelta::new(account_id, storage_delta.clone(), vault_delta.clone(), ONE).unwrap();
     }
 
+    #[test]
+    fn account_delta_storage_and_vault_only() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        let storage_delta = AccountStorageDelta::from_iters(
+            [],
+            [(StorageSlotName::mock(1), Word::from([1, 2, 3, 4u32]))],
+            [],
+        );
+        let fungible: Asset = FungibleAsset::mock(10);
+        let vault_delta = AccountVaultDelta::from_iters([fungible], []);
+        let nonce_delta = Felt::new(3);
+
+        let delta =
+            AccountDelta::new(account_id, storage_delta.clone(), vault_delta.clone(), nonce_delta)
+                .unwrap();
+
+        let storage_only = delta.storage_only();
+        assert_eq!(storage_only.id(), account_id);
+        assert_eq!(storage_only.storage(), &storage_delta);
+        assert!(storage_only.vault().is_empty());
+        assert_eq!(storage_only.nonce_delta(), nonce_delta);
+
+        let vault_only = delta.vault_only();
+        assert_eq!(vault_only.id(), account_id);
+        assert!(vault_only.storage().is_empty());
+        assert_eq!(vault_only.vault(), &vault_delta);
+        assert_eq!(vault_only.nonce_delta(), nonce_delta);
+
+        // the split deltas commit to different data than the full delta
+        assert_ne!(storage_only.to_commitment(), delta.to_commitment());
+        assert_ne!(vault_only.to_commitment(), delta.to_commitment());
+        assert_ne!(storage_only.to_commitment(), vault_only.to_commitment());
+
+        // merging the split deltas recreates the full delta with a doubled nonce delta
+        let mut merged = storage_only;
+        merged.merge(vault_only).unwrap();
+        assert_eq!(merged.storage(), delta.storage());
+        assert_eq!(merged.vault(), delta.vault());
+        assert_eq!(merged.nonce_delta(), nonce_delta + nonce_delta);
+
+        // splitting a delta with an empty component preserves the nonce delta
+        let nonce_only =
+            AccountDelta::new(account_id, storage_delta, AccountVaultDelta::default(), ONE)
+                .unwrap()
+                .vault_only();
+        assert!(nonce_only.storage().is_empty() && nonce_only.vault().is_empty());
+        assert_eq!(nonce_only.nonce_delta(), ONE);
+    }
+
     #[test]
     fn account_delta_nonce_overflow() {
         let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
@@ -751,4 +984,197 @@ mod tests {
         let update_details_delta = AccountUpdateDetails::Delta(account_delta);
         assert_eq!(update_details_delta.to_bytes().len(), update_details_delta.get_size_hint());
     }
+    #[test]
+    fn account_delta_pretty_print() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        let map_slot = StorageSlotName::mock(1);
+        let map_key = Word::from([1, 2, 3, 4u32]);
+        let map_value = Word::from([5, 6, 7, 8u32]);
+
+        let mut storage_delta = AccountStorageDelta::new();
+        storage_delta.set_map_item(map_slot.clone(), map_key, map_value).unwrap();
+
+        let fungible = FungibleAsset::mock(100).unwrap_fungible();
+        let non_fungible = NonFungibleAsset::mock(&[1, 2, 3]).unwrap_non_fungible();
+        let vault_delta = AccountVaultDelta::from_iters([fungible.into()], [non_fungible.into()]);
+
+        let delta = AccountDelta::new(account_id, storage_delta, vault_delta, ONE).unwrap();
+
+        let expected = format!(
+            "account delta
+    account id: {account_id}
+    nonce delta: 1
+    storage
+        map {map_slot}
+            {map_key}: {map_value}
+    vault
+        fungible {}: +100
+        non-fungible {}: {} removed",
+            fungible.faucet_id(),
+            non_fungible.faucet_id_prefix(),
+            non_fungible.vault_key(),
+        );
+        assert_eq!(delta.to_string(), expected);
+    }
+
+    #[test]
+    fn account_delta_size_limit() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        let small_map = StorageSlotName::mock(1);
+        let large_map = StorageSlotName::mock(2);
+
+        let mut storage_delta = AccountStorageDelta::new();
+        storage_delta
+            .set_map_item(small_map.clone(), Word::from([1, 0, 0, 0u32]), Word::from([1u32; 4]))
+            .unwrap();
+        for i in 0..100u32 {
+            storage_delta
+                .set_map_item(large_map.clone(), Word::from([i, 1, 0, 0]), Word::from([i; 4]))
+                .unwrap();
+        }
+
+        let vault_delta = AccountVaultDelta::default();
+        let delta = AccountDelta::new(account_id, storage_delta, vault_delta, ONE).unwrap();
+        let encoded_size = delta.to_bytes().len();
+        assert_eq!(delta.encoded_size(), encoded_size);
+
+        let breakdown = delta.size_breakdown();
+        assert_eq!(breakdown.total(), encoded_size);
+        assert_eq!(breakdown.storage(), delta.storage().to_bytes().len());
+        assert_eq!(breakdown.vault(), delta.vault().to_bytes().len());
+        assert_eq!(breakdown.map_slots().len(), 2);
+        assert_eq!(breakdown.largest_map_slot().unwrap().0, &large_map);
+
+        delta.check_size_limit(encoded_size).unwrap();
+        assert_matches!(
+            delta.check_size_limit(encoded_size - 1).unwrap_err(),
+            AccountDeltaError::SizeLimitExceeded { account_id: id, limit, breakdown: err } => {
+                assert_eq!(id, account_id);
+                assert_eq!(limit, encoded_size - 1);
+                assert_eq!(*err, breakdown);
+            }
+        );
+    }
+
+    /// Tests that the streaming delta commitment equals the hash of the delta's elements for
+    /// randomized deltas.
+    #[test]
+    fn account_delta_commitment_matches_elements_hash() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+
+        let empty_delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::new(),
+            AccountVaultDelta::default(),
+            ZERO,
+        )
+        .unwrap();
+        assert_eq!(empty_delta.to_commitment(), Word::empty());
+        assert_eq!(empty_delta.to_commitment(), Hasher::hash_elements(&empty_delta.to_elements()));
+
+        for _ in 0..20 {
+            let mut storage_delta = AccountStorageDelta::new();
+            for slot_idx in 0..rng.random_range(0..4) {
+                storage_delta
+                    .set_item(StorageSlotName::mock(slot_idx), Word::from(rng.random::<[u32; 4]>()))
+                    .unwrap();
+            }
+            for slot_idx in 4..rng.random_range(4..8) {
+                let slot_name = StorageSlotName::mock(slot_idx);
+                storage_delta.insert_empty_map_delta(slot_name.clone());
+                for _ in 0..rng.random_range(0..50) {
+                    storage_delta
+                        .set_map_item(
+                            slot_name.clone(),
+                            Word::from(rng.random::<[u32; 4]>()),
+                            Word::from(rng.random::<[u32; 4]>()),
+                        )
+                        .unwrap();
+                }
+            }
+
+            let mut added_assets = Vec::new();
+            let mut removed_assets = Vec::new();
+            for _ in 0..rng.random_range(0..6) {
+                let faucet_id = AccountIdBuilder::new()
+                    .account_type(AccountType::FungibleFaucet)
+                    .build_with_rng(&mut rng);
+                let asset: Asset =
+                    FungibleAsset::new(faucet_id, rng.random_range(1..1000)).unwrap().into();
+
+                if rng.random() {
+                    added_assets.push(asset);
+                } else {
+                    removed_assets.push(asset);
+                }
+            }
+
+            let vault_delta = AccountVaultDelta::from_iters(added_assets, removed_assets);
+            let delta = AccountDelta::new(account_id, storage_delta, vault_delta, ONE).unwrap();
+
+            assert_eq!(delta.to_commitment(), Hasher::hash_elements(&delta.to_elements()));
+        }
+    }
+
+    // PROPERTY TESTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a strategy generating an empty account together with two deltas for it.
+    fn account_with_two_deltas() -> impl Strategy<Value = (Account, AccountDelta, AccountDelta)> {
+        any_account_id(AccountType::RegularAccountUpdatableCode, AccountStorageMode::Public)
+            .prop_flat_map(|account_id| {
+                let account = Account::new_unchecked(
+                    account_id,
+                    AssetVault::default(),
+                    storage_for_deltas(MAX_GENERATED_DELTA_SLOTS),
+                    AccountCode::mock(),
+                    ONE,
+                    None,
+                );
+                (
+                    Just(account),
+                    any_account_delta_for(account_id),
+                    any_account_delta_for(account_id),
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn proptest_merged_delta_apply_equals_sequential_apply(
+            (account, delta0, delta1) in account_with_two_deltas()
+        ) {
+            let mut sequential = account.clone();
+            sequential.apply_delta(&delta0).unwrap();
+            sequential.apply_delta(&delta1).unwrap();
+
+            let mut merged_delta = delta0;
+            merged_delta.merge(delta1).unwrap();
+            let mut merged = account;
+            merged.apply_delta(&merged_delta).unwrap();
+
+            prop_assert_eq!(merged, sequential);
+        }
+
+        #[test]
+        fn proptest_account_delta_serialization_round_trip(delta in any_account_delta()) {
+            let bytes = delta.to_bytes();
+            prop_assert_eq!(bytes.len(), delta.get_size_hint());
+            prop_assert_eq!(AccountDelta::read_from_bytes(&bytes).unwrap(), delta);
+        }
+
+        /// Converting an account into a full state delta and back results in the same account.
+        #[test]
+        fn proptest_full_state_delta_account_round_trip(
+            (account, delta0, _) in account_with_two_deltas()
+        ) {
+            let mut account = account;
+            account.apply_delta(&delta0).unwrap();
+
+            let full_state_delta = AccountDelta::try_from(account.clone()).unwrap();
+            prop_assert!(full_state_delta.is_full_state());
+            prop_assert_eq!(Account::try_from(&full_state_delta).unwrap(), account);
+        }
+    }
 }
@@ -0,0 +1,76 @@
+use alloc::collections::BTreeMap;
+
+use super::{AccountStorageDelta, AccountVaultDelta};
+use crate::account::StorageSlotName;
+use crate::utils::Serializable;
+
+// ACCOUNT DELTA SIZE BREAKDOWN
+// ================================================================================================
+
+/// A breakdown of the encoded size of an [`AccountDelta`](super::AccountDelta) in bytes.
+///
+/// This is returned as part of
+/// [`AccountDeltaError::SizeLimitExceeded`](crate::errors::AccountDeltaError::SizeLimitExceeded)
+/// so that callers can identify which part of the delta exceeded the limit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountDeltaSizeBreakdown {
+    total: usize,
+    vault: usize,
+    storage: usize,
+    map_slots: BTreeMap<StorageSlotName, usize>,
+}
+
+impl AccountDeltaSizeBreakdown {
+    /// Returns a new [`AccountDeltaSizeBreakdown`] of a delta with the provided total encoded size
+    /// and storage and vault deltas.
+    ///
+    /// This allows computing the breakdown of a delta which is still being built and therefore
+    /// cannot be constructed as an [`AccountDelta`](super::AccountDelta) yet.
+    pub fn new(total: usize, storage: &AccountStorageDelta, vault: &AccountVaultDelta) -> Self {
+        let map_slots = storage
+            .maps()
+            .map(|(slot_name, map_delta)| {
+                (slot_name.clone(), slot_name.get_size_hint() + map_delta.get_size_hint())
+            })
+            .collect();
+
+        Self {
+            total,
+            vault: vault.get_size_hint(),
+            storage: storage.get_size_hint(),
+            map_slots,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the encoded size of the entire delta.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the encoded size of the vault delta.
+    pub fn vault(&self) -> usize {
+        self.vault
+    }
+
+    /// Returns the encoded size of the storage delta, including all map slots.
+    pub fn storage(&self) -> usize {
+        self.storage
+    }
+
+    /// Returns the encoded size of each storage map delta, including the encoded slot name.
+    pub fn map_slots(&self) -> &BTreeMap<StorageSlotName, usize> {
+        &self.map_slots
+    }
+
+    /// Returns the name and encoded size of the largest storage map delta, if the delta contains
+    /// any map slots.
+    pub fn largest_map_slot(&self) -> Option<(&StorageSlotName, usize)> {
+        self.map_slots
+            .iter()
+            .max_by_key(|(_, size)| **size)
+            .map(|(slot_name, size)| (slot_name, *size))
+    }
+}
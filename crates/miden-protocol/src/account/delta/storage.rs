@@ -6,12 +6,20 @@ use super::{
     AccountDeltaError,
     ByteReader,
     ByteWriter,
+    DeltaElementSink,
     Deserializable,
     DeserializationError,
     Serializable,
     Word,
 };
-use crate::account::{StorageMap, StorageSlotContent, StorageSlotName, StorageSlotType};
+use crate::account::{
+    AccountStorage,
+    StorageMap,
+    StorageSlotContent,
+    StorageSlotName,
+    StorageSlotType,
+};
+use crate::errors::AccountError;
 use crate::{EMPTY_WORD, Felt, LexicographicWord, ZERO};
 
 // ACCOUNT STORAGE DELTA
@@ -37,6 +45,58 @@ impl AccountStorageDelta {
         Self { deltas }
     }
 
+    /// Computes the storage delta that transforms the `before` storage into the `after` storage.
+    ///
+    /// The delta contains a value slot delta for every value slot whose value changed and a map
+    /// slot delta for every map slot with at least one changed entry. Entries removed from a map
+    /// are represented as cleared entries, i.e. their value is set to [`EMPTY_WORD`].
+    ///
+    /// Applying the returned delta to `before` results in storage equal to `after`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `before` and `after` do not contain the same set of slot names.
+    /// - a slot is a value slot in one storage and a map slot in the other.
+    pub fn from_account_diff(
+        before: &AccountStorage,
+        after: &AccountStorage,
+    ) -> Result<Self, AccountError> {
+        if let Some(slot) = after.slots().iter().find(|slot| before.get(slot.name()).is_none()) {
+            return Err(AccountError::StorageSlotNameNotFound { slot_name: slot.name().clone() });
+        }
+
+        let mut deltas = BTreeMap::new();
+        for before_slot in before.slots() {
+            let slot_name = before_slot.name();
+            let after_slot = after.get(slot_name).ok_or_else(|| {
+                AccountError::StorageSlotNameNotFound { slot_name: slot_name.clone() }
+            })?;
+
+            match (before_slot.content(), after_slot.content()) {
+                (StorageSlotContent::Value(old_value), StorageSlotContent::Value(new_value)) => {
+                    if old_value != new_value {
+                        deltas.insert(slot_name.clone(), StorageSlotDelta::Value(*new_value));
+                    }
+                },
+                (StorageSlotContent::Map(before_map), StorageSlotContent::Map(after_map)) => {
+                    let map_delta = StorageMapDelta::from_map_diff(before_map, after_map);
+                    if !map_delta.is_empty() {
+                        deltas.insert(slot_name.clone(), StorageSlotDelta::Map(map_delta));
+                    }
+                },
+                (StorageSlotContent::Value(_), StorageSlotContent::Map(_)) => {
+                    return Err(AccountError::StorageSlotNotValue(slot_name.clone()));
+                },
+                (StorageSlotContent::Map(_), StorageSlotContent::Value(_)) => {
+                    return Err(AccountError::StorageSlotNotMap(slot_name.clone()));
+                },
+            }
+        }
+
+        Ok(Self::from_raw(deltas))
+    }
+
     /// Returns the delta for the provided slot name, or `None` if no delta exists.
     pub fn get(&self, slot_name: &StorageSlotName) -> Option<&StorageSlotDelta> {
         self.deltas.get(slot_name)
@@ -171,7 +231,7 @@ impl AccountStorageDelta {
 
     /// Appends the storage slots delta to the given `elements` from which the delta commitment will
     /// be computed.
-    pub(super) fn append_delta_elements(&self, elements: &mut Vec<Felt>) {
+    pub(super) fn append_delta_elements(&self, elements: &mut impl DeltaElementSink) {
         const DOMAIN_VALUE: Felt = Felt::new(2);
         const DOMAIN_MAP: Felt = Felt::new(3);
 
@@ -180,31 +240,29 @@ impl AccountStorageDelta {
 
             match slot_delta {
                 StorageSlotDelta::Value(new_value) => {
-                    elements.extend_from_slice(&[
-                        DOMAIN_VALUE,
-                        ZERO,
-                        slot_id.suffix(),
-                        slot_id.prefix(),
-                    ]);
-                    elements.extend_from_slice(new_value.as_elements());
+                    elements.append_double_word(
+                        Word::from([DOMAIN_VALUE, ZERO, slot_id.suffix(), slot_id.prefix()]),
+                        *new_value,
+                    );
                 },
                 StorageSlotDelta::Map(map_delta) => {
                     for (key, value) in map_delta.entries() {
-                        elements.extend_from_slice(key.inner().as_elements());
-                        elements.extend_from_slice(value.as_elements());
+                        elements.append_double_word(*key.inner(), *value);
                     }
 
                     let num_changed_entries = Felt::try_from(map_delta.num_entries()).expect(
                         "number of changed entries should not exceed max representable felt",
                     );
 
-                    elements.extend_from_slice(&[
-                        DOMAIN_MAP,
-                        num_changed_entries,
-                        slot_id.suffix(),
-                        slot_id.prefix(),
-                    ]);
-                    elements.extend_from_slice(EMPTY_WORD.as_elements());
+                    elements.append_double_word(
+                        Word::from([
+                            DOMAIN_MAP,
+                            num_changed_entries,
+                            slot_id.suffix(),
+                            slot_id.prefix(),
+                        ]),
+                        EMPTY_WORD,
+                    );
                 },
             }
         }
@@ -473,6 +531,27 @@ impl StorageMapDelta {
         Self(map)
     }
 
+    /// Computes the map delta that transforms the `before` map into the `after` map.
+    fn from_map_diff(before: &StorageMap, after: &StorageMap) -> Self {
+        let mut delta = Self::default();
+        if before.root() == after.root() {
+            return delta;
+        }
+
+        for (raw_key, value) in after.entries() {
+            if before.get(raw_key) != *value {
+                delta.insert(*raw_key, *value);
+            }
+        }
+        for (raw_key, _) in before.entries() {
+            if after.get(raw_key) == EMPTY_WORD {
+                delta.insert(*raw_key, EMPTY_WORD);
+            }
+        }
+
+        delta
+    }
+
     /// Returns the number of changed entries in this map delta.
     pub fn num_entries(&self) -> usize {
         self.0.len()
@@ -485,6 +564,31 @@ impl StorageMapDelta {
         &self.0
     }
 
+    /// Returns an iterator over the updated entries in this storage map delta keyed by the raw map
+    /// key.
+    ///
+    /// This is the key used by the Rust-side APIs of [`StorageMap`], e.g. [`StorageMap::get`].
+    pub fn entries_raw(&self) -> impl Iterator<Item = (&Word, &Word)> + '_ {
+        self.0.iter().map(|(key, value)| (key.inner(), value))
+    }
+
+    /// Returns an iterator over the updated entries in this storage map delta keyed by the hashed
+    /// map key.
+    ///
+    /// This is the key under which the values are stored in the SMT of the storage map, see
+    /// [`StorageMap::hash_key`]. Note that the entries are yielded in the order of their raw keys.
+    pub fn entries_hashed(&self) -> impl Iterator<Item = (Word, &Word)> + '_ {
+        self.0.iter().map(|(key, value)| (StorageMap::hash_key(*key.inner()), value))
+    }
+
+    /// Returns the updated value of the provided raw map key, or `None` if the key is not changed
+    /// by this delta.
+    ///
+    /// A key that is removed from the map is reported as updated to [`EMPTY_WORD`].
+    pub fn get(&self, raw_key: &Word) -> Option<Word> {
+        self.0.get(&LexicographicWord::new(*raw_key)).copied()
+    }
+
     /// Inserts an item into the storage map delta.
     pub fn insert(&mut self, raw_key: Word, value: Word) {
         self.0.insert(LexicographicWord::new(raw_key), value);
@@ -613,13 +717,23 @@ impl Deserializable for StorageMapDelta {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use anyhow::Context;
     use assert_matches::assert_matches;
 
     use super::{AccountStorageDelta, Deserializable, Serializable};
-    use crate::account::{StorageMapDelta, StorageSlotDelta, StorageSlotName};
-    use crate::errors::AccountDeltaError;
-    use crate::{ONE, Word};
+    use crate::account::{
+        AccountStorage,
+        AppliedChanges,
+        StorageMap,
+        StorageMapDelta,
+        StorageSlot,
+        StorageSlotDelta,
+        StorageSlotName,
+    };
+    use crate::errors::{AccountDeltaError, AccountError};
+    use crate::{EMPTY_WORD, ONE, Word};
 
     #[test]
     fn account_storage_delta_returns_err_on_slot_type_mismatch() {
@@ -645,6 +759,103 @@ mod tests {
         });
     }
 
+    #[test]
+    fn storage_delta_from_account_diff() -> anyhow::Result<()> {
+        let value_slot = StorageSlotName::mock(0);
+        let unchanged_slot = StorageSlotName::mock(1);
+        let map_slot = StorageSlotName::mock(2);
+        let [key0, key1, key2] = [1u32, 2, 3].map(|k| Word::from([k, 0, 0, 0]));
+
+        let before = AccountStorage::new(vec![
+            StorageSlot::with_value(value_slot.clone(), Word::from([1, 2, 3, 4u32])),
+            StorageSlot::with_value(unchanged_slot.clone(), Word::from([5, 6, 7, 8u32])),
+            StorageSlot::with_map(
+                map_slot.clone(),
+                StorageMap::with_entries([
+                    (key0, Word::from([1u32; 4])),
+                    (key1, Word::from([1u32, 0, 0, 0])),
+                ])?,
+            ),
+        ])?;
+        let after = AccountStorage::new(vec![
+            StorageSlot::with_value(value_slot.clone(), Word::from([9, 10, 11, 12u32])),
+            StorageSlot::with_value(unchanged_slot.clone(), Word::from([5, 6, 7, 8u32])),
+            StorageSlot::with_map(
+                map_slot.clone(),
+                StorageMap::with_entries([
+                    (key0, Word::from([2u32; 4])),
+                    (key2, Word::from([1u32, 0, 0, 0])),
+                ])?,
+            ),
+        ])?;
+
+        let delta = AccountStorageDelta::from_account_diff(&before, &after)?;
+        assert_eq!(
+            delta,
+            AccountStorageDelta::new()
+                .add_updated_values([(value_slot.clone(), Word::from([9, 10, 11, 12u32]))])
+                .add_updated_maps([(
+                    map_slot.clone(),
+                    StorageMapDelta::from_iters(
+                        [key1],
+                        [(key0, Word::from([2u32; 4])), (key2, Word::from([1u32, 0, 0, 0]))]
+                    ),
+                )])
+        );
+
+        let mut applied = before.clone();
+        applied.apply_delta(&delta, &mut AppliedChanges::new(ONE))?;
+        assert_eq!(applied, after);
+
+        assert!(AccountStorageDelta::from_account_diff(&after, &after)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn storage_delta_from_account_diff_rejects_mismatched_layouts() -> anyhow::Result<()> {
+        let slot_name = StorageSlotName::mock(0);
+        let value_storage =
+            AccountStorage::new(vec![StorageSlot::with_empty_value(slot_name.clone())])?;
+        let map_storage =
+            AccountStorage::new(vec![StorageSlot::with_empty_map(slot_name.clone())])?;
+        let empty_storage = AccountStorage::new(vec![])?;
+
+        assert_matches!(
+            AccountStorageDelta::from_account_diff(&value_storage, &map_storage),
+            Err(AccountError::StorageSlotNotValue(name)) if name == slot_name
+        );
+        assert_matches!(
+            AccountStorageDelta::from_account_diff(&map_storage, &value_storage),
+            Err(AccountError::StorageSlotNotMap(name)) if name == slot_name
+        );
+        assert_matches!(
+            AccountStorageDelta::from_account_diff(&value_storage, &empty_storage),
+            Err(AccountError::StorageSlotNameNotFound { .. })
+        );
+        assert_matches!(
+            AccountStorageDelta::from_account_diff(&empty_storage, &value_storage),
+            Err(AccountError::StorageSlotNameNotFound { .. })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn storage_map_delta_get() {
+        let updated_key = Word::from([1, 2, 3, 4u32]);
+        let removed_key = Word::from([5, 6, 7, 8u32]);
+        let updated_value = Word::from([9, 10, 11, 12u32]);
+
+        let delta = StorageMapDelta::from_iters([removed_key], [(updated_key, updated_value)]);
+        assert!(!delta.is_empty());
+        assert_eq!(delta.get(&updated_key), Some(updated_value));
+        assert_eq!(delta.get(&removed_key), Some(EMPTY_WORD));
+        assert_eq!(delta.get(&Word::from([1, 1, 1, 1u32])), None);
+
+        assert!(StorageMapDelta::default().is_empty());
+    }
+
     #[test]
     fn test_is_empty() {
         let storage_delta = AccountStorageDelta::new();
@@ -718,6 +929,26 @@ mod tests {
         assert_eq!(deserialized, storage_map_delta);
     }
 
+    #[test]
+    fn storage_map_delta_raw_and_hashed_entries() {
+        let raw_key = Word::from([1, 2, 3, 4u32]);
+        let value = Word::from([5, 6, 7, 8u32]);
+        let map_delta = StorageMapDelta::from_iters([], [(raw_key, value)]);
+
+        assert_eq!(map_delta.entries_raw().collect::<Vec<_>>(), [(&raw_key, &value)]);
+        assert_eq!(
+            map_delta.entries_hashed().collect::<Vec<_>>(),
+            [(StorageMap::hash_key(raw_key), &value)]
+        );
+
+        // applying the delta results in a map which contains the value under the hashed key
+        let mut map = StorageMap::new();
+        map.apply_delta(&map_delta).unwrap();
+        for (hashed_key, value) in map_delta.entries_hashed() {
+            assert_eq!(map.get_hashed(&hashed_key), *value);
+        }
+    }
+
     #[test]
     fn test_serde_storage_slot_value_delta() {
         let slot_delta = StorageSlotDelta::with_empty_value();
@@ -2,16 +2,18 @@ use alloc::collections::BTreeMap;
 use alloc::collections::btree_map::Entry;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::fmt;
 
 use super::{
     AccountDeltaError,
     ByteReader,
     ByteWriter,
+    DeltaElementSink,
     Deserializable,
     DeserializationError,
     Serializable,
 };
-use crate::account::{AccountId, AccountType};
+use crate::account::AccountId;
 use crate::asset::{Asset, FungibleAsset, NonFungibleAsset};
 use crate::{Felt, LexicographicWord, ONE, Word, ZERO};
 
@@ -85,9 +87,67 @@ impl AccountVaultDelta {
         self.fungible.merge(other.fungible)
     }
 
+    /// Returns the changes of this delta as a flat list of [`AssetChange`]s.
+    ///
+    /// The fungible asset changes are listed first, followed by the non-fungible asset changes,
+    /// each in the order in which they are committed to in the delta commitment.
+    pub fn to_changes(&self) -> Vec<AssetChange> {
+        let fungible = self
+            .fungible
+            .iter()
+            .map(|(&faucet_id, &amount)| AssetChange::Fungible { faucet_id, amount });
+        let non_fungible = self
+            .non_fungible
+            .iter()
+            .map(|(&asset, &action)| AssetChange::NonFungible { asset, action });
+
+        fungible.chain(non_fungible).collect()
+    }
+
+    /// Creates a vault delta from a flat list of [`AssetChange`]s.
+    ///
+    /// Changes of the same fungible asset are accumulated, and changes of the same non-fungible
+    /// asset with opposite actions cancel each other out, as when the changes were tracked one by
+    /// one.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the faucet ID of a fungible asset change is not the ID of a fungible faucet.
+    /// - the absolute amount of a fungible asset change, or of the accumulated changes of the same
+    ///   fungible asset, exceeds [`FungibleAsset::MAX_AMOUNT`].
+    /// - accumulating the amounts of the same fungible asset overflows an `i64`.
+    /// - the same non-fungible asset is added or removed twice.
+    pub fn from_changes(
+        changes: impl IntoIterator<Item = AssetChange>,
+    ) -> Result<Self, AccountDeltaError> {
+        let mut delta = Self::default();
+
+        for change in changes {
+            match change {
+                AssetChange::Fungible { faucet_id, amount } => {
+                    if !faucet_id.account_type().is_fungible_faucet() {
+                        return Err(AccountDeltaError::NotAFungibleFaucetId(faucet_id));
+                    }
+                    validate_fungible_amount(faucet_id, amount)?;
+                    delta.fungible.add_delta(faucet_id, amount)?;
+
+                    // the commitment to the delta requires every amount to be a valid asset amount
+                    if let Some(accumulated) = delta.fungible.amount(&faucet_id) {
+                        validate_fungible_amount(faucet_id, accumulated)?;
+                    }
+                },
+                AssetChange::NonFungible { asset, action } => {
+                    delta.non_fungible.apply_action(asset, action)?;
+                },
+            }
+        }
+
+        Ok(delta)
+    }
+
     /// Appends the vault delta to the given `elements` from which the delta commitment will be
     /// computed.
-    pub(super) fn append_delta_elements(&self, elements: &mut Vec<Felt>) {
+    pub(super) fn append_delta_elements(&self, elements: &mut impl DeltaElementSink) {
         self.fungible().append_delta_elements(elements);
         self.non_fungible().append_delta_elements(elements);
     }
@@ -181,6 +241,16 @@ impl Deserializable for AccountVaultDelta {
     }
 }
 
+/// Returns an error if the absolute value of the provided fungible asset amount delta exceeds
+/// [`FungibleAsset::MAX_AMOUNT`].
+fn validate_fungible_amount(faucet_id: AccountId, amount: i64) -> Result<(), AccountDeltaError> {
+    if amount.unsigned_abs() > FungibleAsset::MAX_AMOUNT {
+        return Err(AccountDeltaError::FungibleAssetDeltaAmountTooLarge { faucet_id, amount });
+    }
+
+    Ok(())
+}
+
 // FUNGIBLE ASSET DELTA
 // ================================================================================================
 
@@ -233,7 +303,12 @@ impl FungibleAssetDelta {
         self.0.is_empty()
     }
 
-    /// Returns an iterator over the (key, value) pairs of the map.
+    /// Returns an iterator over the faucet IDs and amount deltas of the fungible assets in the
+    /// delta.
+    ///
+    /// Positive amounts denote additions and negative amounts denote removals; zero amounts are
+    /// never yielded. The assets are yielded in ascending order of their faucet IDs, which is the
+    /// order in which they are committed to in the delta commitment.
     pub fn iter(&self) -> impl Iterator<Item = (&AccountId, &i64)> {
         self.0.iter()
     }
@@ -300,7 +375,7 @@ impl FungibleAssetDelta {
     /// Returns an error if one or more fungible assets' faucet IDs are invalid.
     fn validate(&self) -> Result<(), AccountDeltaError> {
         for faucet_id in self.0.keys() {
-            if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            if !faucet_id.account_type().is_fungible_faucet() {
                 return Err(AccountDeltaError::NotAFungibleFaucetId(*faucet_id));
             }
         }
@@ -318,7 +393,7 @@ impl FungibleAssetDelta {
     /// prefix is unique, it will always decide on the ordering of a link map key, so less
     /// significant elements are unimportant. This implicit sort should therefore always match the
     /// link map key ordering, however this is subtle and fragile.
-    pub(super) fn append_delta_elements(&self, elements: &mut Vec<Felt>) {
+    pub(super) fn append_delta_elements(&self, elements: &mut impl DeltaElementSink) {
         for (faucet_id, amount_delta) in self.iter() {
             // Note that this iterator is guaranteed to never yield zero amounts, so we don't have
             // to exclude those explicitly.
@@ -331,8 +406,10 @@ impl FungibleAssetDelta {
                 .expect("absolute amount delta should be less than i64::MAX");
             let was_added = if *amount_delta > 0 { ONE } else { ZERO };
 
-            elements.extend_from_slice(&[DOMAIN_ASSET, was_added, ZERO, ZERO]);
-            elements.extend_from_slice(Word::from(asset).as_elements());
+            elements.append_double_word(
+                Word::from([DOMAIN_ASSET, was_added, ZERO, ZERO]),
+                Word::from(asset),
+            );
         }
     }
 }
@@ -413,11 +490,30 @@ impl NonFungibleAssetDelta {
         self.0.is_empty()
     }
 
-    /// Returns an iterator over the (key, value) pairs of the map.
+    /// Returns an iterator over the non-fungible assets in the delta and whether they were added
+    /// or removed.
+    ///
+    /// The assets are yielded in the lexicographic order of their words, which is the order in
+    /// which they are committed to in the delta commitment.
     pub fn iter(&self) -> impl Iterator<Item = (&NonFungibleAsset, &NonFungibleDeltaAction)> {
         self.0.iter().map(|(key, value)| (key.inner(), value))
     }
 
+    /// Returns the action recorded for the provided asset in this delta, or `None` if the delta
+    /// does not affect the asset.
+    pub fn action_for(&self, asset: &NonFungibleAsset) -> Option<NonFungibleDeltaAction> {
+        self.0.get(&LexicographicWord::new(*asset)).copied()
+    }
+
+    /// Returns `true` if merging `other` into this delta would fail, i.e. if both deltas add or
+    /// both deltas remove the same asset.
+    ///
+    /// This allows checking whether two deltas are compatible without attempting the merge, which
+    /// would return [`AccountDeltaError::DuplicateNonFungibleVaultUpdate`] for such assets.
+    pub fn conflicts_with(&self, other: &NonFungibleAssetDelta) -> bool {
+        other.0.iter().any(|(asset, action)| self.0.get(asset) == Some(action))
+    }
+
     /// Merges another delta into this one, overwriting any existing values.
     ///
     /// The result is validated as part of the merge.
@@ -477,15 +573,17 @@ impl NonFungibleAssetDelta {
 
     /// Appends the non-fungible asset vault delta to the given `elements` from which the delta
     /// commitment will be computed.
-    pub(super) fn append_delta_elements(&self, elements: &mut Vec<Felt>) {
+    pub(super) fn append_delta_elements(&self, elements: &mut impl DeltaElementSink) {
         for (asset, action) in self.iter() {
             let was_added = match action {
                 NonFungibleDeltaAction::Remove => ZERO,
                 NonFungibleDeltaAction::Add => ONE,
             };
 
-            elements.extend_from_slice(&[DOMAIN_ASSET, was_added, ZERO, ZERO]);
-            elements.extend_from_slice(Word::from(*asset).as_elements());
+            elements.append_double_word(
+                Word::from([DOMAIN_ASSET, was_added, ZERO, ZERO]),
+                Word::from(*asset),
+            );
         }
     }
 }
@@ -539,18 +637,77 @@ pub enum NonFungibleDeltaAction {
     Remove,
 }
 
+// ASSET CHANGE
+// ================================================================================================
+
+/// A single asset change of an [`AccountVaultDelta`].
+///
+/// This is a flat representation of the fungible and non-fungible asset deltas, see
+/// [`AccountVaultDelta::to_changes`] and [`AccountVaultDelta::from_changes`].
+///
+/// Asset changes are displayed as `+100 <faucet ID>` or `-100 <faucet ID>` for fungible assets,
+/// and as `+<asset>` or `-<asset>` for non-fungible assets, where the asset is displayed as a
+/// hex-encoded word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetChange {
+    /// The balance of the fungible asset issued by the faucet changed by the signed amount.
+    Fungible { faucet_id: AccountId, amount: i64 },
+    /// The non-fungible asset was added to or removed from the vault.
+    NonFungible {
+        asset: NonFungibleAsset,
+        action: NonFungibleDeltaAction,
+    },
+}
+
+impl fmt::Display for AssetChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetChange::Fungible { faucet_id, amount } => write!(f, "{amount:+} {faucet_id}"),
+            AssetChange::NonFungible { asset, action } => {
+                let sign = match action {
+                    NonFungibleDeltaAction::Add => '+',
+                    NonFungibleDeltaAction::Remove => '-',
+                };
+                write!(f, "{sign}{}", Word::from(*asset))
+            },
+        }
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountVaultDelta, Deserializable, Serializable};
-    use crate::account::{AccountId, AccountIdPrefix};
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::{
+        AccountDeltaError,
+        AccountVaultDelta,
+        AssetChange,
+        Deserializable,
+        FungibleAssetDelta,
+        NonFungibleAssetDelta,
+        NonFungibleDeltaAction,
+        Serializable,
+    };
+    use crate::account::{
+        AccountDelta,
+        AccountId,
+        AccountIdPrefix,
+        AccountStorageDelta,
+        AccountType,
+    };
     use crate::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
     use crate::testing::account_id::{
         ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
         ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+        AccountIdBuilder,
     };
+    use crate::{Felt, LexicographicWord, ONE, Word, ZERO};
 
     #[test]
     fn test_serde_account_vault() {
@@ -657,4 +814,304 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    /// Returns the (was_added, asset) pairs committed to by the provided commitment elements.
+    fn committed_assets(elements: &[Felt]) -> Vec<(Felt, Word)> {
+        elements
+            .chunks_exact(8)
+            .map(|double_word| {
+                (
+                    double_word[1],
+                    Word::from([double_word[4], double_word[5], double_word[6], double_word[7]]),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fungible_asset_delta_iteration_order_matches_commitment_order() {
+        let faucet_ids: Vec<AccountId> = (0..5u8)
+            .map(|seed| {
+                AccountIdBuilder::new()
+                    .account_type(AccountType::FungibleFaucet)
+                    .build_with_seed([seed; 32])
+            })
+            .collect();
+
+        let mut delta = FungibleAssetDelta::default();
+        for (idx, faucet_id) in faucet_ids.iter().enumerate() {
+            let asset = FungibleAsset::new(*faucet_id, 100 + idx as u64).unwrap();
+            if idx % 2 == 0 {
+                delta.add(asset).unwrap();
+            } else {
+                delta.remove(asset).unwrap();
+            }
+        }
+
+        let iterated: Vec<(AccountId, i64)> =
+            delta.iter().map(|(faucet_id, amount)| (*faucet_id, *amount)).collect();
+        assert_eq!(iterated.len(), faucet_ids.len());
+        assert!(iterated.is_sorted_by_key(|(faucet_id, _)| *faucet_id));
+
+        let mut elements = Vec::new();
+        delta.append_delta_elements(&mut elements);
+
+        let expected: Vec<(Felt, Word)> = iterated
+            .iter()
+            .map(|(faucet_id, amount)| {
+                let was_added = if *amount > 0 { ONE } else { ZERO };
+                let asset = FungibleAsset::new(*faucet_id, amount.unsigned_abs()).unwrap();
+                (was_added, Word::from(asset))
+            })
+            .collect();
+        assert_eq!(committed_assets(&elements), expected);
+    }
+
+    #[test]
+    fn non_fungible_asset_delta_iteration_order_matches_commitment_order() {
+        let faucet_id_prefix = NonFungibleAsset::mock_issuer().prefix();
+        let asset_data = [[9, 9, 9, 9], [1, 2, 3, 4], [4, 3, 2, 1], [5, 5, 5, 5]];
+        let assets: Vec<NonFungibleAsset> = asset_data
+            .into_iter()
+            .map(|data| {
+                let details =
+                    NonFungibleAssetDetails::new(faucet_id_prefix, data.to_vec()).unwrap();
+                NonFungibleAsset::new(&details).unwrap()
+            })
+            .collect();
+
+        let mut delta = NonFungibleAssetDelta::default();
+        for (idx, asset) in assets.iter().enumerate() {
+            if idx % 2 == 0 {
+                delta.add(*asset).unwrap();
+            } else {
+                delta.remove(*asset).unwrap();
+            }
+        }
+
+        let iterated: Vec<(NonFungibleAsset, NonFungibleDeltaAction)> =
+            delta.iter().map(|(asset, action)| (*asset, *action)).collect();
+        assert_eq!(iterated.len(), assets.len());
+        assert!(iterated.is_sorted_by_key(|(asset, _)| LexicographicWord::new(*asset)));
+
+        let mut elements = Vec::new();
+        delta.append_delta_elements(&mut elements);
+
+        let expected: Vec<(Felt, Word)> = iterated
+            .iter()
+            .map(|(asset, action)| {
+                let was_added = match action {
+                    NonFungibleDeltaAction::Add => ONE,
+                    NonFungibleDeltaAction::Remove => ZERO,
+                };
+                (was_added, Word::from(*asset))
+            })
+            .collect();
+        assert_eq!(committed_assets(&elements), expected);
+    }
+
+    #[test]
+    fn non_fungible_asset_delta_conflicts() {
+        let faucet_id_prefix = NonFungibleAsset::mock_issuer().prefix();
+        let [asset0, asset1, asset2] = [[1, 2, 3], [4, 5, 6], [7, 8, 9]].map(|data| {
+            let details = NonFungibleAssetDetails::new(faucet_id_prefix, data.to_vec()).unwrap();
+            NonFungibleAsset::new(&details).unwrap()
+        });
+
+        let mut delta = NonFungibleAssetDelta::default();
+        delta.add(asset0).unwrap();
+        delta.remove(asset1).unwrap();
+
+        assert_eq!(delta.action_for(&asset0), Some(NonFungibleDeltaAction::Add));
+        assert_eq!(delta.action_for(&asset1), Some(NonFungibleDeltaAction::Remove));
+        assert_eq!(delta.action_for(&asset2), None);
+
+        // opposite actions cancel out and unrelated assets are independent
+        let mut compatible = NonFungibleAssetDelta::default();
+        compatible.remove(asset0).unwrap();
+        compatible.add(asset1).unwrap();
+        compatible.add(asset2).unwrap();
+        assert!(!delta.conflicts_with(&compatible));
+        assert!(!compatible.conflicts_with(&delta));
+
+        let mut merged = delta.clone();
+        merged.merge(compatible).unwrap();
+        assert_eq!(merged.action_for(&asset0), None);
+        assert_eq!(merged.action_for(&asset1), None);
+        assert_eq!(merged.action_for(&asset2), Some(NonFungibleDeltaAction::Add));
+
+        // the same action on the same asset conflicts
+        for (asset, action) in
+            [(asset0, NonFungibleDeltaAction::Add), (asset1, NonFungibleDeltaAction::Remove)]
+        {
+            let mut conflicting = NonFungibleAssetDelta::default();
+            match action {
+                NonFungibleDeltaAction::Add => conflicting.add(asset).unwrap(),
+                NonFungibleDeltaAction::Remove => conflicting.remove(asset).unwrap(),
+            }
+            conflicting.add(asset2).unwrap();
+
+            assert!(delta.conflicts_with(&conflicting));
+            assert!(conflicting.conflicts_with(&delta));
+            assert_matches!(
+                delta.clone().merge(conflicting).unwrap_err(),
+                AccountDeltaError::DuplicateNonFungibleVaultUpdate(duplicate) if duplicate == asset
+            );
+        }
+    }
+
+    #[test]
+    fn asset_changes_round_trip() {
+        let faucet0 = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let faucet1 = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let nft0 = NonFungibleAsset::mock(&[1, 2, 3]).unwrap_non_fungible();
+        let nft1 = NonFungibleAsset::mock(&[4, 5, 6]).unwrap_non_fungible();
+
+        let delta = AccountVaultDelta::from_iters(
+            [FungibleAsset::new(faucet0, 100).unwrap().into(), nft0.into()],
+            [FungibleAsset::new(faucet1, 50).unwrap().into(), nft1.into()],
+        );
+
+        let changes = delta.to_changes();
+        assert_eq!(changes.len(), 4);
+        assert!(changes.contains(&AssetChange::Fungible { faucet_id: faucet0, amount: 100 }));
+        assert!(changes.contains(&AssetChange::Fungible { faucet_id: faucet1, amount: -50 }));
+        assert!(changes.contains(&AssetChange::NonFungible {
+            asset: nft0,
+            action: NonFungibleDeltaAction::Add,
+        }));
+        assert!(changes.contains(&AssetChange::NonFungible {
+            asset: nft1,
+            action: NonFungibleDeltaAction::Remove,
+        }));
+        assert_eq!(AccountVaultDelta::from_changes(changes).unwrap(), delta);
+        assert_eq!(
+            AccountVaultDelta::from_changes(AccountVaultDelta::default().to_changes()).unwrap(),
+            AccountVaultDelta::default()
+        );
+
+        assert_eq!(
+            AssetChange::Fungible { faucet_id: faucet0, amount: 100 }.to_string(),
+            format!("+100 {faucet0}")
+        );
+        assert_eq!(
+            AssetChange::Fungible { faucet_id: faucet1, amount: -50 }.to_string(),
+            format!("-50 {faucet1}")
+        );
+        assert_eq!(
+            AssetChange::NonFungible {
+                asset: nft1,
+                action: NonFungibleDeltaAction::Remove
+            }
+            .to_string(),
+            format!("-{}", Word::from(nft1))
+        );
+    }
+
+    #[test]
+    fn asset_changes_at_max_amount_can_be_committed_to() {
+        let faucet0 = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let faucet1 = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let max_amount = FungibleAsset::MAX_AMOUNT as i64;
+
+        let vault_delta = AccountVaultDelta::from_changes([
+            AssetChange::Fungible { faucet_id: faucet0, amount: max_amount },
+            AssetChange::Fungible { faucet_id: faucet1, amount: -max_amount },
+        ])
+        .unwrap();
+        let account_id = AccountIdBuilder::new()
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .build_with_seed([5; 32]);
+        let delta =
+            AccountDelta::new(account_id, AccountStorageDelta::default(), vault_delta.clone(), ONE)
+                .unwrap();
+
+        let expected = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::default(),
+            AccountVaultDelta::from_iters(
+                [FungibleAsset::new(faucet0, FungibleAsset::MAX_AMOUNT).unwrap().into()],
+                [FungibleAsset::new(faucet1, FungibleAsset::MAX_AMOUNT).unwrap().into()],
+            ),
+            ONE,
+        )
+        .unwrap();
+        assert_eq!(vault_delta, *expected.vault());
+        assert_eq!(delta.to_commitment(), expected.to_commitment());
+    }
+
+    #[test]
+    fn asset_changes_are_validated() {
+        let faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let nft = NonFungibleAsset::mock(&[1, 2, 3]).unwrap_non_fungible();
+        let add_nft = AssetChange::NonFungible {
+            asset: nft,
+            action: NonFungibleDeltaAction::Add,
+        };
+        let remove_nft = AssetChange::NonFungible {
+            asset: nft,
+            action: NonFungibleDeltaAction::Remove,
+        };
+
+        // changes of the same asset are accumulated
+        let delta = AccountVaultDelta::from_changes([
+            AssetChange::Fungible { faucet_id: faucet, amount: 100 },
+            AssetChange::Fungible { faucet_id: faucet, amount: -30 },
+            add_nft,
+            remove_nft,
+        ])
+        .unwrap();
+        assert_eq!(delta.fungible().amount(&faucet), Some(70));
+        assert!(delta.non_fungible().is_empty());
+
+        // amounts must be valid asset amounts, individually and accumulated
+        let max_amount = FungibleAsset::MAX_AMOUNT as i64;
+        for amount in [i64::MAX, i64::MIN, max_amount + 1, -max_amount - 1] {
+            assert_matches!(
+                AccountVaultDelta::from_changes([AssetChange::Fungible { faucet_id: faucet, amount }]),
+                Err(AccountDeltaError::FungibleAssetDeltaAmountTooLarge { faucet_id, amount: err_amount }) => {
+                    assert_eq!(faucet_id, faucet);
+                    assert_eq!(err_amount, amount);
+                }
+            );
+        }
+        assert_matches!(
+            AccountVaultDelta::from_changes([
+                AssetChange::Fungible { faucet_id: faucet, amount: max_amount },
+                AssetChange::Fungible { faucet_id: faucet, amount: 1 },
+            ]),
+            Err(AccountDeltaError::FungibleAssetDeltaAmountTooLarge { amount, .. }) => {
+                assert_eq!(amount, max_amount + 1);
+            }
+        );
+        assert_matches!(
+            AccountVaultDelta::from_changes([
+                AssetChange::Fungible { faucet_id: faucet, amount: max_amount },
+                AssetChange::Fungible { faucet_id: faucet, amount: max_amount },
+            ]),
+            Err(AccountDeltaError::FungibleAssetDeltaOverflow { faucet_id, current, delta }) => {
+                assert_eq!(faucet_id, faucet);
+                assert_eq!(current, max_amount);
+                assert_eq!(delta, max_amount);
+            }
+        );
+
+        assert_matches!(
+            AccountVaultDelta::from_changes([add_nft, add_nft]),
+            Err(AccountDeltaError::DuplicateNonFungibleVaultUpdate(asset)) if asset == nft
+        );
+        assert_matches!(
+            AccountVaultDelta::from_changes([remove_nft, remove_nft]),
+            Err(AccountDeltaError::DuplicateNonFungibleVaultUpdate(asset)) if asset == nft
+        );
+
+        let account_id = AccountIdBuilder::new()
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .build_with_seed([5; 32]);
+        let change = AssetChange::Fungible { faucet_id: account_id, amount: 1 };
+        assert_matches!(
+            AccountVaultDelta::from_changes([change]),
+            Err(AccountDeltaError::NotAFungibleFaucetId(id)) if id == account_id
+        );
+    }
 }
@@ -11,7 +11,6 @@ use crate::transaction::memory::{
     ACCT_NONCE_IDX,
     ACCT_STORAGE_COMMITMENT_OFFSET,
     ACCT_VAULT_ROOT_OFFSET,
-    MemoryOffset,
 };
 use crate::utils::serde::{Deserializable, Serializable};
 use crate::{WORD_SIZE, Word, WordError};
@@ -38,6 +37,31 @@ pub struct AccountHeader {
 }
 
 impl AccountHeader {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of field elements the account header is encoded into, see
+    /// [`AccountHeader::to_elements`].
+    pub const NUM_ELEMENTS: usize = ACCT_DATA_MEM_SIZE;
+
+    /// The index of the account ID suffix in the encoded account header.
+    pub const ID_SUFFIX_IDX: usize = ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_ID_SUFFIX_IDX;
+
+    /// The index of the account ID prefix in the encoded account header.
+    pub const ID_PREFIX_IDX: usize = ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_ID_PREFIX_IDX;
+
+    /// The index of the account nonce in the encoded account header.
+    pub const NONCE_IDX: usize = ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_NONCE_IDX;
+
+    /// The offset of the vault root word in the encoded account header.
+    pub const VAULT_ROOT_OFFSET: usize = ACCT_VAULT_ROOT_OFFSET as usize;
+
+    /// The offset of the storage commitment word in the encoded account header.
+    pub const STORAGE_COMMITMENT_OFFSET: usize = ACCT_STORAGE_COMMITMENT_OFFSET as usize;
+
+    /// The offset of the code commitment word in the encoded account header.
+    pub const CODE_COMMITMENT_OFFSET: usize = ACCT_CODE_COMMITMENT_OFFSET as usize;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Creates a new [AccountHeader].
@@ -57,28 +81,33 @@ impl AccountHeader {
         }
     }
 
-    /// Parses the account header data returned by the VM into individual account component
-    /// commitments. Returns a tuple of account ID, vault root, storage commitment, code
-    /// commitment, and nonce.
-    pub(crate) fn try_from_elements(elements: &[Felt]) -> Result<AccountHeader, AccountError> {
-        if elements.len() != ACCT_DATA_MEM_SIZE {
+    /// Parses an account header from the provided field elements.
+    ///
+    /// The elements are expected to be laid out as produced by [`AccountHeader::to_elements`],
+    /// which is also the layout of the account data in the memory of the transaction kernel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the number of elements is not [`AccountHeader::NUM_ELEMENTS`].
+    /// - the account ID encoded in the elements is invalid.
+    pub fn try_from_elements(elements: &[Felt]) -> Result<AccountHeader, AccountError> {
+        if elements.len() != Self::NUM_ELEMENTS {
             return Err(AccountError::HeaderDataIncorrectLength {
                 actual: elements.len(),
-                expected: ACCT_DATA_MEM_SIZE,
+                expected: Self::NUM_ELEMENTS,
             });
         }
 
-        let id = AccountId::try_from([
-            elements[ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_ID_PREFIX_IDX],
-            elements[ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_ID_SUFFIX_IDX],
-        ])
-        .map_err(AccountError::FinalAccountHeaderIdParsingFailed)?;
-        let nonce = elements[ACCT_ID_AND_NONCE_OFFSET as usize + ACCT_NONCE_IDX];
-        let vault_root = parse_word(elements, ACCT_VAULT_ROOT_OFFSET)
+        let id =
+            AccountId::try_from([elements[Self::ID_PREFIX_IDX], elements[Self::ID_SUFFIX_IDX]])
+                .map_err(AccountError::FinalAccountHeaderIdParsingFailed)?;
+        let nonce = elements[Self::NONCE_IDX];
+        let vault_root = parse_word(elements, Self::VAULT_ROOT_OFFSET)
             .expect("we should have sliced off exactly 4 bytes");
-        let storage_commitment = parse_word(elements, ACCT_STORAGE_COMMITMENT_OFFSET)
+        let storage_commitment = parse_word(elements, Self::STORAGE_COMMITMENT_OFFSET)
             .expect("we should have sliced off exactly 4 bytes");
-        let code_commitment = parse_word(elements, ACCT_CODE_COMMITMENT_OFFSET)
+        let code_commitment = parse_word(elements, Self::CODE_COMMITMENT_OFFSET)
             .expect("we should have sliced off exactly 4 bytes");
 
         Ok(AccountHeader::new(id, nonce, vault_root, storage_commitment, code_commitment))
@@ -127,7 +156,7 @@ impl AccountHeader {
         self.code_commitment
     }
 
-    /// Converts the account header into a vector of field elements.
+    /// Converts the account header into an array of field elements.
     ///
     /// This is done by first converting the account header data into an array of Words as follows:
     /// ```text
@@ -138,15 +167,26 @@ impl AccountHeader {
     ///     [CODE_COMMITMENT]
     /// ]
     /// ```
-    /// And then concatenating the resulting elements into a single vector.
+    /// And then concatenating the resulting elements into a single array. The position of each
+    /// field is given by the constants of [`AccountHeader`], e.g. [`AccountHeader::NONCE_IDX`].
+    ///
+    /// This is the inverse of [`AccountHeader::try_from_elements`].
+    pub fn to_elements(&self) -> [Felt; Self::NUM_ELEMENTS] {
+        let mut elements = [ZERO; Self::NUM_ELEMENTS];
+        elements[Self::ID_SUFFIX_IDX] = self.id.suffix();
+        elements[Self::ID_PREFIX_IDX] = self.id.prefix().as_felt();
+        elements[Self::NONCE_IDX] = self.nonce;
+        write_word(&mut elements, Self::VAULT_ROOT_OFFSET, self.vault_root);
+        write_word(&mut elements, Self::STORAGE_COMMITMENT_OFFSET, self.storage_commitment);
+        write_word(&mut elements, Self::CODE_COMMITMENT_OFFSET, self.code_commitment);
+        elements
+    }
+
+    /// Converts the account header into a vector of field elements.
+    ///
+    /// See [`AccountHeader::to_elements`] for the layout of the elements.
     pub fn as_elements(&self) -> Vec<Felt> {
-        [
-            &[self.id.suffix(), self.id.prefix().as_felt(), ZERO, self.nonce],
-            self.vault_root.as_elements(),
-            self.storage_commitment.as_elements(),
-            self.code_commitment.as_elements(),
-        ]
-        .concat()
+        self.to_elements().to_vec()
     }
 }
 
@@ -179,9 +219,9 @@ impl From<&Account> for AccountHeader {
         Self {
             id: account.id(),
             nonce: account.nonce(),
-            vault_root: account.vault().root(),
-            storage_commitment: account.storage().to_commitment(),
-            code_commitment: account.code().commitment(),
+            vault_root: account.vault_commitment(),
+            storage_commitment: account.storage_commitment(),
+            code_commitment: account.code_commitment(),
         }
     }
 }
@@ -220,8 +260,13 @@ impl Deserializable for AccountHeader {
 // ================================================================================================
 
 /// Creates a new `Word` instance from the slice of `Felt`s using provided offset.
-fn parse_word(data: &[Felt], offset: MemoryOffset) -> Result<Word, WordError> {
-    Word::try_from(&data[offset as usize..offset as usize + WORD_SIZE])
+fn parse_word(data: &[Felt], offset: usize) -> Result<Word, WordError> {
+    Word::try_from(&data[offset..offset + WORD_SIZE])
+}
+
+/// Writes the provided word into the slice of `Felt`s at the provided offset.
+fn write_word(data: &mut [Felt], offset: usize, word: Word) {
+    data[offset..offset + WORD_SIZE].copy_from_slice(word.as_elements());
 }
 
 // TESTS
@@ -229,6 +274,7 @@ fn parse_word(data: &[Felt], offset: MemoryOffset) -> Result<Word, WordError> {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use miden_core::Felt;
     use miden_core::utils::{Deserializable, Serializable};
 
@@ -237,6 +283,7 @@ mod tests {
     use crate::account::StorageSlotContent;
     use crate::account::tests::build_account;
     use crate::asset::FungibleAsset;
+    use crate::errors::AccountError;
 
     #[test]
     fn test_serde_account_storage() {
@@ -252,4 +299,33 @@ mod tests {
         let deserialized_header = AccountHeader::read_from_bytes(&header_bytes).unwrap();
         assert_eq!(deserialized_header, account_header);
     }
+
+    #[test]
+    fn account_header_elements_roundtrip() {
+        let word = Word::from([1, 2, 3, 4u32]);
+        let account = build_account(
+            vec![FungibleAsset::mock(99)],
+            Felt::new(7),
+            vec![StorageSlotContent::Value(word)],
+        );
+        let account_header = AccountHeader::from(&account);
+
+        let elements = account_header.to_elements();
+        assert_eq!(elements[AccountHeader::ID_SUFFIX_IDX], account.id().suffix());
+        assert_eq!(elements[AccountHeader::ID_PREFIX_IDX], account.id().prefix().as_felt());
+        assert_eq!(elements[AccountHeader::NONCE_IDX], Felt::new(7));
+        let vault_root =
+            &elements[AccountHeader::VAULT_ROOT_OFFSET..AccountHeader::VAULT_ROOT_OFFSET + 4];
+        assert_eq!(vault_root, account.vault().root().as_elements());
+        assert_eq!(account_header.as_elements(), elements.to_vec());
+
+        let parsed_header = AccountHeader::try_from_elements(&elements).unwrap();
+        assert_eq!(parsed_header, account_header);
+
+        let error = AccountHeader::try_from_elements(&elements[1..]).unwrap_err();
+        assert_matches!(
+            error,
+            AccountError::HeaderDataIncorrectLength { actual: 15, expected: 16 }
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use crate::asset::{Asset, AssetVault};
+use crate::asset::{Asset, AssetVault, FungibleAsset};
 use crate::errors::AccountError;
 use crate::utils::serde::{
     ByteReader,
@@ -17,6 +17,7 @@ pub use account_id::{
     AccountId,
     AccountIdPrefix,
     AccountIdPrefixV0,
+    AccountIdSeed,
     AccountIdV0,
     AccountIdVersion,
     AccountStorageMode,
@@ -38,8 +39,11 @@ pub use component::{AccountComponent, AccountComponentCode, AccountComponentMeta
 pub mod delta;
 pub use delta::{
     AccountDelta,
+    AccountDeltaSizeBreakdown,
     AccountStorageDelta,
     AccountVaultDelta,
+    AppliedChanges,
+    AssetChange,
     FungibleAssetDelta,
     NonFungibleAssetDelta,
     NonFungibleDeltaAction,
@@ -51,6 +55,7 @@ pub mod storage;
 pub use storage::{
     AccountStorage,
     AccountStorageHeader,
+    FaucetSysdata,
     PartialStorage,
     PartialStorageMap,
     StorageMap,
@@ -116,6 +121,8 @@ impl Account {
     /// - an account seed is not provided but the account's nonce indicates the account is new.
     /// - an account seed is provided but the account ID derived from it is invalid or does not
     ///   match the provided account's ID.
+    /// - the account is a fungible faucet and its [`FaucetSysdata`] slot is missing or invalid,
+    ///   e.g. because the total issuance exceeds the maximum amount of a fungible asset.
     pub fn new(
         id: AccountId,
         vault: AssetVault,
@@ -126,6 +133,10 @@ impl Account {
     ) -> Result<Self, AccountError> {
         validate_account_seed(id, code.commitment(), storage.to_commitment(), seed, nonce)?;
 
+        if id.account_type() == AccountType::FungibleFaucet {
+            FaucetSysdata::read(&storage)?;
+        }
+
         Ok(Self::new_unchecked(id, vault, storage, code, nonce, seed))
     }
 
@@ -211,12 +222,27 @@ impl Account {
         hash_account(
             self.id,
             self.nonce,
-            self.vault.root(),
-            self.storage.to_commitment(),
-            self.code.commitment(),
+            self.vault_commitment(),
+            self.storage_commitment(),
+            self.code_commitment(),
         )
     }
 
+    /// Returns the commitment to the vault of this account, i.e. the root of its asset vault.
+    pub fn vault_commitment(&self) -> Word {
+        self.vault.root()
+    }
+
+    /// Returns the commitment to the storage of this account.
+    pub fn storage_commitment(&self) -> Word {
+        self.storage.to_commitment()
+    }
+
+    /// Returns the commitment to the code of this account.
+    pub fn code_commitment(&self) -> Word {
+        self.code.commitment()
+    }
+
     /// Returns the commitment of this account as used for the initial account state commitment in
     /// transaction proofs.
     ///
@@ -323,6 +349,8 @@ impl Account {
     /// Applies the provided delta to this account. This updates account vault, storage, and nonce
     /// to the values specified by the delta.
     ///
+    /// See [`Self::apply_delta_with_changes`] for a variant that also reports what changed.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -333,23 +361,58 @@ impl Account {
     /// - The nonce specified in the provided delta smaller than or equal to the current account
     ///   nonce.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
+        self.apply_delta_with_changes(delta).map(|_| ())
+    }
+
+    /// Applies the provided delta to this account and returns an [`AppliedChanges`] report
+    /// describing the assets, storage values, storage map entries and nonce that changed.
+    ///
+    /// The report is computed while the delta is applied, i.e. without diffing the account
+    /// states before and after the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the same cases as [`Self::apply_delta`].
+    pub fn apply_delta_with_changes(
+        &mut self,
+        delta: &AccountDelta,
+    ) -> Result<AppliedChanges, AccountError> {
         if delta.is_full_state() {
             return Err(AccountError::ApplyFullStateDeltaToAccount);
         }
 
+        let mut changes = AppliedChanges::new(self.nonce);
+
         // update vault; we don't check vault delta validity here because `AccountDelta` can contain
         // only valid vault deltas
         self.vault
             .apply_delta(delta.vault())
             .map_err(AccountError::AssetVaultUpdateError)?;
+        for (&faucet_id, &amount) in delta.vault().fungible().iter() {
+            let asset = FungibleAsset::new(faucet_id, amount.unsigned_abs())
+                .expect("fungible asset delta was applied to the vault")
+                .into();
+            if amount >= 0 {
+                changes.record_added_asset(asset);
+            } else {
+                changes.record_removed_asset(asset);
+            }
+        }
+        for (&asset, &action) in delta.vault().non_fungible().iter() {
+            match action {
+                NonFungibleDeltaAction::Add => changes.record_added_asset(asset.into()),
+                NonFungibleDeltaAction::Remove => changes.record_removed_asset(asset.into()),
+            }
+        }
 
         // update storage
-        self.storage.apply_delta(delta.storage())?;
+        self.storage.apply_delta(delta.storage(), &mut changes)?;
 
         // update nonce
         self.increment_nonce(delta.nonce_delta())?;
+        changes.set_new_nonce(self.nonce);
 
-        Ok(())
+        Ok(changes)
     }
 
     /// Increments the nonce of this account by the provided increment.
@@ -577,6 +640,7 @@ fn validate_components_support_account_type(
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::{BTreeMap, BTreeSet};
     use alloc::vec::Vec;
 
     use assert_matches::assert_matches;
@@ -584,6 +648,8 @@ mod tests {
     use miden_core::FieldElement;
     use miden_crypto::utils::{Deserializable, Serializable};
     use miden_crypto::{Felt, Word};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
 
     use super::{
         AccountCode,
@@ -598,8 +664,10 @@ mod tests {
         Account,
         AccountBuilder,
         AccountComponent,
+        AccountHeader,
         AccountIdVersion,
         AccountType,
+        FaucetSysdata,
         PartialAccount,
         StorageMap,
         StorageMapDelta,
@@ -629,6 +697,18 @@ mod tests {
         assert_eq!(deserialized, account);
     }
 
+    #[test]
+    fn test_account_commitment_accessors() {
+        let asset_0 = FungibleAsset::mock(99);
+        let storage_slot = StorageSlotContent::Value(Word::from([1, 2, 3, 4u32]));
+        let account = build_account(vec![asset_0], Felt::new(1), vec![storage_slot]);
+
+        assert_eq!(account.vault_commitment(), account.vault().root());
+        assert_eq!(account.storage_commitment(), account.storage().to_commitment());
+        assert_eq!(account.code_commitment(), account.code().commitment());
+        assert_eq!(account.commitment(), AccountHeader::from(&account).commitment());
+    }
+
     #[test]
     fn test_serde_account_delta() {
         let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
@@ -793,6 +873,168 @@ mod tests {
         account.apply_delta(&account_delta).unwrap()
     }
 
+    #[test]
+    fn apply_delta_with_changes_matches_account_diff() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let map_keys = [Word::from([101, 102, 103, 104u32]), Word::from([105, 106, 107, 108u32])];
+        let existing_nft = NonFungibleAsset::mock(&[1, 2, 3]);
+
+        for _ in 0..50 {
+            let storage_map = StorageMap::with_entries(
+                map_keys.iter().map(|key| (*key, Word::from([1, 2, 3, rng.random::<u32>()]))),
+            )
+            .unwrap();
+            let value_0 = Word::from([5, 6, 7, 8u32]);
+            let mut account = build_account(
+                vec![FungibleAsset::mock(100), existing_nft],
+                Felt::new(1),
+                vec![
+                    StorageSlotContent::Value(value_0),
+                    StorageSlotContent::Value(Word::empty()),
+                    StorageSlotContent::Map(storage_map),
+                ],
+            );
+            let initial_account = account.clone();
+
+            // build a random delta
+            let fungible_amount = rng.random_range(1..=50);
+            let (mut added_assets, mut removed_assets) = if rng.random() {
+                (vec![FungibleAsset::mock(fungible_amount)], vec![])
+            } else {
+                (vec![], vec![FungibleAsset::mock(fungible_amount)])
+            };
+            if rng.random() {
+                removed_assets.push(existing_nft);
+            } else {
+                added_assets.push(NonFungibleAsset::mock(&[4, rng.random::<u8>()]));
+            }
+
+            // randomly keep the current value of the first slot to check that unchanged values are
+            // not reported
+            let new_value_0 = if rng.random() { value_0 } else { random_word(&mut rng) };
+            let mut updated_map_entries = Vec::new();
+            for key in map_keys.into_iter().chain([random_word(&mut rng)]) {
+                if rng.random() {
+                    let value = if rng.random() {
+                        Word::empty()
+                    } else {
+                        random_word(&mut rng)
+                    };
+                    updated_map_entries.push((key, value));
+                }
+            }
+            let storage_delta = AccountStorageDelta::new()
+                .add_updated_values([
+                    (StorageSlotName::mock(0), new_value_0),
+                    (StorageSlotName::mock(1), random_word(&mut rng)),
+                ])
+                .add_updated_maps([(
+                    StorageSlotName::mock(2),
+                    StorageMapDelta::from_iters([], updated_map_entries),
+                )]);
+            let account_delta = build_account_delta(
+                account.id(),
+                added_assets,
+                removed_assets,
+                Felt::new(1),
+                storage_delta,
+            );
+
+            let changes = account.apply_delta_with_changes(&account_delta).unwrap();
+            let (expected_added, expected_removed, expected_values, expected_map_entries) =
+                diff_accounts(&initial_account, &account);
+
+            let to_words =
+                |assets: &[Asset]| assets.iter().copied().map(Word::from).collect::<BTreeSet<_>>();
+            assert_eq!(to_words(changes.added_assets()), expected_added);
+            assert_eq!(to_words(changes.removed_assets()), expected_removed);
+            assert_eq!(changes.updated_values(), &expected_values);
+            assert_eq!(changes.updated_map_entries(), &expected_map_entries);
+            assert_eq!(changes.new_nonce(), account.nonce());
+            assert_eq!(changes.new_nonce(), Felt::new(2));
+        }
+    }
+
+    fn random_word(rng: &mut impl Rng) -> Word {
+        Word::from([rng.random::<u32>(), rng.random(), rng.random(), rng.random()])
+    }
+
+    /// Independently computes the changes between two states of the same account by comparing
+    /// their vaults and storages.
+    #[allow(clippy::type_complexity)]
+    fn diff_accounts(
+        before: &Account,
+        after: &Account,
+    ) -> (
+        BTreeSet<Word>,
+        BTreeSet<Word>,
+        BTreeMap<StorageSlotName, (Word, Word)>,
+        BTreeMap<StorageSlotName, BTreeMap<Word, (Word, Word)>>,
+    ) {
+        let mut added_assets = BTreeSet::new();
+        let mut removed_assets = BTreeSet::new();
+
+        let mut fungible_diffs = BTreeMap::<AccountId, i128>::new();
+        for asset in after.vault().assets() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    *fungible_diffs.entry(asset.faucet_id()).or_default() += asset.amount() as i128;
+                },
+                Asset::NonFungible(_) if !before.vault().assets().any(|a| a == asset) => {
+                    added_assets.insert(Word::from(asset));
+                },
+                Asset::NonFungible(_) => (),
+            }
+        }
+        for asset in before.vault().assets() {
+            match asset {
+                Asset::Fungible(asset) => {
+                    *fungible_diffs.entry(asset.faucet_id()).or_default() -= asset.amount() as i128;
+                },
+                Asset::NonFungible(_) if !after.vault().assets().any(|a| a == asset) => {
+                    removed_assets.insert(Word::from(asset));
+                },
+                Asset::NonFungible(_) => (),
+            }
+        }
+        for (faucet_id, diff) in fungible_diffs.into_iter().filter(|(_, diff)| *diff != 0) {
+            let asset = FungibleAsset::new(faucet_id, diff.unsigned_abs() as u64).unwrap();
+            if diff > 0 {
+                added_assets.insert(Word::from(Asset::Fungible(asset)));
+            } else {
+                removed_assets.insert(Word::from(Asset::Fungible(asset)));
+            }
+        }
+
+        let mut updated_values = BTreeMap::new();
+        let mut updated_map_entries = BTreeMap::new();
+        for slot in before.storage().slots() {
+            let after_slot = after.storage().get(slot.name()).unwrap();
+            match (slot.content(), after_slot.content()) {
+                (StorageSlotContent::Value(old), StorageSlotContent::Value(new)) => {
+                    if old != new {
+                        updated_values.insert(slot.name().clone(), (*old, *new));
+                    }
+                },
+                (StorageSlotContent::Map(old), StorageSlotContent::Map(new)) => {
+                    let keys: BTreeSet<Word> =
+                        old.entries().chain(new.entries()).map(|(key, _)| *key).collect();
+                    let changed_entries: BTreeMap<Word, (Word, Word)> = keys
+                        .into_iter()
+                        .map(|key| (key, (old.get(&key), new.get(&key))))
+                        .filter(|(_, (old_value, new_value))| old_value != new_value)
+                        .collect();
+                    if !changed_entries.is_empty() {
+                        updated_map_entries.insert(slot.name().clone(), changed_entries);
+                    }
+                },
+                _ => panic!("slot types should not change"),
+            }
+        }
+
+        (added_assets, removed_assets, updated_values, updated_map_entries)
+    }
+
     pub fn build_account_delta(
         account_id: AccountId,
         added_assets: Vec<Asset>,
@@ -920,6 +1162,40 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that the total issuance of fungible faucets is validated when creating accounts.
+    #[test]
+    fn fungible_faucet_issuance_validation() -> anyhow::Result<()> {
+        let account = AccountBuilder::new([5; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_auth_component(NoopAuthComponent)
+            .with_component(AddComponent)
+            .build_existing()?;
+        let (id, vault, mut storage, code, nonce, _) = account.into_parts();
+
+        let mut sysdata = FaucetSysdata::read(&storage)?;
+        assert_eq!(sysdata.issued(), 0);
+
+        // issuing up to the maximum amount is valid
+        let issued_word = sysdata.apply_issuance(FungibleAsset::MAX_AMOUNT)?;
+        storage.set_item(FaucetSysdata::slot_name(), issued_word)?;
+        Account::new(id, vault.clone(), storage.clone(), code.clone(), nonce, None)?;
+
+        // an issuance beyond the maximum amount is invalid
+        let overflowing_word = Word::from([
+            Felt::ZERO,
+            Felt::ZERO,
+            Felt::ZERO,
+            Felt::new(FungibleAsset::MAX_AMOUNT + 1),
+        ]);
+        storage.set_item(FaucetSysdata::slot_name(), overflowing_word)?;
+        let err = Account::new(id, vault, storage, code, nonce, None).unwrap_err();
+        assert_matches!(err, AccountError::FaucetIssuanceExceedsMaxAmount(issued) => {
+            assert_eq!(issued, FungibleAsset::MAX_AMOUNT + 1);
+        });
+
+        Ok(())
+    }
+
     #[test]
     fn incrementing_nonce_should_remove_seed() -> anyhow::Result<()> {
         let mut account = AccountBuilder::new([5; 32])
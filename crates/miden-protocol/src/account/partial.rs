@@ -70,6 +70,26 @@ impl PartialAccount {
         Ok(account)
     }
 
+    /// Creates a new [`PartialAccount`] tracking the full storage and vault of the provided
+    /// account.
+    ///
+    /// Unlike the conversion via [`From<&Account>`], which tracks only the minimal necessary data
+    /// of existing accounts, the returned partial account contains all storage map entries and
+    /// assets of the account. Executing a transaction against it therefore never requires
+    /// retrieving additional witnesses and behaves identically to executing against the full
+    /// account.
+    pub fn from_full(account: &Account) -> Self {
+        Self::new(
+            account.id(),
+            account.nonce(),
+            account.code().clone(),
+            PartialStorage::new_full(account.storage().clone()),
+            PartialVault::new_full(account.vault().clone()),
+            account.seed(),
+        )
+        .expect("account should ensure that seed is valid for account")
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
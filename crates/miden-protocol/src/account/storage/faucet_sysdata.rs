@@ -0,0 +1,244 @@
+use super::{AccountError, AccountStorage, StorageSlotContent, StorageSlotName};
+use crate::asset::FungibleAsset;
+use crate::{Felt, Word, ZERO, slot_name};
+
+const FAUCET_SYSDATA_SLOT_NAME: &StorageSlotName = &slot_name!("miden::protocol::faucet::sysdata");
+
+// FAUCET SYSDATA
+// ================================================================================================
+
+/// The protocol system data of a fungible faucet, stored in the protocol-reserved
+/// [`FaucetSysdata::slot_name`] slot.
+///
+/// The slot stores the total issuance of the faucet's token as `[0, 0, 0, issued]`. The issuance
+/// is updated by the transaction kernel whenever the faucet mints or burns assets and can never
+/// exceed [`FungibleAsset::MAX_AMOUNT`]. Faucet components which define a max supply for their
+/// token can validate the issuance against it via [`FaucetSysdata::validate_max_supply`].
+///
+/// For non-fungible faucets the reserved slot is a map of the issued assets instead, which is not
+/// represented by this type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FaucetSysdata {
+    issued: u64,
+}
+
+impl FaucetSysdata {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The index of the total issuance in the value of the sysdata slot.
+    const ISSUED_IDX: usize = 3;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new faucet system data with the provided total issuance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `issued` exceeds [`FungibleAsset::MAX_AMOUNT`].
+    pub fn new(issued: u64) -> Result<Self, AccountError> {
+        if issued > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::FaucetIssuanceExceedsMaxAmount(issued));
+        }
+
+        Ok(Self { issued })
+    }
+
+    /// Reads the faucet system data from the sysdata slot of the provided storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The storage does not contain the sysdata slot or it is not a value slot.
+    /// - The value of the slot does not have the layout `[0, 0, 0, issued]`.
+    /// - The issuance exceeds [`FungibleAsset::MAX_AMOUNT`].
+    pub fn read(storage: &AccountStorage) -> Result<Self, AccountError> {
+        let slot_name = Self::slot_name();
+        let slot = storage.get(slot_name).ok_or_else(|| AccountError::StorageSlotNameNotFound {
+            slot_name: slot_name.clone(),
+        })?;
+        let StorageSlotContent::Value(value) = slot.content() else {
+            return Err(AccountError::StorageSlotNotValue(slot_name.clone()));
+        };
+
+        if value.as_elements()[..Self::ISSUED_IDX].iter().any(|element| *element != ZERO) {
+            return Err(AccountError::StorageSlotInvalidLayout {
+                slot_name: slot_name.clone(),
+                expected_layout: "faucet sysdata",
+                source: None,
+            });
+        }
+
+        Self::new(value[Self::ISSUED_IDX].as_int())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [`StorageSlotName`] of the protocol-reserved faucet sysdata slot.
+    ///
+    /// Account components can never declare a slot with this name.
+    pub fn slot_name() -> &'static StorageSlotName {
+        FAUCET_SYSDATA_SLOT_NAME
+    }
+
+    /// Returns the total issuance of the faucet's token.
+    pub fn issued(&self) -> u64 {
+        self.issued
+    }
+
+    /// Returns the amount of tokens that can still be issued before the issuance reaches
+    /// [`FungibleAsset::MAX_AMOUNT`].
+    pub fn remaining(&self) -> u64 {
+        FungibleAsset::MAX_AMOUNT - self.issued
+    }
+
+    /// Returns the value of the sysdata slot representing this system data.
+    pub fn to_word(&self) -> Word {
+        Word::from([ZERO, ZERO, ZERO, Felt::new(self.issued)])
+    }
+
+    /// Validates the total issuance against the provided max supply of the faucet's token.
+    ///
+    /// The protocol does not define where a faucet stores the max supply of its token, so it is
+    /// up to the faucet components to read it from their storage and validate it here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issuance exceeds `max_supply`.
+    pub fn validate_max_supply(&self, max_supply: u64) -> Result<(), AccountError> {
+        if self.issued > max_supply {
+            return Err(AccountError::FaucetIssuanceExceedsMaxSupply {
+                issued: self.issued,
+                max_supply,
+            });
+        }
+
+        Ok(())
+    }
+
+    // MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Increases the total issuance by `amount` and returns the updated value of the sysdata slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the new issuance would exceed [`FungibleAsset::MAX_AMOUNT`], in which
+    /// case the system data is left unchanged.
+    pub fn apply_issuance(&mut self, amount: u64) -> Result<Word, AccountError> {
+        *self = Self::new(self.issued.saturating_add(amount))?;
+        Ok(self.to_word())
+    }
+}
+
+impl From<FaucetSysdata> for Word {
+    fn from(sysdata: FaucetSysdata) -> Self {
+        sysdata.to_word()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::FaucetSysdata;
+    use crate::account::{AccountStorage, StorageSlot};
+    use crate::asset::FungibleAsset;
+    use crate::errors::AccountError;
+    use crate::{Felt, ONE, Word, ZERO};
+
+    fn storage_with_sysdata(value: Word) -> AccountStorage {
+        AccountStorage::new(vec![StorageSlot::with_value(
+            FaucetSysdata::slot_name().clone(),
+            value,
+        )])
+        .unwrap()
+    }
+
+    #[test]
+    fn faucet_sysdata_read_and_apply_issuance() -> anyhow::Result<()> {
+        assert_eq!(AccountStorage::faucet_sysdata_slot(), FaucetSysdata::slot_name());
+
+        let storage = storage_with_sysdata(Word::from([ZERO, ZERO, ZERO, Felt::new(100)]));
+        let mut sysdata = FaucetSysdata::read(&storage)?;
+        assert_eq!(sysdata.issued(), 100);
+        assert_eq!(sysdata.remaining(), FungibleAsset::MAX_AMOUNT - 100);
+
+        let updated = sysdata.apply_issuance(50)?;
+        assert_eq!(updated, Word::from([ZERO, ZERO, ZERO, Felt::new(150)]));
+        assert_eq!(FaucetSysdata::read(&storage_with_sysdata(updated))?, sysdata);
+
+        // an empty slot is the sysdata of a faucet that has not issued any tokens
+        let storage = storage_with_sysdata(Word::empty());
+        assert_eq!(FaucetSysdata::read(&storage)?, FaucetSysdata::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn faucet_sysdata_issuance_overflow() -> anyhow::Result<()> {
+        let mut sysdata = FaucetSysdata::new(FungibleAsset::MAX_AMOUNT - 10)?;
+
+        let err = sysdata.apply_issuance(11).unwrap_err();
+        assert_matches!(err, AccountError::FaucetIssuanceExceedsMaxAmount(issued) => {
+            assert_eq!(issued, FungibleAsset::MAX_AMOUNT + 1);
+        });
+        assert_eq!(sysdata.issued(), FungibleAsset::MAX_AMOUNT - 10);
+
+        assert_matches!(
+            sysdata.apply_issuance(u64::MAX).unwrap_err(),
+            AccountError::FaucetIssuanceExceedsMaxAmount(_)
+        );
+
+        sysdata.apply_issuance(10)?;
+        assert_eq!(sysdata.remaining(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn faucet_sysdata_read_rejects_malformed_slots() {
+        let too_large = Felt::new(FungibleAsset::MAX_AMOUNT + 1);
+        assert_matches!(
+            FaucetSysdata::read(&storage_with_sysdata(Word::from([ZERO, ZERO, ZERO, too_large]))),
+            Err(AccountError::FaucetIssuanceExceedsMaxAmount(_))
+        );
+
+        assert_matches!(
+            FaucetSysdata::read(&storage_with_sysdata(Word::from([ONE, ZERO, ZERO, ONE]))),
+            Err(AccountError::StorageSlotInvalidLayout { .. })
+        );
+
+        let map_storage = AccountStorage::new(vec![StorageSlot::with_empty_map(
+            FaucetSysdata::slot_name().clone(),
+        )])
+        .unwrap();
+        assert_matches!(
+            FaucetSysdata::read(&map_storage),
+            Err(AccountError::StorageSlotNotValue(_))
+        );
+
+        assert_matches!(
+            FaucetSysdata::read(&AccountStorage::new(vec![]).unwrap()),
+            Err(AccountError::StorageSlotNameNotFound { .. })
+        );
+    }
+
+    #[test]
+    fn faucet_sysdata_validate_max_supply() -> anyhow::Result<()> {
+        let sysdata = FaucetSysdata::new(1000)?;
+        sysdata.validate_max_supply(1000)?;
+
+        assert_matches!(
+            sysdata.validate_max_supply(999).unwrap_err(),
+            AccountError::FaucetIssuanceExceedsMaxSupply { issued: 1000, max_supply: 999 }
+        );
+
+        Ok(())
+    }
+}
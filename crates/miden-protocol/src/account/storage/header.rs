@@ -5,6 +5,7 @@ use alloc::vec::Vec;
 
 use super::map::EMPTY_STORAGE_MAP_ROOT;
 use super::{AccountStorage, Felt, StorageSlotType, Word};
+use crate::ZERO;
 use crate::account::{StorageSlot, StorageSlotId, StorageSlotName};
 use crate::crypto::SequentialCommit;
 use crate::errors::AccountError;
@@ -15,7 +16,6 @@ use crate::utils::serde::{
     DeserializationError,
     Serializable,
 };
-use crate::{FieldElement, ZERO};
 
 // ACCOUNT STORAGE HEADER
 // ================================================================================================
@@ -32,6 +32,25 @@ pub struct AccountStorageHeader {
 }
 
 impl AccountStorageHeader {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of field elements each slot is encoded into, see
+    /// [`AccountStorageHeader::to_elements`].
+    pub const NUM_ELEMENTS_PER_SLOT: usize = StorageSlot::NUM_ELEMENTS;
+
+    /// The index of the slot type within the encoding of a slot.
+    pub const SLOT_TYPE_IDX: usize = 1;
+
+    /// The index of the slot ID suffix within the encoding of a slot.
+    pub const SLOT_ID_SUFFIX_IDX: usize = 2;
+
+    /// The index of the slot ID prefix within the encoding of a slot.
+    pub const SLOT_ID_PREFIX_IDX: usize = 3;
+
+    /// The offset of the slot value word within the encoding of a slot.
+    pub const SLOT_VALUE_OFFSET: usize = 4;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -123,6 +142,14 @@ impl AccountStorageHeader {
         self.slots.iter().find(|slot| slot.id() == slot_id)
     }
 
+    /// Returns the names of the slots in this header, keyed by their slot IDs.
+    ///
+    /// Since [`AccountStorageHeader::to_elements`] only commits to slot IDs, the returned map is
+    /// what [`AccountStorageHeader::try_from_elements`] needs to reconstruct this header.
+    pub fn slot_names(&self) -> BTreeMap<StorageSlotId, StorageSlotName> {
+        self.slots.iter().map(|slot| (slot.id(), slot.name().clone())).collect()
+    }
+
     /// Indicates whether the slot with the given `name` is a map slot.
     ///
     /// # Errors
@@ -148,7 +175,11 @@ impl AccountStorageHeader {
     /// [[0, slot_type, slot_id_suffix, slot_id_prefix], SLOT_VALUE]
     /// ```
     ///
-    /// And then concatenating the resulting elements into a single vector.
+    /// And then concatenating the resulting elements into a single vector. The position of each
+    /// field within a slot is given by the constants of [`AccountStorageHeader`], e.g.
+    /// [`AccountStorageHeader::SLOT_TYPE_IDX`].
+    ///
+    /// This is the inverse of [`AccountStorageHeader::try_from_elements`].
     pub fn to_elements(&self) -> Vec<Felt> {
         <Self as SequentialCommit>::to_elements(self)
     }
@@ -156,26 +187,27 @@ impl AccountStorageHeader {
     /// Reconstructs an [`AccountStorageHeader`] from field elements with provided slot names.
     ///
     /// The elements are expected to be groups of 8 elements per slot:
-    /// `[[0, slot_type, slot_id_suffix, slot_id_prefix], SLOT_VALUE]`
+    /// `[[0, slot_type, slot_id_suffix, slot_id_prefix], SLOT_VALUE]`, as produced by
+    /// [`AccountStorageHeader::to_elements`].
     pub fn try_from_elements(
         elements: &[Felt],
         slot_names: &BTreeMap<StorageSlotId, StorageSlotName>,
     ) -> Result<Self, AccountError> {
-        if !elements.len().is_multiple_of(StorageSlot::NUM_ELEMENTS) {
+        if !elements.len().is_multiple_of(Self::NUM_ELEMENTS_PER_SLOT) {
             return Err(AccountError::other(
                 "storage header elements length must be divisible by 8",
             ));
         }
 
         let mut slots = Vec::new();
-        for chunk in elements.chunks_exact(StorageSlot::NUM_ELEMENTS) {
-            // Parse slot type from second element.
-            let slot_type_felt = chunk[1];
+        for chunk in elements.chunks_exact(Self::NUM_ELEMENTS_PER_SLOT) {
+            // Parse slot type.
+            let slot_type_felt = chunk[Self::SLOT_TYPE_IDX];
             let slot_type = slot_type_felt.try_into()?;
 
-            // Parse slot ID from third and fourth elements.
-            let slot_id_suffix = chunk[2];
-            let slot_id_prefix = chunk[3];
+            // Parse slot ID.
+            let slot_id_suffix = chunk[Self::SLOT_ID_SUFFIX_IDX];
+            let slot_id_prefix = chunk[Self::SLOT_ID_PREFIX_IDX];
             let parsed_slot_id = StorageSlotId::new(slot_id_suffix, slot_id_prefix);
 
             // Retrieve slot name from the map.
@@ -183,8 +215,9 @@ impl AccountStorageHeader {
                 format!("slot name not found for slot ID {}", parsed_slot_id),
             ))?;
 
-            // Parse slot value from last 4 elements.
-            let slot_value = Word::new([chunk[4], chunk[5], chunk[6], chunk[7]]);
+            // Parse slot value.
+            let slot_value = Word::try_from(&chunk[Self::SLOT_VALUE_OFFSET..])
+                .expect("slot value should consist of the last 4 elements of the slot");
 
             let slot_header = StorageSlotHeader::new(slot_name, slot_type, slot_value);
             slots.push(slot_header);
@@ -305,13 +338,11 @@ impl StorageSlotHeader {
     pub(crate) fn to_elements(&self) -> [Felt; StorageSlot::NUM_ELEMENTS] {
         let id = self.id();
         let mut elements = [ZERO; StorageSlot::NUM_ELEMENTS];
-        elements[0..4].copy_from_slice(&[
-            Felt::ZERO,
-            self.r#type.as_felt(),
-            id.suffix(),
-            id.prefix(),
-        ]);
-        elements[4..8].copy_from_slice(self.value.as_elements());
+        elements[AccountStorageHeader::SLOT_TYPE_IDX] = self.r#type.as_felt();
+        elements[AccountStorageHeader::SLOT_ID_SUFFIX_IDX] = id.suffix();
+        elements[AccountStorageHeader::SLOT_ID_PREFIX_IDX] = id.prefix();
+        elements[AccountStorageHeader::SLOT_VALUE_OFFSET..]
+            .copy_from_slice(self.value.as_elements());
         elements
     }
 }
@@ -420,6 +451,19 @@ mod tests {
         let single_slot_header = AccountStorageHeader::new(vec![slot1.clone()]).unwrap();
         let single_elements = single_slot_header.to_elements();
 
+        // Check the layout of the elements.
+        assert_eq!(single_elements.len(), AccountStorageHeader::NUM_ELEMENTS_PER_SLOT);
+        assert_eq!(
+            single_elements[AccountStorageHeader::SLOT_TYPE_IDX],
+            StorageSlotType::Value.as_felt()
+        );
+        assert_eq!(single_elements[AccountStorageHeader::SLOT_ID_SUFFIX_IDX], slot1.id().suffix());
+        assert_eq!(single_elements[AccountStorageHeader::SLOT_ID_PREFIX_IDX], slot1.id().prefix());
+        assert_eq!(
+            &single_elements[AccountStorageHeader::SLOT_VALUE_OFFSET..],
+            slot1.value().as_elements()
+        );
+
         // Call from_elements.
         let slot_names = BTreeMap::from([(slot1.id(), slot1.name().clone())]);
         let reconstructed_single =
@@ -459,6 +503,25 @@ mod tests {
             AccountStorageHeader::try_from_elements(&multi_elements, &slot_names).unwrap();
 
         assert_eq!(multi_slot_header, reconstructed_multi);
+        assert_eq!(multi_slot_header.slot_names(), slot_names);
+    }
+
+    #[test]
+    fn test_to_elements_from_elements_round_trip_with_slot_names() {
+        let storage = AccountStorage::mock();
+        let header = AccountStorageHeader::from(&storage);
+
+        let reconstructed =
+            AccountStorageHeader::try_from_elements(&header.to_elements(), &header.slot_names())
+                .unwrap();
+        assert_eq!(reconstructed, header);
+
+        // the slot names of all value and map slots are preserved
+        for slot in storage.slots() {
+            let reconstructed_slot = reconstructed.find_slot_header_by_id(slot.id()).unwrap();
+            assert_eq!(reconstructed_slot.name(), slot.name());
+            assert_eq!(reconstructed_slot.slot_type(), slot.slot_type());
+        }
     }
 
     #[test]
@@ -136,6 +136,15 @@ impl StorageMap {
         self.entries.get(raw_key).copied().unwrap_or_default()
     }
 
+    /// Returns the value corresponding to the hashed key or [`Self::EMPTY_VALUE`] if the key is
+    /// not associated with a value.
+    ///
+    /// The `hashed_key` is the key under which the value is stored in the underlying [`Smt`], i.e.
+    /// the result of [`Self::hash_key`] applied to the raw key.
+    pub fn get_hashed(&self, hashed_key: &Word) -> Word {
+        self.smt.get_value(hashed_key)
+    }
+
     /// Returns an opening of the leaf associated with raw key.
     ///
     /// Conceptually, an opening is a Merkle path to the leaf, as well as the leaf itself.
@@ -207,7 +216,19 @@ impl StorageMap {
     // UTILITY FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
-    /// Hashes the given key to get the key of the SMT.
+    /// Hashes the given raw map key to get the key of the underlying [`Smt`].
+    ///
+    /// All values of a storage map are stored in the SMT under the hash of their raw key, which
+    /// keeps the leaves of the SMT uniformly distributed regardless of the keys chosen by the user.
+    /// Consequently:
+    /// - Rust-side APIs such as [`Self::get`], [`Self::insert`] and [`Self::open`] take the raw
+    ///   key.
+    /// - The SMT proof of a [`StorageMapWitness`] and the leaf index of an entry are derived from
+    ///   the hashed key.
+    ///
+    /// The transaction kernel applies the same hashing when reading from or writing to a storage
+    /// map. Witnesses for map items are requested from the data store by raw key, while the SMT
+    /// proofs provided to the kernel are always keyed by the hashed key.
     pub fn hash_key(raw_key: Word) -> Word {
         Hasher::hash_elements(raw_key.as_elements())
     }
@@ -297,4 +318,33 @@ mod tests {
         let error = StorageMap::with_entries(storage_map_leaves_2).unwrap_err();
         assert_matches!(error, StorageMapError::DuplicateKey { .. });
     }
+
+    #[test]
+    fn storage_map_raw_and_hashed_keys() {
+        let raw_key = Word::from([101, 102, 103, 104u32]);
+        let value = Word::from([1, 2, 3, 4u32]);
+        let hashed_key = StorageMap::hash_key(raw_key);
+        let storage_map = StorageMap::with_entries([(raw_key, value)]).unwrap();
+
+        // the Rust-side API is keyed by the raw key
+        assert_eq!(storage_map.get(&raw_key), value);
+        assert_eq!(storage_map.get(&hashed_key), Word::empty());
+        assert_eq!(storage_map.entries().next(), Some((&raw_key, &value)));
+
+        // the SMT is keyed by the hashed key
+        assert_eq!(storage_map.get_hashed(&hashed_key), value);
+        assert_eq!(storage_map.get_hashed(&raw_key), Word::empty());
+        assert_eq!(
+            StorageMap::map_key_to_leaf_index(raw_key),
+            StorageMap::hashed_map_key_to_leaf_index(hashed_key)
+        );
+
+        // the witness exposes the raw key, while its proof contains the hashed key
+        let witness = storage_map.open(&raw_key);
+        assert_eq!(witness.get(&raw_key), Some(value));
+        assert_eq!(witness.entries().next(), Some((&raw_key, &value)));
+        assert_eq!(witness.proof().get(&hashed_key), Some(value));
+        assert_eq!(witness.proof().get(&raw_key), None);
+        assert!(witness.proof().verify_membership(&hashed_key, &value, &storage_map.root()));
+    }
 }
@@ -1,9 +1,12 @@
+use alloc::boxed::Box;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::error::Error;
 
 use super::{
     AccountError,
     AccountStorageDelta,
+    AppliedChanges,
     ByteReader,
     ByteWriter,
     Deserializable,
@@ -12,7 +15,10 @@ use super::{
     Serializable,
     Word,
 };
-use crate::account::{AccountComponent, AccountType};
+use crate::ZERO;
+use crate::account::auth::PublicKeyCommitment;
+use crate::account::{AccountComponent, AccountId, AccountType};
+use crate::asset::{FungibleAsset, TokenSymbol};
 use crate::crypto::SequentialCommit;
 use crate::utils::sync::LazyLock;
 
@@ -28,13 +34,14 @@ pub use header::{AccountStorageHeader, StorageSlotHeader};
 mod partial;
 pub use partial::PartialStorage;
 
-static FAUCET_SYSDATA_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::protocol::faucet::sysdata")
-        .expect("storage slot name should be valid")
-});
+mod faucet_sysdata;
+pub use faucet_sysdata::FaucetSysdata;
 
+/// All slot names reserved by the protocol.
+///
+/// Reserved slot names cannot be used by account components.
 static RESERVED_SLOT_NAMES: LazyLock<Vec<StorageSlotName>> =
-    LazyLock::new(|| vec![FAUCET_SYSDATA_SLOT_NAME.clone()]);
+    LazyLock::new(|| vec![FaucetSysdata::slot_name().clone()]);
 
 /// Returns `true` if the provided slot name is reserved by the protocol.
 pub fn is_reserved_slot_name(slot_name: &StorageSlotName) -> bool {
@@ -135,7 +142,7 @@ impl AccountStorage {
             let AccountComponent { storage_slots, .. } = component;
             storage_slots.into_iter()
         }) {
-            if is_reserved_slot_name(component_slot.name()) {
+            if component_slot.name().is_reserved() {
                 return Err(AccountError::StorageSlotNameMustNotBeFaucetSysdata);
             }
 
@@ -149,8 +156,10 @@ impl AccountStorage {
     // --------------------------------------------------------------------------------------------
 
     /// Returns the [`StorageSlotName`] of the faucet's protocol system data.
+    ///
+    /// This is the same as [`FaucetSysdata::slot_name`].
     pub fn faucet_sysdata_slot() -> &'static StorageSlotName {
-        &FAUCET_SYSDATA_SLOT_NAME
+        FaucetSysdata::slot_name()
     }
 
     /// Converts storage slots of this account storage into a vector of field elements.
@@ -192,6 +201,15 @@ impl AccountStorage {
             .expect("slots should be valid as ensured by AccountStorage")
     }
 
+    /// Returns an iterator over the names of the protocol-reserved slots present in this storage,
+    /// e.g. [`AccountStorage::faucet_sysdata_slot`] for faucet accounts.
+    pub fn reserved_slots(&self) -> impl Iterator<Item = &StorageSlotName> {
+        self.slots
+            .iter()
+            .map(StorageSlot::name)
+            .filter(|slot_name| slot_name.is_reserved())
+    }
+
     /// Returns a reference to the storage slot with the provided name, if it exists, `None`
     /// otherwise.
     pub fn get(&self, slot_name: &StorageSlotName) -> Option<&StorageSlot> {
@@ -218,6 +236,8 @@ impl AccountStorage {
 
     /// Returns a map item from the map in the storage slot with the given name.
     ///
+    /// The `key` is the raw map key, see [`StorageMap::hash_key`] for details.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -228,10 +248,139 @@ impl AccountStorage {
         slot_name: &StorageSlotName,
         key: Word,
     ) -> Result<Word, AccountError> {
+        self.get_map(slot_name).map(|map| map.get(&key))
+    }
+
+    /// Returns a map item from the map in the storage slot with the given name by its hashed key.
+    ///
+    /// The `hashed_key` is the key under which the item is stored in the SMT of the map, i.e. the
+    /// result of [`StorageMap::hash_key`] applied to the raw key. This is useful when working with
+    /// keys taken from storage map witnesses, whose SMT proofs are keyed by the hashed key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A slot with the provided name does not exist.
+    /// - If the [`StorageSlot`] is not [`StorageSlotType::Map`].
+    pub fn get_map_item_hashed(
+        &self,
+        slot_name: &StorageSlotName,
+        hashed_key: Word,
+    ) -> Result<Word, AccountError> {
+        self.get_map(slot_name).map(|map| map.get_hashed(&hashed_key))
+    }
+
+    /// Returns the account ID stored in the value slot with the given name.
+    ///
+    /// The value must have the layout `[0, 0, suffix, prefix]` produced by
+    /// [`StorageSlot::with_account_id`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A slot with the provided name does not exist.
+    /// - The [`StorageSlot`] is not [`StorageSlotType::Value`].
+    /// - The value does not have the expected layout or does not encode a valid account ID.
+    pub fn get_account_id(&self, slot_name: &StorageSlotName) -> Result<AccountId, AccountError> {
+        const LAYOUT: &str = "account ID";
+
+        let value = self.get_value(slot_name)?;
+        if value[0] != ZERO || value[1] != ZERO {
+            return Err(invalid_layout(slot_name, LAYOUT, None));
+        }
+
+        AccountId::try_from([value[3], value[2]])
+            .map_err(|err| invalid_layout(slot_name, LAYOUT, Some(Box::new(err))))
+    }
+
+    /// Returns the public key commitment stored in the value slot with the given name, e.g. by
+    /// [`StorageSlot::with_public_key`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A slot with the provided name does not exist.
+    /// - The [`StorageSlot`] is not [`StorageSlotType::Value`].
+    pub fn get_public_key_commitment(
+        &self,
+        slot_name: &StorageSlotName,
+    ) -> Result<PublicKeyCommitment, AccountError> {
+        self.get_value(slot_name).map(PublicKeyCommitment::from)
+    }
+
+    /// Returns the token symbol, decimals and max supply stored in the value slot with the given
+    /// name.
+    ///
+    /// The value must have the layout `[max_supply, decimals, token_symbol, 0]` produced by
+    /// [`StorageSlot::with_token_metadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A slot with the provided name does not exist.
+    /// - The [`StorageSlot`] is not [`StorageSlotType::Value`].
+    /// - The value does not have the expected layout, the decimals do not fit into a `u8`, the
+    ///   token symbol is invalid or the max supply exceeds [`FungibleAsset::MAX_AMOUNT`].
+    pub fn get_token_metadata(
+        &self,
+        slot_name: &StorageSlotName,
+    ) -> Result<(TokenSymbol, u8, Felt), AccountError> {
+        const LAYOUT: &str = "token metadata";
+
+        let value = self.get_value(slot_name)?;
+        let [max_supply, decimals, token_symbol, padding] = *value;
+        if padding != ZERO || max_supply.as_int() > FungibleAsset::MAX_AMOUNT {
+            return Err(invalid_layout(slot_name, LAYOUT, None));
+        }
+
+        let decimals = u8::try_from(decimals.as_int())
+            .map_err(|err| invalid_layout(slot_name, LAYOUT, Some(Box::new(err))))?;
+        let token_symbol = TokenSymbol::try_from(token_symbol)
+            .map_err(|err| invalid_layout(slot_name, LAYOUT, Some(Box::new(err))))?;
+
+        Ok((token_symbol, decimals, max_supply))
+    }
+
+    /// Returns the pair of `u64` values stored in the value slot with the given name.
+    ///
+    /// The value must consist of the 32-bit limbs `[a_lo, a_hi, b_lo, b_hi]` produced by
+    /// [`StorageSlot::with_u64_pair`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A slot with the provided name does not exist.
+    /// - The [`StorageSlot`] is not [`StorageSlotType::Value`].
+    /// - Any of the elements of the value is not a valid `u32` limb.
+    pub fn get_u64_pair(&self, slot_name: &StorageSlotName) -> Result<(u64, u64), AccountError> {
+        let value = self.get_value(slot_name)?;
+
+        let mut limbs = [0u64; 4];
+        for (limb, element) in limbs.iter_mut().zip(value.iter()) {
+            *limb = u32::try_from(element.as_int())
+                .map_err(|err| invalid_layout(slot_name, "u64 pair", Some(Box::new(err))))?
+                .into();
+        }
+
+        Ok((limbs[0] | (limbs[1] << 32), limbs[2] | (limbs[3] << 32)))
+    }
+
+    /// Returns the value in the value slot with the given name.
+    fn get_value(&self, slot_name: &StorageSlotName) -> Result<Word, AccountError> {
         self.get(slot_name)
             .ok_or_else(|| AccountError::StorageSlotNameNotFound { slot_name: slot_name.clone() })
             .and_then(|slot| match slot.content() {
-                StorageSlotContent::Map(map) => Ok(map.get(&key)),
+                StorageSlotContent::Value(value) => Ok(*value),
+                _ => Err(AccountError::StorageSlotNotValue(slot_name.clone())),
+            })
+    }
+
+    /// Returns the map in the storage slot with the given name.
+    fn get_map(&self, slot_name: &StorageSlotName) -> Result<&StorageMap, AccountError> {
+        self.get(slot_name)
+            .ok_or_else(|| AccountError::StorageSlotNameNotFound { slot_name: slot_name.clone() })
+            .and_then(|slot| match slot.content() {
+                StorageSlotContent::Map(map) => Ok(map),
                 _ => Err(AccountError::StorageSlotNotMap(slot_name.clone())),
             })
     }
@@ -239,16 +388,22 @@ impl AccountStorage {
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Applies the provided delta to this account storage.
+    /// Applies the provided delta to this account storage and records the changed values and map
+    /// entries in the provided [`AppliedChanges`].
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The updates violate storage constraints.
-    pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+    pub(super) fn apply_delta(
+        &mut self,
+        delta: &AccountStorageDelta,
+        changes: &mut AppliedChanges,
+    ) -> Result<(), AccountError> {
         // Update storage values
         for (slot_name, &value) in delta.values() {
-            self.set_item(slot_name, value)?;
+            let old_value = self.set_item(slot_name, value)?;
+            changes.record_value_update(slot_name, old_value, value);
         }
 
         // Update storage maps
@@ -262,7 +417,11 @@ impl AccountStorage {
                 _ => return Err(AccountError::StorageSlotNotMap(slot_name.clone())),
             };
 
-            storage_map.apply_delta(map_delta)?;
+            for (&key, &value) in map_delta.entries() {
+                let raw_key = key.into_inner();
+                let old_value = storage_map.insert(raw_key, value)?;
+                changes.record_map_entry_update(slot_name, raw_key, old_value, value);
+            }
         }
 
         Ok(())
@@ -394,16 +553,45 @@ impl Deserializable for AccountStorage {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the error for a value slot whose value does not have the expected layout.
+fn invalid_layout(
+    slot_name: &StorageSlotName,
+    expected_layout: &'static str,
+    source: Option<Box<dyn Error + Send + Sync + 'static>>,
+) -> AccountError {
+    AccountError::StorageSlotInvalidLayout {
+        slot_name: slot_name.clone(),
+        expected_layout,
+        source,
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use assert_matches::assert_matches;
 
     use super::{AccountStorage, Deserializable, Serializable};
-    use crate::account::{AccountStorageHeader, StorageSlot, StorageSlotHeader, StorageSlotName};
+    use crate::account::auth::AuthSecretKey;
+    use crate::account::{
+        AccountId,
+        AccountStorageHeader,
+        AccountType,
+        StorageSlot,
+        StorageSlotHeader,
+        StorageSlotName,
+    };
+    use crate::asset::{FungibleAsset, TokenSymbol};
     use crate::errors::AccountError;
+    use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+    use crate::{Felt, ONE, Word, ZERO};
 
     #[test]
     fn test_serde_account_storage() -> anyhow::Result<()> {
@@ -472,4 +660,111 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reserved_slots() -> anyhow::Result<()> {
+        let faucet_storage = AccountStorage::from_components(vec![], AccountType::FungibleFaucet)?;
+        assert_eq!(
+            faucet_storage.reserved_slots().collect::<Vec<_>>(),
+            [AccountStorage::faucet_sysdata_slot()]
+        );
+
+        let regular_storage = AccountStorage::new(vec![StorageSlot::with_empty_value(
+            StorageSlotName::new("miden::test::value")?,
+        )])?;
+        assert_eq!(regular_storage.reserved_slots().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_storage_slots_round_trip() -> anyhow::Result<()> {
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+        let public_key = AuthSecretKey::new_falcon512_rpo().public_key();
+        let token_symbol = TokenSymbol::new("POL")?;
+        let max_supply = Felt::new(FungibleAsset::MAX_AMOUNT);
+
+        let account_id_slot = StorageSlotName::mock(0);
+        let public_key_slot = StorageSlotName::mock(1);
+        let metadata_slot = StorageSlotName::mock(2);
+        let u64_pair_slot = StorageSlotName::mock(3);
+
+        let storage = AccountStorage::new(vec![
+            StorageSlot::with_account_id(account_id_slot.clone(), account_id),
+            StorageSlot::with_public_key(public_key_slot.clone(), &public_key),
+            StorageSlot::with_token_metadata(metadata_slot.clone(), token_symbol, 8, max_supply),
+            StorageSlot::with_u64_pair(u64_pair_slot.clone(), u64::MAX, 1 << 40),
+        ])?;
+
+        assert_eq!(storage.get_account_id(&account_id_slot)?, account_id);
+        assert_eq!(
+            storage.get_public_key_commitment(&public_key_slot)?,
+            public_key.to_commitment()
+        );
+        assert_eq!(storage.get_token_metadata(&metadata_slot)?, (token_symbol, 8, max_supply));
+        assert_eq!(storage.get_u64_pair(&u64_pair_slot)?, (u64::MAX, 1 << 40));
+
+        // the token metadata uses the layout of the fungible faucet metadata slot
+        assert_eq!(
+            storage.get_item(&metadata_slot)?,
+            Word::from([max_supply, Felt::from(8u8), token_symbol.into(), ZERO])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_storage_getters_reject_malformed_slots() -> anyhow::Result<()> {
+        let map_slot = StorageSlotName::mock(0);
+        let padded_slot = StorageSlotName::mock(1);
+        let invalid_id_slot = StorageSlotName::mock(2);
+        let invalid_metadata_slot = StorageSlotName::mock(3);
+        let invalid_limb_slot = StorageSlotName::mock(4);
+
+        let storage = AccountStorage::new(vec![
+            StorageSlot::with_empty_map(map_slot.clone()),
+            // non-zero padding
+            StorageSlot::with_value(padded_slot.clone(), Word::from([1u32, 1, 1, 1])),
+            // the prefix and suffix do not form a valid account ID
+            StorageSlot::with_value(invalid_id_slot.clone(), Word::from([ZERO, ZERO, ONE, ONE])),
+            // decimals do not fit into a u8
+            StorageSlot::with_value(
+                invalid_metadata_slot.clone(),
+                Word::from([ONE, Felt::new(256), ONE, ZERO]),
+            ),
+            // limbs exceed u32::MAX
+            StorageSlot::with_value(
+                invalid_limb_slot.clone(),
+                Word::from([Felt::new(u64::from(u32::MAX) + 1), ZERO, ZERO, ZERO]),
+            ),
+        ])?;
+
+        assert_matches!(
+            storage.get_account_id(&map_slot).unwrap_err(),
+            AccountError::StorageSlotNotValue(name) if name == map_slot
+        );
+        assert_matches!(
+            storage.get_public_key_commitment(&StorageSlotName::mock(5)).unwrap_err(),
+            AccountError::StorageSlotNameNotFound { .. }
+        );
+
+        for slot_name in [&padded_slot, &invalid_id_slot] {
+            assert_matches!(
+                storage.get_account_id(slot_name).unwrap_err(),
+                AccountError::StorageSlotInvalidLayout { expected_layout: "account ID", .. }
+            );
+        }
+        for slot_name in [&padded_slot, &invalid_metadata_slot] {
+            assert_matches!(
+                storage.get_token_metadata(slot_name).unwrap_err(),
+                AccountError::StorageSlotInvalidLayout { expected_layout: "token metadata", .. }
+            );
+        }
+        assert_matches!(
+            storage.get_u64_pair(&invalid_limb_slot).unwrap_err(),
+            AccountError::StorageSlotInvalidLayout { expected_layout: "u64 pair", .. }
+        );
+
+        Ok(())
+    }
 }
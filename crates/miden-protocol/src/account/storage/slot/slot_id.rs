@@ -31,7 +31,7 @@ impl StorageSlotId {
     // --------------------------------------------------------------------------------------------
 
     /// Creates a new [`StorageSlotId`] from the provided felts.
-    pub fn new(suffix: Felt, prefix: Felt) -> Self {
+    pub const fn new(suffix: Felt, prefix: Felt) -> Self {
         Self { suffix, prefix }
     }
 
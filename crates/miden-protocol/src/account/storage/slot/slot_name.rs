@@ -1,8 +1,10 @@
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use core::fmt::Display;
+use core::hash::{Hash, Hasher};
 use core::str::FromStr;
 
+use crate::account::storage::is_reserved_slot_name;
 use crate::account::storage::slot::StorageSlotId;
 use crate::errors::StorageSlotNameError;
 use crate::utils::serde::{ByteWriter, Deserializable, DeserializationError, Serializable};
@@ -36,7 +38,7 @@ use crate::utils::serde::{ByteWriter, Deserializable, DeserializationError, Seri
 /// - Each component must not start with an underscore.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StorageSlotName {
-    name: Arc<str>,
+    name: SlotNameStr,
     id: StorageSlotId,
 }
 
@@ -63,15 +65,34 @@ impl StorageSlotName {
         let name: Arc<str> = name.into();
         Self::validate(&name)?;
         let id = StorageSlotId::from_str(&name);
-        Ok(Self { name, id })
+        Ok(Self { name: SlotNameStr::Shared(name), id })
+    }
+
+    /// Constructs a new [`StorageSlotName`] from a static string and its slot ID without
+    /// validating either of them.
+    ///
+    /// This is not meant to be called directly. Use the [`slot_name!`](crate::slot_name) macro
+    /// instead, which validates the name and computes its ID at compile time before calling this
+    /// constructor.
+    #[doc(hidden)]
+    pub const fn from_static_unchecked(name: &'static str, id: StorageSlotId) -> Self {
+        debug_assert!(Self::is_valid(name));
+        Self { name: SlotNameStr::Static(name), id }
     }
 
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns `true` if the provided string is a valid slot name, `false` otherwise.
+    ///
+    /// See the type-level docs for the requirements. This can be evaluated in a `const` context.
+    pub const fn is_valid(name: &str) -> bool {
+        matches!(Self::validate(name), Ok(()))
+    }
+
     /// Returns the slot name as a string slice.
     pub fn as_str(&self) -> &str {
-        &self.name
+        self.name.as_str()
     }
 
     /// Returns the slot name as a string slice.
@@ -80,8 +101,8 @@ impl StorageSlotName {
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> u8 {
         // SAFETY: Slot name validation should enforce length fits into a u8.
-        debug_assert!(self.name.len() <= Self::MAX_LENGTH);
-        self.name.len() as u8
+        debug_assert!(self.as_str().len() <= Self::MAX_LENGTH);
+        self.as_str().len() as u8
     }
 
     /// Returns the [`StorageSlotId`] derived from the slot name.
@@ -89,6 +110,16 @@ impl StorageSlotName {
         self.id
     }
 
+    /// Returns `true` if this slot name is reserved by the protocol, `false` otherwise.
+    ///
+    /// Reserved slot names, such as [`AccountStorage::faucet_sysdata_slot`], cannot be used by
+    /// account components.
+    ///
+    /// [`AccountStorage::faucet_sysdata_slot`]: crate::account::AccountStorage::faucet_sysdata_slot
+    pub fn is_reserved(&self) -> bool {
+        is_reserved_slot_name(self)
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -212,7 +243,7 @@ impl FromStr for StorageSlotName {
 
 impl From<StorageSlotName> for String {
     fn from(slot_name: StorageSlotName) -> Self {
-        slot_name.name.to_string()
+        slot_name.as_str().to_string()
     }
 }
 
@@ -242,6 +273,47 @@ impl Deserializable for StorageSlotName {
     }
 }
 
+// SLOT NAME STRING
+// ================================================================================================
+
+/// The string of a [`StorageSlotName`], which is either borrowed from a static string, so that
+/// slot names can be constructed in `const` contexts, or shared.
+#[derive(Clone)]
+enum SlotNameStr {
+    Static(&'static str),
+    Shared(Arc<str>),
+}
+
+impl SlotNameStr {
+    /// Returns the string as a string slice.
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Static(name) => name,
+            Self::Shared(name) => name,
+        }
+    }
+}
+
+impl PartialEq for SlotNameStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SlotNameStr {}
+
+impl Hash for SlotNameStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl core::fmt::Debug for SlotNameStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -252,6 +324,7 @@ mod tests {
     use assert_matches::assert_matches;
 
     use super::*;
+    use crate::account::AccountStorage;
 
     // A string containing all allowed characters of a slot name.
     const FULL_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz_ABCDEFGHIJKLMNOPQRSTUVWXYZ_0123456789";
@@ -396,6 +469,16 @@ mod tests {
         Ok(())
     }
 
+    // Reserved slot name tests
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn faucet_sysdata_slot_name_is_reserved() -> anyhow::Result<()> {
+        assert!(AccountStorage::faucet_sysdata_slot().is_reserved());
+        assert!(!StorageSlotName::new("miden::test::value")?.is_reserved());
+        Ok(())
+    }
+
     // Serialization tests
     // --------------------------------------------------------------------------------------------
 
@@ -413,6 +496,30 @@ mod tests {
         Ok(())
     }
 
+    // Macro tests
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn slot_name_macro_matches_runtime_constructor() -> anyhow::Result<()> {
+        const SLOT_NAME: StorageSlotName = crate::slot_name!("miden::test::slot_name");
+        const _: () = assert!(StorageSlotName::is_valid("miden::test::slot_name"));
+
+        let slot_name = StorageSlotName::new("miden::test::slot_name")?;
+        assert_eq!(SLOT_NAME, slot_name);
+        assert_eq!(SLOT_NAME.id(), slot_name.id());
+        assert_eq!(crate::slot_name!("miden::a").id(), StorageSlotName::new("miden::a")?.id());
+        Ok(())
+    }
+
+    #[test]
+    fn slot_name_is_valid_matches_validation() {
+        for name in ["miden::test", "miden::_test", "miden:test", "miden", "miden::te-st", ""] {
+            assert_eq!(StorageSlotName::is_valid(name), StorageSlotName::new(name).is_ok());
+        }
+        assert!(StorageSlotName::is_valid(&get_max_length_slot_name()));
+        assert!(!StorageSlotName::is_valid(&(get_max_length_slot_name() + "a")));
+    }
+
     // Test helpers
     // --------------------------------------------------------------------------------------------
 
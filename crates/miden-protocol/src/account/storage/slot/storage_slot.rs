@@ -1,6 +1,8 @@
-use crate::Word;
+use crate::account::auth::PublicKey;
 use crate::account::storage::slot::StorageSlotId;
-use crate::account::{StorageMap, StorageSlotContent, StorageSlotName, StorageSlotType};
+use crate::account::{AccountId, StorageMap, StorageSlotContent, StorageSlotName, StorageSlotType};
+use crate::asset::TokenSymbol;
+use crate::{Felt, Word, ZERO};
 
 /// An individual storage slot in [`AccountStorage`](crate::account::AccountStorage).
 ///
@@ -54,6 +56,52 @@ impl StorageSlot {
         Self::new(name, StorageSlotContent::empty_map())
     }
 
+    /// Creates a new value [`StorageSlot`] with the given [`StorageSlotName`] storing the provided
+    /// account ID as `[0, 0, suffix, prefix]`.
+    ///
+    /// The account ID can be read back via the `get_account_id` method of
+    /// [`AccountStorage`](crate::account::AccountStorage).
+    pub fn with_account_id(name: StorageSlotName, account_id: AccountId) -> Self {
+        let value = Word::from([ZERO, ZERO, account_id.suffix(), account_id.prefix().as_felt()]);
+        Self::with_value(name, value)
+    }
+
+    /// Creates a new value [`StorageSlot`] with the given [`StorageSlotName`] storing the
+    /// commitment to the provided public key.
+    ///
+    /// The commitment can be read back via the `get_public_key_commitment` method of
+    /// [`AccountStorage`](crate::account::AccountStorage).
+    pub fn with_public_key(name: StorageSlotName, public_key: &PublicKey) -> Self {
+        Self::with_value(name, public_key.to_commitment().into())
+    }
+
+    /// Creates a new value [`StorageSlot`] with the given [`StorageSlotName`] storing fungible
+    /// token metadata as `[max_supply, decimals, token_symbol, 0]`, which is the layout of the
+    /// metadata slot of fungible faucets.
+    ///
+    /// The metadata can be read back via the `get_token_metadata` method of
+    /// [`AccountStorage`](crate::account::AccountStorage).
+    pub fn with_token_metadata(
+        name: StorageSlotName,
+        token_symbol: TokenSymbol,
+        decimals: u8,
+        max_supply: Felt,
+    ) -> Self {
+        let value = Word::from([max_supply, Felt::from(decimals), token_symbol.into(), ZERO]);
+        Self::with_value(name, value)
+    }
+
+    /// Creates a new value [`StorageSlot`] with the given [`StorageSlotName`] storing the provided
+    /// pair of `u64` values as their 32-bit limbs `[a_lo, a_hi, b_lo, b_hi]`.
+    ///
+    /// The values can be read back via the `get_u64_pair` method of
+    /// [`AccountStorage`](crate::account::AccountStorage).
+    pub fn with_u64_pair(name: StorageSlotName, a: u64, b: u64) -> Self {
+        let [a_lo, a_hi] = u64_to_limbs(a);
+        let [b_lo, b_hi] = u64_to_limbs(b);
+        Self::with_value(name, Word::from([a_lo, a_hi, b_lo, b_hi]))
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -113,6 +161,14 @@ impl PartialOrd for StorageSlot {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the low and high 32-bit limbs of the provided value.
+fn u64_to_limbs(value: u64) -> [Felt; 2] {
+    [Felt::from(value as u32), Felt::from((value >> 32) as u32)]
+}
+
 // SERIALIZATION
 // ================================================================================================
 
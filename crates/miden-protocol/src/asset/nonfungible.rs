@@ -4,7 +4,7 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use super::vault::AssetVaultKey;
-use super::{AccountIdPrefix, AccountType, Asset, AssetError, Felt, Hasher, Word};
+use super::{AccountIdPrefix, Asset, AssetError, Felt, Hasher, Word};
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use crate::{FieldElement, WORD_SIZE};
 
@@ -71,7 +71,7 @@ impl NonFungibleAsset {
     /// # Errors
     /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
     pub fn from_parts(faucet_id: AccountIdPrefix, mut data_hash: Word) -> Result<Self, AssetError> {
-        if !matches!(faucet_id.account_type(), AccountType::NonFungibleFaucet) {
+        if !faucet_id.account_type().is_non_fungible_faucet() {
             return Err(AssetError::NonFungibleFaucetIdTypeMismatch(faucet_id));
         }
 
@@ -138,7 +138,7 @@ impl NonFungibleAsset {
             .map_err(|err| AssetError::InvalidFaucetAccountId(Box::new(err)))?;
 
         let account_type = faucet_id.account_type();
-        if !matches!(account_type, AccountType::NonFungibleFaucet) {
+        if !account_type.is_non_fungible_faucet() {
             return Err(AssetError::NonFungibleFaucetIdTypeMismatch(faucet_id));
         }
 
@@ -240,7 +240,7 @@ impl NonFungibleAssetDetails {
     /// # Errors
     /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
     pub fn new(faucet_id: AccountIdPrefix, asset_data: Vec<u8>) -> Result<Self, AssetError> {
-        if !matches!(faucet_id.account_type(), AccountType::NonFungibleFaucet) {
+        if !faucet_id.account_type().is_non_fungible_faucet() {
             return Err(AssetError::NonFungibleFaucetIdTypeMismatch(faucet_id));
         }
 
@@ -38,13 +38,29 @@ impl TokenSymbol {
     /// - The length of the provided string is less than 1 or greater than 6.
     /// - The provided token string contains characters that are not uppercase ASCII.
     pub const fn from_static_str(symbol: &'static str) -> Self {
-        match encode_symbol_to_felt(symbol) {
-            Ok(felt) => Self(felt),
+        match Self::new_const(symbol) {
+            Ok(token_symbol) => token_symbol,
             // We cannot format the error in a const context.
             Err(_) => panic!("invalid token symbol"),
         }
     }
 
+    /// Constructs a new [`TokenSymbol`] from a static string.
+    ///
+    /// This is the fallible counterpart of [`Self::from_static_str`] and can be evaluated in a
+    /// `const` context as well.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The length of the provided string is less than 1 or greater than 6.
+    /// - The provided token string contains characters that are not uppercase ASCII.
+    pub const fn new_const(symbol: &'static str) -> Result<Self, TokenSymbolError> {
+        match encode_symbol_to_felt(symbol) {
+            Ok(felt) => Ok(Self(felt)),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Creates a new [`TokenSymbol`] instance from the provided token name string.
     ///
     /// # Errors
@@ -68,6 +84,33 @@ impl TokenSymbol {
     }
 }
 
+// TOKEN SYMBOL MACRO
+// ================================================================================================
+
+/// Constructs a [`TokenSymbol`](crate::asset::TokenSymbol) from a string constant which is
+/// validated at compile time.
+///
+/// ```rust
+/// # use miden_protocol::token_symbol;
+/// let symbol = token_symbol!("ETH");
+/// assert_eq!(symbol.to_string().unwrap(), "ETH");
+/// ```
+///
+/// Invalid token symbols fail to compile:
+///
+/// ```compile_fail
+/// # use miden_protocol::token_symbol;
+/// let symbol = token_symbol!("ABCDEFG");
+/// ```
+#[macro_export]
+macro_rules! token_symbol {
+    ($symbol:expr) => {{
+        const SYMBOL: $crate::asset::TokenSymbol =
+            $crate::asset::TokenSymbol::from_static_str($symbol);
+        SYMBOL
+    }};
+}
+
 impl From<TokenSymbol> for Felt {
     fn from(symbol: TokenSymbol) -> Self {
         symbol.0
@@ -290,6 +333,44 @@ mod test {
         }
     }
 
+    const _TOKEN4: Result<TokenSymbol, TokenSymbolError> = TokenSymbol::new_const("MIDENC");
+    const _TOKEN5: TokenSymbol = crate::token_symbol!("POL");
+
+    #[test]
+    fn test_new_const_length_boundaries() {
+        let token_symbol = TokenSymbol::new_const("ZZZZZZ").unwrap();
+        assert_eq!(Felt::from(token_symbol).as_int(), TokenSymbol::MAX_ENCODED_VALUE);
+        assert_eq!(token_symbol.to_string().unwrap(), "ZZZZZZ");
+        assert_eq!(
+            Felt::from(TokenSymbol::new_const("A").unwrap()),
+            Felt::from(TokenSymbol::new("A").unwrap())
+        );
+
+        assert_matches!(TokenSymbol::new_const(""), Err(TokenSymbolError::InvalidLength(0)));
+        assert_matches!(TokenSymbol::new_const("ABCDEFG"), Err(TokenSymbolError::InvalidLength(7)));
+        assert_matches!(TokenSymbol::new_const("ETh"), Err(TokenSymbolError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_token_symbol_encoded_value_boundary() {
+        let max = Felt::new(TokenSymbol::MAX_ENCODED_VALUE);
+        assert_eq!(TokenSymbol::try_from(max).unwrap().to_string().unwrap(), "ZZZZZZ");
+
+        let too_large = TokenSymbol::MAX_ENCODED_VALUE + 1;
+        assert_matches!(
+            TokenSymbol::try_from(Felt::new(too_large)),
+            Err(TokenSymbolError::ValueTooLarge(value)) if value == too_large
+        );
+    }
+
+    #[test]
+    fn test_token_symbol_macro() {
+        assert_eq!(
+            Felt::from(crate::token_symbol!("MIDEN")),
+            Felt::from(TokenSymbol::new("MIDEN").unwrap())
+        );
+    }
+
     #[test]
     #[should_panic(expected = "invalid token symbol")]
     fn token_symbol_panics_on_empty_string() {
@@ -4,7 +4,6 @@ use miden_crypto::merkle::InnerNodeInfo;
 use miden_processor::SMT_DEPTH;
 
 use super::{
-    AccountType,
     Asset,
     ByteReader,
     ByteWriter,
@@ -90,7 +89,7 @@ impl AssetVault {
     /// # Errors
     /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
     pub fn get_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
-        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+        if !faucet_id.account_type().is_fungible_faucet() {
             return Err(AssetVaultError::NotAFungibleFaucetId(faucet_id));
         }
 
@@ -16,8 +16,9 @@ use crate::transaction::{
 };
 
 type BatchInputNotes = Vec<InputNoteCommitment>;
+type BatchErasedNotes = Vec<NoteHeader>;
 type BlockInputNotes = Vec<InputNoteCommitment>;
-type ErasedNotes = Vec<Nullifier>;
+type BlockErasedNotes = Vec<Nullifier>;
 type BlockOutputNotes = BTreeMap<NoteId, (BatchId, OutputNote)>;
 type BatchOutputNotes = Vec<OutputNote>;
 
@@ -60,7 +61,7 @@ impl InputOutputNoteTracker<TransactionId> {
         unauthenticated_note_proofs: &BTreeMap<NoteId, NoteInclusionProof>,
         partial_blockchain: &PartialBlockchain,
         batch_reference_block: &BlockHeader,
-    ) -> Result<(BatchInputNotes, BatchOutputNotes), ProposedBatchError> {
+    ) -> Result<(BatchInputNotes, BatchErasedNotes, BatchOutputNotes), ProposedBatchError> {
         let input_notes_iter = txs.clone().flat_map(|tx| {
             tx.input_notes()
                 .iter()
@@ -79,16 +80,23 @@ impl InputOutputNoteTracker<TransactionId> {
         )
         .map_err(ProposedBatchError::from)?;
 
-        let (batch_input_notes, _erased_notes, batch_output_notes) =
+        let (batch_input_notes, erased_notes, batch_output_notes) =
             tracker.erase_notes().map_err(ProposedBatchError::from)?;
 
+        let erased_notes = erased_notes
+            .into_iter()
+            .map(|erased_note| {
+                erased_note.header().cloned().expect("erased notes should be unauthenticated")
+            })
+            .collect();
+
         // Collect the remaining (non-erased) output notes into the final set of output notes.
         let final_output_notes = batch_output_notes
             .into_iter()
             .map(|(_, (_, output_note))| output_note)
             .collect();
 
-        Ok((batch_input_notes, final_output_notes))
+        Ok((batch_input_notes, erased_notes, final_output_notes))
     }
 }
 
@@ -100,7 +108,7 @@ impl InputOutputNoteTracker<BatchId> {
         unauthenticated_note_proofs: &BTreeMap<NoteId, NoteInclusionProof>,
         partial_blockchain: &PartialBlockchain,
         prev_block: &BlockHeader,
-    ) -> Result<(BlockInputNotes, ErasedNotes, BlockOutputNotes), ProposedBlockError> {
+    ) -> Result<(BlockInputNotes, BlockErasedNotes, BlockOutputNotes), ProposedBlockError> {
         let input_notes_iter = batches.clone().flat_map(|batch| {
             batch
                 .input_notes()
@@ -124,6 +132,8 @@ impl InputOutputNoteTracker<BatchId> {
         let (block_input_notes, erased_notes, block_output_notes) =
             tracker.erase_notes().map_err(ProposedBlockError::from)?;
 
+        let erased_notes = erased_notes.iter().map(InputNoteCommitment::nullifier).collect();
+
         Ok((block_input_notes, erased_notes, block_output_notes))
     }
 }
@@ -191,14 +201,14 @@ impl<ContainerId: Copy> InputOutputNoteTracker<ContainerId> {
     /// If it is, the note is considered erased and added to the list of erased notes, otherwise it
     /// is added to the final input notes.
     ///
-    /// Returns the sets of input notes, erased notes and output notes.
+    /// Returns the sets of input notes, erased (unauthenticated) input notes and output notes.
     #[allow(clippy::type_complexity)]
     fn erase_notes(
         mut self,
     ) -> Result<
         (
             Vec<InputNoteCommitment>,
-            ErasedNotes,
+            Vec<InputNoteCommitment>,
             BTreeMap<NoteId, (ContainerId, OutputNote)>,
         ),
         InputOutputNoteTrackerError<ContainerId>,
@@ -216,7 +226,7 @@ impl<ContainerId: Copy> InputOutputNoteTracker<ContainerId> {
                     // adding it to the erased notes and, crucially, not adding it to the
                     // final_input_notes.
                     if is_output_note {
-                        erased_notes.push(input_note_commitment.nullifier());
+                        erased_notes.push(input_note_commitment.clone());
                     } else {
                         final_input_notes.push(input_note_commitment.clone());
                     }
@@ -7,7 +7,7 @@ use crate::account::AccountId;
 use crate::batch::{BatchAccountUpdate, BatchId, InputOutputNoteTracker};
 use crate::block::{BlockHeader, BlockNumber};
 use crate::errors::ProposedBatchError;
-use crate::note::{NoteId, NoteInclusionProof};
+use crate::note::{NoteHeader, NoteId, NoteInclusionProof};
 use crate::transaction::{
     InputNoteCommitment,
     InputNotes,
@@ -54,6 +54,9 @@ pub struct ProposedBatch {
     /// The output notes of this batch. This consists of all notes created by transactions in the
     /// batch that are not consumed within the same batch. These are sorted by [`OutputNote::id`].
     output_notes: Vec<OutputNote>,
+    /// The notes that were created and consumed within the batch and so are neither input nor
+    /// output notes of the batch. These are sorted by the nullifier of the note.
+    erased_notes: Vec<NoteHeader>,
 }
 
 impl ProposedBatch {
@@ -260,7 +263,7 @@ impl ProposedBatch {
 
         // Check for duplicate output notes and remove all output notes from the batch output note
         // set that are consumed by transactions.
-        let (input_notes, output_notes) = InputOutputNoteTracker::from_transactions(
+        let (input_notes, erased_notes, output_notes) = InputOutputNoteTracker::from_transactions(
             transactions.iter().map(AsRef::as_ref),
             &unauthenticated_note_proofs,
             &partial_blockchain,
@@ -293,6 +296,7 @@ impl ProposedBatch {
             batch_expiration_block_num,
             input_notes,
             output_notes,
+            erased_notes,
         })
     }
 
@@ -352,6 +356,15 @@ impl ProposedBatch {
         &self.output_notes
     }
 
+    /// Returns the headers of the notes that were erased from the batch.
+    ///
+    /// A note is erased if it is created by one transaction in the batch and consumed as an
+    /// unauthenticated note by another transaction in the same batch. Such a note is neither an
+    /// input nor an output note of the batch.
+    pub fn erased_notes(&self) -> &[NoteHeader] {
+        &self.erased_notes
+    }
+
     /// Consumes the proposed batch and returns its underlying parts.
     #[allow(clippy::type_complexity)]
     pub fn into_parts(
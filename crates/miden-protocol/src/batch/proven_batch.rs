@@ -3,6 +3,8 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use crate::account::AccountId;
+#[cfg(any(feature = "testing", test))]
+use crate::batch::ProposedBatch;
 use crate::batch::{BatchAccountUpdate, BatchId};
 use crate::block::BlockNumber;
 use crate::errors::ProvenBatchError;
@@ -66,6 +68,46 @@ impl ProvenBatch {
         })
     }
 
+    /// Creates a new [`ProvenBatch`] from the provided [`ProposedBatch`] without proving it.
+    ///
+    /// The transaction proofs of the batch are not verified. Since batches are not yet proven
+    /// recursively, no proof is attached to the resulting batch either.
+    ///
+    /// This is intended for tests which need to exercise the block pipeline without a batch
+    /// prover.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch fails the checks of [`ProvenBatch::new`].
+    #[cfg(any(feature = "testing", test))]
+    pub fn from_proposed_unchecked(
+        proposed_batch: ProposedBatch,
+    ) -> Result<Self, ProvenBatchError> {
+        let transactions = proposed_batch.transaction_headers();
+        let (
+            _transactions,
+            reference_block_header,
+            _partial_blockchain,
+            _unauthenticated_note_proofs,
+            id,
+            account_updates,
+            input_notes,
+            output_notes,
+            batch_expiration_block_num,
+        ) = proposed_batch.into_parts();
+
+        Self::new(
+            id,
+            reference_block_header.commitment(),
+            reference_block_header.block_num(),
+            account_updates,
+            input_notes,
+            output_notes,
+            batch_expiration_block_num,
+            transactions,
+        )
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -1,11 +1,14 @@
 use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
 
 use miden_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use miden_crypto::merkle::mmr::{Forest, Mmr, MmrError, MmrPeaks, MmrProof, PartialMmr};
 use miden_processor::DeserializationError;
 
 use crate::Word;
-use crate::block::BlockNumber;
+use crate::block::{BlockHeader, BlockNumber};
+use crate::errors::{BlockchainError, PartialBlockchainError};
+use crate::transaction::PartialBlockchain;
 
 /// The [Merkle Mountain Range](Mmr) defining the Miden blockchain.
 ///
@@ -38,6 +41,31 @@ impl Blockchain {
         Self { mmr: Mmr::new() }
     }
 
+    /// Returns a new blockchain containing the commitments of the provided block headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the headers are not ordered by block number, starting at the genesis
+    /// block and without gaps.
+    pub fn from_headers(
+        headers: impl IntoIterator<Item = BlockHeader>,
+    ) -> Result<Self, BlockchainError> {
+        let mut chain = Self::new();
+        for header in headers {
+            let expected = BlockNumber::from(chain.num_blocks());
+            if header.block_num() != expected {
+                return Err(BlockchainError::UnexpectedBlockNumber {
+                    expected,
+                    actual: header.block_num(),
+                });
+            }
+
+            chain.push(header.commitment());
+        }
+
+        Ok(chain)
+    }
+
     /// Construct a new blockchain from an [`Mmr`] without validation.
     pub fn from_mmr_unchecked(mmr: Mmr) -> Self {
         Self { mmr }
@@ -153,6 +181,57 @@ impl Blockchain {
         Ok(partial_mmr)
     }
 
+    /// Creates a [`PartialBlockchain`] at the state of the given `checkpoint` which tracks the
+    /// provided block headers.
+    ///
+    /// The hashed peaks of the returned partial blockchain match the chain commitment of the
+    /// checkpoint block, so it contains the minimal data needed to authenticate the provided
+    /// blocks against that block.
+    ///
+    /// The blocks are provided as headers rather than block numbers because the blockchain only
+    /// stores block commitments, while a [`PartialBlockchain`] must contain the headers of the
+    /// blocks it tracks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the specified `checkpoint` exceeds the number of blocks in the chain.
+    /// - any of the provided blocks is not in the state of the chain specified by `checkpoint`,
+    ///   i.e. its number is not smaller than `checkpoint`.
+    /// - the same block is provided more than once.
+    /// - any of the provided block headers does not match the commitment of the block in the chain.
+    pub fn partial_for_blocks(
+        &self,
+        blocks: impl IntoIterator<Item = BlockHeader>,
+        checkpoint: BlockNumber,
+    ) -> Result<PartialBlockchain, PartialBlockchainError> {
+        if checkpoint.as_u32() > self.num_blocks() {
+            return Err(PartialBlockchainError::CheckpointTooBig {
+                chain_length: self.num_blocks(),
+                checkpoint,
+            });
+        }
+
+        let block_headers: Vec<BlockHeader> = blocks.into_iter().collect();
+        let mut block_nums = BTreeSet::new();
+        for header in block_headers.iter() {
+            if header.block_num() >= checkpoint {
+                return Err(PartialBlockchainError::block_num_too_big(
+                    checkpoint.as_usize(),
+                    header.block_num(),
+                ));
+            }
+            block_nums.insert(header.block_num());
+        }
+
+        // SAFETY: The checkpoint and all blocks were validated to be in the chain above.
+        let partial_mmr = self
+            .partial_mmr_from_blocks(&block_nums, checkpoint)
+            .expect("checkpoint and blocks should be in the chain");
+
+        PartialBlockchain::new(partial_mmr, block_headers)
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -185,3 +264,119 @@ impl Deserializable for Blockchain {
         Ok(Self::from_mmr_unchecked(chain))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::Blockchain;
+    use crate::Word;
+    use crate::block::{BlockHeader, BlockNumber};
+    use crate::errors::{BlockchainError, PartialBlockchainError};
+
+    fn mock_headers(num_blocks: u32) -> Vec<BlockHeader> {
+        (0..num_blocks)
+            .map(|block_num| BlockHeader::mock(block_num, None, None, &[], Word::empty()))
+            .collect()
+    }
+
+    #[test]
+    fn blockchain_from_headers() {
+        let headers = mock_headers(5);
+
+        let chain = Blockchain::from_headers(headers.clone()).unwrap();
+        let mut expected_chain = Blockchain::new();
+        for header in headers.iter() {
+            expected_chain.push(header.commitment());
+        }
+        assert_eq!(chain.num_blocks(), 5);
+        assert_eq!(chain.commitment(), expected_chain.commitment());
+
+        assert_eq!(Blockchain::from_headers([]).unwrap().num_blocks(), 0);
+
+        // the chain must start at the genesis block
+        assert_matches!(
+            Blockchain::from_headers(headers[1..].iter().cloned()),
+            Err(BlockchainError::UnexpectedBlockNumber { expected, actual })
+                if expected == BlockNumber::GENESIS && actual == BlockNumber::from(1)
+        );
+
+        // the chain cannot have gaps
+        let headers_with_gap = headers
+            .iter()
+            .filter(|header| header.block_num() != BlockNumber::from(3))
+            .cloned();
+        assert_matches!(
+            Blockchain::from_headers(headers_with_gap),
+            Err(BlockchainError::UnexpectedBlockNumber { expected, actual })
+                if expected == BlockNumber::from(3) && actual == BlockNumber::from(4)
+        );
+    }
+
+    #[test]
+    fn partial_for_blocks() {
+        let headers = mock_headers(9);
+        let chain = Blockchain::from_headers(headers.clone()).unwrap();
+
+        // checkpoints at and around the peak boundaries of the MMR, including a chain which only
+        // contains the genesis block
+        for checkpoint in [1u32, 2, 3, 4, 5, 7, 8, 9] {
+            let checkpoint = BlockNumber::from(checkpoint);
+            // for the first checkpoint, the parent of the checkpoint is the genesis block itself
+            let blocks = BTreeSet::from([BlockNumber::GENESIS, checkpoint.parent().unwrap()]);
+            let block_headers =
+                blocks.iter().map(|block_num| headers[block_num.as_usize()].clone());
+
+            let partial_chain = chain.partial_for_blocks(block_headers, checkpoint).unwrap();
+
+            assert_eq!(partial_chain.chain_length(), checkpoint);
+            assert_eq!(
+                partial_chain.peaks().hash_peaks(),
+                chain.peaks_at(checkpoint).unwrap().hash_peaks()
+            );
+            for block_num in blocks {
+                assert!(partial_chain.contains_block(block_num));
+            }
+        }
+
+        // no blocks tracked
+        let partial_chain = chain.partial_for_blocks([], BlockNumber::from(6)).unwrap();
+        assert_eq!(partial_chain.num_tracked_blocks(), 0);
+        assert_eq!(
+            partial_chain.peaks().hash_peaks(),
+            chain.peaks_at(BlockNumber::from(6)).unwrap().hash_peaks()
+        );
+    }
+
+    #[test]
+    fn partial_for_blocks_fails_for_blocks_outside_of_checkpoint() {
+        let headers = mock_headers(9);
+        let chain = Blockchain::from_headers(headers.clone()).unwrap();
+
+        assert_matches!(
+            chain.partial_for_blocks([], BlockNumber::from(10)),
+            Err(PartialBlockchainError::CheckpointTooBig { chain_length: 9, checkpoint })
+                if checkpoint == BlockNumber::from(10)
+        );
+
+        // the checkpoint block itself is not in the chain at the state of the checkpoint
+        assert_matches!(
+            chain.partial_for_blocks([headers[5].clone()], BlockNumber::from(5)),
+            Err(PartialBlockchainError::BlockNumTooBig { chain_length: 5, block_num })
+                if block_num == BlockNumber::from(5)
+        );
+
+        // a header which is not part of the chain
+        let other_header = BlockHeader::mock(2, None, None, &[], Word::empty());
+        assert_matches!(
+            chain.partial_for_blocks([other_header], BlockNumber::from(5)),
+            Err(PartialBlockchainError::BlockHeaderCommitmentMismatch { .. })
+        );
+    }
+}
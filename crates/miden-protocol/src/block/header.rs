@@ -1,7 +1,7 @@
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use crate::account::{AccountId, AccountType};
+use crate::account::AccountId;
 use crate::block::BlockNumber;
 use crate::crypto::dsa::ecdsa_k256_keccak::PublicKey;
 use crate::errors::FeeError;
@@ -349,7 +349,7 @@ impl FeeParameters {
     /// Returns an error if:
     /// - the provided native asset ID is not a fungible faucet account ID.
     pub fn new(native_asset_id: AccountId, verification_base_fee: u32) -> Result<Self, FeeError> {
-        if !matches!(native_asset_id.account_type(), AccountType::FungibleFaucet) {
+        if !native_asset_id.account_type().is_fungible_faucet() {
             return Err(FeeError::NativeAssetIdNotFungible {
                 account_type: native_asset_id.account_type(),
             });
@@ -1,4 +1,5 @@
 use alloc::string::ToString;
+use alloc::vec::Vec;
 
 use miden_crypto::merkle::SparseMerklePath;
 
@@ -80,6 +81,24 @@ impl BlockNoteTree {
         self.0.open(&index.leaf_index()).path
     }
 
+    /// Returns merkle paths for the notes at the specified leaf indices, in the same order as the
+    /// provided indices.
+    ///
+    /// The leaf index of a note can be obtained via [`BlockNoteIndex::leaf_index_value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the indices is out of bounds for the tree depth.
+    pub fn open_many(&self, indices: &[usize]) -> Result<Vec<SparseMerklePath>, MerkleError> {
+        indices
+            .iter()
+            .map(|&index| {
+                let leaf_index = LeafIndex::new(index as u64)?;
+                Ok(self.0.open(&leaf_index).path)
+            })
+            .collect()
+    }
+
     /// Returns the number of notes in this block note tree.
     pub fn num_notes(&self) -> usize {
         self.0.num_leaves()
@@ -0,0 +1,91 @@
+use crate::{Felt, Hasher, WORD_SIZE, Word, ZERO};
+
+// DOUBLE WORD HASHER
+// ================================================================================================
+
+/// Absorbs double words directly into the state of a [`Hasher`] without materializing the
+/// sequence of elements.
+///
+/// Since a double word is exactly the rate of the hasher and the capacity starts out as zero, the
+/// resulting digest is equal to [`Hasher::hash_elements`] over the concatenation of all absorbed
+/// double words. Absorbing into a fixed-size state makes the hasher cheap to clone, which allows
+/// maintaining running commitments over growing sequences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DoubleWordHasher {
+    state: [Felt; Hasher::STATE_WIDTH],
+    num_absorbed: usize,
+}
+
+impl DoubleWordHasher {
+    /// Returns a new hasher in its initial state.
+    pub fn new() -> Self {
+        Self {
+            state: [ZERO; Hasher::STATE_WIDTH],
+            num_absorbed: 0,
+        }
+    }
+
+    /// Absorbs the double word consisting of `first` and `second`.
+    pub fn absorb(&mut self, first: Word, second: Word) {
+        let rate = &mut self.state[Hasher::RATE_RANGE];
+        rate[..WORD_SIZE].copy_from_slice(first.as_elements());
+        rate[WORD_SIZE..].copy_from_slice(second.as_elements());
+        Hasher::apply_permutation(&mut self.state);
+        self.num_absorbed += 1;
+    }
+
+    /// Returns the number of double words absorbed so far.
+    pub fn num_absorbed(&self) -> usize {
+        self.num_absorbed
+    }
+
+    /// Returns the digest of all double words absorbed so far, or [`Word::empty`] if none were
+    /// absorbed.
+    pub fn digest(&self) -> Word {
+        if self.num_absorbed == 0 {
+            return Word::empty();
+        }
+
+        let digest: [Felt; WORD_SIZE] = self.state[Hasher::DIGEST_RANGE]
+            .try_into()
+            .expect("digest range should contain exactly one word");
+        Word::from(digest)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use proptest::prelude::*;
+
+    use super::DoubleWordHasher;
+    use crate::testing::strategies::any_word;
+    use crate::{Felt, Hasher, Word};
+
+    proptest! {
+        #[test]
+        fn proptest_digest_equals_hash_of_absorbed_elements(
+            double_words in prop::collection::vec((any_word(), any_word()), 1..20)
+        ) {
+            let mut hasher = DoubleWordHasher::new();
+            let mut elements: Vec<Felt> = Vec::new();
+            for (first, second) in double_words.iter() {
+                hasher.absorb(*first, *second);
+                elements.extend_from_slice(first.as_elements());
+                elements.extend_from_slice(second.as_elements());
+            }
+
+            prop_assert_eq!(hasher.num_absorbed(), double_words.len());
+            prop_assert_eq!(hasher.digest(), Hasher::hash_elements(&elements));
+        }
+    }
+
+    #[test]
+    fn digest_of_nothing_is_empty_word() {
+        assert_eq!(DoubleWordHasher::new().digest(), Word::empty());
+    }
+}
@@ -15,14 +15,17 @@ use thiserror::Error;
 
 use super::account::AccountId;
 use super::asset::{FungibleAsset, NonFungibleAsset, TokenSymbol};
+use super::crypto::ies::IesError;
 use super::crypto::merkle::MerkleError;
 use super::note::NoteId;
 use super::{MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH, Word};
 use crate::account::component::{SchemaTypeError, StorageValueName, StorageValueNameError};
 use crate::account::{
     AccountCode,
+    AccountDeltaSizeBreakdown,
     AccountIdPrefix,
     AccountStorage,
+    AccountStorageMode,
     AccountType,
     StorageSlotId,
     // StorageValueName,
@@ -163,6 +166,19 @@ pub enum AccountError {
     StorageSlotNotMap(StorageSlotName),
     #[error("storage slot {0} is not of type value")]
     StorageSlotNotValue(StorageSlotName),
+    #[error(
+        "faucet token issuance {0} exceeds the maximum amount of a fungible asset {max}",
+        max = FungibleAsset::MAX_AMOUNT
+    )]
+    FaucetIssuanceExceedsMaxAmount(u64),
+    #[error("faucet token issuance {issued} exceeds the max supply {max_supply} of the token")]
+    FaucetIssuanceExceedsMaxSupply { issued: u64, max_supply: u64 },
+    #[error("storage slot {slot_name} does not contain a valid {expected_layout}")]
+    StorageSlotInvalidLayout {
+        slot_name: StorageSlotName,
+        expected_layout: &'static str,
+        source: Option<Box<dyn Error + Send + Sync + 'static>>,
+    },
     #[error("storage slot name {0} is assigned to more than one slot")]
     DuplicateStorageSlotName(StorageSlotName),
     #[error(
@@ -247,6 +263,15 @@ pub enum AccountIdError {
     AccountIdSuffixLeastSignificantByteMustBeZero,
     #[error("failed to decode bech32 string into account ID")]
     Bech32DecodeError(#[source] Bech32Error),
+    #[error(
+        "seed derives account ID with type {actual_type} and storage mode {actual_storage_mode} but expected type {expected_type} and storage mode {expected_storage_mode}"
+    )]
+    SeedDoesNotMatchAccountTypeOrStorageMode {
+        expected_type: AccountType,
+        expected_storage_mode: AccountStorageMode,
+        actual_type: AccountType,
+        actual_storage_mode: AccountStorageMode,
+    },
 }
 
 // SLOT NAME ERROR
@@ -385,6 +410,11 @@ pub enum AccountDeltaError {
         current: i64,
         delta: i64,
     },
+    #[error(
+        "fungible asset issued by faucet {faucet_id} has delta {amount} whose absolute value exceeds the max amount of a fungible asset {}",
+        FungibleAsset::MAX_AMOUNT
+    )]
+    FungibleAssetDeltaAmountTooLarge { faucet_id: AccountId, amount: i64 },
     #[error(
         "account update of type `{left_update_type}` cannot be merged with account update of type `{right_update_type}`"
     )]
@@ -411,6 +441,17 @@ pub enum AccountDeltaError {
     NotAFungibleFaucetId(AccountId),
     #[error("cannot merge two full state deltas")]
     MergingFullStateDeltas,
+    #[error(
+        "account delta for account {account_id} has an encoded size of {} bytes (vault: {} bytes, storage: {} bytes) which exceeds the limit of {limit} bytes",
+        breakdown.total(),
+        breakdown.vault(),
+        breakdown.storage()
+    )]
+    SizeLimitExceeded {
+        account_id: AccountId,
+        limit: usize,
+        breakdown: Box<AccountDeltaSizeBreakdown>,
+    },
 }
 
 // STORAGE MAP ERROR
@@ -558,6 +599,12 @@ pub enum NoteError {
     InconsistentNoteTag(NoteType, u64),
     #[error("adding fungible asset amounts would exceed maximum allowed amount")]
     AddFungibleAssetBalanceError(#[source] AssetError),
+    #[error("subtracting fungible asset amounts would underflow")]
+    SubtractFungibleAssetBalanceError(#[source] AssetError),
+    #[error("fungible asset from issuer {0} does not exist in note")]
+    FungibleAssetNotFound(AccountId),
+    #[error("non fungible asset {0} does not exist in note")]
+    NonFungibleAssetNotFound(NonFungibleAsset),
     #[error("note sender is not a valid account ID")]
     NoteSenderInvalidAccountId(#[source] AccountIdError),
     #[error(
@@ -581,12 +628,30 @@ pub enum NoteError {
         node_index_in_block: u16,
         highest_index: usize,
     },
+    #[error(
+        "inclusion proof of note {note_id} is for block {proof_block_num} but was verified against block {block_num}"
+    )]
+    NoteInclusionProofBlockMismatch {
+        note_id: NoteId,
+        proof_block_num: BlockNumber,
+        block_num: BlockNumber,
+    },
+    #[error("note {note_id} is not included in the note tree of block {block_num}")]
+    NoteNotInBlock { note_id: NoteId, block_num: BlockNumber },
+    #[error("note must target between 1 and {max} accounts, but {num_targets} were provided")]
+    InvalidNumberOfTargetAccounts { num_targets: usize, max: usize },
     #[error("note network execution requires a public note but note is of type {0}")]
     NetworkExecutionRequiresPublicNote(NoteType),
     #[error("failed to assemble note script:\n{}", PrintDiagnostic::new(.0))]
     NoteScriptAssemblyError(Report),
     #[error("failed to deserialize note script")]
     NoteScriptDeserializationError(#[source] DeserializationError),
+    #[error("failed to seal note details")]
+    NoteDetailsSealingFailed(#[source] IesError),
+    #[error("failed to unseal note details")]
+    NoteDetailsUnsealingFailed(#[source] IesError),
+    #[error("failed to deserialize unsealed note details")]
+    UnsealedNoteDetailsDeserializationFailed(#[source] DeserializationError),
     #[error("note contains {0} assets which exceeds the maximum of {max}", max = NoteAssets::MAX_NUM_ASSETS)]
     TooManyAssets(usize),
     #[error("note contains {0} storage items which exceeds the maximum of {max}", max = MAX_NOTE_STORAGE_ITEMS)]
@@ -631,6 +696,20 @@ impl NoteError {
     }
 }
 
+// BLOCKCHAIN ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum BlockchainError {
+    #[error(
+        "expected block header with number {expected} but got block header with number {actual}"
+    )]
+    UnexpectedBlockNumber {
+        expected: BlockNumber,
+        actual: BlockNumber,
+    },
+}
+
 // PARTIAL BLOCKCHAIN ERROR
 // ================================================================================================
 
@@ -644,6 +723,12 @@ pub enum PartialBlockchainError {
         block_num: BlockNumber,
     },
 
+    #[error("checkpoint {checkpoint} exceeds the length {chain_length} of the blockchain")]
+    CheckpointTooBig {
+        chain_length: u32,
+        checkpoint: BlockNumber,
+    },
+
     #[error("duplicate block {block_num} in partial blockchain")]
     DuplicateBlock { block_num: BlockNumber },
 
@@ -674,6 +759,23 @@ impl PartialBlockchainError {
     }
 }
 
+// TRANSACTION ARGS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionArgsError {
+    #[error("advice map entry with key {key} has conflicting values")]
+    ConflictingAdviceMapEntry { key: Word },
+    #[error("note {note_id} has conflicting note arguments")]
+    ConflictingNoteArgs { note_id: NoteId },
+    #[error("transaction arguments have conflicting transaction scripts")]
+    ConflictingTransactionScript,
+    #[error("transaction arguments have conflicting transaction script arguments")]
+    ConflictingTransactionScriptArgs,
+    #[error("transaction arguments have conflicting authentication arguments")]
+    ConflictingAuthArgs,
+}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
@@ -703,6 +805,8 @@ pub enum TransactionInputError {
     InputNoteBlockNotInPartialBlockchain(NoteId),
     #[error("input note with id {0} was not created in block {1}")]
     InputNoteNotInBlock(NoteId, BlockNumber),
+    #[error("input note with id {0} is not part of the input notes")]
+    InputNoteNotFound(NoteId),
     #[error(
         "total number of input notes is {0} which exceeds the maximum of {MAX_INPUT_NOTES_PER_TX}"
     )]
@@ -744,6 +848,108 @@ pub enum TransactionInputsExtractionError {
     SmtLeafError(#[from] SmtLeafError),
 }
 
+// TRANSACTION WITNESS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionWitnessError {
+    #[error("stack inputs of the transaction witness are invalid: {0}")]
+    StackInputsInvalid(Box<str>),
+    #[error("host data not found in the advice map of the transaction witness")]
+    HostDataNotFound,
+    #[error("failed to deserialize the host data of the transaction witness")]
+    HostDataDeserializationFailed(#[source] DeserializationError),
+    #[error(
+        "account of the transaction witness has ID {actual} but the transaction was executed against account {expected}"
+    )]
+    AccountIdMismatch { expected: AccountId, actual: AccountId },
+    #[error(
+        "account of the transaction witness has initial commitment {actual} but the transaction was executed against commitment {expected}"
+    )]
+    AccountCommitmentMismatch { expected: Word, actual: Word },
+    #[error("failed to construct the account of the transaction witness")]
+    InvalidAccount(#[source] AccountError),
+    #[error("seed of the new account not found in the advice map")]
+    AccountSeedNotFound,
+    #[error("storage map with root {0} of the new account not found in the advice map")]
+    StorageMapNotFound(Word),
+    #[error("failed to construct storage map with root {0} of the new account")]
+    InvalidStorageMap(Word, #[source] StorageMapError),
+    #[error("input note data not found in the advice map")]
+    InputNoteDataNotFound,
+    #[error("input note data in the advice map is malformed: {0}")]
+    InputNoteDataInvalid(Box<str>),
+    #[error("storage with commitment {1} of input note {0} not found in the advice map")]
+    InputNoteStorageNotFound(usize, Word),
+    #[error("script with root {1} of input note {0} not found in the host data")]
+    InputNoteScriptNotFound(usize, Word),
+    #[error("failed to construct an asset of input note {0}")]
+    InvalidInputNoteAsset(usize, #[source] AssetError),
+    #[error("failed to construct input note {0}")]
+    InvalidInputNote(usize, #[source] NoteError),
+    #[error("inclusion path of input note {0} not found in the merkle store")]
+    InputNotePathNotFound(usize, #[source] MerkleError),
+    #[error("failed to construct the input notes of the transaction witness")]
+    InvalidInputNotes(#[source] TransactionInputError),
+    #[error(
+        "input notes of the transaction witness have commitment {actual} but the transaction was executed against commitment {expected}"
+    )]
+    InputNotesCommitmentMismatch { expected: Word, actual: Word },
+}
+
+// ACCOUNT INPUTS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum AccountInputsError {
+    #[error(
+        "account witness is for account {witness_account_id} but the account has ID {account_id}"
+    )]
+    AccountIdMismatch {
+        account_id: AccountId,
+        witness_account_id: AccountId,
+    },
+    #[error(
+        "account witness of account {account_id} proves commitment {witness_commitment} but the account has commitment {account_commitment}"
+    )]
+    AccountCommitmentMismatch {
+        account_id: AccountId,
+        account_commitment: Word,
+        witness_commitment: Word,
+    },
+    #[error(
+        "account root {computed} computed from the witness of account {account_id} does not match the account root {expected} of block {block_num}"
+    )]
+    StaleWitness {
+        account_id: AccountId,
+        block_num: BlockNumber,
+        expected: Word,
+        computed: Word,
+    },
+    #[error("failed to compute the account root from the witness of account {0}")]
+    AccountRootComputationFailed(AccountId, #[source] SmtProofError),
+    #[error(
+        "account header of account {account_id} has code commitment {expected} but the provided code has commitment {actual}"
+    )]
+    CodeCommitmentMismatch {
+        account_id: AccountId,
+        expected: Word,
+        actual: Word,
+    },
+    #[error(
+        "account header of account {account_id} has storage commitment {expected} but the provided storage header has commitment {actual}"
+    )]
+    StorageCommitmentMismatch {
+        account_id: AccountId,
+        expected: Word,
+        actual: Word,
+    },
+    #[error("failed to parse the storage header of account {0}")]
+    InvalidStorageHeader(AccountId, #[source] AccountError),
+    #[error("failed to construct the partial account {0}")]
+    InvalidPartialAccount(AccountId, #[source] AccountError),
+}
+
 // TRANSACTION OUTPUT ERROR
 // ===============================================================================================
 
@@ -814,6 +1020,10 @@ pub enum ProvenTransactionError {
     },
     #[error("failed to construct input notes for proven transaction")]
     InputNotesError(TransactionInputError),
+    #[error("proven transaction contains multiple input notes with note ID {0}")]
+    DuplicateInputNoteId(NoteId),
+    #[error("note header attached to authenticated input note {0} is not of a public note")]
+    PrivateInputNoteHeader(NoteId),
     #[error("private account {0} should not have account details")]
     PrivateAccountWithDetails(AccountId),
     #[error("account {0} with public state is missing its account details")]
@@ -1178,3 +1388,32 @@ pub enum AuthSchemeError {
     #[error("auth scheme identifier `{0}` is not valid")]
     InvalidAuthSchemeIdentifier(u8),
 }
+
+// PROTO CONVERSION ERROR
+// ================================================================================================
+
+#[cfg(feature = "proto")]
+#[derive(Debug, Error)]
+pub enum ProtoConversionError {
+    #[error("required field `{0}` is missing")]
+    MissingField(&'static str),
+    #[error("field `{field}` must have {expected} bytes but has {actual}")]
+    InvalidLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("failed to deserialize field `{field}`")]
+    DeserializationFailed {
+        field: &'static str,
+        source: DeserializationError,
+    },
+    #[error("invalid word")]
+    Word(#[from] crate::WordError),
+    #[error("invalid account ID")]
+    AccountId(#[from] AccountIdError),
+    #[error("invalid note")]
+    Note(#[from] NoteError),
+    #[error("invalid fee parameters")]
+    Fee(#[from] FeeError),
+}
@@ -6,6 +6,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+// Allows the procedural macros of miden-protocol-macros to refer to this crate by name.
+extern crate self as miden_protocol;
+
 pub mod account;
 pub mod address;
 pub mod asset;
@@ -13,6 +16,8 @@ pub mod batch;
 pub mod block;
 pub mod errors;
 pub mod note;
+#[cfg(feature = "proto")]
+pub mod proto;
 mod protocol;
 pub mod transaction;
 
@@ -32,6 +37,7 @@ pub use miden_core_lib::CoreLibrary;
 pub use miden_crypto::hash::rpo::Rpo256 as Hasher;
 pub use miden_crypto::word;
 pub use miden_crypto::word::{LexicographicWord, Word, WordError};
+pub use miden_protocol_macros::slot_name;
 pub use protocol::ProtocolLib;
 
 pub mod assembly {
@@ -57,7 +63,12 @@ pub mod assembly {
 }
 
 pub mod crypto {
-    pub use miden_crypto::{SequentialCommit, dsa, hash, ies, merkle, rand, utils};
+    pub use miden_crypto::{SequentialCommit, dsa, hash, ies, merkle, utils};
+
+    pub mod rand;
+
+    mod double_word_hasher;
+    pub(crate) use double_word_hasher::DoubleWordHasher;
 }
 
 pub mod utils {
@@ -79,7 +90,7 @@ pub mod utils {
 pub mod vm {
     pub use miden_assembly_syntax::ast::{AttributeSet, QualifiedProcedureName};
     pub use miden_core::sys_events::SystemEvent;
-    pub use miden_core::{AdviceMap, EventId, Program, ProgramInfo};
+    pub use miden_core::{AdviceMap, DebugOptions, EventId, Program, ProgramInfo};
     pub use miden_mast_package::{
         MastArtifact,
         Package,
@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
 
+use crate::account::AccountId;
 use crate::asset::{Asset, FungibleAsset, NonFungibleAsset};
 use crate::errors::NoteError;
 use crate::utils::serde::{
@@ -127,6 +128,94 @@ impl NoteAssets {
         })
     }
 
+    /// Returns the total amount of the fungible asset issued by the provided faucet, or `0` if the
+    /// list does not contain an asset issued by this faucet.
+    pub fn total_fungible(&self, faucet_id: AccountId) -> u64 {
+        self.iter_fungible()
+            .filter(|asset| asset.faucet_id() == faucet_id)
+            .map(|asset| asset.amount())
+            .sum()
+    }
+
+    // ARITHMETIC
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new list of note assets which contains all assets of this list and the provided
+    /// asset.
+    ///
+    /// If a fungible asset issued by the same faucet is already in the list, the provided asset is
+    /// merged into it. Otherwise, the asset is appended to the end of the list. Either way, the
+    /// order of the existing assets is preserved.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset is already in the list.
+    /// - A fungible asset issued by the same faucet exists in the list and adding both assets
+    ///   together results in an invalid asset.
+    /// - Adding the asset to the list will push the list beyond the [Self::MAX_NUM_ASSETS] limit.
+    pub fn try_add(&self, asset: &Asset) -> Result<Self, NoteError> {
+        let mut assets = self.clone();
+        assets.add_asset(*asset)?;
+        Ok(assets)
+    }
+
+    /// Returns a new list of note assets which contains all assets of this list without the
+    /// provided asset.
+    ///
+    /// For a fungible asset, its amount is subtracted from the asset issued by the same faucet,
+    /// and that asset is removed from the list if its remaining amount is zero. The order of the
+    /// remaining assets is preserved.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The list does not contain a fungible asset issued by the same faucet as the provided
+    ///   fungible asset.
+    /// - The list does not contain the provided non-fungible asset.
+    /// - The amount of the provided fungible asset exceeds the amount in the list.
+    pub fn try_subtract(&self, asset: &Asset) -> Result<Self, NoteError> {
+        let mut assets = self.assets.clone();
+        let Some(index) = assets.iter().position(|own_asset| own_asset.is_same(asset)) else {
+            return Err(match asset {
+                Asset::Fungible(asset) => NoteError::FungibleAssetNotFound(asset.faucet_id()),
+                Asset::NonFungible(asset) => NoteError::NonFungibleAssetNotFound(*asset),
+            });
+        };
+
+        match (assets[index], asset) {
+            (Asset::Fungible(own_asset), Asset::Fungible(asset)) => {
+                let remaining =
+                    own_asset.sub(*asset).map_err(NoteError::SubtractFungibleAssetBalanceError)?;
+                if remaining.amount() == 0 {
+                    assets.remove(index);
+                } else {
+                    assets[index] = Asset::Fungible(remaining);
+                }
+            },
+            _ => {
+                assets.remove(index);
+            },
+        }
+
+        let hash = compute_asset_commitment(&assets);
+        Ok(Self { assets, hash })
+    }
+
+    /// Splits the provided fungible asset off this list of note assets.
+    ///
+    /// Returns a tuple of the remaining assets, as returned by [Self::try_subtract], and a list
+    /// containing only the split off asset.
+    ///
+    /// # Errors
+    /// Returns an error if the asset cannot be subtracted from this list (see
+    /// [Self::try_subtract]).
+    pub fn split(&self, asset: FungibleAsset) -> Result<(Self, Self), NoteError> {
+        let asset = Asset::Fungible(asset);
+        let remaining = self.try_subtract(&asset)?;
+        let split = Self::new(vec![asset])?;
+
+        Ok((remaining, split))
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -240,10 +329,15 @@ impl Deserializable for NoteAssets {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
     use super::{NoteAssets, compute_asset_commitment};
     use crate::Word;
     use crate::account::AccountId;
     use crate::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use crate::errors::NoteError;
     use crate::testing::account_id::{
         ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
         ACCOUNT_ID_PRIVATE_NON_FUNGIBLE_FAUCET,
@@ -292,4 +386,131 @@ mod tests {
         assert_eq!(fungible_assets.next().unwrap(), asset2.unwrap_fungible());
         assert_eq!(fungible_assets.next(), None);
     }
+
+    #[test]
+    fn try_add_merges_fungible_assets() {
+        let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_NON_FUNGIBLE_FAUCET).unwrap();
+        let details = NonFungibleAssetDetails::new(account_id.prefix(), vec![1, 2, 3]).unwrap();
+
+        let asset1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 100).unwrap());
+        let asset2 = Asset::Fungible(FungibleAsset::new(faucet_id_2, 50).unwrap());
+        let non_fungible_asset = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+        let assets = NoteAssets::new(vec![asset1, non_fungible_asset]).unwrap();
+
+        // a fungible asset from the same faucet is merged in place
+        let merged = assets.try_add(&FungibleAsset::new(faucet_id_1, 20).unwrap().into()).unwrap();
+        let expected = Asset::Fungible(FungibleAsset::new(faucet_id_1, 120).unwrap());
+        assert_eq!(merged.iter().copied().collect::<Vec<_>>(), vec![expected, non_fungible_asset]);
+        assert_eq!(merged.commitment(), compute_asset_commitment(&[expected, non_fungible_asset]));
+        assert_eq!(merged.total_fungible(faucet_id_1), 120);
+
+        // the original list is not modified
+        assert_eq!(assets.total_fungible(faucet_id_1), 100);
+
+        // a fungible asset from another faucet is appended
+        let appended = assets.try_add(&asset2).unwrap();
+        assert_eq!(
+            appended.iter().copied().collect::<Vec<_>>(),
+            vec![asset1, non_fungible_asset, asset2]
+        );
+        assert_eq!(appended.total_fungible(faucet_id_2), 50);
+
+        // the same non-fungible asset cannot be added twice
+        assert_matches!(
+            assets.try_add(&non_fungible_asset),
+            Err(NoteError::DuplicateNonFungibleAsset(asset))
+                if Asset::NonFungible(asset) == non_fungible_asset
+        );
+
+        // merging fungible assets cannot exceed the maximum amount
+        let max_asset = FungibleAsset::new(faucet_id_1, FungibleAsset::MAX_AMOUNT).unwrap();
+        assert_matches!(
+            assets.try_add(&max_asset.into()),
+            Err(NoteError::AddFungibleAssetBalanceError(_))
+        );
+    }
+
+    #[test]
+    fn try_add_respects_max_num_assets() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_NON_FUNGIBLE_FAUCET).unwrap();
+
+        let fungible_asset = Asset::Fungible(FungibleAsset::new(faucet_id, 100).unwrap());
+        let mut asset_list = vec![fungible_asset];
+        asset_list.extend((0..NoteAssets::MAX_NUM_ASSETS - 1).map(|i| {
+            let details = NonFungibleAssetDetails::new(
+                account_id.prefix(),
+                (i as u32).to_le_bytes().to_vec(),
+            )
+            .unwrap();
+            Asset::NonFungible(NonFungibleAsset::new(&details).unwrap())
+        }));
+        let assets = NoteAssets::new(asset_list).unwrap();
+        assert_eq!(assets.num_assets(), NoteAssets::MAX_NUM_ASSETS);
+
+        // merging into an existing fungible asset does not increase the number of assets
+        let merged = assets.try_add(&fungible_asset).unwrap();
+        assert_eq!(merged.num_assets(), NoteAssets::MAX_NUM_ASSETS);
+        assert_eq!(merged.total_fungible(faucet_id), 200);
+
+        // a new asset does not fit into the list anymore
+        let details = NonFungibleAssetDetails::new(account_id.prefix(), vec![0xff; 5]).unwrap();
+        let new_asset = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+        assert_matches!(
+            assets.try_add(&new_asset),
+            Err(NoteError::TooManyAssets(num_assets))
+                if num_assets == NoteAssets::MAX_NUM_ASSETS + 1
+        );
+    }
+
+    #[test]
+    fn try_subtract_and_split() {
+        let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_NON_FUNGIBLE_FAUCET).unwrap();
+        let details = NonFungibleAssetDetails::new(account_id.prefix(), vec![1, 2, 3]).unwrap();
+
+        let asset1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 100).unwrap());
+        let asset2 = Asset::Fungible(FungibleAsset::new(faucet_id_2, 50).unwrap());
+        let non_fungible_asset = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+        let assets = NoteAssets::new(vec![asset1, non_fungible_asset, asset2]).unwrap();
+
+        // subtracting the full amount removes the asset, preserving the order of the others
+        let remaining = assets.try_subtract(&asset1).unwrap();
+        assert_eq!(remaining.iter().copied().collect::<Vec<_>>(), vec![non_fungible_asset, asset2]);
+        assert_eq!(remaining.commitment(), compute_asset_commitment(&[non_fungible_asset, asset2]));
+
+        let remaining = assets.try_subtract(&non_fungible_asset).unwrap();
+        assert_eq!(remaining.iter().copied().collect::<Vec<_>>(), vec![asset1, asset2]);
+
+        assert_matches!(
+            assets.try_subtract(&FungibleAsset::new(faucet_id_1, 101).unwrap().into()),
+            Err(NoteError::SubtractFungibleAssetBalanceError(_))
+        );
+        assert_matches!(
+            remaining.try_subtract(&non_fungible_asset),
+            Err(NoteError::NonFungibleAssetNotFound(_))
+        );
+        assert_matches!(
+            NoteAssets::default().try_subtract(&asset1),
+            Err(NoteError::FungibleAssetNotFound(faucet_id)) if faucet_id == faucet_id_1
+        );
+
+        // splitting off a partial amount keeps the remainder in place
+        let (remaining, split) =
+            assets.split(FungibleAsset::new(faucet_id_1, 30).unwrap()).unwrap();
+        let expected_remainder = Asset::Fungible(FungibleAsset::new(faucet_id_1, 70).unwrap());
+        assert_eq!(
+            remaining.iter().copied().collect::<Vec<_>>(),
+            vec![expected_remainder, non_fungible_asset, asset2]
+        );
+        assert_eq!(split.total_fungible(faucet_id_1), 30);
+        assert_eq!(split.num_assets(), 1);
+        assert_eq!(
+            remaining.total_fungible(faucet_id_1) + split.total_fungible(faucet_id_1),
+            assets.total_fungible(faucet_id_1)
+        );
+    }
 }
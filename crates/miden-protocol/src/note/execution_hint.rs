@@ -1,6 +1,8 @@
 // NOTE EXECUTION HINT
 // ================================================================================================
 
+use core::fmt;
+
 use crate::Felt;
 use crate::block::BlockNumber;
 use crate::errors::NoteError;
@@ -58,6 +60,11 @@ impl NoteExecutionHint {
     pub(crate) const AFTER_BLOCK_TAG: u8 = 2;
     pub(crate) const ON_BLOCK_SLOT_TAG: u8 = 3;
 
+    /// The maximum length of a round of a [NoteExecutionHint::OnBlockSlot] hint, in powers of 2.
+    ///
+    /// A round of `2^32` blocks spans all block numbers.
+    pub const MAX_ROUND_LEN: u8 = 32;
+
     // CONSTRUCTORS
     // ------------------------------------------------------------------------------------------------
 
@@ -76,12 +83,54 @@ impl NoteExecutionHint {
         NoteExecutionHint::AfterBlock { block_num }
     }
 
+    /// Creates a [NoteExecutionHint::AfterBlock] variant based on the given `block_num`, validating
+    /// it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `block_num` is `u32::MAX`.
+    pub fn try_after_block(block_num: BlockNumber) -> Result<Self, NoteError> {
+        if block_num.as_u32() == u32::MAX {
+            return Err(NoteError::NoteExecutionHintAfterBlockCannotBeU32Max);
+        }
+
+        Ok(NoteExecutionHint::after_block(block_num))
+    }
+
     /// Creates a [NoteExecutionHint::OnBlockSlot] for the given parameters. See the variants
     /// documentation for details on the parameters.
     pub fn on_block_slot(round_len: u8, slot_len: u8, slot_offset: u8) -> Self {
         NoteExecutionHint::OnBlockSlot { round_len, slot_len, slot_offset }
     }
 
+    /// Creates a [NoteExecutionHint::OnBlockSlot] for the given parameters, validating them first.
+    /// See the variants documentation for details on the parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `round_len` exceeds [`Self::MAX_ROUND_LEN`].
+    /// - `slot_len` exceeds `round_len`.
+    /// - `slot_offset` does not identify a slot within the round, i.e. if it is not less than
+    ///   `2^(round_len - slot_len)`.
+    pub fn try_on_block_slot(
+        round_len: u8,
+        slot_len: u8,
+        slot_offset: u8,
+    ) -> Result<Self, NoteError> {
+        let hint = NoteExecutionHint::on_block_slot(round_len, slot_len, slot_offset);
+
+        let is_valid = round_len <= Self::MAX_ROUND_LEN
+            && slot_len <= round_len
+            && u64::from(slot_offset) < 1 << (round_len - slot_len);
+        if !is_valid {
+            let (tag, payload) = hint.into_parts();
+            return Err(NoteError::InvalidNoteExecutionHintPayload(tag, payload));
+        }
+
+        Ok(hint)
+    }
+
     pub fn from_parts(tag: u8, payload: u32) -> Result<NoteExecutionHint, NoteError> {
         match tag {
             Self::NONE_TAG => {
@@ -117,7 +166,9 @@ impl NoteExecutionHint {
     /// Returns whether the note execution conditions validate for the given `block_num`
     ///
     /// # Returns
-    /// - `None` if we don't know whether the note can be consumed.
+    /// - `None` if we don't know whether the note can be consumed, which is the case for
+    ///   [NoteExecutionHint::None] and for [NoteExecutionHint::OnBlockSlot] hints with parameters
+    ///   rejected by [NoteExecutionHint::try_on_block_slot].
     /// - `Some(true)` if the note is consumable for the given `block_num`
     /// - `Some(false)` if the note is not consumable for the given `block_num`
     pub fn can_be_consumed(&self, block_num: BlockNumber) -> Option<bool> {
@@ -129,17 +180,16 @@ impl NoteExecutionHint {
                 Some(block_num >= hint_block_num.as_u32())
             },
             NoteExecutionHint::OnBlockSlot { round_len, slot_len, slot_offset } => {
-                let round_len_blocks: u32 = 1 << round_len;
-                let slot_len_blocks: u32 = 1 << slot_len;
-
-                let block_round_index = block_num / round_len_blocks;
+                // Reject parameters that cannot be constructed through the validating constructor,
+                // so that the block arithmetic below cannot overflow.
+                Self::try_on_block_slot(*round_len, *slot_len, *slot_offset).ok()?;
 
-                let slot_start_block =
-                    block_round_index * round_len_blocks + (*slot_offset as u32) * slot_len_blocks;
-                let slot_end_block = slot_start_block + slot_len_blocks;
+                // The arithmetic is done in u64 since a round of 2^32 blocks does not fit in u32.
+                let round_len_blocks: u64 = 1 << round_len;
+                let slot_len_blocks: u64 = 1 << slot_len;
 
-                let can_be_consumed = block_num >= slot_start_block && block_num < slot_end_block;
-                Some(can_be_consumed)
+                let block_slot_index = (u64::from(block_num) % round_len_blocks) / slot_len_blocks;
+                Some(block_slot_index == u64::from(*slot_offset))
             },
         }
     }
@@ -161,6 +211,24 @@ impl NoteExecutionHint {
     }
 }
 
+/// Describes the conditions of the [`NoteExecutionHint`] in words.
+impl fmt::Display for NoteExecutionHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteExecutionHint::None => f.write_str("unknown execution conditions"),
+            NoteExecutionHint::Always => f.write_str("executable at any block"),
+            NoteExecutionHint::AfterBlock { block_num } => {
+                write!(f, "executable from block {block_num} onwards")
+            },
+            NoteExecutionHint::OnBlockSlot { round_len, slot_len, slot_offset } => write!(
+                f,
+                "executable in slot {slot_offset} of 2^{slot_len} blocks of every round of \
+                 2^{round_len} blocks"
+            ),
+        }
+    }
+}
+
 /// Converts a [`NoteExecutionHint`] into a [`Felt`] with the layout documented on the type.
 impl From<NoteExecutionHint> for Felt {
     fn from(value: NoteExecutionHint) -> Self {
@@ -197,6 +265,9 @@ impl From<NoteExecutionHint> for u64 {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
+    use assert_matches::assert_matches;
 
     use super::*;
 
@@ -272,4 +343,78 @@ mod tests {
 
         NoteExecutionHint::from_parts(10, 1).unwrap_err();
     }
+
+    #[test]
+    fn test_constructor_validation() {
+        assert_matches!(
+            NoteExecutionHint::try_after_block(u32::MAX.into()),
+            Err(NoteError::NoteExecutionHintAfterBlockCannotBeU32Max)
+        );
+
+        for (round_len, slot_len, slot_offset) in [(33, 0, 0), (5, 6, 0), (10, 7, 8), (3, 0, 8)] {
+            assert_matches!(
+                NoteExecutionHint::try_on_block_slot(round_len, slot_len, slot_offset),
+                Err(NoteError::InvalidNoteExecutionHintPayload(tag, _))
+                    if tag == NoteExecutionHint::ON_BLOCK_SLOT_TAG
+            );
+
+            // decoding is lenient, but the consumability of such hints is unknown
+            let hint = NoteExecutionHint::on_block_slot(round_len, slot_len, slot_offset);
+            let (tag, payload) = hint.into_parts();
+            assert_eq!(NoteExecutionHint::from_parts(tag, payload).unwrap(), hint);
+            assert!(hint.can_be_consumed(0.into()).is_none());
+        }
+
+        NoteExecutionHint::try_on_block_slot(32, 32, 0).unwrap();
+        NoteExecutionHint::try_on_block_slot(10, 7, 7).unwrap();
+        NoteExecutionHint::try_on_block_slot(0, 0, 0).unwrap();
+    }
+
+    #[test]
+    fn test_can_be_consumed_at_boundaries() {
+        // the last slot of a round ends right before the next round starts
+        let last_slot = NoteExecutionHint::try_on_block_slot(4, 2, 3).unwrap();
+        assert!(!last_slot.can_be_consumed(11.into()).unwrap());
+        assert!(last_slot.can_be_consumed(12.into()).unwrap());
+        assert!(last_slot.can_be_consumed(15.into()).unwrap());
+        assert!(!last_slot.can_be_consumed(16.into()).unwrap());
+        assert!(last_slot.can_be_consumed(28.into()).unwrap());
+
+        // the slots of the last round end at the largest block number
+        let max_block = BlockNumber::from(u32::MAX);
+        assert!(last_slot.can_be_consumed(max_block).unwrap());
+        let second_to_last_slot = NoteExecutionHint::try_on_block_slot(4, 2, 2).unwrap();
+        assert!(!second_to_last_slot.can_be_consumed(max_block).unwrap());
+
+        // a round of 2^32 blocks spans all block numbers
+        let first_half = NoteExecutionHint::try_on_block_slot(32, 31, 0).unwrap();
+        let second_half = NoteExecutionHint::try_on_block_slot(32, 31, 1).unwrap();
+        for block_num in [0, (1 << 31) - 1] {
+            assert!(first_half.can_be_consumed(block_num.into()).unwrap());
+            assert!(!second_half.can_be_consumed(block_num.into()).unwrap());
+        }
+        for block_num in [1 << 31, u32::MAX] {
+            assert!(!first_half.can_be_consumed(block_num.into()).unwrap());
+            assert!(second_half.can_be_consumed(block_num.into()).unwrap());
+        }
+
+        // a single slot spanning the whole round is always active
+        let whole_round = NoteExecutionHint::try_on_block_slot(32, 32, 0).unwrap();
+        assert!(whole_round.can_be_consumed(0.into()).unwrap());
+        assert!(whole_round.can_be_consumed(max_block).unwrap());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(NoteExecutionHint::none().to_string(), "unknown execution conditions");
+        assert_eq!(NoteExecutionHint::always().to_string(), "executable at any block");
+        assert_eq!(
+            NoteExecutionHint::after_block(15.into()).to_string(),
+            "executable from block 15 onwards"
+        );
+        assert_eq!(
+            NoteExecutionHint::on_block_slot(10, 7, 1).to_string(),
+            "executable in slot 1 of 2^7 blocks of every round of 2^10 blocks"
+        );
+    }
 }
@@ -1,4 +1,6 @@
-use miden_crypto::merkle::SparseMerklePath;
+use alloc::collections::BTreeMap;
+
+use miden_crypto::merkle::{MerklePath, SparseMerklePath};
 
 use super::{
     ByteReader,
@@ -6,11 +8,12 @@ use super::{
     Deserializable,
     DeserializationError,
     NoteError,
+    NoteId,
     Serializable,
 };
-use crate::block::BlockNumber;
+use crate::block::{BlockHeader, BlockNumber};
 use crate::crypto::merkle::InnerNodeInfo;
-use crate::{MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH, Word};
+use crate::{Hasher, MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH, Word};
 
 /// Contains information about the location of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -94,6 +97,120 @@ impl NoteInclusionProof {
             .authenticated_nodes(self.location.node_index_in_block().into(), note_commitment)
             .expect("note index is not out of bounds")
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the provided ID and commitment is included in the note tree of
+    /// the provided block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - this proof was created for a block other than the provided one.
+    /// - the note commitment does not open to the note root of the provided block header.
+    pub fn verify(
+        &self,
+        note_id: NoteId,
+        note_commitment: Word,
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        self.validate_block_num(note_id, block_header)?;
+
+        let note_index = self.location.node_index_in_block().into();
+        self.note_path
+            .verify(note_index, note_commitment, &block_header.note_root())
+            .map_err(|_| NoteError::NoteNotInBlock {
+                note_id,
+                block_num: block_header.block_num(),
+            })
+    }
+
+    /// Verifies a batch of note inclusion proofs against the note tree of the provided block.
+    ///
+    /// Each entry consists of the note ID, the note commitment and the inclusion proof of that
+    /// note. Parent nodes computed while verifying one proof are reused by subsequent proofs
+    /// sharing the same path segment, so verifying many notes of the same block requires
+    /// considerably fewer hash computations than calling [`Self::verify`] for each of them.
+    ///
+    /// The result is the same as calling [`Self::verify`] for each entry in order and returning
+    /// the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first entry for which [`Self::verify`] would fail.
+    pub fn verify_batch(
+        proofs: &[(NoteId, Word, &NoteInclusionProof)],
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        let note_root = block_header.note_root();
+
+        // Maps a parent node, identified by its depth and index, to the children it was computed
+        // from and its value. The cached value is only reused if the children are identical.
+        let mut parents: BTreeMap<(u8, u64), ([Word; 2], Word)> = BTreeMap::new();
+
+        for (note_id, note_commitment, proof) in proofs {
+            proof.validate_block_num(*note_id, block_header)?;
+
+            let not_in_block_err = || NoteError::NoteNotInBlock {
+                note_id: *note_id,
+                block_num: block_header.block_num(),
+            };
+
+            let path = MerklePath::from(proof.note_path.clone());
+            let mut depth = path.depth();
+            let mut index = u64::from(proof.location.node_index_in_block());
+            if index.checked_shr(u32::from(depth)).is_some_and(|high_bits| high_bits != 0) {
+                return Err(not_in_block_err());
+            }
+
+            let mut node = *note_commitment;
+            for sibling in path.nodes() {
+                let children = if index & 1 == 0 {
+                    [node, *sibling]
+                } else {
+                    [*sibling, node]
+                };
+                depth -= 1;
+                index >>= 1;
+
+                node = match parents.get(&(depth, index)) {
+                    Some((cached_children, parent)) if *cached_children == children => *parent,
+                    _ => {
+                        let parent = Hasher::merge(&children);
+                        parents.insert((depth, index), (children, parent));
+                        parent
+                    },
+                };
+            }
+
+            if node != note_root {
+                return Err(not_in_block_err());
+            }
+        }
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if this proof was not created for the provided block.
+    fn validate_block_num(
+        &self,
+        note_id: NoteId,
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        if self.location.block_num() != block_header.block_num() {
+            return Err(NoteError::NoteInclusionProofBlockMismatch {
+                note_id,
+                proof_block_num: self.location.block_num(),
+                block_num: block_header.block_num(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 // SERIALIZATION
@@ -130,3 +247,92 @@ impl Deserializable for NoteInclusionProof {
         Ok(Self { location, note_path })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::account::AccountId;
+    use crate::block::{BlockNoteIndex, BlockNoteTree};
+    use crate::note::{NoteMetadata, NoteTag, NoteType, compute_note_commitment};
+    use crate::testing::account_id::ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE;
+
+    #[test]
+    fn verify_batch_matches_individual_verification() -> anyhow::Result<()> {
+        const NUM_NOTES: usize = 100;
+
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE)?;
+        let notes: Vec<_> = (0..NUM_NOTES)
+            .map(|idx| {
+                let note_id = NoteId::new(Word::from([idx as u32, 1, 2, 3]), Word::empty());
+                let metadata =
+                    NoteMetadata::new(sender, NoteType::Private, NoteTag::from(idx as u32));
+                let note_index = BlockNoteIndex::new(idx / 32, idx % 32).unwrap();
+                (note_index, note_id, metadata)
+            })
+            .collect();
+
+        let note_tree = BlockNoteTree::with_entries(
+            notes.iter().map(|(index, note_id, metadata)| (*index, *note_id, metadata)),
+        )?;
+        let block_num = BlockNumber::from(1);
+        let block_header =
+            BlockHeader::mock(block_num, None, Some(note_tree.root()), &[], Word::empty());
+
+        let leaf_indices: Vec<usize> =
+            notes.iter().map(|(index, ..)| index.leaf_index_value() as usize).collect();
+        let paths = note_tree.open_many(&leaf_indices)?;
+        let proofs = leaf_indices
+            .iter()
+            .zip(paths)
+            .map(|(&leaf_index, path)| NoteInclusionProof::new(block_num, leaf_index as u16, path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entries: Vec<_> = notes
+            .iter()
+            .zip(proofs.iter())
+            .map(|((_, note_id, metadata), proof)| {
+                (*note_id, compute_note_commitment(*note_id, metadata), proof)
+            })
+            .collect();
+
+        // All proofs are valid.
+        for (note_id, note_commitment, proof) in &entries {
+            proof.verify(*note_id, *note_commitment, &block_header)?;
+        }
+        NoteInclusionProof::verify_batch(&entries, &block_header)?;
+
+        // Tampering with a commitment results in the same error on both paths.
+        let (tampered_note_id, ..) = entries[57];
+        entries[57].1 = Word::from([9, 9, 9, 9u32]);
+        let individual_err = entries
+            .iter()
+            .find_map(|(note_id, note_commitment, proof)| {
+                proof.verify(*note_id, *note_commitment, &block_header).err()
+            })
+            .expect("tampered entry should fail verification");
+        let batch_err = NoteInclusionProof::verify_batch(&entries, &block_header).unwrap_err();
+
+        assert_matches!(
+            individual_err,
+            NoteError::NoteNotInBlock { note_id, .. } if note_id == tampered_note_id
+        );
+        assert_eq!(batch_err.to_string(), individual_err.to_string());
+
+        // Verifying against a different block fails on both paths.
+        let other_block_header =
+            BlockHeader::mock(2, None, Some(note_tree.root()), &[], Word::empty());
+        let batch_err =
+            NoteInclusionProof::verify_batch(&entries, &other_block_header).unwrap_err();
+        assert_matches!(batch_err, NoteError::NoteInclusionProofBlockMismatch { .. });
+
+        Ok(())
+    }
+}
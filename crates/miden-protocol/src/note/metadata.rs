@@ -1,3 +1,6 @@
+use alloc::format;
+use alloc::vec::Vec;
+
 use super::{
     AccountId,
     ByteReader,
@@ -12,7 +15,12 @@ use super::{
 };
 use crate::Hasher;
 use crate::errors::NoteError;
-use crate::note::{NoteAttachment, NoteAttachmentKind, NoteAttachmentScheme};
+use crate::note::{
+    NoteAttachment,
+    NoteAttachmentContent,
+    NoteAttachmentKind,
+    NoteAttachmentScheme,
+};
 
 // NOTE METADATA
 // ================================================================================================
@@ -86,6 +94,48 @@ impl NoteMetadata {
         }
     }
 
+    /// Reconstructs a [`NoteMetadata`] from its header and attachment words.
+    ///
+    /// This is the inverse of [`NoteMetadata::to_header_word`] and
+    /// [`NoteMetadata::to_attachment_word`]. If the header indicates an array attachment, its
+    /// elements are retrieved by calling `array_elements` with the attachment word, i.e., the
+    /// commitment to the array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The header word is not a valid note metadata header.
+    /// - The elements of an array attachment are not provided or do not match the attachment word.
+    pub(crate) fn try_from_words(
+        header_word: Word,
+        attachment_word: Word,
+        array_elements: impl FnOnce(Word) -> Option<Vec<Felt>>,
+    ) -> Result<Self, NoteError> {
+        let header = NoteMetadataHeader::try_from(header_word)?;
+
+        let content = match header.attachment_kind {
+            NoteAttachmentKind::None => NoteAttachmentContent::None,
+            NoteAttachmentKind::Word => NoteAttachmentContent::new_word(attachment_word),
+            NoteAttachmentKind::Array => {
+                let elements = array_elements(attachment_word).ok_or_else(|| {
+                    NoteError::other(format!(
+                        "elements of array attachment {attachment_word} not found"
+                    ))
+                })?;
+                let content = NoteAttachmentContent::new_array(elements)?;
+                if content.to_word() != attachment_word {
+                    return Err(NoteError::other(format!(
+                        "elements of array attachment do not match commitment {attachment_word}"
+                    )));
+                }
+                content
+            },
+        };
+        let attachment = NoteAttachment::new(header.attachment_scheme, content)?;
+
+        Ok(Self::new(header.sender, header.note_type, header.tag).with_attachment(attachment))
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
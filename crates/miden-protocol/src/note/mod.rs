@@ -60,6 +60,9 @@ pub use script::NoteScript;
 mod file;
 pub use file::NoteFile;
 
+mod sealed;
+pub use sealed::SealedNoteDetails;
+
 // NOTE
 // ================================================================================================
 
@@ -233,3 +236,26 @@ impl Deserializable for Note {
         Ok(Self::new(assets, metadata, recipient))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::utils::{Deserializable, Serializable};
+    use proptest::prelude::*;
+
+    use super::Note;
+    use crate::testing::strategies::any_note;
+
+    proptest! {
+        #[test]
+        fn proptest_note_serialization_round_trip(note in any_note()) {
+            let deserialized = Note::read_from_bytes(&note.to_bytes()).unwrap();
+
+            prop_assert_eq!(deserialized.id(), note.id());
+            prop_assert_eq!(deserialized.nullifier(), note.nullifier());
+            prop_assert_eq!(deserialized, note);
+        }
+    }
+}
@@ -117,6 +117,22 @@ impl NoteTag {
     pub fn as_u32(&self) -> u32 {
         self.0
     }
+
+    /// Returns `true` if this tag targets the given `account_id`, i.e. if it is equal to
+    /// [`NoteTag::with_custom_account_target`] for the account and some tag length.
+    ///
+    /// This applies to all account storage modes alike, since account target tags are constructed
+    /// in the same way for local and network accounts. Use case tags only match an account if
+    /// their bits happen to coincide with the most significant bits of its ID prefix.
+    ///
+    /// Since tags are not validated, this is only a best-effort filter: tags constructed in other
+    /// ways may match unrelated accounts.
+    pub fn matches(&self, account_id: AccountId) -> bool {
+        let high_bits = (account_id.prefix().as_u64() >> 32) as u32;
+        // Create a mask that keeps all bits down to the least significant set bit of the tag.
+        let mask = u32::MAX.checked_shl(self.0.trailing_zeros()).unwrap_or(0);
+        high_bits & mask == self.0
+    }
 }
 
 impl fmt::Display for NoteTag {
@@ -263,4 +279,55 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn matches_account_target_tags() -> anyhow::Result<()> {
+        let account_id = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+        let other_account_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)?;
+
+        for tag_len in 0..=NoteTag::MAX_ACCOUNT_TARGET_TAG_LENGTH {
+            let tag = NoteTag::with_custom_account_target(account_id, tag_len)?;
+            assert!(tag.matches(account_id), "tag of length {tag_len} should match");
+        }
+
+        // A tag encoding all 32 bits only matches the exact account.
+        let exact_tag = NoteTag::with_custom_account_target(
+            account_id,
+            NoteTag::MAX_ACCOUNT_TARGET_TAG_LENGTH,
+        )?;
+        assert!(exact_tag.matches(account_id));
+        assert!(!exact_tag.matches(other_account_id));
+
+        assert!(!NoteTag::with_account_target(other_account_id).matches(account_id));
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_network_account_tags() -> anyhow::Result<()> {
+        let network_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_NETWORK_ACCOUNT_IMMUTABLE_CODE)?;
+        let local_account_id = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+
+        let tag = NoteTag::with_account_target(network_account_id);
+        assert!(tag.matches(network_account_id));
+        assert!(!tag.matches(local_account_id));
+
+        Ok(())
+    }
+
+    #[test]
+    fn matches_use_case_tags() -> anyhow::Result<()> {
+        let account_id = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+        let account_high_bits = (account_id.prefix().as_u64() >> 32) as u32;
+
+        // A use case tag whose least significant bit is set must match all 32 bits.
+        let use_case_tag = NoteTag::new(!account_high_bits | 1);
+        assert!(!use_case_tag.matches(account_id));
+
+        // The zero tag does not encode any bits and thus matches every account.
+        assert!(NoteTag::new(0).matches(account_id));
+
+        Ok(())
+    }
 }
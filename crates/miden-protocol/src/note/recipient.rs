@@ -32,7 +32,7 @@ pub struct NoteRecipient {
 
 impl NoteRecipient {
     pub fn new(serial_num: Word, script: NoteScript, storage: NoteStorage) -> Self {
-        let digest = compute_recipient_digest(serial_num, &script, &storage);
+        let digest = Self::compute_digest(serial_num, script.root(), storage.commitment());
         Self { serial_num, script, storage, digest }
     }
 
@@ -60,12 +60,20 @@ impl NoteRecipient {
     pub fn digest(&self) -> Word {
         self.digest
     }
-}
 
-fn compute_recipient_digest(serial_num: Word, script: &NoteScript, storage: &NoteStorage) -> Word {
-    let serial_num_hash = Hasher::merge(&[serial_num, Word::empty()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.root()]);
-    Hasher::merge(&[merge_script, storage.commitment()])
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes the recipient digest from its serial number, script root and storage commitment.
+    ///
+    /// This allows computing the digest of a recipient without having the full script and
+    /// storage at hand, e.g. to commit to a recipient before revealing its details. The result is
+    /// equal to [`Self::digest`] of the recipient built from the same data.
+    pub fn compute_digest(serial_num: Word, script_root: Word, storage_commitment: Word) -> Word {
+        let serial_num_hash = Hasher::merge(&[serial_num, Word::empty()]);
+        let merge_script = Hasher::merge(&[serial_num_hash, script_root]);
+        Hasher::merge(&[merge_script, storage_commitment])
+    }
 }
 
 // SERIALIZATION
@@ -98,3 +106,34 @@ impl Deserializable for NoteRecipient {
         Ok(Self::new(serial_num, script, storage))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteRecipient, NoteScript, NoteStorage, Word};
+    use crate::Felt;
+
+    #[test]
+    fn recipient_digest_is_deterministic() {
+        let serial_num = Word::from([1, 2, 3, 4u32]);
+        let storage = NoteStorage::new(vec![Felt::new(5), Felt::new(6)]).unwrap();
+
+        let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), storage.clone());
+        let other = NoteRecipient::new(serial_num, NoteScript::mock(), storage.clone());
+        assert_eq!(recipient.digest(), other.digest());
+        assert_eq!(
+            recipient.digest(),
+            NoteRecipient::compute_digest(
+                serial_num,
+                NoteScript::mock().root(),
+                storage.commitment()
+            )
+        );
+
+        let other_serial_num = Word::from([4, 3, 2, 1u32]);
+        let other = NoteRecipient::new(other_serial_num, NoteScript::mock(), storage);
+        assert_ne!(recipient.digest(), other.digest());
+    }
+}
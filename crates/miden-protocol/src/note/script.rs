@@ -48,6 +48,8 @@ impl NoteScript {
 
     /// Returns a new [NoteScript] deserialized from the provided bytes.
     ///
+    /// The bytes are expected to be produced by [`Serializable::to_bytes`].
+    ///
     /// # Errors
     /// Returns an error if note script deserialization fails.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, NoteError> {
@@ -109,10 +111,19 @@ impl NoteScript {
     }
 
     /// Returns an entrypoint node ID of the current script.
+    ///
+    /// The MAST root of the entrypoint procedure is returned by [`Self::root`].
     pub fn entrypoint(&self) -> MastNodeId {
         self.entrypoint
     }
 
+    /// Returns the MAST roots of all procedures in the [MastForest] backing this note script.
+    ///
+    /// The returned roots include the root of the script's entrypoint.
+    pub fn procedure_roots(&self) -> Vec<Word> {
+        self.mast.procedure_digests().collect()
+    }
+
     /// Returns a new [NoteScript] with the provided advice map entries merged into the
     /// underlying [MastForest].
     ///
@@ -255,7 +266,11 @@ impl Display for NoteScript {
 
 #[cfg(test)]
 mod tests {
-    use super::{Felt, NoteScript, Vec};
+    use assert_matches::assert_matches;
+    use miden_processor::MastNodeExt;
+
+    use super::{Felt, NoteError, NoteScript, Serializable, Vec};
+    use crate::Word;
     use crate::assembly::Assembler;
     use crate::testing::note::DEFAULT_NOTE_CODE;
 
@@ -272,6 +287,36 @@ mod tests {
         assert_eq!(note_script, decoded);
     }
 
+    #[test]
+    fn test_note_script_to_from_bytes() {
+        let assembler = Assembler::default();
+        let program = assembler.assemble_program(DEFAULT_NOTE_CODE).unwrap();
+        let note_script = NoteScript::new(program);
+
+        let decoded = NoteScript::from_bytes(&note_script.to_bytes()).unwrap();
+        assert_eq!(note_script, decoded);
+        assert_eq!(note_script.root(), decoded.root());
+
+        assert_matches!(
+            NoteScript::from_bytes(&[1, 2, 3]),
+            Err(NoteError::NoteScriptDeserializationError(_))
+        );
+    }
+
+    #[test]
+    fn test_note_script_procedure_roots() {
+        let assembler = Assembler::default();
+        let program = assembler.assemble_program(DEFAULT_NOTE_CODE).unwrap();
+        let note_script = NoteScript::new(program);
+
+        assert_ne!(note_script.root(), Word::empty());
+        assert_eq!(note_script.root(), note_script.mast()[note_script.entrypoint()].digest());
+
+        let procedure_roots = note_script.procedure_roots();
+        assert!(!procedure_roots.is_empty());
+        assert!(procedure_roots.contains(&note_script.root()));
+    }
+
     #[test]
     fn test_note_script_with_advice_map() {
         use miden_core::{AdviceMap, Word};
@@ -0,0 +1,213 @@
+use alloc::vec::Vec;
+
+use miden_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable, SliceReader};
+use miden_processor::DeserializationError;
+use rand::{CryptoRng, RngCore};
+
+use super::NoteDetails;
+use crate::crypto::ies::{IesScheme, SealedMessage, SealingKey, UnsealingKey};
+use crate::errors::NoteError;
+
+/// The associated data bound to every sealed message of [`SealedNoteDetails`], which prevents
+/// messages sealed for other purposes from being unsealed as note details.
+const SEALED_NOTE_DETAILS_DOMAIN: &[u8] = b"miden::protocol::note::sealed_details";
+
+// SEALED NOTE DETAILS
+// ================================================================================================
+
+/// [`NoteDetails`] encrypted to the public key of their recipient.
+///
+/// Sealed note details are meant for the off-chain delivery of notes, e.g. via note files or QR
+/// codes, such that only the intended recipient learns the details of the note and thus which
+/// account the note is meant for.
+///
+/// The details are sealed with the integrated encryption scheme of the recipient's
+/// [`SealingKey`], using a fresh ephemeral key for every sealed message, and can only be unsealed
+/// with the matching [`UnsealingKey`]. Any modification of the sealed message is detected when
+/// unsealing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedNoteDetails {
+    sealed_message: SealedMessage,
+}
+
+impl SealedNoteDetails {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Seals the provided note details to the provided recipient key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encryption of the note details fails.
+    pub fn seal<R: CryptoRng + RngCore>(
+        details: &NoteDetails,
+        recipient_key: &SealingKey,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let sealed_message = recipient_key
+            .seal_bytes_with_associated_data(rng, &details.to_bytes(), SEALED_NOTE_DETAILS_DOMAIN)
+            .map_err(NoteError::NoteDetailsSealingFailed)?;
+
+        Ok(Self { sealed_message })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the integrated encryption scheme with which the note details were sealed.
+    pub fn scheme(&self) -> IesScheme {
+        let (scheme, _) = self.parse_header();
+        scheme
+    }
+
+    /// Returns the serialized ephemeral public key with which the note details were sealed.
+    ///
+    /// The key is fresh for every sealed message and carries no information about the recipient.
+    pub fn ephemeral_public_key(&self) -> Vec<u8> {
+        let (_, ephemeral_public_key) = self.parse_header();
+        ephemeral_public_key
+    }
+
+    /// Returns the underlying sealed message.
+    pub fn sealed_message(&self) -> &SealedMessage {
+        &self.sealed_message
+    }
+
+    // UNSEALING
+    // --------------------------------------------------------------------------------------------
+
+    /// Unseals the note details with the provided secret key of the recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the key does not match the key the note details were sealed to.
+    /// - the sealed message was tampered with.
+    /// - the unsealed bytes are not valid note details.
+    pub fn unseal(&self, secret_key: &UnsealingKey) -> Result<NoteDetails, NoteError> {
+        let bytes = secret_key
+            .unseal_bytes_with_associated_data(
+                self.sealed_message.clone(),
+                SEALED_NOTE_DETAILS_DOMAIN,
+            )
+            .map_err(NoteError::NoteDetailsUnsealingFailed)?;
+
+        NoteDetails::read_from_bytes(&bytes)
+            .map_err(NoteError::UnsealedNoteDetailsDeserializationFailed)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses the scheme and the ephemeral public key from the serialized sealed message, since
+    /// [`SealedMessage`] does not expose them directly.
+    fn parse_header(&self) -> (IesScheme, Vec<u8>) {
+        let bytes = self.sealed_message.to_bytes();
+        let mut reader = SliceReader::new(&bytes);
+
+        let scheme = reader
+            .read_u8()
+            .ok()
+            .and_then(|scheme| IesScheme::try_from(scheme).ok())
+            .expect("sealed message should start with a valid scheme");
+        let ephemeral_public_key = Vec::<u8>::read_from(&mut reader)
+            .expect("sealed message should contain the ephemeral public key");
+
+        (scheme, ephemeral_public_key)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SealedNoteDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.sealed_message.write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.sealed_message.get_size_hint()
+    }
+}
+
+impl Deserializable for SealedNoteDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let sealed_message = SealedMessage::read_from(source)?;
+        Ok(Self { sealed_message })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_core::utils::{Deserializable, Serializable};
+
+    use super::SealedNoteDetails;
+    use crate::crypto::dsa::{ecdsa_k256_keccak, eddsa_25519_sha512};
+    use crate::crypto::ies::{IesScheme, SealingKey, UnsealingKey};
+    use crate::errors::NoteError;
+    use crate::note::{Note, NoteDetails};
+
+    fn x25519_keys() -> (SealingKey, UnsealingKey) {
+        let secret_key = eddsa_25519_sha512::SecretKey::with_rng(&mut rand::rng());
+        (
+            SealingKey::X25519XChaCha20Poly1305(secret_key.public_key()),
+            UnsealingKey::X25519XChaCha20Poly1305(secret_key),
+        )
+    }
+
+    #[test]
+    fn sealed_note_details_round_trip() -> anyhow::Result<()> {
+        let details = NoteDetails::from(Note::mock_noop(Default::default()));
+        let (sealing_key, unsealing_key) = x25519_keys();
+
+        let sealed = SealedNoteDetails::seal(&details, &sealing_key, &mut rand::rng())?;
+        assert_eq!(sealed.scheme(), IesScheme::X25519XChaCha20Poly1305);
+        assert!(!sealed.ephemeral_public_key().is_empty());
+
+        let deserialized = SealedNoteDetails::read_from_bytes(&sealed.to_bytes())?;
+        assert_eq!(deserialized, sealed);
+        assert_eq!(deserialized.unseal(&unsealing_key)?, details);
+
+        // every sealed message uses a fresh ephemeral key
+        let resealed = SealedNoteDetails::seal(&details, &sealing_key, &mut rand::rng())?;
+        assert_ne!(resealed.ephemeral_public_key(), sealed.ephemeral_public_key());
+
+        // the details cannot be unsealed with another key
+        let (_, other_unsealing_key) = x25519_keys();
+        assert_matches!(
+            sealed.unseal(&other_unsealing_key),
+            Err(NoteError::NoteDetailsUnsealingFailed(_))
+        );
+
+        let k256_secret_key = ecdsa_k256_keccak::SecretKey::with_rng(&mut rand::rng());
+        assert_matches!(
+            sealed.unseal(&UnsealingKey::K256XChaCha20Poly1305(k256_secret_key)),
+            Err(NoteError::NoteDetailsUnsealingFailed(_))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sealed_note_details_reject_tampered_ciphertext() -> anyhow::Result<()> {
+        let details = NoteDetails::from(Note::mock_noop(Default::default()));
+        let (sealing_key, unsealing_key) = x25519_keys();
+        let sealed = SealedNoteDetails::seal(&details, &sealing_key, &mut rand::rng())?;
+
+        // the ciphertext is serialized last, so flipping the last byte tampers with it
+        let mut bytes = sealed.to_bytes();
+        *bytes.last_mut().unwrap() ^= 1;
+        let tampered = SealedNoteDetails::read_from_bytes(&bytes)?;
+
+        assert_matches!(
+            tampered.unseal(&unsealing_key),
+            Err(NoteError::NoteDetailsUnsealingFailed(_))
+        );
+
+        Ok(())
+    }
+}
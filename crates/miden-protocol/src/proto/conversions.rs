@@ -0,0 +1,225 @@
+use super::messages as proto;
+use crate::Word;
+use crate::account::AccountId;
+use crate::block::{BlockHeader, BlockNumber, FeeParameters};
+use crate::crypto::dsa::ecdsa_k256_keccak::PublicKey;
+use crate::errors::ProtoConversionError;
+use crate::note::{NoteAttachment, NoteMetadata, NoteTag, NoteType};
+use crate::transaction::ProvenTransaction;
+use crate::utils::serde::{Deserializable, Serializable};
+
+// DIGEST
+// ================================================================================================
+
+impl From<Word> for proto::Digest {
+    fn from(word: Word) -> Self {
+        let [d0, d1, d2, d3]: [u64; 4] = word.into();
+        Self { d0, d1, d2, d3 }
+    }
+}
+
+impl TryFrom<proto::Digest> for Word {
+    type Error = ProtoConversionError;
+
+    /// Converts the digest into a [`Word`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the elements is not a canonical field element.
+    fn try_from(digest: proto::Digest) -> Result<Self, Self::Error> {
+        Ok(Word::try_from([digest.d0, digest.d1, digest.d2, digest.d3])?)
+    }
+}
+
+// ACCOUNT ID
+// ================================================================================================
+
+impl From<AccountId> for proto::AccountId {
+    fn from(account_id: AccountId) -> Self {
+        Self {
+            id: <[u8; 15]>::from(account_id).to_vec(),
+        }
+    }
+}
+
+impl TryFrom<proto::AccountId> for AccountId {
+    type Error = ProtoConversionError;
+
+    /// Converts the message into an [`AccountId`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the ID does not consist of exactly 15 bytes.
+    /// - the bytes violate any of the [`AccountId`] constraints.
+    fn try_from(account_id: proto::AccountId) -> Result<Self, Self::Error> {
+        let bytes = <[u8; 15]>::try_from(account_id.id.as_slice()).map_err(|_| {
+            ProtoConversionError::InvalidLength {
+                field: "id",
+                expected: 15,
+                actual: account_id.id.len(),
+            }
+        })?;
+
+        Ok(AccountId::try_from(bytes)?)
+    }
+}
+
+// NOTE METADATA
+// ================================================================================================
+
+impl From<NoteType> for proto::NoteType {
+    fn from(note_type: NoteType) -> Self {
+        match note_type {
+            NoteType::Public => Self::Public,
+            NoteType::Private => Self::Private,
+        }
+    }
+}
+
+impl From<NoteMetadata> for proto::NoteMetadata {
+    fn from(metadata: NoteMetadata) -> Self {
+        Self {
+            sender: Some(metadata.sender().into()),
+            note_type: proto::NoteType::from(metadata.note_type()).into(),
+            tag: metadata.tag().as_u32(),
+            attachment: metadata.attachment().to_bytes(),
+        }
+    }
+}
+
+impl TryFrom<proto::NoteMetadata> for NoteMetadata {
+    type Error = ProtoConversionError;
+
+    /// Converts the message into [`NoteMetadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the sender is missing or is not a valid [`AccountId`].
+    /// - the note type is not a known [`NoteType`].
+    /// - the attachment cannot be deserialized.
+    fn try_from(metadata: proto::NoteMetadata) -> Result<Self, Self::Error> {
+        let sender = required(metadata.sender, "sender")?.try_into()?;
+        // the values of the message enum match the encoding of the note type, so the note type
+        // validation reports unknown values
+        let note_type = NoteType::try_from(metadata.note_type as u32)?;
+        let attachment = deserialize::<NoteAttachment>(&metadata.attachment, "attachment")?;
+
+        Ok(NoteMetadata::new(sender, note_type, NoteTag::from(metadata.tag))
+            .with_attachment(attachment))
+    }
+}
+
+// BLOCK HEADER
+// ================================================================================================
+
+impl From<FeeParameters> for proto::FeeParameters {
+    fn from(fee_parameters: FeeParameters) -> Self {
+        Self {
+            native_asset_id: Some(fee_parameters.native_asset_id().into()),
+            verification_base_fee: fee_parameters.verification_base_fee(),
+        }
+    }
+}
+
+impl TryFrom<proto::FeeParameters> for FeeParameters {
+    type Error = ProtoConversionError;
+
+    /// Converts the message into [`FeeParameters`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the native asset ID is missing, is not a valid [`AccountId`] or is not
+    /// the ID of a fungible faucet.
+    fn try_from(fee_parameters: proto::FeeParameters) -> Result<Self, Self::Error> {
+        let native_asset_id =
+            required(fee_parameters.native_asset_id, "native_asset_id")?.try_into()?;
+
+        Ok(FeeParameters::new(native_asset_id, fee_parameters.verification_base_fee)?)
+    }
+}
+
+impl From<BlockHeader> for proto::BlockHeader {
+    fn from(header: BlockHeader) -> Self {
+        Self {
+            version: header.version(),
+            prev_block_commitment: Some(header.prev_block_commitment().into()),
+            block_num: header.block_num().as_u32(),
+            chain_commitment: Some(header.chain_commitment().into()),
+            account_root: Some(header.account_root().into()),
+            nullifier_root: Some(header.nullifier_root().into()),
+            note_root: Some(header.note_root().into()),
+            tx_commitment: Some(header.tx_commitment().into()),
+            tx_kernel_commitment: Some(header.tx_kernel_commitment().into()),
+            validator_key: header.validator_key().to_bytes(),
+            fee_parameters: Some(header.fee_parameters().clone().into()),
+            timestamp: header.timestamp(),
+        }
+    }
+}
+
+impl TryFrom<proto::BlockHeader> for BlockHeader {
+    type Error = ProtoConversionError;
+
+    /// Converts the message into a [`BlockHeader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the fields is missing or cannot be converted.
+    fn try_from(header: proto::BlockHeader) -> Result<Self, Self::Error> {
+        Ok(BlockHeader::new(
+            header.version,
+            required(header.prev_block_commitment, "prev_block_commitment")?.try_into()?,
+            BlockNumber::from(header.block_num),
+            required(header.chain_commitment, "chain_commitment")?.try_into()?,
+            required(header.account_root, "account_root")?.try_into()?,
+            required(header.nullifier_root, "nullifier_root")?.try_into()?,
+            required(header.note_root, "note_root")?.try_into()?,
+            required(header.tx_commitment, "tx_commitment")?.try_into()?,
+            required(header.tx_kernel_commitment, "tx_kernel_commitment")?.try_into()?,
+            deserialize::<PublicKey>(&header.validator_key, "validator_key")?,
+            required(header.fee_parameters, "fee_parameters")?.try_into()?,
+            header.timestamp,
+        ))
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl From<ProvenTransaction> for proto::ProvenTransaction {
+    fn from(transaction: ProvenTransaction) -> Self {
+        Self { transaction: transaction.to_bytes() }
+    }
+}
+
+impl TryFrom<proto::ProvenTransaction> for ProvenTransaction {
+    type Error = ProtoConversionError;
+
+    /// Converts the message into a [`ProvenTransaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be deserialized.
+    fn try_from(transaction: proto::ProvenTransaction) -> Result<Self, Self::Error> {
+        deserialize(&transaction.transaction, "transaction")
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the value of the optional message field `field`, or an error if it is not set.
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, ProtoConversionError> {
+    value.ok_or(ProtoConversionError::MissingField(field))
+}
+
+/// Deserializes the canonical encoding stored in the bytes field `field`.
+fn deserialize<T: Deserializable>(
+    bytes: &[u8],
+    field: &'static str,
+) -> Result<T, ProtoConversionError> {
+    T::read_from_bytes(bytes)
+        .map_err(|source| ProtoConversionError::DeserializationFailed { field, source })
+}
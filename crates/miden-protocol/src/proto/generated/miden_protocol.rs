@@ -0,0 +1,119 @@
+// This file is @generated by prost-build.
+/// The ID of an account.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AccountId {
+    /// The 15 bytes of the account ID in big-endian order.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+}
+/// A word of four field elements, in little-endian element order.
+///
+/// Each element must be a canonical field element, i.e. less than the field modulus.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Digest {
+    #[prost(fixed64, tag = "1")]
+    pub d0: u64,
+    #[prost(fixed64, tag = "2")]
+    pub d1: u64,
+    #[prost(fixed64, tag = "3")]
+    pub d2: u64,
+    #[prost(fixed64, tag = "4")]
+    pub d3: u64,
+}
+/// The fee-related parameters of a block.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FeeParameters {
+    /// The ID of the fungible faucet whose assets are accepted for fee payments.
+    #[prost(message, optional, tag = "1")]
+    pub native_asset_id: ::core::option::Option<AccountId>,
+    /// The base fee capturing the cost for the verification of a transaction.
+    #[prost(fixed32, tag = "2")]
+    pub verification_base_fee: u32,
+}
+/// The header of a block.
+///
+/// The sub commitment and the commitment of the block are derived from the other fields and are
+/// therefore not part of the message.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BlockHeader {
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    #[prost(message, optional, tag = "2")]
+    pub prev_block_commitment: ::core::option::Option<Digest>,
+    #[prost(fixed32, tag = "3")]
+    pub block_num: u32,
+    #[prost(message, optional, tag = "4")]
+    pub chain_commitment: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "5")]
+    pub account_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "6")]
+    pub nullifier_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "7")]
+    pub note_root: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "8")]
+    pub tx_commitment: ::core::option::Option<Digest>,
+    #[prost(message, optional, tag = "9")]
+    pub tx_kernel_commitment: ::core::option::Option<Digest>,
+    /// The canonical encoding of the public key of the validator.
+    #[prost(bytes = "vec", tag = "10")]
+    pub validator_key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "11")]
+    pub fee_parameters: ::core::option::Option<FeeParameters>,
+    #[prost(fixed32, tag = "12")]
+    pub timestamp: u32,
+}
+/// The metadata of a note.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NoteMetadata {
+    /// The ID of the account which created the note.
+    #[prost(message, optional, tag = "1")]
+    pub sender: ::core::option::Option<AccountId>,
+    /// Defines how the note is stored.
+    #[prost(enumeration = "NoteType", tag = "2")]
+    pub note_type: i32,
+    /// The tag used by recipients to identify notes intended for them.
+    #[prost(fixed32, tag = "3")]
+    pub tag: u32,
+    /// The canonical encoding of the note attachment.
+    #[prost(bytes = "vec", tag = "4")]
+    pub attachment: ::prost::alloc::vec::Vec<u8>,
+}
+/// The storage type of a note.
+///
+/// The values match the encoding of the note type in the note metadata.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NoteType {
+    Unspecified = 0,
+    Public = 1,
+    Private = 2,
+}
+impl NoteType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NOTE_TYPE_UNSPECIFIED",
+            Self::Public => "NOTE_TYPE_PUBLIC",
+            Self::Private => "NOTE_TYPE_PRIVATE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOTE_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "NOTE_TYPE_PUBLIC" => Some(Self::Public),
+            "NOTE_TYPE_PRIVATE" => Some(Self::Private),
+            _ => None,
+        }
+    }
+}
+/// A proven transaction.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProvenTransaction {
+    /// The canonical encoding of the proven transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+}
@@ -0,0 +1,48 @@
+//! Protobuf messages for the core protocol types and the conversions between them.
+//!
+//! The messages are defined by the `.proto` files in the `proto` directory of this crate, so that
+//! the wire format versions together with the Rust types. Conversions into messages are
+//! infallible, while conversions from messages validate their contents and report failures as a
+//! [`ProtoConversionError`](crate::errors::ProtoConversionError), which wraps the error of the
+//! respective protocol type (e.g. an [`AccountIdError`](crate::errors::AccountIdError) for
+//! malformed account ID bytes).
+//!
+//! Messages are encoded and decoded through the [`prost::Message`] trait. The Rust types of the
+//! messages are generated from the `.proto` files by the build script and checked into
+//! `src/proto/generated`, so that building this crate does not require compiling the `.proto`
+//! files. To regenerate them after changing a `.proto` file, build the crate with the internal
+//! `proto-codegen` feature and the `BUILD_GENERATED_FILES_IN_SRC` environment variable set.
+
+mod conversions;
+
+#[rustfmt::skip]
+#[path = "generated/miden_protocol.rs"]
+mod messages;
+
+pub use messages::{
+    AccountId,
+    BlockHeader,
+    Digest,
+    FeeParameters,
+    NoteMetadata,
+    NoteType,
+    ProvenTransaction,
+};
+
+#[cfg(test)]
+mod tests;
+
+// PROTO FILES
+// ================================================================================================
+
+/// The `.proto` files defining the messages of this module, as `(file name, contents)` pairs.
+///
+/// The files are embedded at compile time, so the crate fails to build if they are moved out of
+/// this crate.
+pub const PROTO_FILES: &[(&str, &str)] = &[
+    ("account.proto", include_str!("../../proto/account.proto")),
+    ("block.proto", include_str!("../../proto/block.proto")),
+    ("note.proto", include_str!("../../proto/note.proto")),
+    ("primitives.proto", include_str!("../../proto/primitives.proto")),
+    ("transaction.proto", include_str!("../../proto/transaction.proto")),
+];
@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use assert_matches::assert_matches;
+use prost::Message;
+
+use super::{PROTO_FILES, messages as proto};
+use crate::account::{AccountId, AccountIdVersion, AccountStorageMode, AccountType};
+use crate::asset::FungibleAsset;
+use crate::block::{BlockHeader, BlockNumber};
+use crate::errors::{AccountIdError, FeeError, NoteError, ProtoConversionError};
+use crate::note::{NoteAttachment, NoteAttachmentScheme, NoteMetadata, NoteTag, NoteType};
+use crate::testing::account_id::{
+    ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    ACCOUNT_ID_SENDER,
+};
+use crate::transaction::{ProvenTransaction, ProvenTransactionBuilder};
+use crate::vm::ExecutionProof;
+use crate::{Felt, Word};
+
+/// Converts `value` into its message, encodes and decodes the message and asserts that it
+/// converts back into `value`.
+fn assert_roundtrip<T, M>(value: T)
+where
+    T: Clone + Debug + PartialEq + Into<M> + TryFrom<M, Error = ProtoConversionError>,
+    M: Message + Default,
+{
+    let message: M = value.clone().into();
+    let decoded = M::decode(message.encode_to_vec().as_slice()).expect("failed to decode message");
+
+    assert_eq!(T::try_from(decoded).expect("failed to convert message"), value);
+}
+
+#[test]
+fn digest_roundtrip() {
+    assert_roundtrip::<_, proto::Digest>(Word::from([1u32, 2, 3, 4]));
+    assert_roundtrip::<_, proto::Digest>(Word::from(
+        [Felt::new(u64::MAX - u64::from(u32::MAX)); 4],
+    ));
+
+    let non_canonical = proto::Digest { d0: u64::MAX, d1: 0, d2: 0, d3: 0 };
+    assert_matches!(Word::try_from(non_canonical), Err(ProtoConversionError::Word(_)));
+}
+
+#[test]
+fn account_id_roundtrip() {
+    for id in [ACCOUNT_ID_SENDER, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET] {
+        assert_roundtrip::<_, proto::AccountId>(AccountId::try_from(id).unwrap());
+    }
+
+    let too_short = proto::AccountId { id: vec![0; 14] };
+    assert_matches!(
+        AccountId::try_from(too_short),
+        Err(ProtoConversionError::InvalidLength { field: "id", expected: 15, actual: 14 })
+    );
+
+    // the most significant bit of the suffix must be zero
+    let mut bytes = <[u8; 15]>::from(AccountId::try_from(ACCOUNT_ID_SENDER).unwrap());
+    bytes[8] |= 0x80;
+    assert_matches!(
+        AccountId::try_from(proto::AccountId { id: bytes.to_vec() }),
+        Err(ProtoConversionError::AccountId(
+            AccountIdError::AccountIdSuffixMostSignificantBitMustBeZero
+        ))
+    );
+}
+
+#[test]
+fn note_metadata_roundtrip() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let metadata = NoteMetadata::new(sender, NoteType::Private, NoteTag::from(0x1234_5678));
+    assert_roundtrip::<_, proto::NoteMetadata>(metadata.clone());
+
+    let attachment = NoteAttachment::new_word(NoteAttachmentScheme::new(7), Word::from([5u32; 4]));
+    let metadata =
+        NoteMetadata::new(sender, NoteType::Public, NoteTag::from(3)).with_attachment(attachment);
+    assert_roundtrip::<_, proto::NoteMetadata>(metadata.clone());
+
+    let message = proto::NoteMetadata::from(metadata);
+
+    let unspecified_type = proto::NoteMetadata {
+        note_type: proto::NoteType::Unspecified.into(),
+        ..message.clone()
+    };
+    assert_matches!(
+        NoteMetadata::try_from(unspecified_type),
+        Err(ProtoConversionError::Note(NoteError::UnknownNoteType(_)))
+    );
+
+    let missing_sender = proto::NoteMetadata { sender: None, ..message.clone() };
+    assert_matches!(
+        NoteMetadata::try_from(missing_sender),
+        Err(ProtoConversionError::MissingField("sender"))
+    );
+
+    let truncated_attachment = proto::NoteMetadata {
+        attachment: message.attachment[..message.attachment.len() - 1].to_vec(),
+        ..message
+    };
+    assert_matches!(
+        NoteMetadata::try_from(truncated_attachment),
+        Err(ProtoConversionError::DeserializationFailed { field: "attachment", .. })
+    );
+}
+
+#[test]
+fn block_header_roundtrip() {
+    let header = BlockHeader::mock(3, None, None, &[], Word::from([9u32; 4]));
+    assert_roundtrip::<_, proto::FeeParameters>(header.fee_parameters().clone());
+    assert_roundtrip::<_, proto::BlockHeader>(header.clone());
+
+    let message = proto::BlockHeader::from(header);
+
+    let missing_root = proto::BlockHeader { note_root: None, ..message.clone() };
+    assert_matches!(
+        BlockHeader::try_from(missing_root),
+        Err(ProtoConversionError::MissingField("note_root"))
+    );
+
+    let non_fungible_native_asset = proto::BlockHeader {
+        fee_parameters: Some(proto::FeeParameters {
+            native_asset_id: Some(
+                AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)
+                    .unwrap()
+                    .into(),
+            ),
+            verification_base_fee: 10,
+        }),
+        ..message.clone()
+    };
+    assert_matches!(
+        BlockHeader::try_from(non_fungible_native_asset),
+        Err(ProtoConversionError::Fee(FeeError::NativeAssetIdNotFungible { .. }))
+    );
+
+    let invalid_validator_key = proto::BlockHeader { validator_key: vec![1, 2, 3], ..message };
+    assert_matches!(
+        BlockHeader::try_from(invalid_validator_key),
+        Err(ProtoConversionError::DeserializationFailed { field: "validator_key", .. })
+    );
+}
+
+#[test]
+fn proven_transaction_roundtrip() -> anyhow::Result<()> {
+    let account_id = AccountId::dummy(
+        [1; 15],
+        AccountIdVersion::Version0,
+        AccountType::FungibleFaucet,
+        AccountStorageMode::Private,
+    );
+
+    let tx = ProvenTransactionBuilder::new(
+        account_id,
+        Word::from([2u32; 4]),
+        Word::from([3u32; 4]),
+        Word::from([4u32; 4]),
+        BlockNumber::from(1),
+        Word::empty(),
+        FungibleAsset::mock(42).unwrap_fungible(),
+        BlockNumber::from(2),
+        ExecutionProof::new_dummy(),
+    )
+    .build()?;
+    assert_roundtrip::<_, proto::ProvenTransaction>(tx);
+
+    let malformed = proto::ProvenTransaction { transaction: vec![0; 8] };
+    assert_matches!(
+        ProvenTransaction::try_from(malformed),
+        Err(ProtoConversionError::DeserializationFailed { field: "transaction", .. })
+    );
+
+    Ok(())
+}
+
+/// Checks that the `.proto` files declare exactly the messages and enums exported by this module.
+#[test]
+fn proto_files_declare_exported_types() {
+    let declarations = |keyword: &str| -> Vec<&str> {
+        let mut names: Vec<&str> = PROTO_FILES
+            .iter()
+            .flat_map(|(_, contents)| contents.lines())
+            .filter_map(|line| line.strip_prefix(keyword)?.strip_suffix(" {"))
+            .collect();
+        names.sort_unstable();
+        names
+    };
+
+    assert_eq!(
+        declarations("message "),
+        [
+            "AccountId",
+            "BlockHeader",
+            "Digest",
+            "FeeParameters",
+            "NoteMetadata",
+            "ProvenTransaction"
+        ]
+    );
+    assert_eq!(declarations("enum "), ["NoteType"]);
+}
@@ -13,4 +13,6 @@ pub mod partial_blockchain;
 pub mod random_signer;
 pub mod slot_name;
 pub mod storage;
+pub mod strategies;
 pub mod tx;
+pub mod vectors;
@@ -1,5 +1,3 @@
-use alloc::vec::Vec;
-
 use crate::block::{BlockHeader, BlockNumber, Blockchain};
 use crate::errors::PartialBlockchainError;
 use crate::transaction::PartialBlockchain;
@@ -34,28 +32,13 @@ impl PartialBlockchain {
     /// Converts the [`Blockchain`] into a [`PartialBlockchain`] by selectively copying all leaves
     /// that are in the given `blocks` iterator.
     ///
-    /// This tracks all blocks in the given iterator in the [`PartialBlockchain`] except for the
-    /// block whose block number equals `ref_block`.
-    ///
-    /// # Panics
-    ///
-    /// Due to being only available in test scenarios, this function panics when one of the given
-    /// blocks does not exist in the provided chain or if the chain does not contain at least the
-    /// genesis block.
+    /// This tracks all blocks in the given iterator in the [`PartialBlockchain`] at the state of
+    /// the chain at `ref_block`. See [`Blockchain::partial_for_blocks`] for details.
     pub fn from_blockchain_at(
         chain: &Blockchain,
         ref_block: BlockNumber,
         blocks: impl IntoIterator<Item = BlockHeader>,
     ) -> Result<PartialBlockchain, PartialBlockchainError> {
-        let block_headers: Vec<_> = blocks.into_iter().collect();
-
-        let partial_mmr = chain
-            .partial_mmr_from_blocks(
-                &block_headers.iter().map(BlockHeader::block_num).collect(),
-                ref_block,
-            )
-            .expect("reference block should be in the chain and set of blocks should be valid");
-
-        PartialBlockchain::new(partial_mmr, block_headers)
+        chain.partial_for_blocks(blocks, ref_block)
     }
 }
@@ -0,0 +1,239 @@
+//! [`proptest`] strategies for generating arbitrary protocol types in property tests.
+//!
+//! The strategies produce values with small, bounded sizes so that property tests stay fast, and
+//! such that the generated values can be combined without hitting protocol limits, e.g. adding
+//! up the fungible assets of several generated deltas does not overflow
+//! [`FungibleAsset::MAX_AMOUNT`].
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use proptest::prelude::*;
+
+use crate::account::{
+    AccountDelta,
+    AccountId,
+    AccountStorage,
+    AccountStorageDelta,
+    AccountStorageMode,
+    AccountType,
+    AccountVaultDelta,
+    StorageMapDelta,
+    StorageSlot,
+    StorageSlotDelta,
+    StorageSlotName,
+};
+use crate::asset::{Asset, FungibleAsset};
+use crate::note::{
+    Note,
+    NoteAssets,
+    NoteMetadata,
+    NoteRecipient,
+    NoteScript,
+    NoteStorage,
+    NoteTag,
+    NoteType,
+};
+use crate::testing::account_id::AccountIdBuilder;
+use crate::{Felt, StarkField, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum amount of the fungible assets generated by [`any_account_delta`] and
+/// [`any_note`].
+pub const MAX_GENERATED_ASSET_AMOUNT: u64 = 1 << 40;
+
+/// The maximum number of storage slots touched by the deltas generated by [`any_account_delta`].
+pub const MAX_GENERATED_DELTA_SLOTS: usize = 8;
+
+/// The maximum number of entries of each storage map delta generated by [`any_account_delta`].
+const MAX_GENERATED_DELTA_MAP_ENTRIES: usize = 8;
+
+/// The maximum number of assets added by the deltas generated by [`any_account_delta`].
+const MAX_GENERATED_DELTA_ASSETS: usize = 4;
+
+/// The maximum nonce delta of the deltas generated by [`any_account_delta`].
+const MAX_GENERATED_NONCE_DELTA: u32 = 1000;
+
+/// The maximum number of assets of the notes generated by [`any_note`].
+const MAX_GENERATED_NOTE_ASSETS: usize = 4;
+
+/// The maximum number of storage items of the notes generated by [`any_note`].
+const MAX_GENERATED_NOTE_STORAGE_ITEMS: usize = 16;
+
+// PRIMITIVES
+// ================================================================================================
+
+/// Returns a strategy generating arbitrary field elements.
+pub fn any_felt() -> impl Strategy<Value = Felt> {
+    (0..Felt::MODULUS).prop_map(Felt::new)
+}
+
+/// Returns a strategy generating arbitrary words.
+pub fn any_word() -> impl Strategy<Value = Word> {
+    prop::array::uniform4(any_felt()).prop_map(Word::from)
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+/// Returns a strategy generating account IDs of the provided type and storage mode.
+pub fn any_account_id(
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+) -> impl Strategy<Value = AccountId> {
+    any::<[u8; 32]>().prop_map(move |seed| {
+        AccountIdBuilder::new()
+            .account_type(account_type)
+            .storage_mode(storage_mode)
+            .build_with_seed(seed)
+    })
+}
+
+/// Returns a strategy generating fungible assets of public fungible faucets with an amount in
+/// `1..=max_amount`.
+///
+/// `max_amount` is capped at [`FungibleAsset::MAX_AMOUNT`].
+///
+/// # Panics
+///
+/// Panics if `max_amount` is zero.
+pub fn any_fungible_asset(max_amount: u64) -> impl Strategy<Value = FungibleAsset> {
+    assert!(max_amount > 0, "max amount must be greater than zero");
+    let max_amount = max_amount.min(FungibleAsset::MAX_AMOUNT);
+
+    (
+        any_account_id(AccountType::FungibleFaucet, AccountStorageMode::Public),
+        1..=max_amount,
+    )
+        .prop_map(|(faucet_id, amount)| {
+            FungibleAsset::new(faucet_id, amount).expect("amount should be valid")
+        })
+}
+
+/// Returns a strategy generating storage map deltas with up to `max_entries` entries.
+pub fn any_storage_map_delta(max_entries: usize) -> impl Strategy<Value = StorageMapDelta> {
+    prop::collection::btree_map(any_word(), any_word(), 0..=max_entries).prop_map(|entries| {
+        let mut delta = StorageMapDelta::default();
+        for (raw_key, value) in entries {
+            delta.insert(raw_key, value);
+        }
+        delta
+    })
+}
+
+/// Returns a strategy generating storage deltas touching up to `max_slots` slots, where each map
+/// slot delta has up to `max_map_entries` entries.
+///
+/// The deltas only touch the slots with the [`StorageSlotName::mock`] names of the indices in
+/// `0..max_slots`, where slots with an even index are value slots and slots with an odd index are
+/// map slots. This is the layout of [`storage_for_deltas`], so the generated deltas can always be
+/// applied to it.
+pub fn any_storage_delta(
+    max_slots: usize,
+    max_map_entries: usize,
+) -> impl Strategy<Value = AccountStorageDelta> {
+    let slot_delta = (any_word(), any_storage_map_delta(max_map_entries));
+
+    prop::collection::vec(prop::option::of(slot_delta), max_slots).prop_map(|slots| {
+        let deltas = slots
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| {
+                let (value, map_delta) = slot?;
+                let slot_delta = if idx % 2 == 0 {
+                    StorageSlotDelta::Value(value)
+                } else {
+                    StorageSlotDelta::Map(map_delta)
+                };
+                Some((StorageSlotName::mock(idx), slot_delta))
+            })
+            .collect();
+
+        AccountStorageDelta::from_raw(deltas)
+    })
+}
+
+/// Returns an empty account storage with `num_slots` slots to which the deltas generated by
+/// [`any_storage_delta`] with the same number of slots can be applied.
+pub fn storage_for_deltas(num_slots: usize) -> AccountStorage {
+    let slots = (0..num_slots)
+        .map(|idx| {
+            let slot_name = StorageSlotName::mock(idx);
+            if idx % 2 == 0 {
+                StorageSlot::with_empty_value(slot_name)
+            } else {
+                StorageSlot::with_empty_map(slot_name)
+            }
+        })
+        .collect();
+
+    AccountStorage::new(slots).expect("storage slots should be valid")
+}
+
+/// Returns a strategy generating partial state deltas for the provided account.
+///
+/// The deltas only add assets to the vault, so that they can be applied to any account, and their
+/// storage deltas touch up to [`MAX_GENERATED_DELTA_SLOTS`] slots, so they can be applied to an
+/// account with the storage returned by [`storage_for_deltas`] for that number of slots.
+pub fn any_account_delta_for(account_id: AccountId) -> impl Strategy<Value = AccountDelta> {
+    (
+        any_storage_delta(MAX_GENERATED_DELTA_SLOTS, MAX_GENERATED_DELTA_MAP_ENTRIES),
+        prop::collection::vec(
+            any_fungible_asset(MAX_GENERATED_ASSET_AMOUNT),
+            0..=MAX_GENERATED_DELTA_ASSETS,
+        ),
+        1..=MAX_GENERATED_NONCE_DELTA,
+    )
+        .prop_map(move |(storage_delta, added_assets, nonce_delta)| {
+            let vault_delta =
+                AccountVaultDelta::from_iters(added_assets.into_iter().map(Asset::from), []);
+            AccountDelta::new(account_id, storage_delta, vault_delta, Felt::from(nonce_delta))
+                .expect("nonce delta should be non-zero")
+        })
+}
+
+/// Returns a strategy generating partial state deltas of public regular accounts.
+///
+/// See [`any_account_delta_for`] for the shape of the generated deltas.
+pub fn any_account_delta() -> impl Strategy<Value = AccountDelta> {
+    any_account_id(AccountType::RegularAccountUpdatableCode, AccountStorageMode::Public)
+        .prop_flat_map(any_account_delta_for)
+}
+
+// NOTES
+// ================================================================================================
+
+/// Returns a strategy generating notes with the [`NoteScript::mock`] script.
+///
+/// The notes have up to four fungible assets of distinct faucets and up to 16 storage items.
+pub fn any_note() -> impl Strategy<Value = Note> {
+    let script = NoteScript::mock();
+    let note_type = prop_oneof![Just(NoteType::Public), Just(NoteType::Private)];
+
+    (
+        any_account_id(AccountType::RegularAccountUpdatableCode, AccountStorageMode::Private),
+        note_type,
+        any::<u32>(),
+        prop::collection::vec(
+            any_fungible_asset(MAX_GENERATED_ASSET_AMOUNT),
+            0..=MAX_GENERATED_NOTE_ASSETS,
+        ),
+        any_word(),
+        prop::collection::vec(any_felt(), 0..=MAX_GENERATED_NOTE_STORAGE_ITEMS),
+    )
+        .prop_map(move |(sender, note_type, tag, assets, serial_num, storage_items)| {
+            // note assets must be of distinct faucets
+            let assets: BTreeMap<AccountId, FungibleAsset> =
+                assets.into_iter().map(|asset| (asset.faucet_id(), asset)).collect();
+            let assets = NoteAssets::new(assets.into_values().map(Asset::from).collect::<Vec<_>>())
+                .expect("note assets should be valid");
+
+            let metadata = NoteMetadata::new(sender, note_type, NoteTag::new(tag));
+            let storage = NoteStorage::new(storage_items).expect("note storage should be valid");
+            let recipient = NoteRecipient::new(serial_num, script.clone(), storage);
+
+            Note::new(assets, metadata, recipient)
+        })
+}
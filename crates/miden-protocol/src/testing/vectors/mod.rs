@@ -0,0 +1,652 @@
+//! Golden test vectors for the commitments and hashes computed by the protocol.
+//!
+//! The vectors allow other implementations of the protocol to check that they compute the same
+//! commitments as this crate. Each vector consists of a set of inputs and the commitments
+//! expected for them, all encoded as hex strings or integers so that they can be serialized to
+//! JSON. The canonical set of vectors is committed as `test_vectors.json` next to this module and
+//! can be regenerated with `make test-vectors`.
+//!
+//! Note script roots are inputs of the note vectors, so the vectors do not depend on the compiled
+//! note scripts and remain stable when the standard note scripts change.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use thiserror::Error;
+
+use crate::account::{
+    AccountDelta,
+    AccountId,
+    AccountStorageDelta,
+    AccountVaultDelta,
+    StorageSlotName,
+};
+use crate::asset::{Asset, FungibleAsset};
+use crate::block::{BlockHeader, BlockNumber, FeeParameters};
+use crate::crypto::dsa::ecdsa_k256_keccak::{PublicKey, SecretKey};
+use crate::note::{NoteAssets, NoteId, NoteRecipient, NoteStorage, Nullifier};
+use crate::testing::account_id::{
+    ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
+    ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+};
+use crate::transaction::{InputNoteCommitmentAccumulator, TransactionId};
+use crate::utils::serde::{Deserializable, Serializable};
+use crate::utils::{bytes_to_hex_string, hex_to_bytes};
+use crate::{Felt, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The version of the test vectors format.
+///
+/// This must be incremented whenever the format of the vectors changes, or when the expected
+/// commitments change due to an intended change in how they are computed.
+pub const TEST_VECTORS_VERSION: u32 = 1;
+
+/// The size of a serialized ECDSA public key in bytes.
+const PUBLIC_KEY_SIZE: usize = 33;
+
+// TEST VECTORS
+// ================================================================================================
+
+/// A versioned set of test vectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct TestVectors {
+    pub version: u32,
+    pub account_deltas: Vec<AccountDeltaTestVector>,
+    pub notes: Vec<NoteTestVector>,
+    pub transaction_ids: Vec<TransactionIdTestVector>,
+    pub block_headers: Vec<BlockHeaderTestVector>,
+}
+
+impl TestVectors {
+    /// Generates the canonical set of test vectors.
+    ///
+    /// All random inputs are drawn from RNGs with fixed seeds, so the result is deterministic.
+    pub fn generate() -> Self {
+        let p2id_note = NoteTestVector::p2id_note();
+        let p2id_nullifier = parse_word(&p2id_note.nullifier)
+            .map(Nullifier::from_raw)
+            .expect("nullifier should be valid");
+        let genesis_header = BlockHeaderTestVector::genesis();
+        let next_header =
+            BlockHeaderTestVector::after(&genesis_header).expect("genesis header should be valid");
+
+        Self {
+            version: TEST_VECTORS_VERSION,
+            account_deltas: vec![
+                AccountDeltaTestVector::empty_delta(),
+                AccountDeltaTestVector::delta_with_map_changes(),
+            ],
+            notes: vec![p2id_note, NoteTestVector::multi_asset_note()],
+            transaction_ids: vec![TransactionIdTestVector::consume_note(p2id_nullifier)],
+            block_headers: vec![genesis_header, next_header],
+        }
+    }
+
+    /// Recomputes the expected commitments of all vectors from their inputs.
+    ///
+    /// The returned vectors are equal to `self` if and only if all commitments match.
+    ///
+    /// # Errors
+    /// Returns an error if any of the inputs cannot be parsed.
+    pub fn recompute(&self) -> Result<Self, TestVectorError> {
+        Ok(Self {
+            version: self.version,
+            account_deltas: self
+                .account_deltas
+                .iter()
+                .map(AccountDeltaTestVector::recompute)
+                .collect::<Result<_, _>>()?,
+            notes: self.notes.iter().map(NoteTestVector::recompute).collect::<Result<_, _>>()?,
+            transaction_ids: self
+                .transaction_ids
+                .iter()
+                .map(TransactionIdTestVector::recompute)
+                .collect::<Result<_, _>>()?,
+            block_headers: self
+                .block_headers
+                .iter()
+                .map(BlockHeaderTestVector::recompute)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+// ACCOUNT DELTA TEST VECTOR
+// ================================================================================================
+
+/// A test vector for [`AccountDelta::to_commitment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccountDeltaTestVector {
+    pub name: String,
+    pub account_id: String,
+    pub nonce_delta: u64,
+    /// The fungible asset amounts added to (positive) or removed from (negative) the vault.
+    pub fungible_asset_deltas: Vec<FungibleAssetDeltaTestInput>,
+    pub storage_values: Vec<StorageValueTestInput>,
+    pub storage_maps: Vec<StorageMapTestInput>,
+    pub commitment: String,
+}
+
+/// A change of the amount of a fungible asset in an [`AccountDeltaTestVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct FungibleAssetDeltaTestInput {
+    pub faucet_id: String,
+    pub amount: i64,
+}
+
+/// A value slot update in an [`AccountDeltaTestVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageValueTestInput {
+    pub slot_name: String,
+    pub value: String,
+}
+
+/// The updated entries of a map slot in an [`AccountDeltaTestVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageMapTestInput {
+    pub slot_name: String,
+    pub entries: Vec<StorageMapEntryTestInput>,
+}
+
+/// A single updated entry of a map slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageMapEntryTestInput {
+    pub key: String,
+    pub value: String,
+}
+
+impl AccountDeltaTestVector {
+    fn empty_delta() -> Self {
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)
+            .expect("account ID should be valid");
+
+        Self {
+            name: "empty_delta".into(),
+            account_id: account_id.to_hex(),
+            nonce_delta: 0,
+            fungible_asset_deltas: Vec::new(),
+            storage_values: Vec::new(),
+            storage_maps: Vec::new(),
+            commitment: String::new(),
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    fn delta_with_map_changes() -> Self {
+        let mut rng = ChaCha20Rng::from_seed([1; 32]);
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE)
+            .expect("account ID should be valid");
+
+        let storage_values = vec![StorageValueTestInput {
+            slot_name: "test_vectors::value_slot".into(),
+            value: random_word(&mut rng).to_hex(),
+        }];
+
+        let mut entries = Vec::new();
+        for idx in 0..3 {
+            let key = random_word(&mut rng);
+            // the last entry is set to the empty word, i.e. it is removed from the map
+            let value = if idx == 2 { Word::empty() } else { random_word(&mut rng) };
+            entries.push(StorageMapEntryTestInput { key: key.to_hex(), value: value.to_hex() });
+        }
+        let storage_maps = vec![StorageMapTestInput {
+            slot_name: "test_vectors::map_slot".into(),
+            entries,
+        }];
+
+        let fungible_asset_deltas = vec![
+            FungibleAssetDeltaTestInput {
+                faucet_id: account_id_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+                amount: 100,
+            },
+            FungibleAssetDeltaTestInput {
+                faucet_id: account_id_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1),
+                amount: -25,
+            },
+        ];
+
+        Self {
+            name: "delta_with_map_changes".into(),
+            account_id: account_id.to_hex(),
+            nonce_delta: 1,
+            fungible_asset_deltas,
+            storage_values,
+            storage_maps,
+            commitment: String::new(),
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    fn recompute(&self) -> Result<Self, TestVectorError> {
+        let err = |field: &str, message: String| TestVectorError::new(&self.name, field, message);
+
+        let account_id =
+            AccountId::from_hex(&self.account_id).map_err(|e| err("account_id", e.to_string()))?;
+        let nonce_delta =
+            Felt::try_from(self.nonce_delta).map_err(|e| err("nonce_delta", e.to_string()))?;
+
+        let mut vault = AccountVaultDelta::default();
+        for asset_delta in self.fungible_asset_deltas.iter() {
+            let faucet_id = AccountId::from_hex(&asset_delta.faucet_id)
+                .map_err(|e| err("fungible_asset_deltas", e.to_string()))?;
+            let asset = FungibleAsset::new(faucet_id, asset_delta.amount.unsigned_abs())
+                .map_err(|e| err("fungible_asset_deltas", e.to_string()))?;
+
+            let result = if asset_delta.amount >= 0 {
+                vault.add_asset(asset.into())
+            } else {
+                vault.remove_asset(asset.into())
+            };
+            result.map_err(|e| err("fungible_asset_deltas", e.to_string()))?;
+        }
+
+        let mut storage = AccountStorageDelta::new();
+        for value in self.storage_values.iter() {
+            let slot_name = StorageSlotName::new(value.slot_name.as_str())
+                .map_err(|e| err("storage_values", e.to_string()))?;
+            let new_value =
+                parse_word(&value.value).map_err(|e| err("storage_values", e.to_string()))?;
+            storage
+                .set_item(slot_name, new_value)
+                .map_err(|e| err("storage_values", e.to_string()))?;
+        }
+        for map in self.storage_maps.iter() {
+            let slot_name = StorageSlotName::new(map.slot_name.as_str())
+                .map_err(|e| err("storage_maps", e.to_string()))?;
+            storage.insert_empty_map_delta(slot_name.clone());
+            for entry in map.entries.iter() {
+                let key = parse_word(&entry.key).map_err(|e| err("storage_maps", e.to_string()))?;
+                let value =
+                    parse_word(&entry.value).map_err(|e| err("storage_maps", e.to_string()))?;
+                storage
+                    .set_map_item(slot_name.clone(), key, value)
+                    .map_err(|e| err("storage_maps", e.to_string()))?;
+            }
+        }
+
+        let delta = AccountDelta::new(account_id, storage, vault, nonce_delta)
+            .map_err(|e| err("nonce_delta", e.to_string()))?;
+
+        Ok(Self {
+            commitment: delta.to_commitment().to_hex(),
+            ..self.clone()
+        })
+    }
+}
+
+// NOTE TEST VECTOR
+// ================================================================================================
+
+/// A test vector for the commitments of a note, i.e. its [`NoteId`] and [`Nullifier`] as well as
+/// the intermediate commitments from which they are computed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoteTestVector {
+    pub name: String,
+    pub serial_num: String,
+    pub script_root: String,
+    pub storage: Vec<u64>,
+    pub assets: Vec<String>,
+    pub storage_commitment: String,
+    pub asset_commitment: String,
+    pub recipient: String,
+    pub note_id: String,
+    pub nullifier: String,
+}
+
+impl NoteTestVector {
+    fn p2id_note() -> Self {
+        let mut rng = ChaCha20Rng::from_seed([2; 32]);
+        let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)
+            .expect("account ID should be valid");
+        let serial_num = random_word(&mut rng);
+        let script_root = random_word(&mut rng);
+
+        Self {
+            name: "p2id_note".into(),
+            serial_num: serial_num.to_hex(),
+            script_root: script_root.to_hex(),
+            storage: vec![target.suffix().as_int(), target.prefix().as_u64()],
+            assets: vec![fungible_asset_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 100)],
+            ..Self::default()
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    fn multi_asset_note() -> Self {
+        let mut rng = ChaCha20Rng::from_seed([3; 32]);
+        let serial_num = random_word(&mut rng);
+        let script_root = random_word(&mut rng);
+        let storage = (0..5).map(|_| random_felt(&mut rng).as_int()).collect();
+
+        Self {
+            name: "multi_asset_note".into(),
+            serial_num: serial_num.to_hex(),
+            script_root: script_root.to_hex(),
+            storage,
+            assets: vec![
+                fungible_asset_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 10),
+                fungible_asset_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1, 20),
+                fungible_asset_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2, 30),
+            ],
+            ..Self::default()
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    fn recompute(&self) -> Result<Self, TestVectorError> {
+        let err = |field: &str, message: String| TestVectorError::new(&self.name, field, message);
+
+        let serial_num =
+            parse_word(&self.serial_num).map_err(|e| err("serial_num", e.to_string()))?;
+        let script_root =
+            parse_word(&self.script_root).map_err(|e| err("script_root", e.to_string()))?;
+
+        let storage_items = self
+            .storage
+            .iter()
+            .map(|item| Felt::try_from(*item).map_err(|e| err("storage", e.to_string())))
+            .collect::<Result<Vec<_>, _>>()?;
+        let storage = NoteStorage::new(storage_items).map_err(|e| err("storage", e.to_string()))?;
+
+        let assets = self
+            .assets
+            .iter()
+            .map(|asset| {
+                let asset = parse_word(asset).map_err(|e| err("assets", e.to_string()))?;
+                Asset::try_from(asset).map_err(|e| err("assets", e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let assets = NoteAssets::new(assets).map_err(|e| err("assets", e.to_string()))?;
+
+        let recipient =
+            NoteRecipient::compute_digest(serial_num, script_root, storage.commitment());
+        let note_id = NoteId::new(recipient, assets.commitment());
+        let nullifier =
+            Nullifier::new(script_root, storage.commitment(), assets.commitment(), serial_num);
+
+        Ok(Self {
+            storage_commitment: storage.commitment().to_hex(),
+            asset_commitment: assets.commitment().to_hex(),
+            recipient: recipient.to_hex(),
+            note_id: note_id.to_hex(),
+            nullifier: nullifier.to_hex(),
+            ..self.clone()
+        })
+    }
+}
+
+// TRANSACTION ID TEST VECTOR
+// ================================================================================================
+
+/// A test vector for [`TransactionId`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct TransactionIdTestVector {
+    pub name: String,
+    pub init_account_commitment: String,
+    pub final_account_commitment: String,
+    pub input_notes_commitment: String,
+    pub output_notes_commitment: String,
+    pub transaction_id: String,
+}
+
+impl TransactionIdTestVector {
+    /// Returns a vector for a transaction consuming a single authenticated note with the provided
+    /// nullifier and creating no output notes.
+    fn consume_note(nullifier: Nullifier) -> Self {
+        let mut rng = ChaCha20Rng::from_seed([4; 32]);
+        let init_account_commitment = random_word(&mut rng);
+        let final_account_commitment = random_word(&mut rng);
+
+        let mut input_notes = InputNoteCommitmentAccumulator::new();
+        input_notes.push(nullifier, None);
+
+        Self {
+            name: "consume_p2id_note".into(),
+            init_account_commitment: init_account_commitment.to_hex(),
+            final_account_commitment: final_account_commitment.to_hex(),
+            input_notes_commitment: input_notes.commitment().to_hex(),
+            output_notes_commitment: Word::empty().to_hex(),
+            transaction_id: String::new(),
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    fn recompute(&self) -> Result<Self, TestVectorError> {
+        let parse = |field: &str, hex: &str| {
+            parse_word(hex).map_err(|e| TestVectorError::new(&self.name, field, e.to_string()))
+        };
+
+        let transaction_id = TransactionId::new(
+            parse("init_account_commitment", &self.init_account_commitment)?,
+            parse("final_account_commitment", &self.final_account_commitment)?,
+            parse("input_notes_commitment", &self.input_notes_commitment)?,
+            parse("output_notes_commitment", &self.output_notes_commitment)?,
+        );
+
+        Ok(Self {
+            transaction_id: transaction_id.to_hex(),
+            ..self.clone()
+        })
+    }
+}
+
+// BLOCK HEADER TEST VECTOR
+// ================================================================================================
+
+/// A test vector for the commitments of a [`BlockHeader`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct BlockHeaderTestVector {
+    pub name: String,
+    pub version: u32,
+    pub prev_block_commitment: String,
+    pub block_num: u32,
+    pub chain_commitment: String,
+    pub account_root: String,
+    pub nullifier_root: String,
+    pub note_root: String,
+    pub tx_commitment: String,
+    pub tx_kernel_commitment: String,
+    /// The serialized validator public key.
+    pub validator_key: String,
+    pub native_asset_id: String,
+    pub verification_base_fee: u32,
+    pub timestamp: u32,
+    pub sub_commitment: String,
+    pub commitment: String,
+}
+
+impl BlockHeaderTestVector {
+    /// Returns a vector for a genesis-like block header, i.e. one without a previous block and
+    /// with an empty chain.
+    fn genesis() -> Self {
+        let mut rng = ChaCha20Rng::from_seed([5; 32]);
+        let account_root = random_word(&mut rng);
+        let nullifier_root = random_word(&mut rng);
+        let note_root = random_word(&mut rng);
+        let tx_commitment = random_word(&mut rng);
+        let tx_kernel_commitment = random_word(&mut rng);
+        let validator_key = SecretKey::with_rng(&mut rng).public_key();
+
+        Self {
+            name: "genesis_block_header".into(),
+            version: 0,
+            prev_block_commitment: Word::empty().to_hex(),
+            block_num: 0,
+            chain_commitment: Word::empty().to_hex(),
+            account_root: account_root.to_hex(),
+            nullifier_root: nullifier_root.to_hex(),
+            note_root: note_root.to_hex(),
+            tx_commitment: tx_commitment.to_hex(),
+            tx_kernel_commitment: tx_kernel_commitment.to_hex(),
+            validator_key: public_key_hex(&validator_key),
+            native_asset_id: account_id_hex(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+            verification_base_fee: 500,
+            timestamp: 1_767_225_600,
+            sub_commitment: String::new(),
+            commitment: String::new(),
+        }
+        .recompute()
+        .expect("generated vector should be valid")
+    }
+
+    /// Returns a vector for the block header following the provided one.
+    fn after(prev: &Self) -> Result<Self, TestVectorError> {
+        let mut rng = ChaCha20Rng::from_seed([6; 32]);
+        let chain_commitment = random_word(&mut rng);
+        let account_root = random_word(&mut rng);
+        let nullifier_root = random_word(&mut rng);
+        let note_root = random_word(&mut rng);
+        let tx_commitment = random_word(&mut rng);
+
+        Self {
+            name: "block_header_after_genesis".into(),
+            prev_block_commitment: prev.commitment.clone(),
+            block_num: prev.block_num + 1,
+            chain_commitment: chain_commitment.to_hex(),
+            account_root: account_root.to_hex(),
+            nullifier_root: nullifier_root.to_hex(),
+            note_root: note_root.to_hex(),
+            tx_commitment: tx_commitment.to_hex(),
+            timestamp: prev.timestamp + 3,
+            ..prev.clone()
+        }
+        .recompute()
+    }
+
+    fn recompute(&self) -> Result<Self, TestVectorError> {
+        let err = |field: &str, message: String| TestVectorError::new(&self.name, field, message);
+        let parse = |field: &str, hex: &str| parse_word(hex).map_err(|e| err(field, e.to_string()));
+
+        let validator_key = hex_to_bytes::<PUBLIC_KEY_SIZE>(&self.validator_key)
+            .map_err(|e| err("validator_key", e.to_string()))
+            .and_then(|bytes| {
+                PublicKey::read_from_bytes(&bytes).map_err(|e| err("validator_key", e.to_string()))
+            })?;
+        let native_asset_id = AccountId::from_hex(&self.native_asset_id)
+            .map_err(|e| err("native_asset_id", e.to_string()))?;
+        let fee_parameters = FeeParameters::new(native_asset_id, self.verification_base_fee)
+            .map_err(|e| err("native_asset_id", e.to_string()))?;
+
+        let header = BlockHeader::new(
+            self.version,
+            parse("prev_block_commitment", &self.prev_block_commitment)?,
+            BlockNumber::from(self.block_num),
+            parse("chain_commitment", &self.chain_commitment)?,
+            parse("account_root", &self.account_root)?,
+            parse("nullifier_root", &self.nullifier_root)?,
+            parse("note_root", &self.note_root)?,
+            parse("tx_commitment", &self.tx_commitment)?,
+            parse("tx_kernel_commitment", &self.tx_kernel_commitment)?,
+            validator_key,
+            fee_parameters,
+            self.timestamp,
+        );
+
+        Ok(Self {
+            sub_commitment: header.sub_commitment().to_hex(),
+            commitment: header.commitment().to_hex(),
+            ..self.clone()
+        })
+    }
+}
+
+// TEST VECTOR ERROR
+// ================================================================================================
+
+/// An error raised when the inputs of a test vector cannot be parsed.
+#[derive(Debug, Error)]
+#[error("invalid field `{field}` in test vector `{vector}`: {message}")]
+pub struct TestVectorError {
+    vector: String,
+    field: String,
+    message: String,
+}
+
+impl TestVectorError {
+    fn new(vector: &str, field: &str, message: String) -> Self {
+        Self {
+            vector: vector.to_string(),
+            field: field.to_string(),
+            message,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn random_felt(rng: &mut ChaCha20Rng) -> Felt {
+    Felt::new(rng.random())
+}
+
+fn random_word(rng: &mut ChaCha20Rng) -> Word {
+    Word::from([random_felt(rng), random_felt(rng), random_felt(rng), random_felt(rng)])
+}
+
+fn parse_word(hex: &str) -> Result<Word, crate::WordError> {
+    Word::try_from(hex)
+}
+
+fn account_id_hex(account_id: u128) -> String {
+    AccountId::try_from(account_id).expect("account ID should be valid").to_hex()
+}
+
+fn fungible_asset_hex(faucet_id: u128, amount: u64) -> String {
+    let faucet_id = AccountId::try_from(faucet_id).expect("account ID should be valid");
+    let asset = FungibleAsset::new(faucet_id, amount).expect("asset should be valid");
+    Word::from(asset).to_hex()
+}
+
+fn public_key_hex(public_key: &PublicKey) -> String {
+    let bytes: [u8; PUBLIC_KEY_SIZE] = public_key
+        .to_bytes()
+        .try_into()
+        .expect("serialized public key should have the expected size");
+    bytes_to_hex_string(bytes)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{TEST_VECTORS_VERSION, TestVectors};
+
+    const TEST_VECTORS: &str = include_str!("test_vectors.json");
+
+    #[test]
+    fn test_vectors_match_recomputed_commitments() {
+        let vectors: TestVectors = serde_json::from_str(TEST_VECTORS).unwrap();
+
+        assert_eq!(vectors.version, TEST_VECTORS_VERSION);
+        assert_eq!(vectors.recompute().unwrap(), vectors);
+    }
+
+    #[test]
+    fn test_vectors_match_generated_vectors() {
+        let vectors: TestVectors = serde_json::from_str(TEST_VECTORS).unwrap();
+
+        assert_eq!(TestVectors::generate(), vectors);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::Word;
+use crate::crypto::DoubleWordHasher;
+use crate::note::{NoteId, NoteMetadata, Nullifier};
+
+// NOTE COMMITMENT ACCUMULATOR
+// ================================================================================================
+
+/// Incrementally computes the commitment to a sequence of output notes.
+///
+/// Pushing the notes of an [`OutputNotes`](super::OutputNotes) in order results in the same
+/// commitment as [`OutputNotes::commitment`](super::OutputNotes::commitment). Each push absorbs
+/// the note into a fixed-size hasher state, so the accumulator is cheap to clone and allows
+/// maintaining a running commitment over a growing collection of notes without rehashing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteCommitmentAccumulator {
+    hasher: DoubleWordHasher,
+}
+
+impl NoteCommitmentAccumulator {
+    /// Returns a new accumulator committing to an empty sequence of notes.
+    pub fn new() -> Self {
+        Self { hasher: DoubleWordHasher::new() }
+    }
+
+    /// Appends the note with the provided ID and metadata to the sequence of notes.
+    pub fn push(&mut self, note_id: NoteId, metadata: &NoteMetadata) {
+        self.hasher.absorb(note_id.as_word(), metadata.to_commitment());
+    }
+
+    /// Returns the number of notes pushed so far.
+    pub fn num_notes(&self) -> usize {
+        self.hasher.num_absorbed()
+    }
+
+    /// Returns the commitment to the notes pushed so far.
+    ///
+    /// For an empty sequence of notes, [`Word::empty`] is returned.
+    pub fn commitment(&self) -> Word {
+        self.hasher.digest()
+    }
+}
+
+impl Default for NoteCommitmentAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// INPUT NOTE COMMITMENT ACCUMULATOR
+// ================================================================================================
+
+/// Incrementally computes the commitment to a sequence of input notes.
+///
+/// Pushing the notes of an [`InputNotes`](super::InputNotes) in order results in the same
+/// commitment as [`InputNotes::commitment`](super::InputNotes::commitment). Like
+/// [`NoteCommitmentAccumulator`], the accumulator is cheap to clone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputNoteCommitmentAccumulator {
+    hasher: DoubleWordHasher,
+}
+
+impl InputNoteCommitmentAccumulator {
+    /// Returns a new accumulator committing to an empty sequence of input notes.
+    pub fn new() -> Self {
+        Self { hasher: DoubleWordHasher::new() }
+    }
+
+    /// Appends the input note with the provided nullifier to the sequence of notes.
+    ///
+    /// The `note_commitment` must be provided for unauthenticated notes, and must be `None` for
+    /// authenticated notes (see [`ToInputNoteCommitments`](super::ToInputNoteCommitments)).
+    pub fn push(&mut self, nullifier: Nullifier, note_commitment: Option<Word>) {
+        self.hasher.absorb(nullifier.as_word(), note_commitment.unwrap_or_default());
+    }
+
+    /// Returns the number of notes pushed so far.
+    pub fn num_notes(&self) -> usize {
+        self.hasher.num_absorbed()
+    }
+
+    /// Returns the commitment to the input notes pushed so far.
+    ///
+    /// For an empty sequence of notes, [`Word::empty`] is returned.
+    pub fn commitment(&self) -> Word {
+        self.hasher.digest()
+    }
+}
+
+impl Default for InputNoteCommitmentAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use proptest::prelude::*;
+
+    use super::{InputNoteCommitmentAccumulator, NoteCommitmentAccumulator};
+    use crate::note::{NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType, Nullifier};
+    use crate::testing::account_id::ACCOUNT_ID_SENDER;
+    use crate::testing::strategies::{any_note, any_word};
+    use crate::transaction::{
+        InputNote,
+        InputNoteCommitment,
+        InputNotes,
+        OutputNote,
+        OutputNotes,
+        ToInputNoteCommitments,
+    };
+    use crate::{Felt, Hasher, Word};
+
+    /// Returns the sequential hash of the provided word pairs, which is how the commitments to
+    /// input and output notes are defined, independently of the accumulators.
+    fn reference_commitment(pairs: impl IntoIterator<Item = (Word, Word)>) -> Word {
+        let elements: Vec<Felt> = pairs
+            .into_iter()
+            .flat_map(|(first, second)| first.into_iter().chain(second))
+            .collect();
+
+        if elements.is_empty() {
+            Word::empty()
+        } else {
+            Hasher::hash_elements(&elements)
+        }
+    }
+
+    fn output_notes_reference_commitment(headers: &[NoteHeader]) -> Word {
+        reference_commitment(
+            headers
+                .iter()
+                .map(|header| (header.id().as_word(), header.metadata().to_commitment())),
+        )
+    }
+
+    fn any_note_header() -> impl Strategy<Value = NoteHeader> {
+        let note_type = prop_oneof![Just(NoteType::Public), Just(NoteType::Private)];
+
+        (any_word(), note_type, any::<u32>()).prop_map(|(note_id, note_type, tag)| {
+            let sender = ACCOUNT_ID_SENDER.try_into().unwrap();
+            let metadata = NoteMetadata::new(sender, note_type, NoteTag::new(tag));
+            NoteHeader::new(NoteId::from_raw(note_id), metadata)
+        })
+    }
+
+    /// Returns a strategy generating the commitments of authenticated and unauthenticated input
+    /// notes.
+    fn any_input_note_commitment() -> impl Strategy<Value = InputNoteCommitment> {
+        prop_oneof![
+            any_word()
+                .prop_map(|nullifier| InputNoteCommitment::from(Nullifier::from_raw(nullifier))),
+            any_note().prop_map(|note| InputNoteCommitment::from(InputNote::unauthenticated(note))),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn proptest_note_commitment_accumulator_matches_reference_commitment(
+            headers in prop::collection::vec(any_note_header(), 0..20)
+        ) {
+            let mut accumulator = NoteCommitmentAccumulator::new();
+            for (idx, header) in headers.iter().enumerate() {
+                // the running commitment must match the commitment of every prefix
+                prop_assert_eq!(
+                    accumulator.commitment(),
+                    output_notes_reference_commitment(&headers[..idx])
+                );
+
+                accumulator.push(header.id(), header.metadata());
+            }
+
+            let expected = output_notes_reference_commitment(&headers);
+            let num_notes = headers.len();
+            let output_notes =
+                OutputNotes::new(headers.into_iter().map(OutputNote::Header).collect()).unwrap();
+            prop_assert_eq!(accumulator.num_notes(), num_notes);
+            prop_assert_eq!(accumulator.commitment(), expected);
+            prop_assert_eq!(output_notes.commitment(), expected);
+        }
+
+        #[test]
+        fn proptest_input_note_commitment_accumulator_matches_reference_commitment(
+            commitments in prop::collection::vec(any_input_note_commitment(), 0..10)
+        ) {
+            let mut accumulator = InputNoteCommitmentAccumulator::new();
+            for commitment in commitments.iter() {
+                accumulator.push(commitment.nullifier(), commitment.note_commitment());
+            }
+
+            let expected = reference_commitment(commitments.iter().map(|commitment| {
+                (
+                    commitment.nullifier().as_word(),
+                    commitment.note_commitment().unwrap_or_default(),
+                )
+            }));
+            let num_notes = commitments.len();
+            let input_notes = InputNotes::new(commitments).unwrap();
+            prop_assert_eq!(accumulator.num_notes(), num_notes);
+            prop_assert_eq!(accumulator.commitment(), expected);
+            prop_assert_eq!(input_notes.commitment(), expected);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::fmt::Display;
 
 use super::{
     AccountDelta,
@@ -13,10 +14,12 @@ use super::{
     TransactionId,
     TransactionOutputs,
 };
+use crate::PrettyPrint;
 use crate::account::PartialAccount;
 use crate::asset::FungibleAsset;
 use crate::block::{BlockHeader, BlockNumber};
-use crate::transaction::TransactionInputs;
+use crate::transaction::outputs::render_asset;
+use crate::transaction::{ReplayBundle, TransactionInputs, TransactionKernel, TransactionWitness};
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -160,6 +163,31 @@ impl ExecutedTransaction {
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a [`ReplayBundle`] containing all the data required to re-execute this transaction
+    /// offline and to compare the outputs of the re-execution against the outputs of this
+    /// transaction.
+    pub fn to_replay_bundle(&self) -> ReplayBundle {
+        ReplayBundle::new(
+            self.tx_inputs.clone(),
+            TransactionKernel::main().hash(),
+            self.tx_outputs.clone(),
+        )
+    }
+
+    /// Consumes this transaction and returns a [`TransactionWitness`] containing the minimal set
+    /// of data required to prove it.
+    pub fn into_witness(self) -> TransactionWitness {
+        let expected_stack_outputs = TransactionKernel::build_output_stack(
+            self.tx_outputs.account.commitment(),
+            self.tx_outputs.account_delta_commitment,
+            self.tx_outputs.output_notes.commitment(),
+            self.tx_outputs.fee,
+            self.tx_outputs.expiration_block_num,
+        );
+
+        TransactionWitness::from_tx_inputs(&self.tx_inputs, expected_stack_outputs)
+    }
+
     /// Returns individual components of this transaction.
     pub fn into_parts(
         self,
@@ -201,6 +229,58 @@ impl Deserializable for ExecutedTransaction {
     }
 }
 
+// PRETTY-PRINTING
+// ================================================================================================
+
+impl PrettyPrint for ExecutedTransaction {
+    fn render(&self) -> miden_core::prettier::Document {
+        use miden_core::prettier::*;
+
+        let input_notes = self.input_notes();
+        let mut notes = Document::Empty;
+        for note in input_notes.iter() {
+            notes +=
+                nl() + text(format!("note {}, nullifier {}", note.id(), note.note().nullifier()));
+        }
+
+        let fields = nl()
+            + text(format!("account id: {}", self.account_id()))
+            + nl()
+            + text(format!(
+                "initial account commitment: {}",
+                self.initial_account().initial_commitment()
+            ))
+            + nl()
+            + text(format!("final account commitment: {}", self.final_account().commitment()))
+            + nl()
+            + text(format!("reference block: {}", self.block_header().block_num()))
+            + nl()
+            + text(format!("expiration block: {}", self.expiration_block_num()))
+            + nl()
+            + const_text("fee: ")
+            + render_asset(&self.fee().into())
+            + nl()
+            + text(format!(
+                "input notes ({}), commitment {}",
+                input_notes.num_notes(),
+                input_notes.commitment()
+            ))
+            + indent(4, notes)
+            + nl()
+            + self.account_delta.render()
+            + nl()
+            + self.output_notes().render();
+
+        text(format!("executed transaction {}", self.id)) + indent(4, fields)
+    }
+}
+
+impl Display for ExecutedTransaction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.pretty_print(f)
+    }
+}
+
 // TRANSACTION MEASUREMENTS
 // ================================================================================================
 
@@ -223,9 +303,44 @@ pub struct TransactionMeasurements {
 }
 
 impl TransactionMeasurements {
+    /// Returns the number of cycles spent executing the prologue.
+    pub fn prologue_cycles(&self) -> usize {
+        self.prologue
+    }
+
+    /// Returns the number of cycles spent processing the input notes, including the execution of
+    /// all note scripts.
+    pub fn notes_cycles(&self) -> usize {
+        self.notes_processing
+    }
+
+    /// Returns the number of cycles spent processing the transaction script.
+    pub fn tx_script_cycles(&self) -> usize {
+        self.tx_script_processing
+    }
+
+    /// Returns the number of cycles spent executing the epilogue, including the authentication
+    /// procedure.
+    pub fn epilogue_cycles(&self) -> usize {
+        self.epilogue
+    }
+
+    /// Returns the number of cycles spent executing the authentication procedure.
+    ///
+    /// The authentication procedure is executed as part of the epilogue, so these cycles are
+    /// included in [`Self::epilogue_cycles`].
+    pub fn auth_cycles(&self) -> usize {
+        self.auth_procedure
+    }
+
     /// Returns the total number of cycles spent executing the transaction.
+    ///
+    /// This is the sum of the prologue, notes, transaction script and epilogue cycles.
     pub fn total_cycles(&self) -> usize {
-        self.prologue + self.notes_processing + self.tx_script_processing + self.epilogue
+        self.prologue_cycles()
+            + self.notes_cycles()
+            + self.tx_script_cycles()
+            + self.epilogue_cycles()
     }
 
     /// Returns the trace length of the transaction which is the next power of 2 of the total cycles
@@ -1,9 +1,22 @@
-use crate::Word;
-use crate::account::{AccountCode, AccountId, PartialAccount, PartialStorage};
+use alloc::collections::BTreeMap;
+
+use crate::account::{
+    AccountCode,
+    AccountHeader,
+    AccountId,
+    AccountStorageHeader,
+    PartialAccount,
+    PartialStorage,
+    StorageSlotId,
+    StorageSlotName,
+};
 use crate::asset::PartialVault;
+use crate::block::BlockHeader;
 use crate::block::account_tree::AccountWitness;
 use crate::crypto::merkle::smt::{SmtProof, SmtProofError};
+use crate::errors::AccountInputsError;
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crate::{Felt, Word};
 
 // ACCOUNT INPUTS
 // ================================================================================================
@@ -27,6 +40,66 @@ impl AccountInputs {
         AccountInputs { partial_account, witness }
     }
 
+    /// Creates a new instance of `AccountInputs` from the account data returned by a node for an
+    /// account proof request.
+    ///
+    /// The partial account is assembled from the account header, the account code and the storage
+    /// header encoded as field elements (see [`AccountStorageHeader::to_elements`]), whose slots
+    /// are named according to `slot_names`. It tracks none of the storage map entries or assets of
+    /// the account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the storage header cannot be parsed from the provided elements and slot names.
+    /// - the commitment of the code or of the storage header does not match the account header.
+    /// - the partial account cannot be constructed, e.g. because the account is new.
+    /// - the witness is for a different account or does not prove the commitment of the account.
+    pub fn from_response(
+        header: AccountHeader,
+        storage_header: &[Felt],
+        code: AccountCode,
+        witness: AccountWitness,
+        slot_names: &BTreeMap<StorageSlotId, StorageSlotName>,
+    ) -> Result<Self, AccountInputsError> {
+        let account_id = header.id();
+
+        if code.commitment() != header.code_commitment() {
+            return Err(AccountInputsError::CodeCommitmentMismatch {
+                account_id,
+                expected: header.code_commitment(),
+                actual: code.commitment(),
+            });
+        }
+
+        let storage_header = AccountStorageHeader::try_from_elements(storage_header, slot_names)
+            .map_err(|err| AccountInputsError::InvalidStorageHeader(account_id, err))?;
+        if storage_header.to_commitment() != header.storage_commitment() {
+            return Err(AccountInputsError::StorageCommitmentMismatch {
+                account_id,
+                expected: header.storage_commitment(),
+                actual: storage_header.to_commitment(),
+            });
+        }
+
+        let partial_storage = PartialStorage::new(storage_header, [])
+            .map_err(|err| AccountInputsError::InvalidPartialAccount(account_id, err))?;
+        let partial_account = PartialAccount::new(
+            account_id,
+            header.nonce(),
+            code,
+            partial_storage,
+            PartialVault::new(header.vault_root()),
+            None,
+        )
+        .map_err(|err| AccountInputsError::InvalidPartialAccount(account_id, err))?;
+
+        let account_inputs = AccountInputs::new(partial_account, witness);
+        account_inputs.validate_witness()?;
+
+        Ok(account_inputs)
+    }
+
     /// Returns the account ID.
     pub fn id(&self) -> AccountId {
         self.partial_account.id()
@@ -71,6 +144,57 @@ impl AccountInputs {
 
         Ok(root)
     }
+
+    /// Validates that these inputs are valid inputs of the account for a transaction with the
+    /// provided reference block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the witness is for a different account or does not prove the commitment of the partial
+    ///   account.
+    /// - the account root computed from the witness does not match the account root of the block,
+    ///   e.g. because the witness was fetched for a different block.
+    pub fn validate(&self, block_header: &BlockHeader) -> Result<(), AccountInputsError> {
+        self.validate_witness()?;
+
+        let account_root = self
+            .compute_account_root()
+            .map_err(|err| AccountInputsError::AccountRootComputationFailed(self.id(), err))?;
+        if account_root != block_header.account_root() {
+            return Err(AccountInputsError::StaleWitness {
+                account_id: self.id(),
+                block_num: block_header.block_num(),
+                expected: block_header.account_root(),
+                computed: account_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates that the witness proves the current commitment of the partial account.
+    fn validate_witness(&self) -> Result<(), AccountInputsError> {
+        if self.witness.id() != self.id() {
+            return Err(AccountInputsError::AccountIdMismatch {
+                account_id: self.id(),
+                witness_account_id: self.witness.id(),
+            });
+        }
+
+        if self.witness.state_commitment() != self.partial_account.commitment() {
+            return Err(AccountInputsError::AccountCommitmentMismatch {
+                account_id: self.id(),
+                account_commitment: self.partial_account.commitment(),
+                witness_commitment: self.witness.state_commitment(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl Serializable for AccountInputs {
@@ -94,19 +218,55 @@ impl Deserializable for AccountInputs {
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::BTreeMap;
     use alloc::vec::Vec;
+    use core::slice;
 
+    use assert_matches::assert_matches;
     use miden_core::Felt;
     use miden_core::utils::{Deserializable, Serializable};
     use miden_crypto::merkle::SparseMerklePath;
     use miden_processor::SMT_DEPTH;
 
-    use crate::account::{Account, AccountCode, AccountId, AccountStorage, PartialAccount};
+    use crate::Word;
+    use crate::account::{
+        Account,
+        AccountCode,
+        AccountHeader,
+        AccountId,
+        AccountStorage,
+        PartialAccount,
+    };
     use crate::asset::AssetVault;
-    use crate::block::account_tree::AccountWitness;
+    use crate::block::BlockHeader;
+    use crate::block::account_tree::{AccountTree, AccountWitness};
+    use crate::errors::AccountInputsError;
     use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
     use crate::transaction::AccountInputs;
 
+    fn mock_account(nonce: u64) -> Account {
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let vault = AssetVault::new(&[]).unwrap();
+        let storage = AccountStorage::mock();
+        Account::new_existing(id, vault, storage, AccountCode::mock(), Felt::new(nonce))
+    }
+
+    /// Returns the account inputs of the provided account assembled from the parts returned by a
+    /// node, with the witness opened against the provided account tree.
+    fn build_inputs_from_response(
+        account: &Account,
+        tree: &AccountTree,
+    ) -> Result<AccountInputs, AccountInputsError> {
+        let storage_header = account.storage().to_header();
+        AccountInputs::from_response(
+            AccountHeader::from(account),
+            &storage_header.to_elements(),
+            account.code().clone(),
+            tree.open(account.id()),
+            &storage_header.slot_names(),
+        )
+    }
+
     #[test]
     fn serde_roundtrip() {
         let id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
@@ -133,4 +293,82 @@ mod tests {
         let deserialized = AccountInputs::read_from_bytes(&serialized).unwrap();
         assert_eq!(deserialized, fpi_inputs);
     }
+
+    #[test]
+    fn account_inputs_from_response() -> anyhow::Result<()> {
+        let account = mock_account(10);
+        let block_header =
+            BlockHeader::mock(1, None, None, slice::from_ref(&account), Word::empty());
+        let tree = AccountTree::with_entries([(account.id(), account.commitment())])?;
+
+        let account_inputs = build_inputs_from_response(&account, &tree)?;
+        assert_eq!(account_inputs.account().commitment(), account.commitment());
+        assert_eq!(account_inputs.storage().header(), &account.storage().to_header());
+        account_inputs.validate(&block_header)?;
+
+        // A storage header which does not match the storage commitment of the header is rejected.
+        let result = AccountInputs::from_response(
+            AccountHeader::from(&account),
+            &[],
+            account.code().clone(),
+            tree.open(account.id()),
+            &BTreeMap::new(),
+        );
+        assert_matches!(result, Err(AccountInputsError::StorageCommitmentMismatch { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_inputs_reject_commitment_mismatch() -> anyhow::Result<()> {
+        let account = mock_account(10);
+        let updated_account = mock_account(11);
+        let tree = AccountTree::with_entries([(account.id(), updated_account.commitment())])?;
+
+        assert_matches!(
+            build_inputs_from_response(&account, &tree),
+            Err(AccountInputsError::AccountCommitmentMismatch {
+                account_commitment,
+                witness_commitment,
+                ..
+            }) => {
+                assert_eq!(account_commitment, account.commitment());
+                assert_eq!(witness_commitment, updated_account.commitment());
+            }
+        );
+
+        let account_inputs =
+            AccountInputs::new(PartialAccount::from(&account), tree.open(account.id()));
+        let block_header =
+            BlockHeader::mock(1, None, None, slice::from_ref(&updated_account), Word::empty());
+        assert_matches!(
+            account_inputs.validate(&block_header),
+            Err(AccountInputsError::AccountCommitmentMismatch { .. })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_inputs_reject_stale_witness() -> anyhow::Result<()> {
+        let account = mock_account(10);
+        let tree = AccountTree::with_entries([(account.id(), account.commitment())])?;
+        let account_inputs = build_inputs_from_response(&account, &tree)?;
+
+        // The account was updated in a later block, so the witness no longer proves the account
+        // root of that block.
+        let updated_account = mock_account(11);
+        let block_header = BlockHeader::mock(2, None, None, &[updated_account], Word::empty());
+
+        assert_matches!(
+            account_inputs.validate(&block_header),
+            Err(AccountInputsError::StaleWitness { block_num, expected, computed, .. }) => {
+                assert_eq!(block_num, 2.into());
+                assert_eq!(expected, block_header.account_root());
+                assert_eq!(computed, tree.root());
+            }
+        );
+
+        Ok(())
+    }
 }
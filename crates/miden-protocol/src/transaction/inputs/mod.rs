@@ -25,6 +25,7 @@ use crate::block::{BlockHeader, BlockNumber};
 use crate::crypto::merkle::SparseMerklePath;
 use crate::errors::{TransactionInputError, TransactionInputsExtractionError};
 use crate::note::{Note, NoteInclusionProof};
+use crate::transaction::tx_args::write_canonical_advice_inputs;
 use crate::transaction::{TransactionAdviceInputs, TransactionArgs, TransactionScript};
 use crate::{Felt, Word};
 
@@ -229,6 +230,28 @@ impl TransactionInputs {
         &self.tx_args
     }
 
+    /// Returns the canonical serialization of these transaction inputs.
+    ///
+    /// The regular serialization of the advice inputs depends on the order in which their Merkle
+    /// store was populated, e.g. on the order in which asset witnesses were fetched. The canonical
+    /// serialization writes the advice inputs in a normalized form instead, so that logically
+    /// equivalent transaction inputs produce the same bytes, which makes them suitable for keying
+    /// a cache of executed transactions.
+    ///
+    /// The canonical bytes are not meant to be deserialized.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut target = Vec::new();
+        self.account.write_into(&mut target);
+        self.block_header.write_into(&mut target);
+        self.blockchain.write_into(&mut target);
+        self.input_notes.write_into(&mut target);
+        self.tx_args.write_canonical_into(&mut target);
+        write_canonical_advice_inputs(&self.advice_inputs, &mut target);
+        self.foreign_account_code.write_into(&mut target);
+        self.foreign_account_slot_names.write_into(&mut target);
+        target
+    }
+
     // DATA EXTRACTORS
     // --------------------------------------------------------------------------------------------
 
@@ -386,6 +409,94 @@ impl TransactionInputs {
         Ok(AccountInputs::new(partial_account, witness))
     }
 
+    /// Checks that the inputs of all provided foreign accounts can be read from the advice inputs
+    /// and returns these transaction inputs if so.
+    ///
+    /// In contrast to [`Self::read_foreign_account_inputs`], this does not stop at the first
+    /// missing piece of data, but reports all of them, including several missing pieces of the
+    /// same account. This allows fetching all missing data in a single round.
+    ///
+    /// # Errors
+    ///
+    /// Returns all errors encountered while reading the inputs of the provided accounts, in the
+    /// order in which the accounts were provided.
+    pub fn read_foreign_accounts_inputs(
+        self,
+        account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> Result<TransactionInputs, Vec<TransactionInputsExtractionError>> {
+        let mut errors = Vec::new();
+        for account_id in account_ids {
+            self.collect_foreign_account_errors(account_id, &mut errors);
+        }
+
+        if errors.is_empty() { Ok(self) } else { Err(errors) }
+    }
+
+    /// Appends all errors encountered while reading the inputs of the provided foreign account to
+    /// `errors`.
+    fn collect_foreign_account_errors(
+        &self,
+        account_id: AccountId,
+        errors: &mut Vec<TransactionInputsExtractionError>,
+    ) {
+        if account_id == self.account().id() {
+            errors.push(TransactionInputsExtractionError::AccountNotForeign);
+            return;
+        }
+
+        // Without the header none of the remaining data can be located.
+        let account_id_key = TransactionAdviceInputs::account_id_map_key(account_id);
+        let Some(header_elements) = self.advice_inputs.map.get(&account_id_key) else {
+            errors.push(TransactionInputsExtractionError::ForeignAccountNotFound(account_id));
+            return;
+        };
+        let header = match AccountHeader::try_from_elements(header_elements) {
+            Ok(header) => header,
+            Err(err) => {
+                errors.push(err.into());
+                return;
+            },
+        };
+
+        let num_errors = errors.len();
+
+        if !self
+            .foreign_account_code
+            .iter()
+            .any(|code| code.commitment() == header.code_commitment())
+        {
+            errors.push(TransactionInputsExtractionError::ForeignAccountCodeNotFound(header.id()));
+        }
+
+        match self.advice_inputs.map.get(&header.storage_commitment()) {
+            Some(storage_header_elements) => {
+                let storage_header = AccountStorageHeader::try_from_elements(
+                    storage_header_elements,
+                    self.foreign_account_slot_names(),
+                )
+                .map_err(TransactionInputsExtractionError::from)
+                .and_then(|storage_header| Ok(PartialStorage::new(storage_header, [])?));
+                if let Err(err) = storage_header {
+                    errors.push(err);
+                }
+            },
+            None => {
+                errors.push(TransactionInputsExtractionError::StorageHeaderNotFound(header.id()))
+            },
+        }
+
+        if let Err(err) = self.read_foreign_account_witness(&header) {
+            errors.push(err);
+        }
+
+        // All pieces are present, so any remaining error stems from assembling them.
+        if errors.len() == num_errors
+            && let Err(err) = self.read_foreign_account_inputs(account_id)
+        {
+            errors.push(err);
+        }
+    }
+
     /// Reads a foreign partial account from the advice inputs based on the account ID corresponding
     /// to the provided header.
     fn read_foreign_partial_account(
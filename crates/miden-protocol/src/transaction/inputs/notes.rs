@@ -1,9 +1,10 @@
 use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 
-use super::TransactionInputError;
+use super::{TransactionInputError, validate_is_in_block};
+use crate::block::BlockHeader;
 use crate::note::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier};
-use crate::transaction::InputNoteCommitment;
+use crate::transaction::{InputNoteCommitment, InputNoteCommitmentAccumulator};
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -11,7 +12,7 @@ use crate::utils::serde::{
     DeserializationError,
     Serializable,
 };
-use crate::{Felt, Hasher, MAX_INPUT_NOTES_PER_TX, Word};
+use crate::{MAX_INPUT_NOTES_PER_TX, Word};
 
 // TO INPUT NOTE COMMITMENT
 // ================================================================================================
@@ -148,10 +149,47 @@ impl InputNotes<InputNote> {
     }
 
     /// Returns a vector of input note commitments based on the input notes.
+    ///
+    /// As in proven transactions built without note headers, authenticated notes are represented
+    /// only by their nullifiers.
     pub fn to_commitments(&self) -> InputNotes<InputNoteCommitment> {
-        let notes = self.notes.iter().map(InputNoteCommitment::from).collect();
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| InputNoteCommitment::from(note).without_public_header())
+            .collect();
         InputNotes::<InputNoteCommitment>::new_unchecked(notes)
     }
+
+    /// Authenticates the input note with the provided `note_id` in place using the provided
+    /// inclusion `proof` and the header of the block in which the note was created.
+    ///
+    /// See [`InputNote::authenticate`] for details.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The input notes do not contain a note with the provided `note_id`.
+    /// - The note cannot be authenticated with the provided proof and block header.
+    pub fn authenticate_note(
+        &mut self,
+        note_id: NoteId,
+        proof: NoteInclusionProof,
+        block_header: &BlockHeader,
+    ) -> Result<(), TransactionInputError> {
+        let note_idx = self
+            .notes
+            .iter()
+            .position(|note| note.id() == note_id)
+            .ok_or(TransactionInputError::InputNoteNotFound(note_id))?;
+
+        self.notes[note_idx] = self.notes[note_idx].clone().authenticate(proof, block_header)?;
+
+        // The number of notes and their nullifiers are unchanged, but authenticated notes do not
+        // contribute their note commitment, so the commitment must be recomputed.
+        self.commitment = build_input_note_commitment(&self.notes);
+
+        Ok(())
+    }
 }
 
 impl<T> IntoIterator for InputNotes<T> {
@@ -214,20 +252,12 @@ impl<T: Deserializable + ToInputNoteCommitments> Deserializable for InputNotes<T
 
 fn build_input_note_commitment<T: ToInputNoteCommitments>(notes: &[T]) -> Word {
     // Note: This implementation must be kept in sync with the kernel's `process_input_notes_data`
-    if notes.is_empty() {
-        return Word::empty();
-    }
-
-    let mut elements: Vec<Felt> = Vec::with_capacity(notes.len() * 2);
+    let mut accumulator = InputNoteCommitmentAccumulator::new();
     for commitment_data in notes {
-        let nullifier = commitment_data.nullifier();
-        let empty_word_or_note_commitment =
-            &commitment_data.note_commitment().map_or(Word::empty(), |note_id| note_id);
-
-        elements.extend_from_slice(nullifier.as_elements());
-        elements.extend_from_slice(empty_word_or_note_commitment.as_elements());
+        accumulator.push(commitment_data.nullifier(), commitment_data.note_commitment());
     }
-    Hasher::hash_elements(&elements)
+
+    accumulator.commitment()
 }
 
 // INPUT NOTE
@@ -261,6 +291,34 @@ impl InputNote {
         Self::Unauthenticated { note }
     }
 
+    /// Authenticates this note using the provided inclusion `proof` and the header of the block
+    /// in which the note was created, and returns the resulting authenticated [InputNote].
+    ///
+    /// This allows upgrading an unauthenticated note once its inclusion proof becomes available.
+    /// If the note is already authenticated, its proof is replaced by the provided one.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the proof does not match the block number of `block_header`.
+    /// - The proof does not open to the note's commitment in the note root of `block_header`.
+    pub fn authenticate(
+        self,
+        proof: NoteInclusionProof,
+        block_header: &BlockHeader,
+    ) -> Result<Self, TransactionInputError> {
+        let note = self.into_note();
+
+        if proof.location().block_num() != block_header.block_num() {
+            return Err(TransactionInputError::InputNoteNotInBlock(
+                note.id(),
+                block_header.block_num(),
+            ));
+        }
+        validate_is_in_block(&note, &proof, block_header)?;
+
+        Ok(Self::authenticated(note, proof))
+    }
+
     // ACCESSORS
     // -------------------------------------------------------------------------------------------
 
@@ -373,9 +431,10 @@ mod input_notes_tests {
     use miden_core::Word;
 
     use super::InputNotes;
+    use crate::block::{BlockHeader, BlockNoteIndex, BlockNoteTree, BlockNumber};
     use crate::errors::TransactionInputError;
-    use crate::note::Note;
-    use crate::transaction::InputNote;
+    use crate::note::{Note, NoteInclusionProof};
+    use crate::transaction::{InputNote, ToInputNoteCommitments};
 
     #[test]
     fn test_duplicate_input_notes() -> anyhow::Result<()> {
@@ -393,4 +452,101 @@ mod input_notes_tests {
 
         Ok(())
     }
+
+    /// Returns an inclusion proof for the given note at index 0 of a block with the given number,
+    /// along with the header of that block.
+    fn note_proof_and_block(
+        note: &Note,
+        block_num: BlockNumber,
+    ) -> anyhow::Result<(NoteInclusionProof, BlockHeader)> {
+        let note_index = BlockNoteIndex::new(0, 0).unwrap();
+        let note_tree = BlockNoteTree::with_entries([(note_index, note.id(), note.metadata())])?;
+        let proof = NoteInclusionProof::new(
+            block_num,
+            note_index.leaf_index_value(),
+            note_tree.open(note_index),
+        )?;
+        let block_header =
+            BlockHeader::mock(block_num, None, Some(note_tree.root()), &[], Word::empty());
+
+        Ok((proof, block_header))
+    }
+
+    #[test]
+    fn authenticate_input_note() -> anyhow::Result<()> {
+        let note = Note::mock_noop(Word::empty());
+        let other_note = Note::mock_noop(Word::from([1, 2, 3, 4u32]));
+        let (proof, block_header) = note_proof_and_block(&note, BlockNumber::from(5))?;
+
+        let mut input_notes =
+            InputNotes::from_unauthenticated_notes(vec![note.clone(), other_note.clone()])?;
+        let unauthenticated_commitment = input_notes.commitment();
+
+        input_notes.authenticate_note(note.id(), proof.clone(), &block_header)?;
+
+        assert_eq!(input_notes.get_note(0), &InputNote::authenticated(note.clone(), proof.clone()));
+        assert_eq!(input_notes.get_note(0).note_commitment(), None);
+        assert_ne!(input_notes.commitment(), unauthenticated_commitment);
+        assert_eq!(
+            input_notes.commitment(),
+            InputNotes::new(input_notes.clone().into_vec())?.commitment()
+        );
+
+        let error = input_notes
+            .authenticate_note(
+                Note::mock_noop(Word::from([5, 6, 7, 8u32])).id(),
+                proof,
+                &block_header,
+            )
+            .unwrap_err();
+        assert_matches!(error, TransactionInputError::InputNoteNotFound(_));
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_input_note_fails_for_wrong_block() -> anyhow::Result<()> {
+        let note = Note::mock_noop(Word::empty());
+        let (proof, _) = note_proof_and_block(&note, BlockNumber::from(5))?;
+        let (_, other_block_header) = note_proof_and_block(&note, BlockNumber::from(6))?;
+
+        let error = InputNote::unauthenticated(note.clone())
+            .authenticate(proof, &other_block_header)
+            .unwrap_err();
+
+        assert_matches!(
+            error,
+            TransactionInputError::InputNoteNotInBlock(note_id, block_num)
+                if note_id == note.id() && block_num == BlockNumber::from(6)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_input_note_fails_for_tampered_path() -> anyhow::Result<()> {
+        let note = Note::mock_noop(Word::empty());
+        let other_note = Note::mock_noop(Word::from([1, 2, 3, 4u32]));
+        let block_num = BlockNumber::from(5);
+
+        // The block contains another note next to the authenticated one, which changes the path.
+        let note_tree = BlockNoteTree::with_entries([
+            (BlockNoteIndex::new(0, 0).unwrap(), note.id(), note.metadata()),
+            (BlockNoteIndex::new(0, 1).unwrap(), other_note.id(), other_note.metadata()),
+        ])?;
+        let block_header =
+            BlockHeader::mock(block_num, None, Some(note_tree.root()), &[], Word::empty());
+        let (tampered_proof, _) = note_proof_and_block(&note, block_num)?;
+
+        let error = InputNote::unauthenticated(note.clone())
+            .authenticate(tampered_proof, &block_header)
+            .unwrap_err();
+
+        assert_matches!(
+            error,
+            TransactionInputError::InputNoteNotInBlock(note_id, _) if note_id == note.id()
+        );
+
+        Ok(())
+    }
 }
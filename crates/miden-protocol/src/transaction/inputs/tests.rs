@@ -66,6 +66,110 @@ fn test_read_foreign_account_inputs_missing_data() {
     );
 }
 
+#[test]
+fn test_read_foreign_accounts_inputs_reports_all_missing_accounts() {
+    use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE;
+
+    let native_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+    let foreign_account_id_1 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2).unwrap();
+    let foreign_account_id_2 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+    // Create minimal transaction inputs with empty advice map.
+    let storage_header = AccountStorageHeader::new(vec![]).unwrap();
+    let partial_account = PartialAccount::new(
+        native_account_id,
+        Felt::new(10),
+        AccountCode::mock(),
+        PartialStorage::new(storage_header, []).unwrap(),
+        PartialVault::new(Word::default()),
+        None,
+    )
+    .unwrap();
+
+    let tx_inputs = TransactionInputs {
+        account: partial_account,
+        block_header: crate::block::BlockHeader::mock(0, None, None, &[], Word::default()),
+        blockchain: crate::transaction::PartialBlockchain::default(),
+        input_notes: crate::transaction::InputNotes::new(vec![]).unwrap(),
+        tx_args: crate::transaction::TransactionArgs::default(),
+        advice_inputs: crate::vm::AdviceInputs::default(),
+        foreign_account_code: Vec::new(),
+        foreign_account_slot_names: BTreeMap::new(),
+    };
+
+    assert!(tx_inputs.clone().read_foreign_accounts_inputs([]).is_ok());
+
+    // Both missing accounts should be reported rather than only the first one.
+    let errors = tx_inputs
+        .read_foreign_accounts_inputs([foreign_account_id_1, foreign_account_id_2])
+        .unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(
+        matches!(errors[0], TransactionInputsExtractionError::ForeignAccountNotFound(id) if id == foreign_account_id_1)
+    );
+    assert!(
+        matches!(errors[1], TransactionInputsExtractionError::ForeignAccountNotFound(id) if id == foreign_account_id_2)
+    );
+}
+
+#[test]
+fn test_read_foreign_accounts_inputs_reports_all_missing_data_of_an_account() {
+    let native_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+    let foreign_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2).unwrap();
+
+    let storage_header = AccountStorageHeader::new(vec![]).unwrap();
+    let partial_account = PartialAccount::new(
+        native_account_id,
+        Felt::new(10),
+        AccountCode::mock(),
+        PartialStorage::new(storage_header, []).unwrap(),
+        PartialVault::new(Word::default()),
+        None,
+    )
+    .unwrap();
+
+    // Only provide the foreign account header, but neither its code nor its storage header.
+    let foreign_header = AccountHeader::new(
+        foreign_account_id,
+        Felt::new(5),
+        Word::default(),
+        Word::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        AccountCode::mock().commitment(),
+    );
+    let mut advice_inputs = crate::vm::AdviceInputs::default();
+    let account_id_key =
+        crate::transaction::TransactionAdviceInputs::account_id_map_key(foreign_account_id);
+    advice_inputs.map.insert(account_id_key, foreign_header.as_elements().to_vec());
+
+    let tx_inputs = TransactionInputs {
+        account: partial_account,
+        block_header: crate::block::BlockHeader::mock(0, None, None, &[], Word::default()),
+        blockchain: crate::transaction::PartialBlockchain::default(),
+        input_notes: crate::transaction::InputNotes::new(vec![]).unwrap(),
+        tx_args: crate::transaction::TransactionArgs::default(),
+        advice_inputs,
+        foreign_account_code: Vec::new(),
+        foreign_account_slot_names: BTreeMap::new(),
+    };
+
+    // Both the missing code and the missing storage header should be reported.
+    let errors = tx_inputs.read_foreign_accounts_inputs([foreign_account_id]).unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(
+        matches!(errors[0], TransactionInputsExtractionError::ForeignAccountCodeNotFound(id) if id == foreign_account_id)
+    );
+    assert!(
+        matches!(errors[1], TransactionInputsExtractionError::StorageHeaderNotFound(id) if id == foreign_account_id)
+    );
+}
+
 #[test]
 fn test_read_foreign_account_inputs_with_storage_data() {
     use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2;
@@ -369,3 +473,62 @@ fn test_transaction_inputs_serialization_with_foreign_slot_names() {
     // Verify the entire structure is identical.
     assert_eq!(original_tx_inputs, deserialized);
 }
+
+#[test]
+fn test_transaction_inputs_canonical_bytes() {
+    use crate::block::account_tree::AccountTree;
+    use crate::crypto::merkle::smt::Smt;
+    use crate::vm::AdviceInputs;
+
+    let native_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2).unwrap();
+
+    let storage_header = AccountStorageHeader::new(vec![]).unwrap();
+    let account = PartialAccount::new(
+        native_account_id,
+        Felt::new(10),
+        AccountCode::mock(),
+        PartialStorage::new(storage_header, []).unwrap(),
+        PartialVault::new(Word::default()),
+        None,
+    )
+    .unwrap();
+
+    let mut account_tree = AccountTree::<Smt>::default();
+    account_tree.insert(native_account_id, Word::from([1, 2, 3, 4u32])).unwrap();
+    account_tree.insert(other_account_id, Word::from([5, 6, 7, 8u32])).unwrap();
+    let witnesses = [account_tree.open(native_account_id), account_tree.open(other_account_id)];
+
+    // The mock header is randomized, so it is built only once and shared by all inputs.
+    let block_header = crate::block::BlockHeader::mock(0, None, None, &[], account_tree.root());
+
+    let tx_inputs = |witness_order: &[usize]| {
+        // Add the witnesses to the advice inputs in the provided order, as if they were fetched
+        // in that order.
+        let mut advice_inputs = AdviceInputs::default();
+        for &idx in witness_order {
+            let witness = &witnesses[idx];
+            advice_inputs.store.extend(witness.authenticated_nodes());
+            advice_inputs.map.insert(witness.leaf().hash(), witness.leaf().to_elements());
+        }
+
+        TransactionInputs {
+            account: account.clone(),
+            block_header: block_header.clone(),
+            blockchain: crate::transaction::PartialBlockchain::default(),
+            input_notes: crate::transaction::InputNotes::new(vec![]).unwrap(),
+            tx_args: crate::transaction::TransactionArgs::default(),
+            advice_inputs: AdviceInputs::default(),
+            foreign_account_code: Vec::new(),
+            foreign_account_slot_names: BTreeMap::new(),
+        }
+        .with_advice_inputs(advice_inputs)
+    };
+
+    let canonical_bytes = tx_inputs(&[0, 1]).canonical_bytes();
+    assert_eq!(tx_inputs(&[1, 0]).canonical_bytes(), canonical_bytes);
+    assert_eq!(tx_inputs(&[1, 0, 1]).canonical_bytes(), canonical_bytes);
+    assert_ne!(tx_inputs(&[0]).canonical_bytes(), canonical_bytes);
+}
@@ -3,6 +3,7 @@ use super::note::{NoteId, Nullifier};
 use super::vm::AdviceInputs;
 use super::{Felt, Hasher, WORD_SIZE, Word, ZERO};
 
+mod commitment_accumulator;
 mod executed_tx;
 mod inputs;
 mod kernel;
@@ -10,11 +11,14 @@ mod ordered_transactions;
 mod outputs;
 mod partial_blockchain;
 mod proven_tx;
+mod replay_bundle;
 mod transaction_id;
 mod tx_args;
 mod tx_header;
 mod tx_summary;
+mod tx_witness;
 
+pub use commitment_accumulator::{InputNoteCommitmentAccumulator, NoteCommitmentAccumulator};
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
 pub use inputs::{AccountInputs, InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
 pub use kernel::{TransactionAdviceInputs, TransactionEventId, TransactionKernel, memory};
@@ -27,7 +31,9 @@ pub use proven_tx::{
     ProvenTransactionBuilder,
     TxAccountUpdate,
 };
+pub use replay_bundle::ReplayBundle;
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
 pub use tx_header::TransactionHeader;
 pub use tx_summary::TransactionSummary;
+pub use tx_witness::{TransactionHostInputs, TransactionWitness};
@@ -1,10 +1,10 @@
 use alloc::collections::BTreeSet;
 use alloc::string::ToString;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
 
 use crate::account::AccountHeader;
-use crate::asset::FungibleAsset;
+use crate::asset::{Asset, FungibleAsset};
 use crate::block::BlockNumber;
 use crate::errors::TransactionOutputError;
 use crate::note::{
@@ -17,6 +17,7 @@ use crate::note::{
     PartialNote,
     compute_note_commitment,
 };
+use crate::transaction::NoteCommitmentAccumulator;
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -24,7 +25,7 @@ use crate::utils::serde::{
     DeserializationError,
     Serializable,
 };
-use crate::{Felt, Hasher, MAX_OUTPUT_NOTES_PER_TX, Word};
+use crate::{MAX_OUTPUT_NOTES_PER_TX, PrettyPrint, Word};
 
 // TRANSACTION OUTPUTS
 // ================================================================================================
@@ -172,17 +173,12 @@ impl OutputNotes {
     pub(crate) fn compute_commitment<'header>(
         notes: impl ExactSizeIterator<Item = &'header NoteHeader>,
     ) -> Word {
-        if notes.len() == 0 {
-            return Word::empty();
-        }
-
-        let mut elements: Vec<Felt> = Vec::with_capacity(notes.len() * 8);
+        let mut accumulator = NoteCommitmentAccumulator::new();
         for note_header in notes {
-            elements.extend_from_slice(note_header.id().as_elements());
-            elements.extend_from_slice(note_header.metadata().to_commitment().as_elements());
+            accumulator.push(note_header.id(), note_header.metadata());
         }
 
-        Hasher::hash_elements(&elements)
+        accumulator.commitment()
     }
 }
 
@@ -206,6 +202,68 @@ impl Deserializable for OutputNotes {
     }
 }
 
+// PRETTY-PRINTING
+// ------------------------------------------------------------------------------------------------
+
+impl PrettyPrint for OutputNotes {
+    fn render(&self) -> miden_core::prettier::Document {
+        use miden_core::prettier::*;
+
+        let mut notes = Document::Empty;
+        for note in self.notes.iter() {
+            let kind = match note {
+                OutputNote::Full(_) => "full",
+                OutputNote::Partial(_) => "partial",
+                OutputNote::Header(_) => "header",
+            };
+            let metadata = note.metadata();
+
+            let mut fields = nl()
+                + text(format!("kind: {kind}"))
+                + nl()
+                + text(format!("type: {}", metadata.note_type()))
+                + nl()
+                + text(format!("sender: {}", metadata.sender()))
+                + nl()
+                + text(format!("tag: {}", metadata.tag()));
+
+            if let Some(assets) = note.assets() {
+                let mut asset_lines = Document::Empty;
+                for asset in assets.iter() {
+                    asset_lines += nl() + render_asset(asset);
+                }
+                fields += nl() + const_text("assets") + indent(4, asset_lines);
+            }
+
+            notes += nl() + text(format!("note {}", note.id())) + indent(4, fields);
+        }
+
+        text(format!("output notes ({}), commitment {}", self.num_notes(), self.commitment))
+            + indent(4, notes)
+    }
+}
+
+impl Display for OutputNotes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.pretty_print(f)
+    }
+}
+
+/// Renders the provided asset as its faucet ID followed by its amount (for fungible assets) or its
+/// vault key (for non-fungible assets).
+pub(crate) fn render_asset(asset: &Asset) -> miden_core::prettier::Document {
+    use miden_core::prettier::text;
+
+    match asset {
+        Asset::Fungible(asset) => {
+            text(format!("fungible {}: {}", asset.faucet_id(), asset.amount()))
+        },
+        Asset::NonFungible(asset) => {
+            text(format!("non-fungible {}: {}", asset.faucet_id_prefix(), asset.vault_key()))
+        },
+    }
+}
+
 // OUTPUT NOTE
 // ================================================================================================
 
@@ -346,10 +404,13 @@ impl Deserializable for OutputNote {
 
 #[cfg(test)]
 mod output_notes_tests {
+    use alloc::string::ToString;
+
     use assert_matches::assert_matches;
 
     use super::OutputNotes;
     use crate::Word;
+    use crate::asset::FungibleAsset;
     use crate::errors::TransactionOutputError;
     use crate::note::Note;
     use crate::transaction::OutputNote;
@@ -368,4 +429,43 @@ mod output_notes_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_output_notes_pretty_print() -> anyhow::Result<()> {
+        let full_note = Note::mock_noop(Word::from([1, 2, 3, 4u32]));
+        let header_note = Note::mock_noop(Word::from([5, 6, 7, 8u32]));
+        let metadata = full_note.metadata();
+        let faucet_id = FungibleAsset::mock(200).unwrap_fungible().faucet_id();
+
+        let output_notes = OutputNotes::new(vec![
+            OutputNote::Full(full_note.clone()),
+            OutputNote::Header(header_note.header().clone()),
+        ])?;
+
+        let expected = format!(
+            "output notes (2), commitment {}
+    note {}
+        kind: full
+        type: private
+        sender: {}
+        tag: {}
+        assets
+            fungible {faucet_id}: 200
+    note {}
+        kind: header
+        type: private
+        sender: {}
+        tag: {}",
+            output_notes.commitment(),
+            full_note.id(),
+            metadata.sender(),
+            metadata.tag(),
+            header_note.id(),
+            metadata.sender(),
+            metadata.tag(),
+        );
+        assert_eq!(output_notes.to_string(), expected);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::fmt::Display;
 
 use super::{InputNote, ToInputNoteCommitments};
 use crate::account::Account;
@@ -8,7 +10,8 @@ use crate::account::delta::AccountUpdateDetails;
 use crate::asset::FungibleAsset;
 use crate::block::BlockNumber;
 use crate::errors::ProvenTransactionError;
-use crate::note::NoteHeader;
+use crate::note::{NoteHeader, NoteId, NoteType};
+use crate::transaction::outputs::render_asset;
 use crate::transaction::{
     AccountId,
     InputNotes,
@@ -25,7 +28,7 @@ use crate::utils::serde::{
     Serializable,
 };
 use crate::vm::ExecutionProof;
-use crate::{ACCOUNT_UPDATE_MAX_SIZE, Word};
+use crate::{ACCOUNT_UPDATE_MAX_SIZE, PrettyPrint, Word};
 
 // PROVEN TRANSACTION
 // ================================================================================================
@@ -86,6 +89,16 @@ impl ProvenTransaction {
         &self.account_update
     }
 
+    /// Returns the details of the update to the account against which this transaction was
+    /// executed.
+    ///
+    /// For public accounts this is the [`AccountUpdateDetails::Delta`] that clients need to apply
+    /// to their copy of the account, while for private accounts it is
+    /// [`AccountUpdateDetails::Private`].
+    pub fn account_update_details(&self) -> &AccountUpdateDetails {
+        self.account_update.details()
+    }
+
     /// Returns a reference to the notes consumed by the transaction.
     pub fn input_notes(&self) -> &InputNotes<InputNoteCommitment> {
         &self.input_notes
@@ -133,6 +146,53 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
+    /// Verifies that the account delta contained in the [`TxAccountUpdate`] commits to the
+    /// declared account delta commitment.
+    ///
+    /// This check is performed when a transaction is built or deserialized, but it allows clients
+    /// to independently re-verify a transaction received from an untrusted source. Since the delta
+    /// commitment is computed over the delta before the fee was removed, the fee is added back to
+    /// the delta before computing its commitment.
+    ///
+    /// The delta commitment cannot be validated for private account updates, so this check always
+    /// succeeds for them. It is validated implicitly as part of transaction proof verification.
+    ///
+    /// The account update commitment `hash(final_state_commitment, account_delta_commitment)`
+    /// output by the transaction kernel is not stored in the transaction. It is recomputed from the
+    /// declared commitments when building the stack outputs for proof verification, see
+    /// [`TransactionKernel::build_output_stack`](crate::transaction::TransactionKernel::build_output_stack).
+    /// It is therefore bound to them by the proof rather than checked here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commitment computed on the account delta does not match the
+    /// declared account delta commitment.
+    pub fn verify_account_update_commitment(&self) -> Result<(), ProvenTransactionError> {
+        let post_fee_account_delta = match self.account_update.details() {
+            AccountUpdateDetails::Private => return Ok(()),
+            AccountUpdateDetails::Delta(post_fee_account_delta) => post_fee_account_delta,
+        };
+
+        // Add the removed fee to the post fee delta to get the pre-fee delta, against which the
+        // delta commitment needs to be validated.
+        let mut pre_fee_account_delta = post_fee_account_delta.clone();
+        pre_fee_account_delta.vault_mut().add_asset(self.fee.into()).map_err(|err| {
+            ProvenTransactionError::AccountDeltaCommitmentMismatch(Box::from(err))
+        })?;
+
+        let expected_commitment = self.account_update.account_delta_commitment();
+        let actual_commitment = pre_fee_account_delta.to_commitment();
+        if expected_commitment != actual_commitment {
+            return Err(ProvenTransactionError::AccountDeltaCommitmentMismatch(Box::from(
+                format!(
+                    "expected account delta commitment {expected_commitment} but found {actual_commitment}"
+                ),
+            )));
+        }
+
+        Ok(())
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -145,7 +205,9 @@ impl ProvenTransaction {
     ///   number of input notes is zero.
     /// - The commitment computed on the actual account delta contained in [`TxAccountUpdate`] does
     ///   not match its declared account delta commitment.
-    fn validate(mut self) -> Result<Self, ProvenTransactionError> {
+    /// - The note header attached to an authenticated input note is not of a public note.
+    /// - The note headers of two input notes have the same note ID.
+    fn validate(self) -> Result<Self, ProvenTransactionError> {
         // Check that either the account state was changed or at least one note was consumed,
         // otherwise this transaction is considered empty.
         if self.account_update.initial_state_commitment()
@@ -155,32 +217,23 @@ impl ProvenTransaction {
             return Err(ProvenTransactionError::EmptyTransaction);
         }
 
-        match &mut self.account_update.details {
-            // The delta commitment cannot be validated for private account updates. It will be
-            // validated as part of transaction proof verification implicitly.
-            AccountUpdateDetails::Private => (),
-            AccountUpdateDetails::Delta(post_fee_account_delta) => {
-                // Add the removed fee to the post fee delta to get the pre-fee delta, against which
-                // the delta commitment needs to be validated.
-                post_fee_account_delta.vault_mut().add_asset(self.fee.into()).map_err(|err| {
-                    ProvenTransactionError::AccountDeltaCommitmentMismatch(Box::from(err))
-                })?;
-
-                let expected_commitment = self.account_update.account_delta_commitment;
-                let actual_commitment = post_fee_account_delta.to_commitment();
-                if expected_commitment != actual_commitment {
-                    return Err(ProvenTransactionError::AccountDeltaCommitmentMismatch(Box::from(
-                        format!(
-                            "expected account delta commitment {expected_commitment} but found {actual_commitment}"
-                        ),
-                    )));
-                }
+        self.verify_account_update_commitment()?;
 
-                // Remove the added fee again to recreate the post fee delta.
-                post_fee_account_delta.vault_mut().remove_asset(self.fee.into()).map_err(
-                    |err| ProvenTransactionError::AccountDeltaCommitmentMismatch(Box::from(err)),
-                )?;
-            },
+        for public_header in self.input_notes.iter().filter_map(InputNoteCommitment::public_header)
+        {
+            if public_header.metadata().note_type() != NoteType::Public {
+                return Err(ProvenTransactionError::PrivateInputNoteHeader(public_header.id()));
+            }
+        }
+
+        // A note ID determines the note's nullifier, so two input notes with distinct nullifiers
+        // cannot have the same ID. This is the only consistency check possible between nullifiers
+        // and note headers without the full note details.
+        let mut seen_note_ids = BTreeSet::new();
+        for note_id in self.input_notes.iter().filter_map(InputNoteCommitment::note_id) {
+            if !seen_note_ids.insert(note_id) {
+                return Err(ProvenTransactionError::DuplicateInputNoteId(note_id));
+            }
         }
 
         Ok(self)
@@ -238,6 +291,82 @@ impl Deserializable for ProvenTransaction {
     }
 }
 
+// PRETTY-PRINTING
+// ================================================================================================
+
+impl PrettyPrint for ProvenTransaction {
+    fn render(&self) -> miden_core::prettier::Document {
+        use miden_core::prettier::*;
+
+        let mut notes = Document::Empty;
+        for note in self.input_notes.iter() {
+            let note_line = match (note.header(), note.public_header()) {
+                (Some(header), _) => {
+                    format!("nullifier {}, unauthenticated note {}", note.nullifier(), header.id())
+                },
+                (None, Some(header)) => {
+                    format!("nullifier {}, public note {}", note.nullifier(), header.id())
+                },
+                (None, None) => format!("nullifier {}", note.nullifier()),
+            };
+            notes += nl() + text(note_line);
+        }
+
+        let account_update = &self.account_update;
+        let account_details = match account_update.details() {
+            AccountUpdateDetails::Private => const_text("account delta: private"),
+            AccountUpdateDetails::Delta(delta) => delta.render(),
+        };
+
+        let fields = nl()
+            + text(format!("account id: {}", account_update.account_id()))
+            + nl()
+            + text(format!(
+                "initial account commitment: {}",
+                account_update.initial_state_commitment()
+            ))
+            + nl()
+            + text(format!(
+                "final account commitment: {}",
+                account_update.final_state_commitment()
+            ))
+            + nl()
+            + text(format!(
+                "account delta commitment: {}",
+                account_update.account_delta_commitment()
+            ))
+            + nl()
+            + text(format!(
+                "reference block: {}, commitment {}",
+                self.ref_block_num, self.ref_block_commitment
+            ))
+            + nl()
+            + text(format!("expiration block: {}", self.expiration_block_num))
+            + nl()
+            + const_text("fee: ")
+            + render_asset(&self.fee.into())
+            + nl()
+            + text(format!(
+                "input notes ({}), commitment {}",
+                self.input_notes.num_notes(),
+                self.input_notes.commitment()
+            ))
+            + indent(4, notes)
+            + nl()
+            + account_details
+            + nl()
+            + self.output_notes.render();
+
+        text(format!("proven transaction {}", self.id)) + indent(4, fields)
+    }
+}
+
+impl Display for ProvenTransaction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.pretty_print(f)
+    }
+}
+
 // PROVEN TRANSACTION BUILDER
 // ================================================================================================
 
@@ -262,6 +391,9 @@ pub struct ProvenTransactionBuilder {
     /// List of [InputNoteCommitment]s of all consumed notes by the transaction.
     input_notes: Vec<InputNoteCommitment>,
 
+    /// Whether the note headers of authenticated public input notes are included.
+    note_headers: bool,
+
     /// List of [OutputNote]s of all notes created by the transaction.
     output_notes: Vec<OutputNote>,
 
@@ -305,6 +437,7 @@ impl ProvenTransactionBuilder {
             account_delta_commitment,
             account_update_details: AccountUpdateDetails::Private,
             input_notes: Vec::new(),
+            note_headers: false,
             output_notes: Vec::new(),
             ref_block_num,
             ref_block_commitment,
@@ -323,6 +456,18 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Sets whether the note headers of authenticated public input notes are included in the
+    /// proven transaction.
+    ///
+    /// By default, authenticated input notes are represented only by their nullifiers. Including
+    /// the headers allows the recipients of the transaction to learn which public notes it
+    /// consumes, at the cost of a larger serialized transaction. The headers are available only
+    /// for notes added as [`InputNote`]s. See [`InputNoteCommitment::public_header`] for details.
+    pub fn with_note_headers(mut self, note_headers: bool) -> Self {
+        self.note_headers = note_headers;
+        self
+    }
+
     /// Add notes consumed by the transaction.
     pub fn add_input_notes<I, T>(mut self, notes: I) -> Self
     where
@@ -350,6 +495,8 @@ impl ProvenTransactionBuilder {
     /// - The total number of input notes is greater than
     ///   [`MAX_INPUT_NOTES_PER_TX`](crate::constants::MAX_INPUT_NOTES_PER_TX).
     /// - The vector of input notes contains duplicates.
+    /// - The note header attached to an authenticated input note is not of a public note.
+    /// - The note headers of two input notes have the same note ID.
     /// - The total number of output notes is greater than
     ///   [`MAX_OUTPUT_NOTES_PER_TX`](crate::constants::MAX_OUTPUT_NOTES_PER_TX).
     /// - The vector of output notes contains duplicates.
@@ -369,8 +516,16 @@ impl ProvenTransactionBuilder {
     /// - The transaction was executed against an account with public state and the update is of
     ///   type [`AccountUpdateDetails::Private`].
     pub fn build(self) -> Result<ProvenTransaction, ProvenTransactionError> {
+        let input_notes = if self.note_headers {
+            self.input_notes
+        } else {
+            self.input_notes
+                .into_iter()
+                .map(InputNoteCommitment::without_public_header)
+                .collect()
+        };
         let input_notes =
-            InputNotes::new(self.input_notes).map_err(ProvenTransactionError::InputNotesError)?;
+            InputNotes::new(input_notes).map_err(ProvenTransactionError::InputNotesError)?;
         let output_notes = OutputNotes::new(self.output_notes)
             .map_err(ProvenTransactionError::OutputNotesError)?;
         let id = TransactionId::new(
@@ -592,13 +747,25 @@ impl Deserializable for TxAccountUpdate {
 /// For notes authenticated by the transaction kernel, the commitment consists only of the note's
 /// nullifier. For notes whose authentication is delayed to batch/block kernels, the commitment
 /// also includes full note header (i.e., note ID and metadata).
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Authenticated public notes may additionally carry their note header, see
+/// [`InputNoteCommitment::public_header`]. This header is not part of the commitment, so it is
+/// ignored when comparing commitments.
+#[derive(Debug, Clone)]
 pub struct InputNoteCommitment {
     nullifier: Nullifier,
     header: Option<NoteHeader>,
+    public_header: Option<NoteHeader>,
 }
 
 impl InputNoteCommitment {
+    /// Serialization tag of an authenticated note without a note header.
+    const AUTHENTICATED: u8 = 0;
+    /// Serialization tag of an unauthenticated note.
+    const UNAUTHENTICATED: u8 = 1;
+    /// Serialization tag of an authenticated public note with its note header.
+    const AUTHENTICATED_WITH_HEADER: u8 = 2;
+
     /// Returns the nullifier of the input note committed to by this commitment.
     pub fn nullifier(&self) -> Nullifier {
         self.nullifier
@@ -620,6 +787,35 @@ impl InputNoteCommitment {
     pub fn is_authenticated(&self) -> bool {
         self.header.is_none()
     }
+
+    /// Returns the header of the authenticated public note committed to by this commitment, if it
+    /// was included.
+    ///
+    /// The header allows identifying the consumed note without an external index, e.g. to detect
+    /// conflicting transactions by note ID. It is informational only: it does not contribute to
+    /// the input notes commitment and so is not covered by the transaction proof.
+    ///
+    /// The header is taken from the same note as the nullifier when converting an authenticated
+    /// public [`InputNote`] into its commitment, which is the only place where the note ID and the
+    /// nullifier can be checked against each other. [`ProvenTransactionBuilder`] keeps it only if
+    /// enabled via [`ProvenTransactionBuilder::with_note_headers`]. Since it is not bound by the
+    /// proof, it must not be trusted beyond the consistency checks of [`ProvenTransaction`].
+    pub fn public_header(&self) -> Option<&NoteHeader> {
+        self.public_header.as_ref()
+    }
+
+    /// Returns the ID of the note committed to by this commitment, if its header is known.
+    ///
+    /// This is the case for unauthenticated notes and for authenticated public notes with a
+    /// [`public_header`](Self::public_header).
+    pub fn note_id(&self) -> Option<NoteId> {
+        self.header.as_ref().or(self.public_header.as_ref()).map(NoteHeader::id)
+    }
+
+    /// Returns this commitment without the [`public_header`](Self::public_header).
+    pub(crate) fn without_public_header(self) -> Self {
+        Self { public_header: None, ..self }
+    }
 }
 
 impl From<InputNote> for InputNoteCommitment {
@@ -631,13 +827,18 @@ impl From<InputNote> for InputNoteCommitment {
 impl From<&InputNote> for InputNoteCommitment {
     fn from(note: &InputNote) -> Self {
         match note {
+            // The nullifier and the note ID cannot be checked against each other without the
+            // note details, so the header is attached here, where both are derived from the note.
             InputNote::Authenticated { note, .. } => Self {
                 nullifier: note.nullifier(),
                 header: None,
+                public_header: (note.metadata().note_type() == NoteType::Public)
+                    .then(|| note.header().clone()),
             },
             InputNote::Unauthenticated { note } => Self {
                 nullifier: note.nullifier(),
                 header: Some(note.header().clone()),
+                public_header: None,
             },
         }
     }
@@ -645,10 +846,27 @@ impl From<&InputNote> for InputNoteCommitment {
 
 impl From<Nullifier> for InputNoteCommitment {
     fn from(nullifier: Nullifier) -> Self {
-        Self { nullifier, header: None }
+        Self {
+            nullifier,
+            header: None,
+            public_header: None,
+        }
     }
 }
 
+/// The [`public_header`](InputNoteCommitment::public_header) is left out of the comparison: it is
+/// derived from the committed note, is not part of the input notes commitment and is not bound by
+/// the transaction proof. Two commitments to the same note are therefore equal regardless of
+/// whether the header is attached, e.g. the input notes of transactions built with and without
+/// [`ProvenTransactionBuilder::with_note_headers`].
+impl PartialEq for InputNoteCommitment {
+    fn eq(&self, other: &Self) -> bool {
+        self.nullifier == other.nullifier && self.header == other.header
+    }
+}
+
+impl Eq for InputNoteCommitment {}
+
 impl ToInputNoteCommitments for InputNoteCommitment {
     fn nullifier(&self) -> Nullifier {
         self.nullifier
@@ -665,16 +883,44 @@ impl ToInputNoteCommitments for InputNoteCommitment {
 impl Serializable for InputNoteCommitment {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.nullifier.write_into(target);
-        self.header.write_into(target);
+        match (&self.header, &self.public_header) {
+            (Some(header), _) => {
+                target.write_u8(Self::UNAUTHENTICATED);
+                header.write_into(target);
+            },
+            (None, Some(public_header)) => {
+                target.write_u8(Self::AUTHENTICATED_WITH_HEADER);
+                public_header.write_into(target);
+            },
+            (None, None) => target.write_u8(Self::AUTHENTICATED),
+        }
     }
 }
 
 impl Deserializable for InputNoteCommitment {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let nullifier = Nullifier::read_from(source)?;
-        let header = <Option<NoteHeader>>::read_from(source)?;
+        let (header, public_header) = match source.read_u8()? {
+            Self::AUTHENTICATED => (None, None),
+            Self::UNAUTHENTICATED => (Some(NoteHeader::read_from(source)?), None),
+            Self::AUTHENTICATED_WITH_HEADER => {
+                let public_header = NoteHeader::read_from(source)?;
+                if public_header.metadata().note_type() != NoteType::Public {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "header of authenticated input note {} must be of a public note",
+                        public_header.id()
+                    )));
+                }
+                (None, Some(public_header))
+            },
+            tag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "unknown input note commitment tag {tag}"
+                )));
+            },
+        };
 
-        Ok(Self { nullifier, header })
+        Ok(Self { nullifier, header, public_header })
     }
 }
 
@@ -686,11 +932,12 @@ mod tests {
     use alloc::collections::BTreeMap;
 
     use anyhow::Context;
+    use assert_matches::assert_matches;
     use miden_core::utils::Deserializable;
     use miden_verifier::ExecutionProof;
     use winter_rand_utils::rand_value;
 
-    use super::ProvenTransaction;
+    use super::{InputNoteCommitment, ProvenTransaction};
     use crate::account::delta::AccountUpdateDetails;
     use crate::account::{
         Account,
@@ -706,20 +953,56 @@ mod tests {
     };
     use crate::asset::FungibleAsset;
     use crate::block::BlockNumber;
+    use crate::crypto::merkle::SparseMerklePath;
     use crate::errors::ProvenTransactionError;
+    use crate::note::{Note, NoteInclusionProof, NoteMetadata, NoteType};
     use crate::testing::account_id::{
         ACCOUNT_ID_PRIVATE_SENDER,
         ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
     };
     use crate::testing::add_component::AddComponent;
     use crate::testing::noop_auth_component::NoopAuthComponent;
-    use crate::transaction::{ProvenTransactionBuilder, TxAccountUpdate};
+    use crate::transaction::{InputNote, ProvenTransactionBuilder, TxAccountUpdate};
     use crate::utils::Serializable;
     use crate::{ACCOUNT_UPDATE_MAX_SIZE, EMPTY_WORD, LexicographicWord, ONE, Word};
 
     fn check_if_sync<T: Sync>() {}
     fn check_if_send<T: Send>() {}
 
+    /// Returns a builder for a proven transaction against a private account.
+    fn mock_tx_builder() -> ProvenTransactionBuilder {
+        let account_id = AccountId::dummy(
+            [1; 15],
+            AccountIdVersion::Version0,
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Private,
+        );
+
+        ProvenTransactionBuilder::new(
+            account_id,
+            Word::from([1, 2, 3, 4u32]),
+            Word::from([5, 6, 7, 8u32]),
+            Word::empty(),
+            BlockNumber::from(1),
+            Word::empty(),
+            FungibleAsset::mock(42).unwrap_fungible(),
+            BlockNumber::from(2),
+            ExecutionProof::new_dummy(),
+        )
+    }
+
+    /// Returns an authenticated input note with a mock inclusion proof.
+    fn authenticated_note(serial_num: u32, note_type: NoteType) -> InputNote {
+        let note = Note::mock_noop(Word::from([serial_num, 0, 0, 0]));
+        let metadata =
+            NoteMetadata::new(note.metadata().sender(), note_type, note.metadata().tag());
+        let note = Note::new(note.assets().clone(), metadata, note.recipient().clone());
+        let proof = NoteInclusionProof::new(BlockNumber::from(0), 0, SparseMerklePath::default())
+            .expect("empty note path should be valid");
+
+        InputNote::authenticated(note, proof)
+    }
+
     /// [ProvenTransaction] being Sync is part of its public API and changing it is backwards
     /// incompatible.
     #[test]
@@ -832,4 +1115,122 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn proven_tx_with_note_headers() -> anyhow::Result<()> {
+        let public_note = authenticated_note(1, NoteType::Public);
+        let private_note = authenticated_note(2, NoteType::Private);
+        let input_notes = [public_note.clone(), private_note.clone()];
+
+        // headers are only attached if enabled on the builder
+        let tx = mock_tx_builder().add_input_notes(&input_notes).build()?;
+        assert!(tx.input_notes().iter().all(|note| note.public_header().is_none()));
+
+        let tx_with_headers = mock_tx_builder()
+            .with_note_headers(true)
+            .add_input_notes(&input_notes)
+            .build()?;
+        let [public_commitment, private_commitment] =
+            [0, 1].map(|idx| tx_with_headers.input_notes().get_note(idx));
+        assert_eq!(public_commitment.public_header(), Some(public_note.note().header()));
+        assert_eq!(public_commitment.note_id(), Some(public_note.id()));
+        assert!(public_commitment.is_authenticated());
+        assert_eq!(private_commitment.public_header(), None);
+        assert_eq!(private_commitment.note_id(), None);
+
+        // the headers are not part of the input notes commitment, nor of the commitment identity
+        assert_eq!(tx.id(), tx_with_headers.id());
+        assert_eq!(public_commitment, &InputNoteCommitment::from(&public_note));
+
+        // the only size overhead is the header of the public note
+        let bytes = tx_with_headers.to_bytes();
+        assert_eq!(bytes.len(), tx.to_bytes().len() + public_note.note().header().to_bytes().len());
+        let deserialized = ProvenTransaction::read_from_bytes(&bytes)?;
+        assert_eq!(deserialized, tx_with_headers);
+        assert_eq!(
+            deserialized.input_notes().get_note(0).public_header(),
+            Some(public_note.note().header())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn proven_tx_note_headers_are_validated() -> anyhow::Result<()> {
+        let public_note = authenticated_note(1, NoteType::Public);
+        let commitment = InputNoteCommitment::from(&public_note);
+        assert_eq!(commitment.public_header(), Some(public_note.note().header()));
+
+        // two input notes with distinct nullifiers cannot have the same note ID
+        let conflicting_commitment = InputNoteCommitment {
+            public_header: commitment.public_header.clone(),
+            ..InputNoteCommitment::from(&authenticated_note(2, NoteType::Public))
+        };
+        let err = mock_tx_builder()
+            .with_note_headers(true)
+            .add_input_notes([commitment.clone(), conflicting_commitment])
+            .build()
+            .unwrap_err();
+        assert_matches!(err, ProvenTransactionError::DuplicateInputNoteId(note_id) => {
+            assert_eq!(note_id, public_note.id());
+        });
+
+        // the header of an authenticated note must be of a public note
+        let private_header = authenticated_note(3, NoteType::Private).note().header().clone();
+        let invalid_commitment = InputNoteCommitment {
+            public_header: Some(private_header.clone()),
+            ..commitment
+        };
+        let err = mock_tx_builder()
+            .with_note_headers(true)
+            .add_input_notes([invalid_commitment.clone()])
+            .build()
+            .unwrap_err();
+        assert_matches!(err, ProvenTransactionError::PrivateInputNoteHeader(note_id) => {
+            assert_eq!(note_id, private_header.id());
+        });
+        assert!(InputNoteCommitment::read_from_bytes(&invalid_commitment.to_bytes()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_account_update_commitment() -> anyhow::Result<()> {
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+        let fee = FungibleAsset::mock(42).unwrap_fungible();
+        let post_fee_delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            ONE,
+        )?;
+
+        // the delta commitment is computed over the delta before the fee was removed
+        let mut pre_fee_delta = post_fee_delta.clone();
+        pre_fee_delta.vault_mut().add_asset(fee.into())?;
+
+        let mut tx = ProvenTransactionBuilder::new(
+            account_id,
+            Word::from([1, 2, 3, 4u32]),
+            Word::from([5, 6, 7, 8u32]),
+            pre_fee_delta.to_commitment(),
+            BlockNumber::from(1),
+            Word::empty(),
+            fee,
+            BlockNumber::from(2),
+            ExecutionProof::new_dummy(),
+        )
+        .account_update_details(AccountUpdateDetails::Delta(post_fee_delta.clone()))
+        .build()
+        .context("failed to build proven transaction")?;
+
+        tx.verify_account_update_commitment()?;
+
+        // a transaction whose delta commitment was tampered with fails verification
+        tx.account_update.account_delta_commitment = post_fee_delta.to_commitment();
+        let err = tx.verify_account_update_commitment().unwrap_err();
+        assert_matches!(err, ProvenTransactionError::AccountDeltaCommitmentMismatch(_));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,106 @@
+use super::{AdviceInputs, TransactionArgs, TransactionInputs, TransactionOutputs};
+use crate::Word;
+use crate::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+
+// REPLAY BUNDLE
+// ================================================================================================
+
+/// Contains all the data required to deterministically re-execute a transaction offline.
+///
+/// A replay bundle captures:
+/// - The [`TransactionInputs`] of an executed transaction, which include the [`TransactionArgs`]
+///   and the full advice inputs requested during execution (including any generated signatures).
+/// - The hash of the transaction kernel program the transaction was executed against.
+/// - The [`TransactionOutputs`] recorded during the original execution, against which the outputs
+///   of a re-execution can be compared.
+///
+/// Since all nondeterministic inputs are part of the bundle, re-executing it does not require
+/// access to a data store or an authenticator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayBundle {
+    tx_inputs: TransactionInputs,
+    kernel_program_hash: Word,
+    tx_outputs: TransactionOutputs,
+}
+
+impl ReplayBundle {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`ReplayBundle`] instantiated from the provided data.
+    pub fn new(
+        tx_inputs: TransactionInputs,
+        kernel_program_hash: Word,
+        tx_outputs: TransactionOutputs,
+    ) -> Self {
+        Self {
+            tx_inputs,
+            kernel_program_hash,
+            tx_outputs,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the inputs of the recorded transaction.
+    pub fn tx_inputs(&self) -> &TransactionInputs {
+        &self.tx_inputs
+    }
+
+    /// Returns a reference to the arguments of the recorded transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        self.tx_inputs.tx_args()
+    }
+
+    /// Returns all the advice inputs requested by the VM while executing the recorded transaction.
+    pub fn advice_inputs(&self) -> &AdviceInputs {
+        self.tx_inputs.advice_inputs()
+    }
+
+    /// Returns the hash of the transaction kernel program the recorded transaction was executed
+    /// against.
+    pub fn kernel_program_hash(&self) -> Word {
+        self.kernel_program_hash
+    }
+
+    /// Returns a reference to the outputs of the recorded transaction.
+    pub fn tx_outputs(&self) -> &TransactionOutputs {
+        &self.tx_outputs
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes the bundle and returns its underlying components.
+    pub fn into_parts(self) -> (TransactionInputs, Word, TransactionOutputs) {
+        (self.tx_inputs, self.kernel_program_hash, self.tx_outputs)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ReplayBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.kernel_program_hash.write_into(target);
+        self.tx_outputs.write_into(target);
+    }
+}
+
+impl Deserializable for ReplayBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let kernel_program_hash = Word::read_from(source)?;
+        let tx_outputs = TransactionOutputs::read_from(source)?;
+
+        Ok(Self::new(tx_inputs, kernel_program_hash, tx_outputs))
+    }
+}
@@ -1,9 +1,11 @@
 use alloc::string::String;
 use core::fmt::{Debug, Display};
+use core::str::FromStr;
 
 use miden_protocol_macros::WordWrapper;
 
 use super::{Felt, Hasher, ProvenTransaction, WORD_SIZE, Word, ZERO};
+use crate::WordError;
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -30,6 +32,18 @@ pub struct TransactionId(Word);
 
 impl TransactionId {
     /// Returns a new [TransactionId] instantiated from the provided transaction components.
+    ///
+    /// Since the ID only depends on commitments that are known once the effects of a transaction
+    /// are known, it can be computed before the transaction is proven, e.g. by a mempool.
+    ///
+    /// The ID is the [`Hasher::hash_elements`] digest of the 16 elements obtained by concatenating
+    /// the following words in order:
+    /// - `init_account_commitment`: the commitment to the account state before the transaction.
+    /// - `final_account_commitment`: the commitment to the account state after the transaction.
+    /// - `input_notes_commitment`: the commitment to the input notes, see
+    ///   [`InputNotes::commitment`](super::InputNotes::commitment).
+    /// - `output_notes_commitment`: the commitment to the output notes, see
+    ///   [`OutputNotes::commitment`](super::OutputNotes::commitment).
     pub fn new(
         init_account_commitment: Word,
         final_account_commitment: Word,
@@ -60,6 +74,25 @@ impl Display for TransactionId {
 // CONVERSIONS INTO TRANSACTION ID
 // ================================================================================================
 
+impl TransactionId {
+    /// Attempts to convert from a hexadecimal string to [TransactionId].
+    ///
+    /// Callers must ensure the provided value is an actual [`TransactionId`].
+    pub fn try_from_hex(hex_value: &str) -> Result<TransactionId, WordError> {
+        Word::try_from(hex_value).map(TransactionId::from_raw)
+    }
+}
+
+impl FromStr for TransactionId {
+    type Err = WordError;
+
+    /// Parses a [TransactionId] from its hexadecimal representation, as produced by its
+    /// [`Display`] implementation.
+    fn from_str(hex_value: &str) -> Result<Self, Self::Err> {
+        Self::try_from_hex(hex_value)
+    }
+}
+
 impl From<&ProvenTransaction> for TransactionId {
     fn from(tx: &ProvenTransaction) -> Self {
         Self::new(
@@ -86,3 +119,37 @@ impl Deserializable for TransactionId {
         Ok(Self(id))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::TransactionId;
+    use crate::{Felt, Hasher, Word};
+
+    #[test]
+    fn transaction_id_new() {
+        let words: [Word; 4] = core::array::from_fn(|idx| {
+            Word::from([idx as u32 * 4, idx as u32 * 4 + 1, idx as u32 * 4 + 2, idx as u32 * 4 + 3])
+        });
+        let elements: Vec<Felt> =
+            words.iter().flat_map(|word| word.as_elements().iter().copied()).collect();
+
+        let id = TransactionId::new(words[0], words[1], words[2], words[3]);
+        assert_eq!(id.as_word(), Hasher::hash_elements(&elements));
+    }
+
+    #[test]
+    fn transaction_id_hex_roundtrip() {
+        let tx_id_hex = "0xc9d31c82c098e060c9b6e3af2710b3fc5009a1a6f82ef9465f8f35d1f5ba4a80";
+        let tx_id: TransactionId = tx_id_hex.parse().unwrap();
+
+        assert_eq!(tx_id.to_string(), tx_id_hex);
+        assert_eq!(TransactionId::try_from_hex(tx_id_hex).unwrap(), tx_id);
+        assert!("0x1234".parse::<TransactionId>().is_err());
+    }
+}
@@ -7,6 +7,7 @@ use miden_processor::MastNodeExt;
 
 use super::{Felt, Hasher, Word};
 use crate::account::auth::{PublicKeyCommitment, Signature};
+use crate::errors::TransactionArgsError;
 use crate::note::{NoteId, NoteRecipient};
 use crate::utils::serde::{
     ByteReader,
@@ -39,6 +40,10 @@ use crate::{EMPTY_WORD, MastForest, MastNodeId};
 ///   this argument is not specified, the [`EMPTY_WORD`] would be used as a default value. If the
 ///   [AdviceInputs] are propagated with some user defined map entries, this argument could be used
 ///   as a key to access the corresponding value.
+/// - Max cycles per note: the maximum number of cycles the execution of a single input note may
+///   take. If this limit is not specified, the execution of a note is only limited by the cycle
+///   limit of the whole transaction. The limit is only checked when the note raises an event and
+///   when its execution ends, so a note which loops forever without raising events is not stopped.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
@@ -46,6 +51,7 @@ pub struct TransactionArgs {
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
     auth_args: Word,
+    max_cycles_per_note: Option<u32>,
 }
 
 impl TransactionArgs {
@@ -63,6 +69,7 @@ impl TransactionArgs {
             note_args: Default::default(),
             advice_inputs,
             auth_args: EMPTY_WORD,
+            max_cycles_per_note: None,
         }
     }
 
@@ -109,6 +116,14 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns new [TransactionArgs] instantiated with the provided maximum number of cycles the
+    /// execution of a single input note may take.
+    #[must_use]
+    pub fn with_max_cycles_per_note(mut self, max_cycles_per_note: u32) -> Self {
+        self.max_cycles_per_note = Some(max_cycles_per_note);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -149,6 +164,12 @@ impl TransactionArgs {
         self.auth_args
     }
 
+    /// Returns the maximum number of cycles the execution of a single input note may take, or
+    /// `None` if the execution of notes is not limited individually.
+    pub fn max_cycles_per_note(&self) -> Option<u32> {
+        self.max_cycles_per_note
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -233,6 +254,104 @@ impl TransactionArgs {
     pub fn extend_advice_inputs(&mut self, advice_inputs: AdviceInputs) {
         self.advice_inputs.extend(advice_inputs);
     }
+
+    /// Merges the `other` transaction arguments into self.
+    ///
+    /// The advice inputs and note arguments are unioned, and the transaction script, its arguments
+    /// and the auth arguments are taken from `other` if they are not set in self. Entries present
+    /// in both with the same value are deduplicated. If both set a maximum number of cycles per
+    /// note, the lower one is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if self and `other` contain different values for:
+    /// - the same advice map key.
+    /// - the arguments of the same note.
+    /// - the transaction script, the transaction script arguments or the auth arguments, where
+    ///   arguments set to [`EMPTY_WORD`] are considered unset.
+    ///
+    /// In case of an error, self is left unchanged.
+    pub fn merge(&mut self, other: TransactionArgs) -> Result<(), TransactionArgsError> {
+        for (key, value) in other.advice_inputs.map.iter() {
+            if self.advice_inputs.map.get(key).is_some_and(|existing| existing != value) {
+                return Err(TransactionArgsError::ConflictingAdviceMapEntry { key: *key });
+            }
+        }
+        for (note_id, args) in other.note_args.iter() {
+            if self.note_args.get(note_id).is_some_and(|existing| existing != args) {
+                return Err(TransactionArgsError::ConflictingNoteArgs { note_id: *note_id });
+            }
+        }
+
+        if let (Some(tx_script), Some(other_tx_script)) = (&self.tx_script, &other.tx_script)
+            && tx_script != other_tx_script
+        {
+            return Err(TransactionArgsError::ConflictingTransactionScript);
+        }
+        let tx_script_args = merge_args(self.tx_script_args, other.tx_script_args)
+            .ok_or(TransactionArgsError::ConflictingTransactionScriptArgs)?;
+        let auth_args = merge_args(self.auth_args, other.auth_args)
+            .ok_or(TransactionArgsError::ConflictingAuthArgs)?;
+
+        self.tx_script = self.tx_script.take().or(other.tx_script);
+        self.tx_script_args = tx_script_args;
+        self.auth_args = auth_args;
+        self.max_cycles_per_note = match (self.max_cycles_per_note, other.max_cycles_per_note) {
+            (Some(limit), Some(other_limit)) => Some(limit.min(other_limit)),
+            (limit, other_limit) => limit.or(other_limit),
+        };
+        self.note_args.extend(other.note_args);
+        self.advice_inputs.extend(other.advice_inputs);
+
+        Ok(())
+    }
+
+    /// Writes the canonical serialization of these transaction arguments into `target`.
+    ///
+    /// This is the regular serialization, except that the advice inputs are written with
+    /// [`write_canonical_advice_inputs`].
+    pub(crate) fn write_canonical_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_script.write_into(target);
+        self.tx_script_args.write_into(target);
+        self.note_args.write_into(target);
+        write_canonical_advice_inputs(&self.advice_inputs, target);
+        self.auth_args.write_into(target);
+        self.max_cycles_per_note.write_into(target);
+    }
+}
+
+/// Writes the provided advice inputs into `target` such that the written bytes do not depend on
+/// the order in which the advice inputs were assembled.
+///
+/// The advice map is written in the order of its keys, and the nodes of the Merkle store, whose
+/// iteration order is unspecified, are written sorted by their hash.
+pub(crate) fn write_canonical_advice_inputs<W: ByteWriter>(
+    advice_inputs: &AdviceInputs,
+    target: &mut W,
+) {
+    advice_inputs.stack.write_into(target);
+    advice_inputs.map.write_into(target);
+
+    let mut nodes: Vec<InnerNodeInfo> = advice_inputs.store.inner_nodes().collect();
+    nodes.sort_unstable_by_key(|node| node.value);
+    target.write_usize(nodes.len());
+    for node in nodes {
+        node.value.write_into(target);
+        node.left.write_into(target);
+        node.right.write_into(target);
+    }
+}
+
+/// Returns the merged value of two arguments where [`EMPTY_WORD`] is considered unset, or `None`
+/// if both are set to different values.
+fn merge_args(args: Word, other_args: Word) -> Option<Word> {
+    if args == EMPTY_WORD || args == other_args {
+        Some(other_args)
+    } else if other_args == EMPTY_WORD {
+        Some(args)
+    } else {
+        None
+    }
 }
 
 /// Concatenates two [`Word`]s into a [`Vec<Felt>`] containing 8 elements.
@@ -256,6 +375,7 @@ impl Serializable for TransactionArgs {
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
         self.auth_args.write_into(target);
+        self.max_cycles_per_note.write_into(target);
     }
 }
 
@@ -266,6 +386,7 @@ impl Deserializable for TransactionArgs {
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
         let auth_args = Word::read_from(source)?;
+        let max_cycles_per_note = Option::<u32>::read_from(source)?;
 
         Ok(Self {
             tx_script,
@@ -273,6 +394,7 @@ impl Deserializable for TransactionArgs {
             note_args,
             advice_inputs,
             auth_args,
+            max_cycles_per_note,
         })
     }
 }
@@ -365,9 +487,14 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::BTreeMap;
+
+    use assert_matches::assert_matches;
     use miden_core::AdviceMap;
     use miden_core::utils::{Deserializable, Serializable};
 
+    use crate::errors::TransactionArgsError;
+    use crate::note::Note;
     use crate::transaction::TransactionArgs;
 
     #[test]
@@ -379,6 +506,91 @@ mod tests {
         assert_eq!(tx_args, decoded);
     }
 
+    #[test]
+    fn test_populated_tx_args_serialization() {
+        use miden_core::{Felt, Word};
+
+        use super::TransactionScript;
+        use crate::assembly::Assembler;
+
+        let program = Assembler::default().assemble_program("begin nop end").unwrap();
+        let note_id = Note::mock_noop(Word::from([1, 2, 3, 4u32])).id();
+        let mut tx_args = TransactionArgs::new(AdviceMap::from_iter([(
+            Word::from([5, 6, 7, 8u32]),
+            vec![Felt::new(9)],
+        )]))
+        .with_tx_script_and_args(TransactionScript::new(program), Word::from([1, 1, 1, 1u32]))
+        .with_note_args(BTreeMap::from([(note_id, Word::from([2, 2, 2, 2u32]))]))
+        .with_auth_args(Word::from([3, 3, 3, 3u32]))
+        .with_max_cycles_per_note(10_000);
+        tx_args.extend_advice_map([(Word::from([4, 4, 4, 4u32]), vec![Felt::new(10)])]);
+
+        let decoded = TransactionArgs::read_from_bytes(&tx_args.to_bytes()).unwrap();
+
+        assert_eq!(tx_args, decoded);
+    }
+
+    #[test]
+    fn test_tx_args_merge() {
+        use miden_core::{Felt, Word};
+
+        let shared_key = Word::from([1, 2, 3, 4u32]);
+        let base_key = Word::from([5, 6, 7, 8u32]);
+        let override_key = Word::from([9, 10, 11, 12u32]);
+        let note_id = Note::mock_noop(Word::from([1, 2, 3, 4u32])).id();
+        let note_args = Word::from([1, 1, 1, 1u32]);
+
+        let mut base = TransactionArgs::new(AdviceMap::from_iter([
+            (shared_key, vec![Felt::new(1)]),
+            (base_key, vec![Felt::new(2)]),
+        ]))
+        .with_auth_args(Word::from([2, 2, 2, 2u32]));
+        let overrides = TransactionArgs::new(AdviceMap::from_iter([
+            (shared_key, vec![Felt::new(1)]),
+            (override_key, vec![Felt::new(3)]),
+        ]))
+        .with_note_args(BTreeMap::from([(note_id, note_args)]))
+        .with_max_cycles_per_note(10_000);
+
+        base.merge(overrides).unwrap();
+
+        let advice_map = &base.advice_inputs().map;
+        assert_eq!(advice_map.len(), 3);
+        assert_eq!(advice_map.get(&shared_key).unwrap().as_ref(), &[Felt::new(1)]);
+        assert_eq!(advice_map.get(&override_key).unwrap().as_ref(), &[Felt::new(3)]);
+        assert_eq!(base.get_note_args(note_id), Some(&note_args));
+        assert_eq!(base.auth_args(), Word::from([2, 2, 2, 2u32]));
+        assert_eq!(base.max_cycles_per_note(), Some(10_000));
+
+        // the stricter of two cycle limits per note is kept
+        base.merge(TransactionArgs::default().with_max_cycles_per_note(20_000)).unwrap();
+        assert_eq!(base.max_cycles_per_note(), Some(10_000));
+        base.merge(TransactionArgs::default().with_max_cycles_per_note(5_000)).unwrap();
+        assert_eq!(base.max_cycles_per_note(), Some(5_000));
+
+        // a conflicting advice map entry is rejected and leaves the args unchanged
+        let conflicting = TransactionArgs::new(AdviceMap::from_iter([
+            (override_key, vec![Felt::new(4)]),
+            (Word::from([0, 0, 0, 1u32]), vec![Felt::new(5)]),
+        ]));
+        let unchanged = base.clone();
+        assert_matches!(
+            base.merge(conflicting),
+            Err(TransactionArgsError::ConflictingAdviceMapEntry { key }) if key == override_key
+        );
+        assert_eq!(base, unchanged);
+
+        let conflicting =
+            TransactionArgs::default().with_note_args(BTreeMap::from([(note_id, Word::empty())]));
+        assert_matches!(
+            base.merge(conflicting),
+            Err(TransactionArgsError::ConflictingNoteArgs { .. })
+        );
+
+        let conflicting = TransactionArgs::default().with_auth_args(Word::from([3, 3, 3, 3u32]));
+        assert_matches!(base.merge(conflicting), Err(TransactionArgsError::ConflictingAuthArgs));
+    }
+
     #[test]
     fn test_transaction_script_with_advice_map() {
         use miden_core::{Felt, Word};
@@ -0,0 +1,553 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec::Vec;
+
+use super::{
+    AdviceInputs,
+    InputNote,
+    InputNotes,
+    TransactionAdviceInputs,
+    TransactionInputs,
+    TransactionKernel,
+    TransactionScript,
+};
+use crate::account::{
+    AccountCode,
+    AccountHeader,
+    AccountId,
+    AccountStorageHeader,
+    PartialAccount,
+    PartialStorage,
+    PartialStorageMap,
+    StorageMap,
+};
+use crate::asset::{Asset, PartialVault};
+use crate::block::BlockNumber;
+use crate::crypto::merkle::{NodeIndex, SparseMerklePath};
+use crate::crypto::utils::{bytes_to_elements_with_padding, padded_elements_to_bytes};
+use crate::errors::TransactionWitnessError;
+use crate::note::{
+    Note,
+    NoteAssets,
+    NoteInclusionProof,
+    NoteMetadata,
+    NoteRecipient,
+    NoteScript,
+    NoteStorage,
+};
+use crate::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+use crate::vm::{StackInputs, StackOutputs};
+use crate::{BLOCK_NOTE_TREE_DEPTH, Felt, FieldElement, Hasher, WORD_SIZE, Word};
+
+// TRANSACTION WITNESS
+// ================================================================================================
+
+/// Contains the minimal set of data required to prove an executed transaction.
+///
+/// The witness consists of the stack and advice inputs of the transaction kernel program, the
+/// hash of the program and the stack outputs the program is expected to produce. The data the
+/// prover host needs beyond that, i.e. the initial state of the account and the consumed notes, is
+/// read back from the advice inputs via [`TransactionWitness::read_host_inputs`]. Most of it is
+/// part of the advice inputs anyway, since the transaction kernel consumes it. The MAST forests of
+/// the executed code and the few account details the kernel does not read from the advice map are
+/// stored in the advice map under a dedicated key when the witness is created.
+#[derive(Debug, Clone)]
+pub struct TransactionWitness {
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    program_hash: Word,
+    expected_stack_outputs: StackOutputs,
+}
+
+impl TransactionWitness {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`TransactionWitness`] instantiated from the provided data.
+    pub fn new(
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        program_hash: Word,
+        expected_stack_outputs: StackOutputs,
+    ) -> Self {
+        Self {
+            stack_inputs,
+            advice_inputs,
+            program_hash,
+            expected_stack_outputs,
+        }
+    }
+
+    /// Returns a new [`TransactionWitness`] for the transaction described by the provided inputs
+    /// which is expected to produce the provided stack outputs.
+    pub(crate) fn from_tx_inputs(
+        tx_inputs: &TransactionInputs,
+        expected_stack_outputs: StackOutputs,
+    ) -> Self {
+        let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(tx_inputs);
+        let mut advice_inputs = advice_inputs.into_advice_inputs();
+
+        let host_data = WitnessHostData::new(tx_inputs);
+        advice_inputs.map.extend([(
+            Self::host_data_key(),
+            bytes_to_elements_with_padding(&host_data.to_bytes()),
+        )]);
+
+        Self::new(
+            stack_inputs,
+            advice_inputs,
+            TransactionKernel::main().hash(),
+            expected_stack_outputs,
+        )
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the stack inputs of the transaction kernel program.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the advice inputs of the transaction kernel program.
+    pub fn advice_inputs(&self) -> &AdviceInputs {
+        &self.advice_inputs
+    }
+
+    /// Returns the hash of the transaction kernel program the transaction was executed against.
+    pub fn program_hash(&self) -> Word {
+        self.program_hash
+    }
+
+    /// Returns the stack outputs the transaction kernel program is expected to produce.
+    pub fn expected_stack_outputs(&self) -> &StackOutputs {
+        &self.expected_stack_outputs
+    }
+
+    // DATA EXTRACTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads the inputs required by the host of the transaction kernel program from the stack and
+    /// advice inputs of this witness.
+    ///
+    /// The account and the input notes are reconstructed from the data the transaction kernel
+    /// reads from the advice provider and checked against the commitments in the stack inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The stack inputs are not valid transaction kernel inputs.
+    /// - The host data or any of the account or note data is missing from the advice inputs or is
+    ///   malformed.
+    /// - The reconstructed account or input notes do not match the commitments in the stack inputs.
+    pub fn read_host_inputs(&self) -> Result<TransactionHostInputs, TransactionWitnessError> {
+        let KernelStackInputs {
+            account_id,
+            initial_account_commitment,
+            input_notes_commitment,
+            ref_block_commitment,
+            ref_block_num,
+        } = KernelStackInputs::parse(&self.stack_inputs)?;
+
+        let host_data = self.read_host_data()?;
+        let account = self.read_account(&host_data)?;
+        if account.id() != account_id {
+            return Err(TransactionWitnessError::AccountIdMismatch {
+                expected: account_id,
+                actual: account.id(),
+            });
+        }
+        if account.initial_commitment() != initial_account_commitment {
+            return Err(TransactionWitnessError::AccountCommitmentMismatch {
+                expected: initial_account_commitment,
+                actual: account.initial_commitment(),
+            });
+        }
+
+        let input_notes = self.read_input_notes(&host_data, input_notes_commitment)?;
+
+        Ok(TransactionHostInputs {
+            account,
+            input_notes,
+            tx_script: host_data.tx_script,
+            foreign_account_code: host_data.foreign_account_code,
+            ref_block_num,
+            ref_block_commitment,
+        })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the advice map key under which the host data of the witness is stored.
+    fn host_data_key() -> Word {
+        Hasher::hash(b"miden::transaction_witness::host_data")
+    }
+
+    /// Reads the host data stored in the advice map when the witness was created.
+    fn read_host_data(&self) -> Result<WitnessHostData, TransactionWitnessError> {
+        let elements = self
+            .advice_inputs
+            .map
+            .get(&Self::host_data_key())
+            .ok_or(TransactionWitnessError::HostDataNotFound)?;
+        let bytes = padded_elements_to_bytes(elements).ok_or_else(|| {
+            TransactionWitnessError::HostDataDeserializationFailed(
+                DeserializationError::InvalidValue("host data is not validly padded".into()),
+            )
+        })?;
+
+        WitnessHostData::read_from_bytes(&bytes)
+            .map_err(TransactionWitnessError::HostDataDeserializationFailed)
+    }
+
+    /// Reads the partial state of the native account before the transaction was executed.
+    ///
+    /// For new accounts, the full storage maps and the account seed are read from the advice map,
+    /// since the host tracks the initial storage of a new account as part of the account delta.
+    fn read_account(
+        &self,
+        host_data: &WitnessHostData,
+    ) -> Result<PartialAccount, TransactionWitnessError> {
+        let header = &host_data.account_header;
+
+        let mut storage_maps = Vec::new();
+        let mut seed = None;
+        if header.nonce() == Felt::ZERO {
+            for map_root in host_data.storage_header.map_slot_roots() {
+                let elements = self
+                    .advice_inputs
+                    .map
+                    .get(&map_root)
+                    .ok_or(TransactionWitnessError::StorageMapNotFound(map_root))?;
+
+                // NOTE: keep in sync with the storage map entries of new accounts in
+                // TransactionAdviceInputs::new, which are laid out as [VALUE, KEY] pairs.
+                let entries: Vec<(Word, Word)> = elements
+                    .chunks_exact(2 * WORD_SIZE)
+                    .map(|entry| (read_word(&entry[WORD_SIZE..]), read_word(&entry[..WORD_SIZE])))
+                    .collect();
+                let storage_map = StorageMap::with_entries(entries)
+                    .map_err(|err| TransactionWitnessError::InvalidStorageMap(map_root, err))?;
+                storage_maps.push(PartialStorageMap::new_full(storage_map));
+            }
+
+            let seed_key = TransactionAdviceInputs::account_id_map_key(header.id());
+            let seed_elements = self
+                .advice_inputs
+                .map
+                .get(&seed_key)
+                .ok_or(TransactionWitnessError::AccountSeedNotFound)?;
+            seed = Some(
+                Word::try_from(&seed_elements[..])
+                    .map_err(|_| TransactionWitnessError::AccountSeedNotFound)?,
+            );
+        }
+
+        let partial_storage = PartialStorage::new(host_data.storage_header.clone(), storage_maps)
+            .map_err(TransactionWitnessError::InvalidAccount)?;
+
+        PartialAccount::new(
+            header.id(),
+            header.nonce(),
+            host_data.account_code.clone(),
+            partial_storage,
+            PartialVault::new(header.vault_root()),
+            seed,
+        )
+        .map_err(TransactionWitnessError::InvalidAccount)
+    }
+
+    /// Reads the notes consumed by the transaction from the input note data in the advice map and
+    /// checks them against the provided input notes commitment.
+    fn read_input_notes(
+        &self,
+        host_data: &WitnessHostData,
+        input_notes_commitment: Word,
+    ) -> Result<InputNotes<InputNote>, TransactionWitnessError> {
+        // Transactions without input notes do not add any note data to the advice map.
+        if input_notes_commitment == InputNotes::<InputNote>::default().commitment() {
+            return Ok(InputNotes::default());
+        }
+
+        let mut note_data: &[Felt] = self
+            .advice_inputs
+            .map
+            .get(&input_notes_commitment)
+            .ok_or(TransactionWitnessError::InputNoteDataNotFound)?;
+
+        let mut notes = Vec::new();
+        while !note_data.is_empty() {
+            notes.push(self.read_input_note(host_data, notes.len(), &mut note_data)?);
+        }
+
+        let input_notes =
+            InputNotes::new(notes).map_err(TransactionWitnessError::InvalidInputNotes)?;
+        if input_notes.commitment() != input_notes_commitment {
+            return Err(TransactionWitnessError::InputNotesCommitmentMismatch {
+                expected: input_notes_commitment,
+                actual: input_notes.commitment(),
+            });
+        }
+
+        Ok(input_notes)
+    }
+
+    /// Reads a single input note from the front of the provided note data and advances the note
+    /// data past it.
+    ///
+    /// NOTE: keep in sync with TransactionAdviceInputs::add_input_notes.
+    fn read_input_note(
+        &self,
+        host_data: &WitnessHostData,
+        note_index: usize,
+        note_data: &mut &[Felt],
+    ) -> Result<InputNote, TransactionWitnessError> {
+        let serial_num = read_word(take_elements(note_data, WORD_SIZE)?);
+        let script_root = read_word(take_elements(note_data, WORD_SIZE)?);
+        let storage_commitment = read_word(take_elements(note_data, WORD_SIZE)?);
+        // The assets commitment and the note args are not needed to reconstruct the note.
+        take_elements(note_data, 2 * WORD_SIZE)?;
+        let header_word = read_word(take_elements(note_data, WORD_SIZE)?);
+        let attachment_word = read_word(take_elements(note_data, WORD_SIZE)?);
+        // The number of storage items is implied by the storage in the advice map.
+        take_elements(note_data, 1)?;
+        let num_assets = take_elements(note_data, 1)?[0].as_int() as usize;
+        let padded_assets = take_elements(note_data, num_assets.next_multiple_of(2) * WORD_SIZE)?;
+        let is_authenticated = take_elements(note_data, 1)?[0] == Felt::ONE;
+
+        let storage_items = self.advice_inputs.map.get(&storage_commitment).ok_or(
+            TransactionWitnessError::InputNoteStorageNotFound(note_index, storage_commitment),
+        )?;
+        let storage = NoteStorage::new(storage_items.to_vec())
+            .map_err(|err| TransactionWitnessError::InvalidInputNote(note_index, err))?;
+
+        let script = host_data
+            .note_scripts
+            .iter()
+            .find(|script| script.root() == script_root)
+            .ok_or(TransactionWitnessError::InputNoteScriptNotFound(note_index, script_root))?
+            .clone();
+
+        let assets = padded_assets[..num_assets * WORD_SIZE]
+            .chunks_exact(WORD_SIZE)
+            .map(|asset| Asset::try_from(read_word(asset)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| TransactionWitnessError::InvalidInputNoteAsset(note_index, err))?;
+        let assets = NoteAssets::new(assets)
+            .map_err(|err| TransactionWitnessError::InvalidInputNote(note_index, err))?;
+
+        let metadata = NoteMetadata::try_from_words(header_word, attachment_word, |commitment| {
+            self.advice_inputs.map.get(&commitment).map(|elements| elements.to_vec())
+        })
+        .map_err(|err| TransactionWitnessError::InvalidInputNote(note_index, err))?;
+
+        let recipient = NoteRecipient::new(serial_num, script, storage);
+        let note = Note::new(assets, metadata, recipient);
+
+        if !is_authenticated {
+            return Ok(InputNote::unauthenticated(note));
+        }
+
+        let block_num = u32::try_from(take_elements(note_data, 1)?[0].as_int()).map_err(|_| {
+            TransactionWitnessError::InputNoteDataInvalid(
+                format!("block number of input note {note_index} should fit into a u32").into(),
+            )
+        })?;
+        // The sub commitment of the block is not needed to reconstruct the inclusion proof.
+        take_elements(note_data, WORD_SIZE)?;
+        let note_root = read_word(take_elements(note_data, WORD_SIZE)?);
+        let node_index_in_block =
+            u16::try_from(take_elements(note_data, 1)?[0].as_int()).map_err(|_| {
+                TransactionWitnessError::InputNoteDataInvalid(
+                    format!("node index of input note {note_index} should fit into a u16").into(),
+                )
+            })?;
+
+        let note_path = NodeIndex::new(BLOCK_NOTE_TREE_DEPTH, node_index_in_block.into())
+            .and_then(|node_index| self.advice_inputs.store.get_path(note_root, node_index))
+            .and_then(|merkle_path| SparseMerklePath::from_sized_iter(merkle_path.path))
+            .map_err(|err| TransactionWitnessError::InputNotePathNotFound(note_index, err))?;
+        let proof = NoteInclusionProof::new(block_num.into(), node_index_in_block, note_path)
+            .map_err(|err| TransactionWitnessError::InvalidInputNote(note_index, err))?;
+
+        Ok(InputNote::authenticated(note, proof))
+    }
+}
+
+// TRANSACTION HOST INPUTS
+// ================================================================================================
+
+/// The inputs required by the host of the transaction kernel program to prove a transaction, as
+/// read from a [`TransactionWitness`].
+#[derive(Debug, Clone)]
+pub struct TransactionHostInputs {
+    /// The partial state of the account before the transaction was executed.
+    pub account: PartialAccount,
+    /// The notes consumed by the transaction.
+    pub input_notes: InputNotes<InputNote>,
+    /// The transaction script executed by the transaction, if any.
+    pub tx_script: Option<TransactionScript>,
+    /// The code of the foreign accounts accessed by the transaction.
+    pub foreign_account_code: Vec<AccountCode>,
+    /// The number of the reference block of the transaction.
+    pub ref_block_num: BlockNumber,
+    /// The commitment of the reference block of the transaction.
+    pub ref_block_commitment: Word,
+}
+
+// KERNEL STACK INPUTS
+// ================================================================================================
+
+/// The values of the stack inputs of the transaction kernel program.
+struct KernelStackInputs {
+    account_id: AccountId,
+    initial_account_commitment: Word,
+    input_notes_commitment: Word,
+    ref_block_commitment: Word,
+    ref_block_num: BlockNumber,
+}
+
+impl KernelStackInputs {
+    /// Parses the stack inputs built by [`TransactionKernel::build_input_stack`].
+    fn parse(stack_inputs: &StackInputs) -> Result<Self, TransactionWitnessError> {
+        // The stack inputs are stored with the top of the stack first, so reversing them yields
+        // the elements in the order in which they were provided, after a single padding element.
+        // NOTE: keep in sync with TransactionKernel::build_input_stack.
+        let elements: Vec<Felt> = stack_inputs.iter().rev().skip(1).copied().collect();
+
+        let ref_block_num = u32::try_from(elements[0].as_int()).map_err(|_| {
+            TransactionWitnessError::StackInputsInvalid(
+                "reference block number should fit into a u32".into(),
+            )
+        })?;
+        let account_id = AccountId::try_from([elements[2], elements[1]]).map_err(|err| {
+            TransactionWitnessError::StackInputsInvalid(format!("invalid account ID: {err}").into())
+        })?;
+
+        Ok(Self {
+            account_id,
+            input_notes_commitment: read_word(&elements[3..7]),
+            initial_account_commitment: read_word(&elements[7..11]),
+            ref_block_commitment: read_word(&elements[11..15]),
+            ref_block_num: ref_block_num.into(),
+        })
+    }
+}
+
+// WITNESS HOST DATA
+// ================================================================================================
+
+/// The data required by the prover host which the transaction kernel does not read from the
+/// advice provider.
+///
+/// The host data is stored in the advice map of the witness, so that the witness itself only
+/// consists of the inputs and outputs of the transaction kernel program.
+struct WitnessHostData {
+    account_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_code: AccountCode,
+    foreign_account_code: Vec<AccountCode>,
+    tx_script: Option<TransactionScript>,
+    note_scripts: Vec<NoteScript>,
+}
+
+impl WitnessHostData {
+    /// Returns the host data of the transaction described by the provided inputs.
+    fn new(tx_inputs: &TransactionInputs) -> Self {
+        let account = tx_inputs.account();
+
+        // Notes consuming the same script share a single copy of it.
+        let note_scripts: BTreeMap<Word, NoteScript> = tx_inputs
+            .input_notes()
+            .iter()
+            .map(|note| (note.note().script().root(), note.note().script().clone()))
+            .collect();
+
+        Self {
+            account_header: AccountHeader::from(account),
+            storage_header: account.storage().header().clone(),
+            account_code: account.code().clone(),
+            foreign_account_code: tx_inputs.foreign_account_code().to_vec(),
+            tx_script: tx_inputs.tx_script().cloned(),
+            note_scripts: note_scripts.into_values().collect(),
+        }
+    }
+}
+
+impl Serializable for WitnessHostData {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.foreign_account_code.write_into(target);
+        self.tx_script.write_into(target);
+        self.note_scripts.write_into(target);
+    }
+}
+
+impl Deserializable for WitnessHostData {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_header: AccountHeader::read_from(source)?,
+            storage_header: AccountStorageHeader::read_from(source)?,
+            account_code: AccountCode::read_from(source)?,
+            foreign_account_code: Vec::<AccountCode>::read_from(source)?,
+            tx_script: Option::<TransactionScript>::read_from(source)?,
+            note_scripts: Vec::<NoteScript>::read_from(source)?,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Removes the first `num_elements` elements from the provided note data and returns them.
+fn take_elements<'data>(
+    note_data: &mut &'data [Felt],
+    num_elements: usize,
+) -> Result<&'data [Felt], TransactionWitnessError> {
+    if note_data.len() < num_elements {
+        return Err(TransactionWitnessError::InputNoteDataInvalid(
+            "input note data ended unexpectedly".into(),
+        ));
+    }
+
+    let (elements, remaining) = note_data.split_at(num_elements);
+    *note_data = remaining;
+    Ok(elements)
+}
+
+/// Returns the word formed by the first four of the provided elements.
+fn read_word(elements: &[Felt]) -> Word {
+    Word::from([elements[0], elements[1], elements[2], elements[3]])
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack_inputs.write_into(target);
+        self.advice_inputs.write_into(target);
+        self.program_hash.write_into(target);
+        self.expected_stack_outputs.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack_inputs = StackInputs::read_from(source)?;
+        let advice_inputs = AdviceInputs::read_from(source)?;
+        let program_hash = Word::read_from(source)?;
+        let expected_stack_outputs = StackOutputs::read_from(source)?;
+
+        Ok(Self::new(stack_inputs, advice_inputs, program_hash, expected_stack_outputs))
+    }
+}
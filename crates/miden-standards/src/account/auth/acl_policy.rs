@@ -0,0 +1,177 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use miden_protocol::account::{Account, AccountCode, StorageSlotName};
+use miden_protocol::errors::AccountError;
+use miden_protocol::{Felt, Word};
+
+use super::{AuthEcdsaK256KeccakAcl, AuthFalcon512RpoAcl};
+use crate::account::components::StandardAccountComponent;
+
+/// The standard components against which trigger procedure roots are resolved to their names.
+const NAMED_COMPONENTS: [StandardAccountComponent; 3] = [
+    StandardAccountComponent::BasicWallet,
+    StandardAccountComponent::BasicFungibleFaucet,
+    StandardAccountComponent::NetworkFungibleFaucet,
+];
+
+// ACL POLICY
+// ================================================================================================
+
+/// The access control policy of an account using one of the procedure-based ACL auth components,
+/// i.e. [`AuthFalcon512RpoAcl`] or [`AuthEcdsaK256KeccakAcl`], as read from the account's storage.
+///
+/// The policy allows tooling to determine which operations on the account will demand a
+/// signature, without having to know the storage layout of the ACL components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AclPolicy {
+    trigger_procedures: Vec<AclTriggerProcedure>,
+    allow_unauthorized_output_notes: bool,
+    allow_unauthorized_input_notes: bool,
+}
+
+impl AclPolicy {
+    /// Reads the ACL policy from the storage of the provided account.
+    ///
+    /// The trigger procedure roots are resolved against the account's code and the standard
+    /// components, so that the names of known procedures are available via
+    /// [`AclTriggerProcedure::name`].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account does not use one of the ACL auth components.
+    /// - The configuration slot contains values other than booleans for the flags.
+    /// - The number of trigger procedures exceeds [`AccountCode::MAX_NUM_PROCEDURES`].
+    /// - A trigger procedure root is missing from the trigger procedure roots map.
+    pub fn read_from_account(account: &Account) -> Result<Self, AccountError> {
+        let storage = account.storage();
+
+        let (config_slot, trigger_procedure_roots_slot) =
+            if storage.get(AuthFalcon512RpoAcl::config_slot()).is_some() {
+                (
+                    AuthFalcon512RpoAcl::config_slot(),
+                    AuthFalcon512RpoAcl::trigger_procedure_roots_slot(),
+                )
+            } else if storage.get(AuthEcdsaK256KeccakAcl::config_slot()).is_some() {
+                (
+                    AuthEcdsaK256KeccakAcl::config_slot(),
+                    AuthEcdsaK256KeccakAcl::trigger_procedure_roots_slot(),
+                )
+            } else {
+                return Err(AccountError::other("account does not use an ACL auth component"));
+            };
+
+        let config = storage.get_item(config_slot)?;
+        let num_trigger_procs = config[0].as_int();
+        let allow_unauthorized_output_notes = parse_flag(config[1], config_slot)?;
+        let allow_unauthorized_input_notes = parse_flag(config[2], config_slot)?;
+
+        if num_trigger_procs > AccountCode::MAX_NUM_PROCEDURES as u64 {
+            return Err(AccountError::other(format!(
+                "ACL tracks {num_trigger_procs} procedures which exceeds the maximum of {}",
+                AccountCode::MAX_NUM_PROCEDURES
+            )));
+        }
+
+        let trigger_procedures = (0..num_trigger_procs as u32)
+            .map(|idx| {
+                let root = storage
+                    .get_map_item(trigger_procedure_roots_slot, Word::from([idx, 0, 0, 0]))?;
+                if root.is_empty() {
+                    return Err(AccountError::other(format!(
+                        "ACL trigger procedure root at index {idx} is missing"
+                    )));
+                }
+
+                Ok(AclTriggerProcedure::new(root, account.code()))
+            })
+            .collect::<Result<Vec<_>, AccountError>>()?;
+
+        Ok(Self {
+            trigger_procedures,
+            allow_unauthorized_output_notes,
+            allow_unauthorized_input_notes,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the procedures which require authentication when called.
+    pub fn trigger_procedures(&self) -> &[AclTriggerProcedure] {
+        &self.trigger_procedures
+    }
+
+    /// Returns `true` if calling the procedure with the provided root requires authentication.
+    pub fn requires_auth_for(&self, proc_root: Word) -> bool {
+        self.trigger_procedures.iter().any(|procedure| procedure.root() == proc_root)
+    }
+
+    /// Returns `true` if output notes can be created without authentication.
+    pub fn allow_unauthorized_output_notes(&self) -> bool {
+        self.allow_unauthorized_output_notes
+    }
+
+    /// Returns `true` if input notes can be consumed without authentication.
+    pub fn allow_unauthorized_input_notes(&self) -> bool {
+        self.allow_unauthorized_input_notes
+    }
+}
+
+// ACL TRIGGER PROCEDURE
+// ================================================================================================
+
+/// A procedure of an [`AclPolicy`] which requires authentication when called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AclTriggerProcedure {
+    root: Word,
+    name: Option<String>,
+    in_account_code: bool,
+}
+
+impl AclTriggerProcedure {
+    /// Creates a new [`AclTriggerProcedure`] from the provided root, resolving it against the
+    /// provided account code and the standard components.
+    fn new(root: Word, code: &AccountCode) -> Self {
+        let name = NAMED_COMPONENTS.iter().find_map(|component| component.procedure_path(root));
+
+        Self {
+            root,
+            name,
+            in_account_code: code.has_procedure(root),
+        }
+    }
+
+    /// Returns the MAST root of the procedure.
+    pub fn root(&self) -> Word {
+        self.root
+    }
+
+    /// Returns the path of the procedure if it is exported from one of the standard components,
+    /// or `None` otherwise.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns `true` if the procedure is part of the account's code.
+    ///
+    /// A trigger procedure which is not part of the account's code can never be called, and so
+    /// never demands a signature.
+    pub fn in_account_code(&self) -> bool {
+        self.in_account_code
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses a boolean flag of the ACL configuration slot.
+fn parse_flag(value: Felt, slot_name: &StorageSlotName) -> Result<bool, AccountError> {
+    match value.as_int() {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(AccountError::other(format!(
+            "ACL configuration in slot {slot_name} contains invalid flag value {other}"
+        ))),
+    }
+}
@@ -1,13 +1,11 @@
 use miden_protocol::account::auth::PublicKeyCommitment;
 use miden_protocol::account::{AccountComponent, StorageSlot, StorageSlotName};
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::slot_name;
 
 use crate::account::components::ecdsa_k256_keccak_library;
 
-static ECDSA_PUBKEY_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::ecdsa_k256_keccak::public_key")
-        .expect("storage slot name should be valid")
-});
+const ECDSA_PUBKEY_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::ecdsa_k256_keccak::public_key");
 
 /// An [`AccountComponent`] implementing the ECDSA K256 Keccak signature scheme for authentication
 /// of transactions.
@@ -36,7 +34,7 @@ impl AuthEcdsaK256Keccak {
 
     /// Returns the [`StorageSlotName`] where the public key is stored.
     pub fn public_key_slot() -> &'static StorageSlotName {
-        &ECDSA_PUBKEY_SLOT_NAME
+        ECDSA_PUBKEY_SLOT_NAME
     }
 }
 
@@ -1,35 +1,24 @@
 use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 
-use miden_protocol::Word;
 use miden_protocol::account::auth::PublicKeyCommitment;
 use miden_protocol::account::{AccountComponent, StorageMap, StorageSlot, StorageSlotName};
 use miden_protocol::errors::AccountError;
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::{Word, slot_name};
 
 use crate::account::components::ecdsa_k256_keccak_multisig_library;
 
-static THRESHOLD_CONFIG_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::ecdsa_k256_keccak_multisig::threshold_config")
-        .expect("storage slot name should be valid")
-});
+const THRESHOLD_CONFIG_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::ecdsa_k256_keccak_multisig::threshold_config");
 
-static APPROVER_PUBKEYS_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::ecdsa_k256_keccak_multisig::approver_public_keys")
-        .expect("storage slot name should be valid")
-});
+const APPROVER_PUBKEYS_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::ecdsa_k256_keccak_multisig::approver_public_keys");
 
-static EXECUTED_TRANSACTIONS_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new(
-        "miden::standards::auth::ecdsa_k256_keccak_multisig::executed_transactions",
-    )
-    .expect("storage slot name should be valid")
-});
+const EXECUTED_TRANSACTIONS_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::ecdsa_k256_keccak_multisig::executed_transactions");
 
-static PROCEDURE_THRESHOLDS_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::ecdsa_k256_keccak_multisig::procedure_thresholds")
-        .expect("storage slot name should be valid")
-});
+const PROCEDURE_THRESHOLDS_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::ecdsa_k256_keccak_multisig::procedure_thresholds");
 
 // MULTISIG AUTHENTICATION COMPONENT
 // ================================================================================================
@@ -131,22 +120,22 @@ impl AuthEcdsaK256KeccakMultisig {
 
     /// Returns the [`StorageSlotName`] where the threshold configuration is stored.
     pub fn threshold_config_slot() -> &'static StorageSlotName {
-        &THRESHOLD_CONFIG_SLOT_NAME
+        THRESHOLD_CONFIG_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the approver public keys are stored.
     pub fn approver_public_keys_slot() -> &'static StorageSlotName {
-        &APPROVER_PUBKEYS_SLOT_NAME
+        APPROVER_PUBKEYS_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the executed transactions are stored.
     pub fn executed_transactions_slot() -> &'static StorageSlotName {
-        &EXECUTED_TRANSACTIONS_SLOT_NAME
+        EXECUTED_TRANSACTIONS_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the procedure thresholds are stored.
     pub fn procedure_thresholds_slot() -> &'static StorageSlotName {
-        &PROCEDURE_THRESHOLDS_SLOT_NAME
+        PROCEDURE_THRESHOLDS_SLOT_NAME
     }
 }
 
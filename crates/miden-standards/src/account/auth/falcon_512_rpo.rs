@@ -1,13 +1,11 @@
 use miden_protocol::account::auth::PublicKeyCommitment;
 use miden_protocol::account::{AccountComponent, StorageSlot, StorageSlotName};
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::slot_name;
 
 use crate::account::components::falcon_512_rpo_library;
 
-static FALCON_PUBKEY_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::falcon512_rpo::public_key")
-        .expect("storage slot name should be valid")
-});
+const FALCON_PUBKEY_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::falcon512_rpo::public_key");
 
 /// An [`AccountComponent`] implementing the Falcon512Rpo signature scheme for authentication of
 /// transactions.
@@ -40,7 +38,7 @@ impl AuthFalcon512Rpo {
 
     /// Returns the [`StorageSlotName`] where the public key is stored.
     pub fn public_key_slot() -> &'static StorageSlotName {
-        &FALCON_PUBKEY_SLOT_NAME
+        FALCON_PUBKEY_SLOT_NAME
     }
 }
 
@@ -1,6 +1,5 @@
 use alloc::vec::Vec;
 
-use miden_protocol::Word;
 use miden_protocol::account::auth::PublicKeyCommitment;
 use miden_protocol::account::{
     AccountCode,
@@ -10,24 +9,18 @@ use miden_protocol::account::{
     StorageSlotName,
 };
 use miden_protocol::errors::AccountError;
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::{Word, slot_name};
 
 use crate::account::components::falcon_512_rpo_acl_library;
 
-static PUBKEY_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::falcon512_rpo_acl::public_key")
-        .expect("storage slot name should be valid")
-});
+const PUBKEY_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::falcon512_rpo_acl::public_key");
 
-static CONFIG_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::falcon512_rpo_acl::config")
-        .expect("storage slot name should be valid")
-});
+const CONFIG_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::falcon512_rpo_acl::config");
 
-static TRIGGER_PROCEDURE_ROOT_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::auth::falcon512_rpo_acl::trigger_procedure_roots")
-        .expect("storage slot name should be valid")
-});
+const TRIGGER_PROCEDURE_ROOT_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::auth::falcon512_rpo_acl::trigger_procedure_roots");
 
 /// Configuration for [`AuthFalcon512RpoAcl`] component.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -160,17 +153,17 @@ impl AuthFalcon512RpoAcl {
 
     /// Returns the [`StorageSlotName`] where the public key is stored.
     pub fn public_key_slot() -> &'static StorageSlotName {
-        &PUBKEY_SLOT_NAME
+        PUBKEY_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the component's configuration is stored.
     pub fn config_slot() -> &'static StorageSlotName {
-        &CONFIG_SLOT_NAME
+        CONFIG_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the trigger procedure roots are stored.
     pub fn trigger_procedure_roots_slot() -> &'static StorageSlotName {
-        &TRIGGER_PROCEDURE_ROOT_SLOT_NAME
+        TRIGGER_PROCEDURE_ROOT_SLOT_NAME
     }
 }
 
@@ -226,6 +219,7 @@ mod tests {
     use miden_protocol::account::AccountBuilder;
 
     use super::*;
+    use crate::account::auth::AclPolicy;
     use crate::account::components::StandardAccountComponent;
     use crate::account::wallets::BasicWallet;
 
@@ -312,6 +306,24 @@ mod tests {
                 .expect("storage map access failed");
             assert_eq!(proc_root, Word::empty());
         }
+
+        // Check the policy parsed from storage
+        let policy = AclPolicy::read_from_account(&account).expect("ACL policy parsing failed");
+        assert_eq!(
+            policy.allow_unauthorized_output_notes(),
+            config.allow_unauthorized_output_notes
+        );
+        assert_eq!(policy.allow_unauthorized_input_notes(), config.allow_unauthorized_input_notes);
+
+        let trigger_roots: Vec<Word> =
+            policy.trigger_procedures().iter().map(|procedure| procedure.root()).collect();
+        assert_eq!(trigger_roots, auth_trigger_procedures);
+        for procedure in policy.trigger_procedures() {
+            assert!(policy.requires_auth_for(procedure.root()));
+            assert!(procedure.in_account_code());
+            assert!(procedure.name().is_some());
+        }
+        assert!(!policy.requires_auth_for(Word::empty()));
     }
 
     /// Test ACL component with no procedures and both authorization flags set to false
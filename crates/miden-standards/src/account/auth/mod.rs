@@ -21,3 +21,6 @@ pub use falcon_512_rpo_acl::{AuthFalcon512RpoAcl, AuthFalcon512RpoAclConfig};
 
 mod falcon_512_rpo_multisig;
 pub use falcon_512_rpo_multisig::{AuthFalcon512RpoMultisig, AuthFalcon512RpoMultisigConfig};
+
+mod acl_policy;
+pub use acl_policy::{AclPolicy, AclTriggerProcedure};
@@ -1,4 +1,5 @@
 use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use miden_processor::MastNodeExt;
@@ -196,18 +197,7 @@ pub enum StandardAccountComponent {
 impl StandardAccountComponent {
     /// Returns the iterator over digests of all procedures exported from the component.
     pub fn procedure_digests(&self) -> impl Iterator<Item = Word> {
-        let library = match self {
-            Self::BasicWallet => BASIC_WALLET_LIBRARY.as_ref(),
-            Self::BasicFungibleFaucet => BASIC_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
-            Self::NetworkFungibleFaucet => NETWORK_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
-            Self::AuthEcdsaK256Keccak => ECDSA_K256_KECCAK_LIBRARY.as_ref(),
-            Self::AuthEcdsaK256KeccakAcl => ECDSA_K256_KECCAK_ACL_LIBRARY.as_ref(),
-            Self::AuthEcdsaK256KeccakMultisig => ECDSA_K256_KECCAK_MULTISIG_LIBRARY.as_ref(),
-            Self::AuthFalcon512Rpo => FALCON_512_RPO_LIBRARY.as_ref(),
-            Self::AuthFalcon512RpoAcl => FALCON_512_RPO_ACL_LIBRARY.as_ref(),
-            Self::AuthFalcon512RpoMultisig => FALCON_512_RPO_MULTISIG_LIBRARY.as_ref(),
-            Self::AuthNoAuth => NO_AUTH_LIBRARY.as_ref(),
-        };
+        let library = self.library();
 
         library
             .exports()
@@ -221,6 +211,41 @@ impl StandardAccountComponent {
             })
     }
 
+    /// Returns the path of the procedure with the provided digest if it is exported from the
+    /// component, or `None` otherwise.
+    pub(crate) fn procedure_path(&self, digest: Word) -> Option<String> {
+        let library = self.library();
+
+        library
+            .exports()
+            .filter(|export| matches!(export, LibraryExport::Procedure(_)))
+            .find(|proc_export| {
+                library
+                    .mast_forest()
+                    .get_node_by_id(proc_export.unwrap_procedure().node)
+                    .expect("export node not in the forest")
+                    .digest()
+                    == digest
+            })
+            .map(|proc_export| proc_export.path().to_string())
+    }
+
+    /// Returns the library of the component.
+    fn library(&self) -> &'static Library {
+        match self {
+            Self::BasicWallet => BASIC_WALLET_LIBRARY.as_ref(),
+            Self::BasicFungibleFaucet => BASIC_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
+            Self::NetworkFungibleFaucet => NETWORK_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
+            Self::AuthEcdsaK256Keccak => ECDSA_K256_KECCAK_LIBRARY.as_ref(),
+            Self::AuthEcdsaK256KeccakAcl => ECDSA_K256_KECCAK_ACL_LIBRARY.as_ref(),
+            Self::AuthEcdsaK256KeccakMultisig => ECDSA_K256_KECCAK_MULTISIG_LIBRARY.as_ref(),
+            Self::AuthFalcon512Rpo => FALCON_512_RPO_LIBRARY.as_ref(),
+            Self::AuthFalcon512RpoAcl => FALCON_512_RPO_ACL_LIBRARY.as_ref(),
+            Self::AuthFalcon512RpoMultisig => FALCON_512_RPO_MULTISIG_LIBRARY.as_ref(),
+            Self::AuthNoAuth => NO_AUTH_LIBRARY.as_ref(),
+        }
+    }
+
     /// Checks whether procedures from the current component are present in the procedures map
     /// and if so it removes these procedures from this map and pushes the corresponding component
     /// interface to the component interface vector.
@@ -120,6 +120,8 @@ impl BasicFungibleFaucet {
     ///   [`FungibleAsset::MAX_AMOUNT`].
     /// - the token symbol encoded value exceeds the maximum value of
     ///   [`TokenSymbol::MAX_ENCODED_VALUE`].
+    /// - the faucet sysdata slot is missing or invalid, or the total issuance of the faucet exceeds
+    ///   the max supply.
     fn try_from_interface(
         interface: AccountInterface,
         storage: &AccountStorage,
@@ -144,7 +146,10 @@ impl BasicFungibleFaucet {
                     }
                 })?;
 
-                return BasicFungibleFaucet::new(token_symbol, decimals, max_supply);
+                let faucet = BasicFungibleFaucet::new(token_symbol, decimals, max_supply)?;
+                super::validate_issuance(storage, faucet.max_supply())?;
+
+                return Ok(faucet);
             }
         }
 
@@ -156,7 +161,7 @@ impl BasicFungibleFaucet {
 
     /// Returns the [`StorageSlotName`] where the [`BasicFungibleFaucet`]'s metadata is stored.
     pub fn metadata_slot() -> &'static StorageSlotName {
-        &super::METADATA_SLOT_NAME
+        super::METADATA_SLOT_NAME
     }
 
     /// Returns the symbol of the faucet.
@@ -311,8 +316,9 @@ pub fn create_basic_fungible_faucet(
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
-    use miden_protocol::account::AccountStorage;
     use miden_protocol::account::auth::PublicKeyCommitment;
+    use miden_protocol::account::{AccountStorage, FaucetSysdata};
+    use miden_protocol::errors::AccountError;
     use miden_protocol::{FieldElement, ONE, Word};
 
     use super::{
@@ -454,6 +460,39 @@ mod tests {
         assert_matches!(err, FungibleFaucetError::NoAvailableInterface);
     }
 
+    #[test]
+    fn faucet_issuance_exceeding_max_supply() -> anyhow::Result<()> {
+        let mock_public_key = PublicKeyCommitment::from(Word::from([0, 1, 2, 3u32]));
+        let mut faucet_account = AccountBuilder::new([7; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(BasicFungibleFaucet::new(TokenSymbol::new("POL")?, 10, Felt::new(100))?)
+            .with_auth_component(AuthFalcon512Rpo::new(mock_public_key))
+            .build_existing()?;
+
+        // issuing up to the max supply is valid
+        faucet_account
+            .storage_mut()
+            .set_item(FaucetSysdata::slot_name(), FaucetSysdata::new(100)?.to_word())?;
+        BasicFungibleFaucet::try_from(&faucet_account)?;
+
+        // an issuance beyond the max supply is invalid
+        faucet_account
+            .storage_mut()
+            .set_item(FaucetSysdata::slot_name(), FaucetSysdata::new(101)?.to_word())?;
+        let err = BasicFungibleFaucet::try_from(&faucet_account)
+            .err()
+            .expect("basic fungible faucet creation should fail");
+        assert_matches!(
+            err,
+            FungibleFaucetError::InvalidIssuance(AccountError::FaucetIssuanceExceedsMaxSupply {
+                issued: 101,
+                max_supply: 100
+            })
+        );
+
+        Ok(())
+    }
+
     /// Check that the obtaining of the basic fungible faucet procedure digests does not panic.
     #[test]
     fn get_faucet_procedures() {
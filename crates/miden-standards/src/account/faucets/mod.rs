@@ -1,9 +1,14 @@
 use alloc::string::String;
 
-use miden_protocol::Felt;
-use miden_protocol::account::{Account, AccountStorage, AccountType, StorageSlotName};
+use miden_protocol::account::{
+    Account,
+    AccountStorage,
+    AccountType,
+    FaucetSysdata,
+    StorageSlotName,
+};
 use miden_protocol::errors::{AccountError, TokenSymbolError};
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::{Felt, slot_name};
 use thiserror::Error;
 
 mod basic_fungible;
@@ -12,10 +17,8 @@ mod network_fungible;
 pub use basic_fungible::{BasicFungibleFaucet, create_basic_fungible_faucet};
 pub use network_fungible::{NetworkFungibleFaucet, create_network_fungible_faucet};
 
-static METADATA_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::fungible_faucets::metadata")
-        .expect("storage slot name should be valid")
-});
+const METADATA_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::fungible_faucets::metadata");
 
 // FUNGIBLE FAUCET
 // ================================================================================================
@@ -51,6 +54,17 @@ impl FungibleFaucetExt for Account {
     }
 }
 
+/// Validates the total issuance of the fungible faucet with the provided storage against the max
+/// supply of its token.
+fn validate_issuance(
+    storage: &AccountStorage,
+    max_supply: Felt,
+) -> Result<(), FungibleFaucetError> {
+    FaucetSysdata::read(storage)
+        .and_then(|sysdata| sysdata.validate_max_supply(max_supply.as_int()))
+        .map_err(FungibleFaucetError::InvalidIssuance)
+}
+
 // FUNGIBLE FAUCET ERROR
 // ================================================================================================
 
@@ -72,6 +86,8 @@ pub enum FungibleFaucetError {
     },
     #[error("invalid token symbol")]
     InvalidTokenSymbol(#[source] TokenSymbolError),
+    #[error("faucet token issuance is invalid")]
+    InvalidIssuance(#[source] AccountError),
     #[error("unsupported authentication scheme: {0}")]
     UnsupportedAuthScheme(String),
     #[error("account creation failed")]
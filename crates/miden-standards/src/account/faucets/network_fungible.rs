@@ -10,8 +10,7 @@ use miden_protocol::account::{
     StorageSlotName,
 };
 use miden_protocol::asset::TokenSymbol;
-use miden_protocol::utils::sync::LazyLock;
-use miden_protocol::{Felt, FieldElement, Word};
+use miden_protocol::{Felt, FieldElement, Word, slot_name};
 
 use super::{BasicFungibleFaucet, FungibleFaucetError};
 use crate::account::auth::NoAuth;
@@ -36,10 +35,8 @@ procedure_digest!(
     network_fungible_faucet_library
 );
 
-static OWNER_CONFIG_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::access::ownable::owner_config")
-        .expect("storage slot name should be valid")
-});
+const OWNER_CONFIG_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::access::ownable::owner_config");
 
 /// An [`AccountComponent`] implementing a network fungible faucet.
 ///
@@ -109,6 +106,8 @@ impl NetworkFungibleFaucet {
     ///   [`miden_protocol::asset::FungibleAsset::MAX_AMOUNT`].
     /// - the token symbol encoded value exceeds the maximum value of
     ///   [`TokenSymbol::MAX_ENCODED_VALUE`].
+    /// - the faucet sysdata slot is missing or invalid, or the total issuance of the faucet exceeds
+    ///   the max supply.
     fn try_from_interface(
         interface: AccountInterface,
         storage: &AccountStorage,
@@ -150,6 +149,7 @@ impl NetworkFungibleFaucet {
                 })?;
 
                 let faucet = BasicFungibleFaucet::new(token_symbol, decimals, max_supply)?;
+                super::validate_issuance(storage, faucet.max_supply())?;
 
                 return Ok(Self { faucet, owner_account_id });
             }
@@ -163,13 +163,13 @@ impl NetworkFungibleFaucet {
 
     /// Returns the [`StorageSlotName`] where the [`NetworkFungibleFaucet`]'s metadata is stored.
     pub fn metadata_slot() -> &'static StorageSlotName {
-        &super::METADATA_SLOT_NAME
+        super::METADATA_SLOT_NAME
     }
 
     /// Returns the [`StorageSlotName`] where the [`NetworkFungibleFaucet`]'s owner configuration is
     /// stored.
     pub fn owner_config_slot() -> &'static StorageSlotName {
-        &OWNER_CONFIG_SLOT_NAME
+        OWNER_CONFIG_SLOT_NAME
     }
 
     /// Returns the symbol of the faucet.
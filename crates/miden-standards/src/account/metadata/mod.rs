@@ -1,17 +1,14 @@
 use alloc::collections::BTreeMap;
 
-use miden_protocol::Word;
 use miden_protocol::account::component::StorageSchema;
 use miden_protocol::account::{AccountComponent, StorageSlot, StorageSlotName};
 use miden_protocol::errors::AccountComponentTemplateError;
-use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::{Word, slot_name};
 
 use crate::account::components::storage_schema_library;
 
-pub static SCHEMA_COMMITMENT_SLOT_NAME: LazyLock<StorageSlotName> = LazyLock::new(|| {
-    StorageSlotName::new("miden::standards::metadata::storage_schema")
-        .expect("storage slot name should be valid")
-});
+pub const SCHEMA_COMMITMENT_SLOT_NAME: &StorageSlotName =
+    &slot_name!("miden::standards::metadata::storage_schema");
 
 /// An [`AccountComponent`] exposing the account storage schema commitment.
 ///
@@ -52,7 +49,7 @@ impl AccountSchemaCommitment {
 
     /// Returns the [`StorageSlotName`] where the schema commitment is stored.
     pub fn schema_commitment_slot() -> &'static StorageSlotName {
-        &SCHEMA_COMMITMENT_SLOT_NAME
+        SCHEMA_COMMITMENT_SLOT_NAME
     }
 }
 
@@ -19,6 +19,9 @@ use miden_protocol::{Felt, Word};
 use crate::errors::CodeBuilderError;
 use crate::standards_lib::StandardsLib;
 
+mod tx_script_template;
+pub use tx_script_template::{TxScriptTemplate, TxScriptTemplateError};
+
 // CODE BUILDER
 // ================================================================================================
 
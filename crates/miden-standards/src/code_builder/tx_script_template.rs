@@ -0,0 +1,177 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::slice;
+
+use miden_protocol::account::AccountId;
+use miden_protocol::asset::FungibleAsset;
+use miden_protocol::errors::NoteError;
+use miden_protocol::note::{NoteAttachment, NoteAttachmentContent, PartialNote};
+use miden_protocol::transaction::TransactionScript;
+use miden_protocol::{Felt, Word};
+use thiserror::Error;
+
+use super::CodeBuilder;
+use crate::account::interface::{AccountComponentInterface, AccountInterfaceError};
+use crate::errors::CodeBuilderError;
+use crate::note::BurnNote;
+
+// TRANSACTION SCRIPT TEMPLATE
+// ================================================================================================
+
+/// A template of a common transaction script, from which the MASM code of the script is generated.
+///
+/// The templates allow executing common transactions without writing any MASM. The parameters of
+/// a template are only ever inserted into the generated code as field elements pushed onto the
+/// stack or as advice map entries, so no user-provided data is interpolated into the code as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxScriptTemplate {
+    /// Mints the single fungible asset of the provided note and sends it in that note.
+    ///
+    /// The script must be executed against the
+    /// [`BasicFungibleFaucet`](crate::account::faucets::BasicFungibleFaucet) which is the sender
+    /// of the note and which issues its asset.
+    MintFungible { note: PartialNote },
+    /// Creates the provided notes and moves their assets from the vault of the account into them.
+    ///
+    /// The script must be executed against a [`BasicWallet`](crate::account::wallets::BasicWallet)
+    /// which is the sender of all notes.
+    SendNotes(Vec<PartialNote>),
+    /// Sends the provided asset to its faucet in a [`BurnNote`] with the provided serial number,
+    /// such that the faucet burns it when consuming the note.
+    ///
+    /// The script must be executed against the `sender` account, which must be a
+    /// [`BasicWallet`](crate::account::wallets::BasicWallet).
+    BurnAsset {
+        sender: AccountId,
+        asset: FungibleAsset,
+        serial_num: Word,
+    },
+    /// Calls the account procedure with the provided MAST root.
+    ///
+    /// The arguments are placed on the stack such that the first argument is on top of the stack.
+    /// At most [`TxScriptTemplate::MAX_PROCEDURE_ARGS`] arguments are supported, and the outputs of
+    /// the procedure are discarded.
+    CallProcedure { root: Word, args: Vec<Felt> },
+}
+
+impl TxScriptTemplate {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of arguments of a [`TxScriptTemplate::CallProcedure`], i.e. the number of
+    /// stack elements accessible to a called procedure.
+    pub const MAX_PROCEDURE_ARGS: usize = 16;
+
+    // COMPILATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Generates the MASM code of this template and compiles it into a [`TransactionScript`] with
+    /// the provided code builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - a [`TxScriptTemplate::MintFungible`] note does not contain exactly one asset issued by its
+    ///   sender.
+    /// - a [`TxScriptTemplate::SendNotes`] template contains no notes or notes with different
+    ///   senders.
+    /// - the BURN note of a [`TxScriptTemplate::BurnAsset`] cannot be created.
+    /// - a [`TxScriptTemplate::CallProcedure`] has more than
+    ///   [`TxScriptTemplate::MAX_PROCEDURE_ARGS`] arguments.
+    /// - the generated script fails to compile.
+    pub fn compile(
+        &self,
+        mut code_builder: CodeBuilder,
+    ) -> Result<TransactionScript, TxScriptTemplateError> {
+        let body = match self {
+            Self::MintFungible { note } => {
+                let notes = slice::from_ref(note);
+                add_attachment_advice(&mut code_builder, notes);
+                AccountComponentInterface::BasicFungibleFaucet
+                    .send_note_body(note.metadata().sender(), notes)
+                    .map_err(TxScriptTemplateError::NoteCreationFailed)?
+            },
+            Self::SendNotes(notes) => {
+                let sender =
+                    notes.first().ok_or(TxScriptTemplateError::NoNotesToSend)?.metadata().sender();
+                add_attachment_advice(&mut code_builder, notes);
+                AccountComponentInterface::BasicWallet
+                    .send_note_body(sender, notes)
+                    .map_err(TxScriptTemplateError::NoteCreationFailed)?
+            },
+            Self::BurnAsset { sender, asset, serial_num } => {
+                let note: PartialNote = BurnNote::create(
+                    *sender,
+                    asset.faucet_id(),
+                    (*asset).into(),
+                    NoteAttachment::default(),
+                    *serial_num,
+                )
+                .map_err(TxScriptTemplateError::BurnNoteCreationFailed)?
+                .into();
+                AccountComponentInterface::BasicWallet
+                    .send_note_body(*sender, slice::from_ref(&note))
+                    .map_err(TxScriptTemplateError::NoteCreationFailed)?
+            },
+            Self::CallProcedure { root, args } => procedure_call_body(*root, args)?,
+        };
+
+        code_builder
+            .compile_tx_script(format!("begin\n{body}\nend"))
+            .map_err(TxScriptTemplateError::InvalidTransactionScript)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds the elements of the array attachments of the provided notes to the advice map of the code
+/// builder, keyed by the commitment of the array.
+fn add_attachment_advice(code_builder: &mut CodeBuilder, notes: &[PartialNote]) {
+    for note in notes {
+        if let NoteAttachmentContent::Array(array) = note.metadata().attachment().content() {
+            code_builder.add_advice_map_entry(array.commitment(), array.as_slice().to_vec());
+        }
+    }
+}
+
+/// Generates the code calling the procedure with the provided root and arguments.
+///
+/// The arguments are dropped after the call, so that the stack has the same depth as before.
+fn procedure_call_body(root: Word, args: &[Felt]) -> Result<String, TxScriptTemplateError> {
+    if args.len() > TxScriptTemplate::MAX_PROCEDURE_ARGS {
+        return Err(TxScriptTemplateError::TooManyProcedureArgs(args.len()));
+    }
+
+    let mut body = String::new();
+    for arg in args.iter().rev() {
+        body.push_str(&format!("push.{arg}\n"));
+    }
+    body.push_str(&format!("call.{root}\n"));
+    for _ in args {
+        body.push_str("drop\n");
+    }
+
+    Ok(body)
+}
+
+// TRANSACTION SCRIPT TEMPLATE ERROR
+// ================================================================================================
+
+/// Errors that can occur when compiling a [`TxScriptTemplate`].
+#[derive(Debug, Error)]
+pub enum TxScriptTemplateError {
+    #[error("send notes template must contain at least one note")]
+    NoNotesToSend,
+    #[error(
+        "procedure call has {0} arguments but at most {max} are supported",
+        max = TxScriptTemplate::MAX_PROCEDURE_ARGS
+    )]
+    TooManyProcedureArgs(usize),
+    #[error("failed to create the BURN note")]
+    BurnNoteCreationFailed(#[source] NoteError),
+    #[error("failed to generate the note creation code")]
+    NoteCreationFailed(#[source] AccountInterfaceError),
+    #[error("failed to compile the transaction script")]
+    InvalidTransactionScript(#[source] CodeBuilderError),
+}
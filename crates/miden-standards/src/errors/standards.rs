@@ -17,6 +17,15 @@ pub const ERR_ATTACHMENT_SCHEME_MISMATCH: MasmError = MasmError::from_static_str
 /// Error Message: "burn requires exactly 1 note asset"
 pub const ERR_BASIC_FUNGIBLE_BURN_WRONG_NUMBER_OF_ASSETS: MasmError = MasmError::from_static_str("burn requires exactly 1 note asset");
 
+/// Error Message: "oracle value does not satisfy the condition of the conditional note"
+pub const ERR_CONDITIONAL_NOTE_CONDITION_NOT_SATISFIED: MasmError = MasmError::from_static_str("oracle value does not satisfy the condition of the conditional note");
+/// Error Message: "conditional note's target account address and transaction address do not match"
+pub const ERR_CONDITIONAL_NOTE_TARGET_ACCT_MISMATCH: MasmError = MasmError::from_static_str("conditional note's target account address and transaction address do not match");
+/// Error Message: "conditional note expects exactly 15 note storage items"
+pub const ERR_CONDITIONAL_NOTE_UNEXPECTED_NUMBER_OF_STORAGE_ITEMS: MasmError = MasmError::from_static_str("conditional note expects exactly 15 note storage items");
+/// Error Message: "conditional note specifies an unknown comparison operator"
+pub const ERR_CONDITIONAL_NOTE_UNKNOWN_OPERATOR: MasmError = MasmError::from_static_str("conditional note specifies an unknown comparison operator");
+
 /// Error Message: "distribute would cause the maximum supply to be exceeded"
 pub const ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED: MasmError = MasmError::from_static_str("distribute would cause the maximum supply to be exceeded");
 
@@ -45,6 +54,11 @@ pub const ERR_P2ID_TARGET_ACCT_MISMATCH: MasmError = MasmError::from_static_str(
 /// Error Message: "P2ID note expects exactly 2 note storage items"
 pub const ERR_P2ID_UNEXPECTED_NUMBER_OF_STORAGE_ITEMS: MasmError = MasmError::from_static_str("P2ID note expects exactly 2 note storage items");
 
+/// Error Message: "none of P2M's target account addresses matches the transaction address"
+pub const ERR_P2M_TARGET_ACCT_MISMATCH: MasmError = MasmError::from_static_str("none of P2M's target account addresses matches the transaction address");
+/// Error Message: "P2M note expects 2 note storage items for each of its 1 to 8 target accounts"
+pub const ERR_P2M_UNEXPECTED_NUMBER_OF_STORAGE_ITEMS: MasmError = MasmError::from_static_str("P2M note expects 2 note storage items for each of its 1 to 8 target accounts");
+
 /// Error Message: "note sender is not the owner"
 pub const ERR_SENDER_NOT_OWNER: MasmError = MasmError::from_static_str("note sender is not the owner");
 
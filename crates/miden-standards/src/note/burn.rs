@@ -2,7 +2,6 @@ use miden_protocol::Word;
 use miden_protocol::account::AccountId;
 use miden_protocol::assembly::Library;
 use miden_protocol::asset::Asset;
-use miden_protocol::crypto::rand::FeltRng;
 use miden_protocol::errors::NoteError;
 use miden_protocol::note::{
     Note,
@@ -18,6 +17,8 @@ use miden_protocol::note::{
 use miden_protocol::utils::Deserializable;
 use miden_protocol::utils::sync::LazyLock;
 
+use super::SerialNumSource;
+
 // NOTE SCRIPT
 // ================================================================================================
 
@@ -66,27 +67,28 @@ impl BurnNote {
     ///
     /// BURN notes are always PUBLIC for network execution.
     ///
-    /// The passed-in `rng` is used to generate a serial number for the note. The note's tag
-    /// is automatically set to the faucet's account ID for proper routing.
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). The note's tag is
+    /// automatically set to the faucet's account ID for proper routing.
     ///
     /// # Parameters
     /// - `sender`: The account ID of the note creator
     /// - `faucet_id`: The account ID of the faucet that will burn the assets
     /// - `fungible_asset`: The fungible asset to be burned
     /// - `attachment`: The [`NoteAttachment`] of the BURN note
-    /// - `rng`: Random number generator for creating the serial number
+    /// - `serial_num`: The source of the serial number, see [`SerialNumSource`]
     ///
     /// # Errors
     /// Returns an error if note creation fails.
-    pub fn create<R: FeltRng>(
+    pub fn create<'rng>(
         sender: AccountId,
         faucet_id: AccountId,
         fungible_asset: Asset,
         attachment: NoteAttachment,
-        rng: &mut R,
+        serial_num: impl Into<SerialNumSource<'rng>>,
     ) -> Result<Note, NoteError> {
         let note_script = Self::script();
-        let serial_num = rng.draw_word();
+        let serial_num = serial_num.into().into_serial_num();
 
         // BURN notes are always public
         let note_type = NoteType::Public;
@@ -0,0 +1,259 @@
+use alloc::vec::Vec;
+
+use miden_protocol::account::{AccountId, StorageSlotName};
+use miden_protocol::assembly::Library;
+use miden_protocol::asset::Asset;
+use miden_protocol::errors::NoteError;
+use miden_protocol::note::{
+    Note,
+    NoteAssets,
+    NoteAttachment,
+    NoteMetadata,
+    NoteRecipient,
+    NoteScript,
+    NoteStorage,
+    NoteTag,
+    NoteType,
+};
+use miden_protocol::utils::Deserializable;
+use miden_protocol::utils::sync::LazyLock;
+use miden_protocol::{Felt, Word};
+
+use super::SerialNumSource;
+
+// NOTE SCRIPT
+// ================================================================================================
+
+// Initialize the conditional note script only once
+static CONDITIONAL_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/conditional.masl"));
+    let library =
+        Library::read_from_bytes(bytes).expect("Shipped conditional library is well-formed");
+    NoteScript::from_library(&library).expect("conditional library contains note script procedure")
+});
+
+// CONDITION OPERATOR
+// ================================================================================================
+
+/// The comparison operator of an [`OracleCondition`].
+///
+/// The operator is applied as `ORACLE_VALUE <operator> THRESHOLD`, where both words are compared
+/// lexicographically as done by `miden::core::word`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ConditionOperator {
+    Eq = 0,
+    Lt = 1,
+    Lte = 2,
+    Gt = 3,
+    Gte = 4,
+}
+
+impl From<ConditionOperator> for Felt {
+    fn from(operator: ConditionOperator) -> Self {
+        Felt::from(operator as u8)
+    }
+}
+
+// ORACLE CONDITION
+// ================================================================================================
+
+/// The condition a [`ConditionalNote`] checks against the storage of a foreign oracle account
+/// before releasing its assets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OracleCondition {
+    oracle_account_id: AccountId,
+    oracle_proc_root: Word,
+    slot_name: StorageSlotName,
+    operator: ConditionOperator,
+    threshold: Word,
+}
+
+impl OracleCondition {
+    /// Returns a new [`OracleCondition`] which is satisfied if the value of the `slot_name` value
+    /// slot of the oracle account compares to `threshold` according to `operator`.
+    ///
+    /// The value is read by invoking the procedure with MAST root `oracle_proc_root` on the
+    /// oracle account. The procedure must have the following signature:
+    ///
+    /// ```text
+    /// Inputs:  [slot_id_prefix, slot_id_suffix, pad(14)]
+    /// Outputs: [VALUE, pad(12)]
+    /// ```
+    pub fn new(
+        oracle_account_id: AccountId,
+        oracle_proc_root: Word,
+        slot_name: StorageSlotName,
+        operator: ConditionOperator,
+        threshold: Word,
+    ) -> Self {
+        Self {
+            oracle_account_id,
+            oracle_proc_root,
+            slot_name,
+            operator,
+            threshold,
+        }
+    }
+
+    /// Returns the ID of the oracle account.
+    pub fn oracle_account_id(&self) -> AccountId {
+        self.oracle_account_id
+    }
+
+    /// Returns the MAST root of the oracle procedure which reads the value.
+    pub fn oracle_proc_root(&self) -> Word {
+        self.oracle_proc_root
+    }
+
+    /// Returns the name of the oracle storage slot holding the value.
+    pub fn slot_name(&self) -> &StorageSlotName {
+        &self.slot_name
+    }
+
+    /// Returns the comparison operator.
+    pub fn operator(&self) -> ConditionOperator {
+        self.operator
+    }
+
+    /// Returns the threshold the oracle value is compared against.
+    pub fn threshold(&self) -> Word {
+        self.threshold
+    }
+
+    /// Returns the foreign account inputs a transaction consuming the note must provide.
+    pub fn foreign_account_requirement(&self) -> ForeignAccountRequirement {
+        ForeignAccountRequirement {
+            account_id: self.oracle_account_id,
+            slot_name: self.slot_name.clone(),
+        }
+    }
+}
+
+// FOREIGN ACCOUNT REQUIREMENT
+// ================================================================================================
+
+/// Describes the foreign account a transaction consuming a [`ConditionalNote`] has to load.
+///
+/// The consumer must provide the [`AccountInputs`](miden_protocol::transaction::AccountInputs) of
+/// the account with [`Self::account_id`] to the transaction, including the storage slot with
+/// [`Self::slot_name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountRequirement {
+    account_id: AccountId,
+    slot_name: StorageSlotName,
+}
+
+impl ForeignAccountRequirement {
+    /// Returns the ID of the foreign account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the name of the storage slot read from the foreign account.
+    pub fn slot_name(&self) -> &StorageSlotName {
+        &self.slot_name
+    }
+}
+
+// CONDITIONAL NOTE
+// ================================================================================================
+
+/// The conditional note which transfers its assets to a target account only if a value in the
+/// storage of a foreign oracle account satisfies an [`OracleCondition`].
+///
+/// The note storage is laid out as follows:
+/// - `[0..4]`: the MAST root of the oracle getter procedure.
+/// - `[4..8]`: the threshold word.
+/// - `[8..10]`: the target account ID, encoded as `[account_id_suffix, account_id_prefix]`.
+/// - `[10..12]`: the oracle account ID, encoded as `[account_id_suffix, account_id_prefix]`.
+/// - `[12..14]`: the oracle slot ID, encoded as `[slot_id_suffix, slot_id_prefix]`.
+/// - `[14]`: the [`ConditionOperator`].
+pub struct ConditionalNote;
+
+impl ConditionalNote {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Expected number of storage items of the conditional note.
+    pub const NUM_STORAGE_ITEMS: usize = 15;
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the script of the conditional note.
+    pub fn script() -> NoteScript {
+        CONDITIONAL_SCRIPT.clone()
+    }
+
+    /// Returns the conditional note script root.
+    pub fn script_root() -> Word {
+        CONDITIONAL_SCRIPT.root()
+    }
+
+    // BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Generates a conditional note which can be consumed by the `target` account once the
+    /// oracle value satisfies the provided `condition`.
+    ///
+    /// Together with the note, the foreign account requirement of the condition is returned, which
+    /// describes the oracle account a consuming transaction has to provide as a foreign account.
+    ///
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). The returned note's
+    /// tag is set to the target's account ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided assets cannot be added to a note.
+    pub fn create<'rng>(
+        sender: AccountId,
+        target: AccountId,
+        condition: OracleCondition,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        attachment: NoteAttachment,
+        serial_num: impl Into<SerialNumSource<'rng>>,
+    ) -> Result<(Note, ForeignAccountRequirement), NoteError> {
+        let serial_num = serial_num.into().into_serial_num();
+        let recipient = Self::build_recipient(target, &condition, serial_num)?;
+
+        let tag = NoteTag::with_account_target(target);
+
+        let metadata = NoteMetadata::new(sender, note_type, tag).with_attachment(attachment);
+        let vault = NoteAssets::new(assets)?;
+
+        Ok((Note::new(vault, metadata, recipient), condition.foreign_account_requirement()))
+    }
+
+    /// Creates a [NoteRecipient] for the conditional note.
+    ///
+    /// Notes created with this recipient will be conditional notes consumable by the specified
+    /// target account once the oracle value satisfies the `condition`.
+    pub fn build_recipient(
+        target: AccountId,
+        condition: &OracleCondition,
+        serial_num: Word,
+    ) -> Result<NoteRecipient, NoteError> {
+        let slot_id = condition.slot_name().id();
+
+        let mut storage = Vec::with_capacity(Self::NUM_STORAGE_ITEMS);
+        storage.extend_from_slice(condition.oracle_proc_root().as_elements());
+        storage.extend_from_slice(condition.threshold().as_elements());
+        storage.extend_from_slice(&[
+            target.suffix(),
+            target.prefix().as_felt(),
+            condition.oracle_account_id().suffix(),
+            condition.oracle_account_id().prefix().as_felt(),
+            slot_id.suffix(),
+            slot_id.prefix(),
+            condition.operator().into(),
+        ]);
+
+        let note_script = Self::script();
+        let note_storage = NoteStorage::new(storage)?;
+
+        Ok(NoteRecipient::new(serial_num, note_script, note_storage))
+    }
+}
@@ -0,0 +1,37 @@
+use miden_protocol::account::AccountId;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::note::Note;
+
+use crate::note::NetworkAccountTarget;
+
+// NOTE EXTENSION TRAIT
+// ================================================================================================
+
+/// An extension for [`Note`] providing checks based on the standard note conventions.
+pub trait NoteExt {
+    /// Returns `true` if the note looks like it is consumable by the account with the given
+    /// `account_id` at `block_num`.
+    ///
+    /// This is a cheap, best-effort heuristic meant to pre-filter notes, e.g. when a wallet scans
+    /// for notes it may be interested in. It is not a substitute for executing the note, e.g.
+    /// with the `NoteConsumptionChecker`. It checks that:
+    /// - if the note has a [`NetworkAccountTarget`] attachment, its target is the account and its
+    ///   execution hint does not rule out consumption at `block_num`.
+    /// - otherwise, the note tag is an account target tag matching the account (see
+    ///   [`NoteTag::matches`](miden_protocol::note::NoteTag::matches)). Notes with use case tags
+    ///   are thus generally reported as not consumable.
+    fn is_consumable_by(&self, account_id: AccountId, block_num: BlockNumber) -> bool;
+}
+
+impl NoteExt for Note {
+    fn is_consumable_by(&self, account_id: AccountId, block_num: BlockNumber) -> bool {
+        match NetworkAccountTarget::try_from(self.metadata().attachment()) {
+            Ok(network_target) => {
+                // If the execution hint does not determine consumability, we cannot rule it out.
+                network_target.target_id() == account_id
+                    && network_target.execution_hint().can_be_consumed(block_num).unwrap_or(true)
+            },
+            Err(_) => self.metadata().tag().matches(account_id),
+        }
+    }
+}
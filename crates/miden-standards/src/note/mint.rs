@@ -2,7 +2,6 @@ use alloc::vec::Vec;
 
 use miden_protocol::account::AccountId;
 use miden_protocol::assembly::Library;
-use miden_protocol::crypto::rand::FeltRng;
 use miden_protocol::errors::NoteError;
 use miden_protocol::note::{
     Note,
@@ -19,6 +18,8 @@ use miden_protocol::utils::Deserializable;
 use miden_protocol::utils::sync::LazyLock;
 use miden_protocol::{Felt, MAX_NOTE_STORAGE_ITEMS, Word};
 
+use super::SerialNumSource;
+
 // NOTE SCRIPT
 // ================================================================================================
 
@@ -69,27 +70,28 @@ impl MintNote {
     /// MINT notes are always PUBLIC (for network execution). Output notes can be either PRIVATE
     /// or PUBLIC depending on the MintNoteStorage variant used.
     ///
-    /// The passed-in `rng` is used to generate a serial number for the note. The note's tag
-    /// is automatically set to the faucet's account ID for proper routing.
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). The note's tag is
+    /// automatically set to the faucet's account ID for proper routing.
     ///
     /// # Parameters
     /// - `faucet_id`: The account ID of the network faucet that will mint the assets
     /// - `sender`: The account ID of the note creator (must be the faucet owner)
     /// - `mint_storage`: The storage configuration specifying private or public output mode
     /// - `attachment`: The [`NoteAttachment`] of the MINT note
-    /// - `rng`: Random number generator for creating the serial number
+    /// - `serial_num`: The source of the serial number, see [`SerialNumSource`]
     ///
     /// # Errors
     /// Returns an error if note creation fails.
-    pub fn create<R: FeltRng>(
+    pub fn create<'rng>(
         faucet_id: AccountId,
         sender: AccountId,
         mint_storage: MintNoteStorage,
         attachment: NoteAttachment,
-        rng: &mut R,
+        serial_num: impl Into<SerialNumSource<'rng>>,
     ) -> Result<Note, NoteError> {
         let note_script = Self::script();
-        let serial_num = rng.draw_word();
+        let serial_num = serial_num.into().into_serial_num();
 
         // MINT notes are always public for network execution
         let note_type = NoteType::Public;
@@ -1,5 +1,6 @@
 use alloc::boxed::Box;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::error::Error;
 
 use miden_protocol::account::AccountId;
@@ -14,6 +15,14 @@ use crate::account::wallets::BasicWallet;
 mod burn;
 pub use burn::BurnNote;
 
+mod conditional;
+pub use conditional::{
+    ConditionOperator,
+    ConditionalNote,
+    ForeignAccountRequirement,
+    OracleCondition,
+};
+
 mod mint;
 pub use mint::{MintNote, MintNoteStorage};
 
@@ -21,10 +30,22 @@ mod p2id;
 pub use p2id::P2idNote;
 
 mod p2ide;
-pub use p2ide::P2ideNote;
+pub use p2ide::{P2ideNote, P2ideNoteStorage};
+
+mod p2m;
+pub use p2m::P2mNote;
 
 mod swap;
-pub use swap::SwapNote;
+pub use swap::{SwapNote, SwapSerialNums};
+
+mod script_roots;
+pub use script_roots::{KnownScript, NoteScriptRoot, ScriptRoots};
+
+mod serial_num;
+pub use serial_num::SerialNumSource;
+
+mod extension;
+pub use extension::NoteExt;
 
 mod network_account_target;
 pub use network_account_target::{NetworkAccountTarget, NetworkAccountTargetError};
@@ -36,12 +57,15 @@ pub use standard_note_attachment::StandardNoteAttachment;
 // ================================================================================================
 
 /// The enum holding the types of standard notes provided by `miden-standards`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StandardNote {
     P2ID,
     P2IDE,
+    P2M,
     SWAP,
     MINT,
     BURN,
+    CONDITIONAL,
 }
 
 impl StandardNote {
@@ -59,6 +83,9 @@ impl StandardNote {
         if note_script_root == P2ideNote::script_root() {
             return Some(Self::P2IDE);
         }
+        if note_script_root == P2mNote::script_root() {
+            return Some(Self::P2M);
+        }
         if note_script_root == SwapNote::script_root() {
             return Some(Self::SWAP);
         }
@@ -68,6 +95,9 @@ impl StandardNote {
         if note_script_root == BurnNote::script_root() {
             return Some(Self::BURN);
         }
+        if note_script_root == ConditionalNote::script_root() {
+            return Some(Self::CONDITIONAL);
+        }
 
         None
     }
@@ -76,13 +106,18 @@ impl StandardNote {
     // --------------------------------------------------------------------------------------------
 
     /// Returns the expected number of storage items of the active note.
+    ///
+    /// For notes with a variable number of storage items, this is the minimum number of storage
+    /// items, e.g. the number of storage items of a P2M note with a single target.
     pub fn expected_num_storage_items(&self) -> usize {
         match self {
             Self::P2ID => P2idNote::NUM_STORAGE_ITEMS,
             Self::P2IDE => P2ideNote::NUM_STORAGE_ITEMS,
+            Self::P2M => P2mNote::NUM_STORAGE_ITEMS_PER_TARGET,
             Self::SWAP => SwapNote::NUM_STORAGE_ITEMS,
             Self::MINT => MintNote::NUM_STORAGE_ITEMS_PRIVATE,
             Self::BURN => BurnNote::NUM_STORAGE_ITEMS,
+            Self::CONDITIONAL => ConditionalNote::NUM_STORAGE_ITEMS,
         }
     }
 
@@ -91,9 +126,11 @@ impl StandardNote {
         match self {
             Self::P2ID => P2idNote::script(),
             Self::P2IDE => P2ideNote::script(),
+            Self::P2M => P2mNote::script(),
             Self::SWAP => SwapNote::script(),
             Self::MINT => MintNote::script(),
             Self::BURN => BurnNote::script(),
+            Self::CONDITIONAL => ConditionalNote::script(),
         }
     }
 
@@ -102,9 +139,11 @@ impl StandardNote {
         match self {
             Self::P2ID => P2idNote::script_root(),
             Self::P2IDE => P2ideNote::script_root(),
+            Self::P2M => P2mNote::script_root(),
             Self::SWAP => SwapNote::script_root(),
             Self::MINT => MintNote::script_root(),
             Self::BURN => BurnNote::script_root(),
+            Self::CONDITIONAL => ConditionalNote::script_root(),
         }
     }
 
@@ -117,9 +156,9 @@ impl StandardNote {
 
         let interface_proc_digests = account_interface.get_procedure_digests();
         match self {
-            Self::P2ID | &Self::P2IDE => {
-                // To consume P2ID and P2IDE notes, the `receive_asset` procedure must be present in
-                // the provided account interface.
+            Self::P2ID | &Self::P2IDE | Self::P2M | Self::CONDITIONAL => {
+                // To consume P2ID, P2IDE, P2M and CONDITIONAL notes, the `receive_asset`
+                // procedure must be present in the provided account interface.
                 interface_proc_digests.contains(&BasicWallet::receive_asset_digest())
             },
             Self::SWAP => {
@@ -181,6 +220,9 @@ impl StandardNote {
     ///     - check that the target account is either the receiver account or the sender account.
     ///     - check that depending on whether the target account is sender or receiver, it could be
     ///       either consumed, or consumed after timelock height, or consumed after reclaim height.
+    /// - for `P2M` note:
+    ///     - check that note storage has a valid number of values.
+    ///     - check that the target account is one of the accounts provided by the note storage.
     fn is_consumable_inner(
         &self,
         note: &Note,
@@ -237,6 +279,17 @@ impl StandardNote {
                     )))
                 }
             },
+            StandardNote::P2M => {
+                let input_account_ids = parse_p2m_storage(note.storage().items())?;
+
+                if input_account_ids.contains(&target_account_id) {
+                    Ok(Some(NoteConsumptionStatus::ConsumableWithAuthorization))
+                } else {
+                    Ok(Some(NoteConsumptionStatus::NeverConsumable(
+                        "none of the account IDs provided to the P2M note storage match the target account ID".into(),
+                    )))
+                }
+            },
 
             // the consumption status of any other note cannot be determined by the static analysis,
             // further checks are necessary.
@@ -301,6 +354,34 @@ fn parse_p2ide_storage(
     Ok((receiver_account_id, reclaim_height, timelock_height))
 }
 
+/// Returns the target account IDs parsed from the provided P2M note storage.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - the length of the provided note storage array is not a multiple of the number of storage items
+///   per target, or specifies no or more than [`P2mNote::MAX_TARGETS`] targets.
+/// - any pair of note storage items does not form a valid account ID.
+fn parse_p2m_storage(note_storage: &[Felt]) -> Result<Vec<AccountId>, StaticAnalysisError> {
+    let num_targets = note_storage.len() / P2mNote::NUM_STORAGE_ITEMS_PER_TARGET;
+    if !note_storage.len().is_multiple_of(P2mNote::NUM_STORAGE_ITEMS_PER_TARGET)
+        || num_targets == 0
+        || num_targets > P2mNote::MAX_TARGETS
+    {
+        return Err(StaticAnalysisError::new(format!(
+            "P2M note should have {} storage items for each of its 1 to {} targets, but {} were provided",
+            P2mNote::NUM_STORAGE_ITEMS_PER_TARGET,
+            P2mNote::MAX_TARGETS,
+            note_storage.len()
+        )));
+    }
+
+    note_storage
+        .chunks_exact(P2mNote::NUM_STORAGE_ITEMS_PER_TARGET)
+        .map(try_read_account_id_from_storage)
+        .collect()
+}
+
 /// Reads the account ID from the first two note storage values.
 ///
 /// Returns None if the note storage values used to construct the account ID are invalid.
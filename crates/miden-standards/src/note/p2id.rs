@@ -4,7 +4,6 @@ use miden_protocol::Word;
 use miden_protocol::account::AccountId;
 use miden_protocol::assembly::Library;
 use miden_protocol::asset::Asset;
-use miden_protocol::crypto::rand::FeltRng;
 use miden_protocol::errors::NoteError;
 use miden_protocol::note::{
     Note,
@@ -20,6 +19,8 @@ use miden_protocol::note::{
 use miden_protocol::utils::Deserializable;
 use miden_protocol::utils::sync::LazyLock;
 
+use super::SerialNumSource;
+
 // NOTE SCRIPT
 // ================================================================================================
 
@@ -64,20 +65,21 @@ impl P2idNote {
     /// This script enables the transfer of assets from the `sender` account to the `target` account
     /// by specifying the target's account ID.
     ///
-    /// The passed-in `rng` is used to generate a serial number for the note. The returned note's
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). The returned note's
     /// tag is set to the target's account ID.
     ///
     /// # Errors
     /// Returns an error if deserialization or compilation of the `P2ID` script fails.
-    pub fn create<R: FeltRng>(
+    pub fn create<'rng>(
         sender: AccountId,
         target: AccountId,
         assets: Vec<Asset>,
         note_type: NoteType,
         attachment: NoteAttachment,
-        rng: &mut R,
+        serial_num: impl Into<SerialNumSource<'rng>>,
     ) -> Result<Note, NoteError> {
-        let serial_num = rng.draw_word();
+        let serial_num = serial_num.into().into_serial_num();
         let recipient = Self::build_recipient(target, serial_num)?;
 
         let tag = NoteTag::with_account_target(target);
@@ -4,7 +4,6 @@ use miden_protocol::account::AccountId;
 use miden_protocol::assembly::Library;
 use miden_protocol::asset::Asset;
 use miden_protocol::block::BlockNumber;
-use miden_protocol::crypto::rand::FeltRng;
 use miden_protocol::errors::NoteError;
 use miden_protocol::note::{
     Note,
@@ -21,6 +20,8 @@ use miden_protocol::utils::Deserializable;
 use miden_protocol::utils::sync::LazyLock;
 use miden_protocol::{Felt, Word};
 
+use super::SerialNumSource;
+
 // NOTE SCRIPT
 // ================================================================================================
 
@@ -57,6 +58,25 @@ impl P2ideNote {
         P2IDE_SCRIPT.root()
     }
 
+    /// Returns the [`P2ideNoteStorage`] of the provided note if it is a P2IDE note.
+    ///
+    /// Returns `None` if the note's script is not the P2IDE script or if its storage is not
+    /// well-formed P2IDE storage.
+    pub fn parse_storage(note: &Note) -> Option<P2ideNoteStorage> {
+        if note.script().root() != Self::script_root() {
+            return None;
+        }
+
+        let (target, reclaim_height, timelock_height) =
+            super::parse_p2ide_storage(note.storage().items()).ok()?;
+
+        Some(P2ideNoteStorage {
+            target,
+            reclaim_height: (reclaim_height != 0).then(|| BlockNumber::from(reclaim_height)),
+            timelock_height: (timelock_height != 0).then(|| BlockNumber::from(timelock_height)),
+        })
+    }
+
     // BUILDERS
     // --------------------------------------------------------------------------------------------
 
@@ -68,12 +88,13 @@ impl P2ideNote {
     /// sender to reclaiming the assets if the note has not been consumed by the target within the
     /// specified timeframe and the optional possibility to add a timelock to the asset transfer.
     ///
-    /// The passed-in `rng` is used to generate a serial number for the note. The returned note's
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). The returned note's
     /// tag is set to the target's account ID.
     ///
     /// # Errors
     /// Returns an error if deserialization or compilation of the `P2ID` script fails.
-    pub fn create<R: FeltRng>(
+    pub fn create<'rng>(
         sender: AccountId,
         target: AccountId,
         assets: Vec<Asset>,
@@ -81,9 +102,9 @@ impl P2ideNote {
         timelock_height: Option<BlockNumber>,
         note_type: NoteType,
         attachment: NoteAttachment,
-        rng: &mut R,
+        serial_num: impl Into<SerialNumSource<'rng>>,
     ) -> Result<Note, NoteError> {
-        let serial_num = rng.draw_word();
+        let serial_num = serial_num.into().into_serial_num();
         let recipient = Self::build_recipient(target, reclaim_height, timelock_height, serial_num)?;
         let tag = NoteTag::with_account_target(target);
 
@@ -118,3 +139,33 @@ impl P2ideNote {
         Ok(NoteRecipient::new(serial_num, note_script, note_storage))
     }
 }
+
+// P2IDE NOTE STORAGE
+// ================================================================================================
+
+/// The data stored in the storage of a P2IDE note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2ideNoteStorage {
+    target: AccountId,
+    reclaim_height: Option<BlockNumber>,
+    timelock_height: Option<BlockNumber>,
+}
+
+impl P2ideNoteStorage {
+    /// Returns the ID of the account the note is addressed to.
+    pub fn target(&self) -> AccountId {
+        self.target
+    }
+
+    /// Returns the block height from which the sender can reclaim the note, or `None` if the note
+    /// is not reclaimable.
+    pub fn reclaim_height(&self) -> Option<BlockNumber> {
+        self.reclaim_height
+    }
+
+    /// Returns the block height until which the note is timelocked, or `None` if the note is not
+    /// timelocked.
+    pub fn timelock_height(&self) -> Option<BlockNumber> {
+        self.timelock_height
+    }
+}
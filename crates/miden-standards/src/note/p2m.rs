@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use miden_protocol::Word;
+use miden_protocol::account::AccountId;
+use miden_protocol::assembly::Library;
+use miden_protocol::asset::Asset;
+use miden_protocol::errors::NoteError;
+use miden_protocol::note::{
+    Note,
+    NoteAssets,
+    NoteAttachment,
+    NoteMetadata,
+    NoteRecipient,
+    NoteScript,
+    NoteStorage,
+    NoteTag,
+    NoteType,
+};
+use miden_protocol::utils::Deserializable;
+use miden_protocol::utils::sync::LazyLock;
+
+use super::SerialNumSource;
+
+// NOTE SCRIPT
+// ================================================================================================
+
+// Initialize the P2M note script only once
+static P2M_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/p2m.masl"));
+    let library = Library::read_from_bytes(bytes).expect("Shipped P2M library is well-formed");
+    NoteScript::from_library(&library).expect("P2M library contains note script procedure")
+});
+
+// P2M NOTE
+// ================================================================================================
+
+/// The P2M (Pay-to-many) note which transfers its assets to whichever of a set of target
+/// accounts consumes it first.
+///
+/// The note storage contains the IDs of the target accounts, each encoded as
+/// `[account_id_suffix, account_id_prefix]`.
+pub struct P2mNote;
+
+impl P2mNote {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of target accounts of a P2M note.
+    pub const MAX_TARGETS: usize = 8;
+
+    /// Number of storage items of the P2M note per target account.
+    pub const NUM_STORAGE_ITEMS_PER_TARGET: usize = 2;
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the script of the P2M (Pay-to-many) note.
+    pub fn script() -> NoteScript {
+        P2M_SCRIPT.clone()
+    }
+
+    /// Returns the P2M (Pay-to-many) note script root.
+    pub fn script_root() -> Word {
+        P2M_SCRIPT.root()
+    }
+
+    // BUILDERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Generates a P2M note - Pay-to-many note.
+    ///
+    /// This script enables the transfer of assets from the `sender` account to any one of the
+    /// `targets` accounts, e.g. a refund which can be claimed by either of two co-signers.
+    ///
+    /// The serial number of the note is taken from `serial_num`, which is either a random number
+    /// generator or an explicit serial number (see [`SerialNumSource`]). Since a tag can only
+    /// target a single account, the returned note's tag is set to the first target's account ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `targets` is empty or contains more than [`Self::MAX_TARGETS`] account IDs.
+    /// - the provided assets cannot be added to a note.
+    pub fn create<'rng>(
+        sender: AccountId,
+        targets: Vec<AccountId>,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+        attachment: NoteAttachment,
+        serial_num: impl Into<SerialNumSource<'rng>>,
+    ) -> Result<Note, NoteError> {
+        let serial_num = serial_num.into().into_serial_num();
+        let recipient = Self::build_recipient(&targets, serial_num)?;
+
+        let tag = NoteTag::with_account_target(targets[0]);
+
+        let metadata = NoteMetadata::new(sender, note_type, tag).with_attachment(attachment);
+        let vault = NoteAssets::new(assets)?;
+
+        Ok(Note::new(vault, metadata, recipient))
+    }
+
+    /// Creates a [NoteRecipient] for the P2M note.
+    ///
+    /// Notes created with this recipient will be P2M notes consumable by any of the specified
+    /// target accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `targets` is empty or contains more than [`Self::MAX_TARGETS`] account
+    /// IDs.
+    pub fn build_recipient(
+        targets: &[AccountId],
+        serial_num: Word,
+    ) -> Result<NoteRecipient, NoteError> {
+        if targets.is_empty() || targets.len() > Self::MAX_TARGETS {
+            return Err(NoteError::InvalidNumberOfTargetAccounts {
+                num_targets: targets.len(),
+                max: Self::MAX_TARGETS,
+            });
+        }
+
+        let note_script = Self::script();
+        let note_storage = NoteStorage::new(
+            targets
+                .iter()
+                .flat_map(|target| [target.suffix(), target.prefix().as_felt()])
+                .collect(),
+        )?;
+
+        Ok(NoteRecipient::new(serial_num, note_script, note_storage))
+    }
+}
@@ -0,0 +1,235 @@
+use core::fmt;
+
+use miden_protocol::{Word, word};
+
+use super::StandardNote;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The version of `miden-standards` from which the shipped note scripts were compiled.
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The script roots of the standard notes shipped with this version of `miden-standards`.
+const CURRENT_SCRIPTS: [(StandardNote, NoteScriptRoot); 7] = [
+    (StandardNote::P2ID, ScriptRoots::P2ID),
+    (StandardNote::P2IDE, ScriptRoots::P2IDE),
+    (StandardNote::P2M, ScriptRoots::P2M),
+    (StandardNote::SWAP, ScriptRoots::SWAP),
+    (StandardNote::MINT, ScriptRoots::MINT),
+    (StandardNote::BURN, ScriptRoots::BURN),
+    (StandardNote::CONDITIONAL, ScriptRoots::CONDITIONAL),
+];
+
+/// The script roots of standard notes shipped by prior releases of `miden-standards`, which are
+/// no longer the roots of the current scripts.
+///
+/// Whenever a release changes the MASM of a standard note script, the root of the script of the
+/// previous release must be added here, so that notes created before the upgrade are still
+/// recognized. Each added root must be checked in the tests of this module against the script of
+/// the release it was taken from, so that a wrong root cannot silently be added to the table.
+const PRIOR_RELEASE_SCRIPTS: &[KnownScript] = &[];
+
+// NOTE SCRIPT ROOT
+// ================================================================================================
+
+/// The root of a note script, i.e. the MAST root of the script's entrypoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoteScriptRoot(Word);
+
+impl NoteScriptRoot {
+    /// Returns a new [`NoteScriptRoot`] from the provided word.
+    pub const fn new(root: Word) -> Self {
+        Self(root)
+    }
+
+    /// Returns the underlying word of the script root.
+    pub fn as_word(&self) -> Word {
+        self.0
+    }
+}
+
+impl From<Word> for NoteScriptRoot {
+    fn from(root: Word) -> Self {
+        Self(root)
+    }
+}
+
+impl From<NoteScriptRoot> for Word {
+    fn from(root: NoteScriptRoot) -> Self {
+        root.0
+    }
+}
+
+impl fmt::Display for NoteScriptRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// KNOWN SCRIPT
+// ================================================================================================
+
+/// A standard note script recognized by [`ScriptRoots::lookup`], together with the version of
+/// `miden-standards` it was shipped with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownScript {
+    note: StandardNote,
+    root: NoteScriptRoot,
+    version: &'static str,
+}
+
+impl KnownScript {
+    /// Returns the standard note the script belongs to.
+    pub fn note(&self) -> StandardNote {
+        self.note
+    }
+
+    /// Returns the root of the script.
+    pub fn root(&self) -> NoteScriptRoot {
+        self.root
+    }
+
+    /// Returns the version of `miden-standards` the script was shipped with.
+    ///
+    /// For scripts of prior releases, this is the last version which shipped the script.
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Returns `true` if the script is the one shipped with this version of `miden-standards`.
+    pub fn is_current(&self) -> bool {
+        self.version == CURRENT_VERSION
+    }
+}
+
+// SCRIPT ROOTS
+// ================================================================================================
+
+/// The registry of the script roots of the standard notes.
+///
+/// The registry contains the roots of the scripts shipped with this version of `miden-standards`
+/// as well as the roots of the scripts shipped by prior releases. This allows recognizing standard
+/// notes by their script root, including notes which were created before an upgrade of their
+/// script.
+///
+/// The current roots are hardcoded so that they can be used in `const` contexts. They must be
+/// updated whenever the MASM of a standard note script changes, which is checked against the
+/// compiled scripts in the tests of this module.
+pub struct ScriptRoots;
+
+impl ScriptRoots {
+    /// The version of `miden-standards` from which the current scripts were compiled.
+    pub const VERSION: &'static str = CURRENT_VERSION;
+
+    /// The root of the current P2ID note script.
+    pub const P2ID: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0x7136e5ece64f831eef6db4a9269e2e12c659bcac3eb109558e988d8a78177ac6"
+    ));
+
+    /// The root of the current P2IDE note script.
+    pub const P2IDE: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0x94629acb26cc18b80fc26542a649c9679d80ecd5532c4d9c7903e527d8dd2ec7"
+    ));
+
+    /// The root of the current SWAP note script.
+    pub const SWAP: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0x0076036d133e56b32bd9ba10f5a9d5ace817bb1b26e3a1b84bdaec76066b0c10"
+    ));
+
+    /// The root of the current P2M note script.
+    pub const P2M: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0xdb9b98479e476f88d6d2079e4d0aae321e8540b79be4f46bff0f47004c9e2fbb"
+    ));
+
+    /// The root of the current MINT note script.
+    pub const MINT: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0xbfd3240fb18f0d2a8be04ed49838a07e5e972b1155d4ff00ccb1d144f7d24f14"
+    ));
+
+    /// The root of the current BURN note script.
+    pub const BURN: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0x8efe89964f614126e71ef98f02b9c19d8512e880d9ca9167cfdb313cd8bd7cb8"
+    ));
+
+    /// The root of the current CONDITIONAL note script.
+    pub const CONDITIONAL: NoteScriptRoot = NoteScriptRoot::new(word!(
+        "0x45aeb9c824c55461185cf0bd81c612a67cd5eaa04a54cc2d50b002a76981ebed"
+    ));
+
+    /// Returns an iterator over the scripts shipped with this version of `miden-standards`.
+    pub fn current() -> impl Iterator<Item = KnownScript> {
+        CURRENT_SCRIPTS.into_iter().map(|(note, root)| KnownScript {
+            note,
+            root,
+            version: CURRENT_VERSION,
+        })
+    }
+
+    /// Returns an iterator over the scripts shipped by prior releases of `miden-standards`.
+    pub fn prior_releases() -> impl Iterator<Item = KnownScript> {
+        PRIOR_RELEASE_SCRIPTS.iter().copied()
+    }
+
+    /// Returns the standard note script with the provided root, or `None` if the root is not the
+    /// root of a current or prior standard note script.
+    pub fn lookup(root: Word) -> Option<KnownScript> {
+        let root = NoteScriptRoot::new(root);
+        Self::current().chain(Self::prior_releases()).find(|script| script.root == root)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn current_roots_match_compiled_scripts() {
+        let expected = [
+            (ScriptRoots::P2ID, StandardNote::P2ID),
+            (ScriptRoots::P2IDE, StandardNote::P2IDE),
+            (ScriptRoots::SWAP, StandardNote::SWAP),
+            (ScriptRoots::P2M, StandardNote::P2M),
+            (ScriptRoots::MINT, StandardNote::MINT),
+            (ScriptRoots::BURN, StandardNote::BURN),
+            (ScriptRoots::CONDITIONAL, StandardNote::CONDITIONAL),
+        ];
+
+        for (root, note) in expected {
+            assert_eq!(root.as_word(), note.script().root());
+
+            let script = ScriptRoots::lookup(root.into()).expect("root should be known");
+            assert_eq!(script.note(), note);
+            assert_eq!(script.version(), ScriptRoots::VERSION);
+            assert!(script.is_current());
+        }
+
+        assert_eq!(ScriptRoots::current().count(), expected.len());
+        assert!(ScriptRoots::lookup(Word::empty()).is_none());
+    }
+
+    #[test]
+    fn prior_release_roots_are_unique_and_outdated() {
+        let current: BTreeSet<_> = ScriptRoots::current().map(|script| script.root()).collect();
+        assert_eq!(current.len(), CURRENT_SCRIPTS.len(), "current script roots must be unique");
+
+        let mut prior = BTreeSet::new();
+        for script in ScriptRoots::prior_releases() {
+            assert!(!script.is_current(), "prior release script of {:?} is current", script.note());
+            assert!(
+                !current.contains(&script.root()),
+                "prior release root {} is the root of a current script",
+                script.root()
+            );
+            assert!(prior.insert(script.root()), "duplicate prior release root {}", script.root());
+
+            let found = ScriptRoots::lookup(script.root().into()).expect("root should be known");
+            assert_eq!(found, script);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use miden_protocol::Word;
+use miden_protocol::crypto::rand::FeltRng;
+
+// SERIAL NUMBER SOURCE
+// ================================================================================================
+
+/// The source of the serial number of a note created by one of the standard note constructors.
+///
+/// The constructors accept anything convertible into a [`SerialNumSource`], so either a mutable
+/// reference to a [`FeltRng`] or an explicit serial number can be passed, e.g. one derived via
+/// [`derive_note_serial`](miden_protocol::crypto::rand::derive_note_serial) to make the note
+/// recoverable from a wallet seed.
+pub enum SerialNumSource<'rng> {
+    /// The serial number is drawn from the provided random number generator.
+    Rng(&'rng mut dyn FeltRng),
+    /// The provided serial number is used as-is.
+    Explicit(Word),
+}
+
+impl SerialNumSource<'_> {
+    /// Returns the serial number, drawing it from the random number generator if necessary.
+    pub fn into_serial_num(self) -> Word {
+        match self {
+            Self::Rng(rng) => rng.draw_word(),
+            Self::Explicit(serial_num) => serial_num,
+        }
+    }
+}
+
+impl<'rng, R: FeltRng> From<&'rng mut R> for SerialNumSource<'rng> {
+    fn from(rng: &'rng mut R) -> Self {
+        Self::Rng(rng)
+    }
+}
+
+impl From<Word> for SerialNumSource<'_> {
+    fn from(serial_num: Word) -> Self {
+        Self::Explicit(serial_num)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_protocol::Word;
+    use miden_protocol::account::AccountId;
+    use miden_protocol::crypto::rand::{FeltRng, RpoRandomCoin, derive_note_serial};
+    use miden_protocol::note::{NoteAttachment, NoteId, NoteType};
+    use miden_protocol::testing::account_id::{
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_SENDER,
+    };
+
+    use super::SerialNumSource;
+    use crate::note::P2idNote;
+
+    fn p2id_note_id<'rng>(serial_num: impl Into<SerialNumSource<'rng>>) -> NoteId {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+        P2idNote::create(
+            sender,
+            target,
+            vec![],
+            NoteType::Private,
+            NoteAttachment::default(),
+            serial_num,
+        )
+        .unwrap()
+        .id()
+    }
+
+    #[test]
+    fn derived_serial_num_yields_reproducible_note_id() {
+        let seed = Word::from([1, 2, 3, 4u32]);
+
+        let note_id = p2id_note_id(derive_note_serial(seed, 7));
+        assert_eq!(note_id, p2id_note_id(derive_note_serial(seed, 7)));
+        assert_ne!(note_id, p2id_note_id(derive_note_serial(seed, 8)));
+    }
+
+    #[test]
+    fn rng_and_explicit_serial_num_are_equivalent() {
+        let mut rng = RpoRandomCoin::new(Word::from([5, 6, 7, 8u32]));
+        // the coin is `Copy`, so drawing from the copy does not advance the original
+        let mut rng_copy = rng;
+        let serial_num = rng_copy.draw_word();
+
+        assert_eq!(p2id_note_id(&mut rng), p2id_note_id(serial_num));
+    }
+}
@@ -33,6 +33,44 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::from_library(&library).expect("SWAP library contains note script procedure")
 });
 
+// SWAP SERIAL NUMBERS
+// ================================================================================================
+
+/// The source of the serial numbers of a SWAP note and its payback note.
+///
+/// Either a mutable reference to a [`FeltRng`] or a `(swap_serial_num, payback_serial_num)` pair
+/// can be passed to [`SwapNote::create`].
+///
+/// The payback serial number must not be derivable from the SWAP note's serial number, since the
+/// latter is revealed to the consumer of the SWAP note (and to everyone if the note is public).
+/// Together with the known payback script, storage and asset, it would allow computing the payback
+/// note's nullifier and thus observing when the sender consumes it. Wallets that derive serial
+/// numbers from a seed should therefore use distinct counters for both notes, e.g.
+/// `derive_note_serial(seed, counter)` and `derive_note_serial(seed, counter + 1)`.
+pub enum SwapSerialNums<'rng> {
+    /// Both serial numbers are drawn from the provided random number generator.
+    Rng(&'rng mut dyn FeltRng),
+    /// The provided serial numbers are used as-is.
+    Explicit {
+        /// The serial number of the SWAP note.
+        swap_serial_num: Word,
+        /// The serial number of the payback note.
+        payback_serial_num: Word,
+    },
+}
+
+impl<'rng, R: FeltRng> From<&'rng mut R> for SwapSerialNums<'rng> {
+    fn from(rng: &'rng mut R) -> Self {
+        Self::Rng(rng)
+    }
+}
+
+impl From<(Word, Word)> for SwapSerialNums<'_> {
+    fn from((swap_serial_num, payback_serial_num): (Word, Word)) -> Self {
+        Self::Explicit { swap_serial_num, payback_serial_num }
+    }
+}
+
 // SWAP NOTE
 // ================================================================================================
 
@@ -69,9 +107,12 @@ impl SwapNote {
     /// that is willing to consume the note. The consumer will receive the `offered_asset` and
     /// will create a new P2ID note with `sender` as target, containing the `requested_asset`.
     ///
+    /// The serial numbers of the SWAP note and the payback note are taken from `serial_nums`,
+    /// either drawn from a random number generator or given explicitly (see [`SwapSerialNums`]).
+    ///
     /// # Errors
     /// Returns an error if deserialization or compilation of the `SWAP` script fails.
-    pub fn create<R: FeltRng>(
+    pub fn create<'rng>(
         sender: AccountId,
         offered_asset: Asset,
         requested_asset: Asset,
@@ -79,7 +120,7 @@ impl SwapNote {
         swap_note_attachment: NoteAttachment,
         payback_note_type: NoteType,
         payback_note_attachment: NoteAttachment,
-        rng: &mut R,
+        serial_nums: impl Into<SwapSerialNums<'rng>>,
     ) -> Result<(Note, NoteDetails), NoteError> {
         if requested_asset == offered_asset {
             return Err(NoteError::other("requested asset same as offered asset"));
@@ -87,7 +128,12 @@ impl SwapNote {
 
         let note_script = Self::script();
 
-        let payback_serial_num = rng.draw_word();
+        let (payback_serial_num, serial_num) = match serial_nums.into() {
+            SwapSerialNums::Rng(rng) => (rng.draw_word(), rng.draw_word()),
+            SwapSerialNums::Explicit { swap_serial_num, payback_serial_num } => {
+                (payback_serial_num, swap_serial_num)
+            },
+        };
         let payback_recipient = P2idNote::build_recipient(sender, payback_serial_num)?;
 
         let requested_asset_word: Word = requested_asset.into();
@@ -111,7 +157,6 @@ impl SwapNote {
 
         // build the tag for the SWAP use case
         let tag = Self::build_tag(swap_note_type, &offered_asset, &requested_asset);
-        let serial_num = rng.draw_word();
 
         // build the outgoing note
         let metadata =
@@ -174,6 +219,8 @@ impl SwapNote {
 mod tests {
     use miden_protocol::account::{AccountId, AccountIdVersion, AccountStorageMode, AccountType};
     use miden_protocol::asset::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use miden_protocol::crypto::rand::derive_note_serial;
+    use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
     use miden_protocol::{self};
 
     use super::*;
@@ -243,4 +290,38 @@ mod tests {
             "swap script root byte 1 should match with the lower two bits set to zero"
         );
     }
+
+    #[test]
+    fn explicit_payback_serial_num_is_independent_of_swap_serial_num() {
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let offered_asset = NonFungibleAsset::mock(&[5, 6, 7, 8]);
+        let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+
+        let seed = Word::from([1, 2, 3, 4u32]);
+        let swap_serial_num = derive_note_serial(seed, 10);
+        let payback_serial_num = derive_note_serial(seed, 11);
+
+        let (swap_note, payback_note) = SwapNote::create(
+            sender,
+            offered_asset,
+            requested_asset,
+            NoteType::Public,
+            NoteAttachment::default(),
+            NoteType::Private,
+            NoteAttachment::default(),
+            (swap_serial_num, payback_serial_num),
+        )
+        .unwrap();
+
+        assert_eq!(swap_note.recipient().serial_num(), swap_serial_num);
+        assert_eq!(payback_note.recipient().serial_num(), payback_serial_num);
+
+        // Knowing the SWAP note details must not allow deriving the payback serial number, and
+        // hence the payback nullifier, from the SWAP serial number.
+        let swap_serial_num = swap_note.recipient().serial_num();
+        for counter in 0..16 {
+            assert_ne!(derive_note_serial(swap_serial_num, counter), payback_serial_num);
+        }
+        assert_ne!(payback_serial_num, swap_serial_num);
+    }
 }
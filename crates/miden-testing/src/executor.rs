@@ -1,11 +1,53 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use anyhow::Context;
 #[cfg(test)]
 use miden_processor::DefaultHost;
 use miden_processor::fast::{ExecutionOutput, FastProcessor};
-use miden_processor::{AdviceInputs, AsyncHost, Program, StackInputs};
-#[cfg(test)]
-use miden_protocol::assembly::Assembler;
+use miden_processor::{
+    AdviceInputs,
+    AsyncHost,
+    ContextId,
+    FutureMaybeSend,
+    MastForest,
+    MastForestStore,
+    Program,
+    StackInputs,
+    StackOutputs,
+};
+use miden_protocol::account::{Account, AccountId, PartialAccount, StorageMapWitness};
+use miden_protocol::assembly::debuginfo::{SourceLanguage, Uri};
+use miden_protocol::assembly::{Assembler, DefaultSourceManager, SourceManagerSync};
+use miden_protocol::asset::{AssetVaultKey, AssetWitness};
+use miden_protocol::block::{BlockHeader, BlockNumber};
+use miden_protocol::note::NoteScript;
+use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE;
+use miden_protocol::transaction::{
+    AccountInputs,
+    PartialBlockchain,
+    TransactionArgs,
+    TransactionInputs,
+    TransactionKernel,
+};
+use miden_protocol::{Felt, Word};
+use miden_standards::code_builder::CodeBuilder;
+use miden_standards::testing::account_component::IncrNonceAuthComponent;
+use miden_standards::testing::mock_account::MockAccountExt;
+use miden_tx::auth::UnreachableAuth;
+use miden_tx::{
+    AccountProcedureIndexMap,
+    DataStore,
+    DataStoreError,
+    ScriptMastForestStore,
+    TransactionExecutorHost,
+    TransactionMastStore,
+};
 
-use crate::ExecError;
+use crate::mock_host::MockHost;
+use crate::{ExecError, MockChain};
 
 // CODE EXECUTOR
 // ================================================================================================
@@ -42,11 +84,6 @@ impl<H: AsyncHost> CodeExecutor<H> {
     #[cfg(test)]
     pub async fn run(self, code: &str) -> Result<ExecutionOutput, ExecError> {
         use alloc::borrow::ToOwned;
-        use alloc::sync::Arc;
-
-        use miden_protocol::assembly::debuginfo::{SourceLanguage, Uri};
-        use miden_protocol::assembly::{DefaultSourceManager, SourceManagerSync};
-        use miden_standards::code_builder::CodeBuilder;
 
         let source_manager: Arc<dyn SourceManagerSync> = Arc::new(DefaultSourceManager::default());
         let assembler: Assembler = CodeBuilder::with_kernel_library(source_manager.clone()).into();
@@ -107,3 +144,358 @@ impl CodeExecutor<DefaultHost> {
         CodeExecutor::new(host)
     }
 }
+
+// KERNEL PROCEDURE RUNNER
+// ================================================================================================
+
+/// Helper for executing a single procedure of the transaction kernel or the protocol library in
+/// isolation.
+///
+/// The runner executes the transaction prologue to install an account, which defaults to the
+/// account of [`TransactionContextBuilder::with_existing_mock_account`], pushes the provided stack
+/// inputs and then executes the procedure. This avoids writing a MASM program for tests that
+/// target a single procedure.
+///
+/// [`TransactionContextBuilder::with_existing_mock_account`]: crate::TransactionContextBuilder::with_existing_mock_account
+#[derive(Debug, Clone, Default)]
+pub struct KernelProcedureRunner {
+    stack: Vec<Felt>,
+    advice_inputs: AdviceInputs,
+    account: Option<PartialAccount>,
+}
+
+impl KernelProcedureRunner {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new runner with empty stack and advice inputs which runs against the mock account.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // BUILDER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the stack inputs of the procedure, where the first element is the top of the stack.
+    ///
+    /// Note that this is the reverse of the order in which a word is laid out on the stack, i.e.
+    /// the word `[a, b, c, d]` is passed as `&[d, c, b, a]`.
+    pub fn with_stack(mut self, stack: &[Felt]) -> Self {
+        self.stack = stack.to_vec();
+        self
+    }
+
+    /// Extends the advice inputs available to the procedure.
+    pub fn with_advice(mut self, advice_inputs: AdviceInputs) -> Self {
+        self.advice_inputs.extend(advice_inputs);
+        self
+    }
+
+    /// Sets the account which is installed by the transaction prologue before the procedure is
+    /// executed.
+    ///
+    /// Only the assets and storage map entries tracked by the partial account can be accessed by
+    /// the procedure. Use [`PartialAccount::from_full`] to make the entire account state
+    /// available.
+    pub fn with_account(mut self, account: PartialAccount) -> Self {
+        self.account = Some(account);
+        self
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the procedure with the provided fully-qualified path, e.g.
+    /// `$kernel::asset_vault::peek_balance` or `miden::protocol::active_account::get_balance`,
+    /// and returns its output.
+    ///
+    /// The stack is truncated to its minimum depth after the procedure returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the transaction inputs for the account cannot be built.
+    /// - the procedure does not exist, i.e. the program calling it fails to assemble.
+    /// - the execution of the procedure fails, in which case the error is an [`ExecError`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the procedure path is not of the form `<module path>::<procedure name>`.
+    pub async fn run(self, procedure: &str) -> anyhow::Result<KernelProcedureOutput> {
+        let (module_path, procedure_name) = procedure
+            .rsplit_once("::")
+            .expect("procedure path should contain a module path");
+        let module_name = module_path.rsplit("::").next().expect("rsplit yields at least one item");
+
+        // the prologue module is always imported to run the prologue
+        let module_import = if module_path == "$kernel::prologue" {
+            String::new()
+        } else {
+            format!("use {module_path}")
+        };
+        let push_inputs: String =
+            self.stack.iter().rev().map(|element| format!("push.{element}\n")).collect();
+
+        let code = format!(
+            "
+            use $kernel::prologue
+            {module_import}
+
+            begin
+                exec.prologue::prepare_transaction
+                {push_inputs}
+                exec.{module_name}::{procedure_name}
+                exec.::miden::core::sys::truncate_stack
+            end
+            "
+        );
+
+        let account = self.account.unwrap_or_else(|| {
+            PartialAccount::from_full(&Account::mock(
+                ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+                IncrNonceAuthComponent,
+            ))
+        });
+
+        // Initialize an ad-hoc mockchain to generate valid block header/MMR data.
+        let mut mock_chain = MockChain::new();
+        mock_chain.prove_next_block().context("failed to prove first block")?;
+        mock_chain.prove_next_block().context("failed to prove second block")?;
+
+        let mut tx_inputs = mock_chain
+            .get_transaction_inputs(account, &[], &[])
+            .context("failed to get transaction inputs from mock chain")?;
+
+        let mut tx_args = TransactionArgs::default();
+        tx_args.extend_advice_inputs(self.advice_inputs);
+        tx_inputs.set_tx_args(tx_args);
+
+        let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(&tx_inputs);
+
+        let source_manager: Arc<dyn SourceManagerSync> = Arc::new(DefaultSourceManager::default());
+        let assembler: Assembler =
+            CodeBuilder::with_mock_libraries_with_source_manager(source_manager.clone()).into();
+        let virtual_source_file =
+            source_manager.load(SourceLanguage::Masm, Uri::new("_kernel_procedure_runner"), code);
+        let program = assembler
+            .assemble_program(virtual_source_file)
+            .map_err(|err| anyhow::anyhow!("{err}"))
+            .context("procedure should exist and be callable")?;
+
+        let store = PartialAccountStore::new(tx_inputs);
+        store.mast_store.insert(TransactionKernel::library().mast_forest().clone());
+        store.mast_store.insert(program.mast_forest().clone());
+
+        let tx_inputs = &store.tx_inputs;
+        let account_procedure_idx_map = AccountProcedureIndexMap::new([tx_inputs.account().code()]);
+        let exec_host = TransactionExecutorHost::<'_, '_, _, UnreachableAuth>::new(
+            tx_inputs.account(),
+            tx_inputs.input_notes().clone(),
+            &store,
+            ScriptMastForestStore::default(),
+            account_procedure_idx_map,
+            None,
+            tx_inputs.block_header().block_num(),
+            0u64,
+            source_manager,
+        );
+        let mut mock_host = MockHost::new(exec_host);
+        mock_host.enable_lazy_loading();
+
+        let output = CodeExecutor::new(mock_host)
+            .stack_inputs(stack_inputs)
+            .extend_advice_inputs(advice_inputs.into_advice_inputs())
+            .execute_program(program)
+            .await?;
+
+        Ok(KernelProcedureOutput { output })
+    }
+}
+
+// PARTIAL ACCOUNT STORE
+// ================================================================================================
+
+/// A [`DataStore`] which serves the data of the native account from the [`PartialAccount`] of the
+/// transaction inputs, used by the [`KernelProcedureRunner`].
+struct PartialAccountStore {
+    tx_inputs: TransactionInputs,
+    mast_store: TransactionMastStore,
+}
+
+impl PartialAccountStore {
+    /// Returns a new store for the provided transaction inputs, with the code of the native account
+    /// loaded into its MAST store.
+    fn new(tx_inputs: TransactionInputs) -> Self {
+        let mast_store = TransactionMastStore::new();
+        mast_store.load_account_code(tx_inputs.account().code());
+
+        Self { tx_inputs, mast_store }
+    }
+
+    /// Returns the native account if it has the provided ID.
+    fn native_account(&self, account_id: AccountId) -> Result<&PartialAccount, DataStoreError> {
+        let account = self.tx_inputs.account();
+        if account.id() != account_id {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok(account)
+    }
+}
+
+impl DataStore for PartialAccountStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        _ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        async move {
+            let account = self.native_account(account_id)?.clone();
+            let block_header = self.tx_inputs.block_header().clone();
+            let blockchain = self.tx_inputs.blockchain().clone();
+
+            Ok((account, block_header, blockchain))
+        }
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        _ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        async move { Err(DataStoreError::AccountNotFound(foreign_account_id)) }
+    }
+
+    fn get_vault_asset_witnesses(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_keys: BTreeSet<AssetVaultKey>,
+    ) -> impl FutureMaybeSend<Result<Vec<AssetWitness>, DataStoreError>> {
+        async move {
+            let vault = self.native_account(account_id)?.vault();
+            if vault.root() != vault_root {
+                return Err(DataStoreError::other(format!(
+                    "native account {account_id} has vault root {} but {vault_root} was requested",
+                    vault.root()
+                )));
+            }
+
+            vault_keys
+                .into_iter()
+                .map(|vault_key| {
+                    vault.open(vault_key).map_err(|err| {
+                        DataStoreError::other_with_source(
+                            format!("failed to open vault key {vault_key} in partial vault"),
+                            err,
+                        )
+                    })
+                })
+                .collect()
+        }
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        async move {
+            let storage_map = self
+                .native_account(account_id)?
+                .storage()
+                .maps()
+                .find(|storage_map| storage_map.root() == map_root)
+                .ok_or_else(|| {
+                    DataStoreError::other(format!(
+                        "failed to find storage map with root {map_root} in partial storage"
+                    ))
+                })?;
+
+            storage_map.open(&map_key).map_err(|err| {
+                DataStoreError::other_with_source(
+                    format!("failed to open key {map_key} in partial storage map"),
+                    err,
+                )
+            })
+        }
+    }
+
+    fn get_note_script(
+        &self,
+        _script_root: Word,
+    ) -> impl FutureMaybeSend<Result<Option<NoteScript>, DataStoreError>> {
+        async move { Ok(None) }
+    }
+}
+
+impl MastForestStore for PartialAccountStore {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        self.mast_store.get(procedure_hash)
+    }
+}
+
+// KERNEL PROCEDURE OUTPUT
+// ================================================================================================
+
+/// The output of a procedure executed by a [`KernelProcedureRunner`].
+pub struct KernelProcedureOutput {
+    output: ExecutionOutput,
+}
+
+impl KernelProcedureOutput {
+    /// Returns the final stack of the execution.
+    pub fn stack(&self) -> &StackOutputs {
+        &self.output.stack
+    }
+
+    /// Returns the element at the provided index of the final stack, where index 0 is the top of
+    /// the stack.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get_stack_element(&self, index: usize) -> Felt {
+        *self.output.stack.get(index).expect("index must be in bounds")
+    }
+
+    /// Returns the word at the provided index of the final stack in big-endian (reversed) order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get_stack_word_be(&self, index: usize) -> Word {
+        self.output.stack.get_stack_word_be(index).expect("index must be in bounds")
+    }
+
+    /// Returns the `num_words` words of the transaction kernel memory starting at the provided
+    /// word-aligned address.
+    ///
+    /// Uninitialized memory is returned as [`Word::empty`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `addr` is not word-aligned.
+    pub fn read_kernel_memory(&self, addr: u32, num_words: u32) -> Vec<Word> {
+        let tx_kernel_context = ContextId::root();
+        let clk = 0u32;
+        let err_ctx = ();
+
+        (0..num_words)
+            .map(|word_idx| {
+                let word_addr = Felt::from(addr + word_idx * 4);
+                self.output
+                    .memory
+                    .read_word(tx_kernel_context, word_addr, clk.into(), &err_ctx)
+                    .expect("expected address to be word-aligned")
+            })
+            .collect()
+    }
+
+    /// Returns the underlying [`ExecutionOutput`].
+    pub fn into_execution_output(self) -> ExecutionOutput {
+        self.output
+    }
+}
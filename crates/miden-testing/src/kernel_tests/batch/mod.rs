@@ -1,2 +1,3 @@
 mod proposed_batch;
+mod proven_batch;
 mod proven_tx_builder;
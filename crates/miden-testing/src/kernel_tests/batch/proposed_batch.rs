@@ -54,7 +54,7 @@ fn setup_chain() -> TestSetup {
     TestSetup { chain, account1, account2, note1 }
 }
 
-fn generate_account(chain: &mut MockChainBuilder) -> Account {
+pub fn generate_account(chain: &mut MockChainBuilder) -> Account {
     let account_builder = Account::builder(rand::rng().random())
         .storage_mode(AccountStorageMode::Private)
         .with_component(MockAccountComponent::with_empty_slots());
@@ -108,6 +108,99 @@ fn note_created_and_consumed_in_same_batch() -> anyhow::Result<()> {
 
     assert_eq!(batch.input_notes().num_notes(), 0);
     assert_eq!(batch.output_notes().len(), 0);
+    assert_eq!(batch.erased_notes(), [note.header().clone()]);
+
+    Ok(())
+}
+
+/// Tests that an error is returned if a note created in the batch is consumed as an unauthenticated
+/// note with the same ID but a different commitment, i.e. different metadata.
+#[test]
+fn erased_note_commitment_mismatch() -> anyhow::Result<()> {
+    let TestSetup { mut chain, account1, account2, .. } = setup_chain();
+    let block1 = chain.block_header(1);
+    let block2 = chain.prove_next_block()?;
+
+    let note = mock_note(40);
+    let mismatched_note = Note::new(
+        note.assets().clone(),
+        mock_note(41).metadata().clone(),
+        note.recipient().clone(),
+    );
+    assert_eq!(note.id(), mismatched_note.id());
+
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Word::empty(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .output_notes(vec![OutputNote::Full(note.clone())])
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Word::empty(), account2.commitment())
+            .ref_block_commitment(block1.commitment())
+            .unauthenticated_notes(vec![mismatched_note.clone()])
+            .build()?;
+
+    let error = ProposedBatch::new(
+        [tx1, tx2].into_iter().map(Arc::new).collect(),
+        block2.header().clone(),
+        chain.latest_partial_blockchain(),
+        BTreeMap::default(),
+    )
+    .unwrap_err();
+
+    assert_matches!(error, ProposedBatchError::NoteCommitmentMismatch {
+        id,
+        input_commitment,
+        output_commitment
+      } if id == note.id() &&
+        input_commitment == mismatched_note.commitment() &&
+        output_commitment == note.commitment()
+    );
+
+    Ok(())
+}
+
+/// Tests that a note created in one batch and consumed as an unauthenticated note in a different
+/// batch is not erased from either batch.
+#[test]
+fn note_created_and_consumed_in_different_batches() -> anyhow::Result<()> {
+    let TestSetup { mut chain, account1, account2, .. } = setup_chain();
+    let block1 = chain.block_header(1);
+    let block2 = chain.prove_next_block()?;
+
+    let note = mock_note(40);
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Word::empty(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .output_notes(vec![OutputNote::Full(note.clone())])
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Word::empty(), account2.commitment())
+            .ref_block_commitment(block1.commitment())
+            .unauthenticated_notes(vec![note.clone()])
+            .build()?;
+
+    let batch1 = ProposedBatch::new(
+        vec![Arc::new(tx1)],
+        block2.header().clone(),
+        chain.latest_partial_blockchain(),
+        BTreeMap::default(),
+    )?;
+    let batch2 = ProposedBatch::new(
+        vec![Arc::new(tx2)],
+        block2.header().clone(),
+        chain.latest_partial_blockchain(),
+        BTreeMap::default(),
+    )?;
+
+    assert!(batch1.erased_notes().is_empty());
+    assert_eq!(batch1.input_notes().num_notes(), 0);
+    assert_eq!(batch1.output_notes(), [OutputNote::Full(note.clone())]);
+
+    assert!(batch2.erased_notes().is_empty());
+    assert_eq!(batch2.output_notes().len(), 0);
+    assert_eq!(batch2.input_notes().num_notes(), 1);
+    assert_eq!(batch2.input_notes().get_note(0).header(), Some(note.header()));
 
     Ok(())
 }
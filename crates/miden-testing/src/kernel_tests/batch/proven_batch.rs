@@ -0,0 +1,92 @@
+use miden_protocol::Word;
+use miden_protocol::batch::ProvenBatch;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::note::NoteType;
+use miden_protocol::transaction::OutputNote;
+
+use super::proposed_batch::{generate_account, mock_note};
+use super::proven_tx_builder::MockProvenTxBuilder;
+use crate::MockChain;
+
+/// Tests that a batch proven without a batch prover carries over the expiration block number and
+/// reference block of the proposed batch.
+#[test]
+fn proven_batch_from_proposed_batch_expiration() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account1 = generate_account(&mut builder);
+    let account2 = generate_account(&mut builder);
+    let mut chain = builder.build()?;
+    let block1 = chain.prove_next_block()?;
+
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Word::empty(), account1.commitment())
+            .ref_block_commitment(block1.header().commitment())
+            .expiration_block_num(BlockNumber::from(35))
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Word::empty(), account2.commitment())
+            .ref_block_commitment(block1.header().commitment())
+            .expiration_block_num(block1.header().block_num() + 1)
+            .build()?;
+
+    let proposed_batch = chain.propose_transaction_batch([tx1, tx2])?;
+    let reference_block = chain.latest_block_header();
+
+    let proven_batch = ProvenBatch::from_proposed_unchecked(proposed_batch.clone())?;
+    assert_eq!(proven_batch.id(), proposed_batch.id());
+    assert_eq!(proven_batch.reference_block_num(), reference_block.block_num());
+    assert_eq!(proven_batch.reference_block_commitment(), reference_block.commitment());
+    assert_eq!(proven_batch.batch_expiration_block_num(), block1.header().block_num() + 1);
+    assert_eq!(proven_batch.transactions().as_slice().len(), 2);
+
+    // the mock chain's batch pipeline must produce the same batch
+    assert_eq!(chain.prove_transaction_batch(proposed_batch)?, proven_batch);
+
+    Ok(())
+}
+
+/// Tests that a note created and consumed within the same batch is erased from the notes of the
+/// batch proven without a batch prover.
+#[test]
+fn proven_batch_from_proposed_batch_erases_notes() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account1 = generate_account(&mut builder);
+    let account2 = generate_account(&mut builder);
+    let account3 = generate_account(&mut builder);
+    let note1 = builder.add_p2id_note(account1.id(), account3.id(), &[], NoteType::Public)?;
+    let mut chain = builder.build()?;
+    let block1 = chain.prove_next_block()?;
+
+    // account1 creates the erased note and account2 consumes it unauthenticated, while account3
+    // consumes an authenticated note that must remain an input note of the batch
+    let erased_note = mock_note(40);
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Word::empty(), account1.commitment())
+            .ref_block_commitment(block1.header().commitment())
+            .output_notes(vec![OutputNote::Full(erased_note.clone())])
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Word::empty(), account2.commitment())
+            .ref_block_commitment(block1.header().commitment())
+            .unauthenticated_notes(vec![erased_note.clone()])
+            .build()?;
+    let tx3 =
+        MockProvenTxBuilder::with_account(account3.id(), Word::empty(), account3.commitment())
+            .ref_block_commitment(block1.header().commitment())
+            .authenticated_notes(vec![note1.clone()])
+            .build()?;
+
+    let proposed_batch = chain.propose_transaction_batch([tx1, tx2, tx3])?;
+    let proven_batch = chain.prove_transaction_batch(proposed_batch)?;
+
+    assert!(proven_batch.output_notes().is_empty());
+    assert_eq!(proven_batch.input_notes().num_notes(), 1);
+    assert_eq!(proven_batch.input_notes().get_note(0).nullifier(), note1.nullifier());
+    assert!(
+        proven_batch
+            .created_nullifiers()
+            .all(|nullifier| nullifier != erased_note.nullifier())
+    );
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use std::string::String;
 
 use anyhow::Context;
+use assert_matches::assert_matches;
 use miden_protocol::account::delta::AccountUpdateDetails;
 use miden_protocol::account::{
     Account,
@@ -36,11 +37,12 @@ use miden_protocol::testing::constants::{
     NON_FUNGIBLE_ASSET_DATA_2,
 };
 use miden_protocol::testing::storage::{MOCK_MAP_SLOT, MOCK_VALUE_SLOT0};
-use miden_protocol::transaction::TransactionScript;
+use miden_protocol::transaction::{InputNotes, TransactionScript};
 use miden_protocol::{EMPTY_WORD, Felt, FieldElement, LexicographicWord, Word, ZERO};
 use miden_standards::code_builder::CodeBuilder;
 use miden_standards::testing::account_component::MockAccountComponent;
-use miden_tx::LocalTransactionProver;
+use miden_tx::auth::UnreachableAuth;
+use miden_tx::{LocalTransactionProver, TransactionExecutor, TransactionExecutorError};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use winter_rand_utils::rand_value;
@@ -1029,6 +1031,124 @@ async fn adding_amount_zero_fungible_asset_to_account_vault_works() -> anyhow::R
     Ok(())
 }
 
+/// Tests that the executor aborts a transaction whose account delta exceeds the configured size
+/// limit and reports the map slot which caused it.
+#[tokio::test]
+async fn account_delta_size_limit_aborts_execution() -> anyhow::Result<()> {
+    const NUM_ENTRIES: u32 = 50;
+    const SIZE_LIMIT: usize = 1024;
+
+    let slot_name = StorageSlotName::mock(0);
+    let TestSetup { mock_chain, account_id, .. } =
+        setup_test([StorageSlot::with_map(slot_name.clone(), StorageMap::new())], [], [])?;
+
+    let set_map_items = (1..=NUM_ENTRIES)
+        .map(|i| {
+            let key = Word::from([i, 0, 0, 0]);
+            format!("push.{key} push.{key} push.SLOT_NAME[0..2] exec.set_map_item")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let tx_script = parse_tx_script(format!(
+        r#"
+      const SLOT_NAME = word("{slot_name}")
+
+      begin
+          {set_map_items}
+      end
+      "#
+    ))?;
+
+    let tx_context = mock_chain
+        .build_tx_context(account_id, &[], &[])?
+        .tx_script(tx_script)
+        .build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager())
+        .with_account_delta_size_limit(SIZE_LIMIT);
+    let result = executor
+        .execute_transaction(account_id, block_ref, InputNotes::default(), tx_args)
+        .await;
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::AccountDeltaSizeLimitExceeded {
+            account_id: id,
+            limit,
+            breakdown,
+        }) => {
+            assert_eq!(id, account_id);
+            assert_eq!(limit, SIZE_LIMIT);
+            assert!(breakdown.total() > SIZE_LIMIT);
+            assert_eq!(breakdown.largest_map_slot().map(|(name, _)| name), Some(&slot_name));
+        }
+    );
+
+    // Without a limit, the same transaction executes successfully.
+    let executed_tx = tx_context.execute().await.context("failed to execute transaction")?;
+    assert!(executed_tx.account_delta().encoded_size() > SIZE_LIMIT);
+
+    Ok(())
+}
+
+/// Tests that the account delta size limit is checked against the normalized delta, i.e. that map
+/// entries which are written and later restored to their initial value do not count towards it.
+#[tokio::test]
+async fn account_delta_size_limit_ignores_restored_map_entries() -> anyhow::Result<()> {
+    const NUM_ENTRIES: u32 = 50;
+    const SIZE_LIMIT: usize = 1024;
+
+    let slot_name = StorageSlotName::mock(0);
+    let TestSetup { mock_chain, account_id, .. } =
+        setup_test([StorageSlot::with_map(slot_name.clone(), StorageMap::new())], [], [])?;
+
+    // Each key is restored right after it is written, so the normalized delta stays empty while
+    // the tracked map delta grows beyond the limit.
+    let set_and_restore_map_items = (1..=NUM_ENTRIES)
+        .map(|i| {
+            let key = Word::from([i, 0, 0, 0]);
+            format!(
+                "push.{key} push.{key} push.SLOT_NAME[0..2] exec.set_map_item
+                 padw push.{key} push.SLOT_NAME[0..2] exec.set_map_item"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let tx_script = parse_tx_script(format!(
+        r#"
+      const SLOT_NAME = word("{slot_name}")
+
+      begin
+          {set_and_restore_map_items}
+      end
+      "#
+    ))?;
+
+    let tx_context = mock_chain
+        .build_tx_context(account_id, &[], &[])?
+        .tx_script(tx_script)
+        .build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager())
+        .with_account_delta_size_limit(SIZE_LIMIT);
+    let executed_tx = executor
+        .execute_transaction(account_id, block_ref, InputNotes::default(), tx_args)
+        .await
+        .context("failed to execute transaction")?;
+
+    assert!(executed_tx.account_delta().storage().is_empty());
+
+    Ok(())
+}
+
 // TEST HELPERS
 // ================================================================================================
 
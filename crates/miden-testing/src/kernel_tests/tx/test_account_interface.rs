@@ -205,7 +205,8 @@ async fn check_note_consumability_partial_success() -> anyhow::Result<()> {
         consumption_info,
         NoteConsumptionInfo {
             successful,
-            failed
+            failed,
+            epilogue_error: None,
         } => {
                 assert_eq!(failed.len(), 2);
                 assert_eq!(successful.len(), 3);
@@ -248,6 +249,180 @@ async fn check_note_consumability_partial_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that a non-terminating note exceeding the per-note cycle limit of the transaction
+/// arguments is reported as failed while the other notes are still reported as consumable.
+#[tokio::test]
+async fn check_note_consumability_note_cycle_limit() -> anyhow::Result<()> {
+    const MAX_CYCLES_PER_NOTE: u32 = 20_000;
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let looping_note = NoteBuilder::new(
+        sender,
+        ChaCha20Rng::from_seed(ChaCha20Rng::from_seed([0_u8; 32]).random()),
+    )
+    .code(format!(
+        "
+        use miden::protocol::active_account
+
+        begin
+            push.1
+            while.true
+                push.{suffix}.{prefix}
+                exec.active_account::get_balance
+                drop
+                push.1
+            end
+        end
+        ",
+        suffix = FungibleAsset::mock_issuer().suffix(),
+        prefix = FungibleAsset::mock_issuer().prefix().as_felt(),
+    ))
+    .build()?;
+
+    let successful_note_1 = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+
+    let successful_note_2 = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(145)],
+        NoteType::Public,
+    )?;
+
+    let mock_chain = builder.build()?;
+    let notes = vec![successful_note_1.clone(), looping_note.clone(), successful_note_2.clone()];
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::Account(account), &[], &notes)?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone().with_max_cycles_per_note(MAX_CYCLES_PER_NOTE);
+
+    let executor =
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context).with_tracing();
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+
+    let consumption_info = notes_checker
+        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .await?;
+
+    assert_matches!(
+        consumption_info,
+        NoteConsumptionInfo {
+            successful,
+            failed,
+            epilogue_error: None,
+        } => {
+            assert_eq!(failed.len(), 1);
+            assert_matches!(
+                &failed[0],
+                FailedNote {
+                    note,
+                    error: TransactionExecutorError::NoteCycleLimitExceeded { note_id, limit },
+                } => {
+                    assert_eq!(note.id(), looping_note.id());
+                    assert_eq!(*note_id, looping_note.id());
+                    assert_eq!(*limit, MAX_CYCLES_PER_NOTE);
+                }
+            );
+            assert_eq!(
+                [successful[0].id(), successful[1].id()],
+                [successful_note_1.id(), successful_note_2.id()],
+            );
+        }
+    );
+    Ok(())
+}
+
+/// Tests that a note exceeding the per-note cycle limit without raising any event is only reported
+/// as failed once its execution ends, since the limit is checked on events.
+#[tokio::test]
+async fn check_note_consumability_note_cycle_limit_without_events() -> anyhow::Result<()> {
+    const MAX_CYCLES_PER_NOTE: u32 = 20_000;
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    // loops for well over the per-note cycle limit, but terminates and raises no events
+    let event_free_note = NoteBuilder::new(
+        sender,
+        ChaCha20Rng::from_seed(ChaCha20Rng::from_seed([1_u8; 32]).random()),
+    )
+    .code(
+        "
+        begin
+            push.10000 push.1
+            while.true
+                sub.1 dup neq.0
+            end
+            drop
+        end
+        ",
+    )
+    .build()?;
+
+    let successful_note = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+
+    let mock_chain = builder.build()?;
+    let notes = vec![event_free_note.clone(), successful_note.clone()];
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::Account(account), &[], &notes)?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone().with_max_cycles_per_note(MAX_CYCLES_PER_NOTE);
+
+    let executor =
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context).with_tracing();
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+
+    let consumption_info = notes_checker
+        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .await?;
+
+    assert_matches!(
+        consumption_info,
+        NoteConsumptionInfo {
+            successful,
+            failed,
+            epilogue_error: None,
+        } => {
+            assert_eq!(failed.len(), 1);
+            assert_matches!(
+                &failed[0],
+                FailedNote {
+                    note,
+                    error: TransactionExecutorError::NoteCycleLimitExceeded { note_id, limit },
+                } => {
+                    assert_eq!(note.id(), event_free_note.id());
+                    assert_eq!(*note_id, event_free_note.id());
+                    assert_eq!(*limit, MAX_CYCLES_PER_NOTE);
+                }
+            );
+            assert_eq!(successful.len(), 1);
+            assert_eq!(successful[0].id(), successful_note.id());
+        }
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn check_note_consumability_epilogue_failure() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
@@ -281,19 +456,94 @@ async fn check_note_consumability_epilogue_failure() -> anyhow::Result<()> {
         .check_notes_consumability(account_id, block_ref, notes, tx_args)
         .await?;
 
+    // The missing authenticator only fails the authentication of the transaction, so the note is
+    // still consumable.
     assert_matches!(
        consumption_info,
        NoteConsumptionInfo {
            successful,
-           failed
+           failed,
+           epilogue_error: Some(TransactionExecutorError::MissingAuthenticator),
        } => {
-           assert!(successful.is_empty());
-           assert_eq!(failed.len(), 1);
+           assert_eq!(successful.len(), 1);
+           assert!(failed.is_empty());
        }
     );
     Ok(())
 }
 
+#[tokio::test]
+async fn check_note_consumability_without_authenticator_with_failing_note() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    // Use basic auth which will cause epilogue failure when paired up with unreachable auth.
+    let account = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    let successful_note_1 = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+    let successful_note_2 = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(145)],
+        NoteType::Public,
+    )?;
+    let failing_note =
+        NoteBuilder::new(ACCOUNT_ID_SENDER.try_into().unwrap(), ChaCha20Rng::from_seed([3_u8; 32]))
+            .code("begin push.1 drop push.0 div end")
+            .dynamically_linked_libraries([TransactionKernel::library()])
+            .build()?;
+
+    let mock_chain = builder.build()?;
+    let notes = vec![successful_note_1.clone(), failing_note.clone(), successful_note_2.clone()];
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::Account(account), &[], &notes)?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    let executor =
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context).with_tracing();
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+
+    let consumption_info = notes_checker
+        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .await?;
+
+    // The note failing in its script is reported as failed, while the remaining notes are
+    // successful despite the transaction not being authorized.
+    assert_matches!(
+        consumption_info,
+        NoteConsumptionInfo {
+            successful,
+            failed,
+            epilogue_error: Some(TransactionExecutorError::MissingAuthenticator),
+        } => {
+            assert_eq!(
+                [successful[0].id(), successful[1].id()],
+                [successful_note_1.id(), successful_note_2.id()],
+            );
+            assert_eq!(failed.len(), 1);
+            assert_matches!(
+                &failed[0],
+                FailedNote {
+                    note,
+                    error: TransactionExecutorError::TransactionProgramExecutionFailed(
+                        ExecutionError::DivideByZero { .. })
+                } => {
+                    assert_eq!(note.id(), failing_note.id());
+                }
+            );
+        }
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn check_note_consumability_epilogue_failure_with_new_combination() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
@@ -367,7 +617,8 @@ async fn check_note_consumability_epilogue_failure_with_new_combination() -> any
         consumption_info,
         NoteConsumptionInfo {
             successful,
-            failed
+            failed,
+            epilogue_error: None,
         } => {
                 assert_eq!(failed.len(), 2);
                 assert_eq!(successful.len(), 3);
@@ -1,5 +1,5 @@
 use assert_matches::assert_matches;
-use miden_protocol::account::AccountId;
+use miden_protocol::account::{Account, AccountId, PartialAccount};
 use miden_protocol::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
 use miden_protocol::errors::AssetVaultError;
 use miden_protocol::errors::tx_kernel::{
@@ -13,50 +13,63 @@ use miden_protocol::testing::account_id::{
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET,
     ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET_1,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
 };
 use miden_protocol::testing::constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA};
 use miden_protocol::transaction::memory;
 use miden_protocol::{Felt, ONE, Word, ZERO};
+use miden_standards::testing::account_component::IncrNonceAuthComponent;
+use miden_standards::testing::mock_account::MockAccountExt;
 
+use crate::executor::KernelProcedureRunner;
 use crate::kernel_tests::tx::ExecutionOutputExt;
-use crate::{TransactionContextBuilder, assert_execution_error};
+use crate::{ExecError, TransactionContextBuilder, assert_execution_error};
 
 /// Tests that account::get_balance returns the correct amount.
 #[tokio::test]
 async fn get_balance_returns_correct_amount() -> anyhow::Result<()> {
-    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
-
+    // the runner installs the mock account by default
+    let account =
+        Account::mock(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, IncrNonceAuthComponent);
     let faucet_id: AccountId = ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap();
-    let code = format!(
-        r#"
-        use $kernel::prologue
-        use miden::protocol::active_account
 
-        begin
-            exec.prologue::prepare_transaction
-
-            push.{suffix} push.{prefix}
-            exec.active_account::get_balance
-            # => [balance]
+    let output = KernelProcedureRunner::new()
+        .with_stack(&[faucet_id.prefix().as_felt(), faucet_id.suffix()])
+        .run("miden::protocol::active_account::get_balance")
+        .await?;
 
-            # truncate the stack
-            swap drop
-        end
-            "#,
-        prefix = faucet_id.prefix().as_felt(),
-        suffix = faucet_id.suffix(),
+    assert_eq!(
+        output.get_stack_element(0).as_int(),
+        account.vault().get_balance(faucet_id).unwrap()
     );
-
-    let exec_output = tx_context.execute_code(&code).await?;
-
     assert_eq!(
-        exec_output.get_stack_element(0).as_int(),
-        tx_context.account().vault().get_balance(faucet_id).unwrap()
+        output.read_kernel_memory(memory::NATIVE_ACCT_VAULT_ROOT_PTR, 1),
+        [account.vault().root()]
     );
 
     Ok(())
 }
 
+/// Tests that the runner returns an error if the procedure accesses an asset which is not tracked
+/// by the partial account.
+#[tokio::test]
+async fn get_balance_fails_for_asset_untracked_by_partial_account() -> anyhow::Result<()> {
+    let account =
+        Account::mock(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, IncrNonceAuthComponent);
+    let faucet_id: AccountId = ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap();
+
+    let result = KernelProcedureRunner::new()
+        .with_account(PartialAccount::from(&account))
+        .with_stack(&[faucet_id.prefix().as_felt(), faucet_id.suffix()])
+        .run("miden::protocol::active_account::get_balance")
+        .await;
+
+    let err = result.err().expect("execution should fail");
+    assert!(err.downcast_ref::<ExecError>().is_some(), "unexpected error: {err:?}");
+
+    Ok(())
+}
+
 /// Tests that asset_vault::peek_balance returns the correct amount.
 #[tokio::test]
 async fn peek_balance_returns_correct_amount() -> anyhow::Result<()> {
@@ -142,26 +155,17 @@ async fn test_has_non_fungible_asset() -> anyhow::Result<()> {
     let non_fungible_asset =
         tx_context.account().vault().assets().find(Asset::is_non_fungible).unwrap();
 
-    let code = format!(
-        "
-        use $kernel::prologue
-        use miden::protocol::active_account
-
-        begin
-            exec.prologue::prepare_transaction
-            push.{non_fungible_asset_key}
-            exec.active_account::has_non_fungible_asset
+    // a word is laid out on the stack in reverse order
+    let mut stack = Word::from(non_fungible_asset).as_elements().to_vec();
+    stack.reverse();
 
-            # truncate the stack
-            swap drop
-        end
-        ",
-        non_fungible_asset_key = Word::from(non_fungible_asset)
-    );
-
-    let exec_output = tx_context.execute_code(&code).await?;
+    let output = KernelProcedureRunner::new()
+        .with_account(PartialAccount::from_full(tx_context.account()))
+        .with_stack(&stack)
+        .run("miden::protocol::active_account::has_non_fungible_asset")
+        .await?;
 
-    assert_eq!(exec_output.get_stack_element(0), ONE);
+    assert_eq!(output.get_stack_element(0), ONE);
 
     Ok(())
 }
@@ -1,9 +1,12 @@
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
+use std::sync::Mutex;
 
+use assert_matches::assert_matches;
 use miden_processor::fast::ExecutionOutput;
-use miden_processor::{AdviceInputs, Felt};
+use miden_processor::{AdviceInputs, Felt, FutureMaybeSend, MastForest};
 use miden_protocol::account::{
     Account,
     AccountBuilder,
@@ -12,15 +15,28 @@ use miden_protocol::account::{
     AccountProcedureRoot,
     AccountStorage,
     AccountStorageMode,
+    PartialAccount,
+    StorageMap,
+    StorageMapWitness,
     StorageSlot,
 };
 use miden_protocol::assembly::DefaultSourceManager;
-use miden_protocol::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+use miden_protocol::asset::{
+    Asset,
+    AssetVaultKey,
+    AssetWitness,
+    FungibleAsset,
+    NonFungibleAsset,
+    NonFungibleAssetDetails,
+};
+use miden_protocol::block::{BlockHeader, BlockNumber};
+use miden_protocol::errors::AccountInputsError;
 use miden_protocol::errors::tx_kernel::{
     ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT,
     ERR_FOREIGN_ACCOUNT_INVALID_COMMITMENT,
     ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
 };
+use miden_protocol::note::NoteScript;
 use miden_protocol::testing::account_id::{
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
     ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET,
@@ -38,15 +54,31 @@ use miden_protocol::transaction::memory::{
     ACCT_VAULT_ROOT_OFFSET,
     NATIVE_ACCOUNT_DATA_PTR,
 };
+use miden_protocol::transaction::{AccountInputs, PartialBlockchain};
 use miden_protocol::{FieldElement, Word, ZERO};
 use miden_standards::code_builder::CodeBuilder;
 use miden_standards::testing::account_component::MockAccountComponent;
-use miden_tx::LocalTransactionProver;
+use miden_tx::auth::UnreachableAuth;
+use miden_tx::{
+    AccountInputsExt,
+    DataStore,
+    DataStoreError,
+    LocalTransactionProver,
+    MastForestStore,
+    TransactionExecutor,
+    TransactionExecutorError,
+};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 
 use crate::kernel_tests::tx::ExecutionOutputExt;
-use crate::{Auth, MockChainBuilder, assert_execution_error, assert_transaction_executor_error};
+use crate::{
+    Auth,
+    MockChainBuilder,
+    TransactionContext,
+    assert_execution_error,
+    assert_transaction_executor_error,
+};
 
 // SIMPLE FPI TESTS
 // ================================================================================================
@@ -91,6 +123,7 @@ async fn test_fpi_memory_single_account() -> anyhow::Result<()> {
     let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -359,11 +392,13 @@ async fn test_fpi_memory_two_accounts() -> anyhow::Result<()> {
     let foreign_account_1 = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component_1)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let foreign_account_2 = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component_2)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -566,6 +601,7 @@ async fn test_fpi_execute_foreign_procedure() -> anyhow::Result<()> {
     let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -725,6 +761,7 @@ async fn foreign_account_can_get_balance_and_presence_of_asset() -> anyhow::Resu
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
         .with_assets(vec![fungible_asset, non_fungible_asset])
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -830,6 +867,7 @@ async fn foreign_account_get_initial_balance() -> anyhow::Result<()> {
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
         .with_assets(vec![fungible_asset])
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -974,6 +1012,7 @@ async fn test_nested_fpi_cyclic_invocation() -> anyhow::Result<()> {
     let second_foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(second_foreign_account_component)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // ------ FIRST FOREIGN ACCOUNT ---------------------------------------------------------------
@@ -1036,6 +1075,7 @@ async fn test_nested_fpi_cyclic_invocation() -> anyhow::Result<()> {
     let first_foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(first_foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // ------ NATIVE ACCOUNT ---------------------------------------------------------------
@@ -1167,6 +1207,7 @@ async fn test_prove_fpi_two_foreign_accounts_chain() -> anyhow::Result<()> {
     let second_foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(second_foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // ------ FIRST FOREIGN ACCOUNT ---------------------------------------------------------------
@@ -1210,6 +1251,7 @@ async fn test_prove_fpi_two_foreign_accounts_chain() -> anyhow::Result<()> {
     let first_foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(first_foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // ------ NATIVE ACCOUNT ---------------------------------------------------------------
@@ -1335,6 +1377,7 @@ async fn test_nested_fpi_stack_overflow() -> anyhow::Result<()> {
     let last_foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(last_foreign_account_component)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()
         .unwrap();
 
@@ -1384,6 +1427,7 @@ async fn test_nested_fpi_stack_overflow() -> anyhow::Result<()> {
         let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
             .with_auth_component(Auth::IncrNonce)
             .with_component(foreign_account_component)
+            .storage_mode(AccountStorageMode::Public)
             .build_existing()
             .unwrap();
 
@@ -1498,6 +1542,7 @@ async fn test_nested_fpi_native_account_invocation() -> anyhow::Result<()> {
     let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // ------ NATIVE ACCOUNT ---------------------------------------------------------------
@@ -1597,6 +1642,7 @@ async fn test_fpi_stale_account() -> anyhow::Result<()> {
     let mut foreign_account = AccountBuilder::new([5; 32])
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component)
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new([4; 32])
@@ -1672,6 +1718,66 @@ async fn test_fpi_stale_account() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that foreign account inputs are anchored in the block they were fetched for, and that they
+/// are no longer anchored once the foreign account is updated.
+#[tokio::test]
+async fn foreign_account_inputs_verify_against_block() -> anyhow::Result<()> {
+    let mut builder = MockChainBuilder::new();
+    let foreign_account = builder.add_existing_mock_account(Auth::IncrNonce)?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    let ref_block = mock_chain.latest_block_header();
+    let (account, witness) = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+    let account_inputs = AccountInputs::new(PartialAccount::from(&account), witness);
+    account_inputs.verify_against_block(&ref_block)?;
+
+    // Update the foreign account in a new block, which makes the previous inputs stale.
+    let executed_tx = mock_chain
+        .build_tx_context(foreign_account.id(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+    mock_chain.add_pending_executed_transaction(&executed_tx)?;
+    mock_chain.prove_next_block()?;
+    let new_ref_block = mock_chain.latest_block_header();
+
+    assert_matches!(
+        account_inputs.verify_against_block(&new_ref_block),
+        Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+            account_id,
+            source: AccountInputsError::StaleWitness { block_num, .. },
+        }) => {
+            assert_eq!(account_id, foreign_account.id());
+            assert_eq!(block_num, new_ref_block.block_num());
+        }
+    );
+
+    let (updated_account, witness) = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+    let updated_inputs =
+        AccountInputs::new(PartialAccount::from(&updated_account), witness.clone());
+    updated_inputs.verify_against_block(&new_ref_block)?;
+    assert_matches!(
+        updated_inputs.verify_against_block(&ref_block),
+        Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+            source: AccountInputsError::StaleWitness { .. },
+            ..
+        })
+    );
+
+    // A witness for the current state does not anchor a stale account.
+    let stale_inputs = AccountInputs::new(PartialAccount::from(&account), witness);
+    assert_matches!(
+        stale_inputs.verify_against_block(&new_ref_block),
+        Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+            source: AccountInputsError::AccountCommitmentMismatch { .. },
+            ..
+        })
+    );
+
+    Ok(())
+}
+
 /// This test checks that our `miden::get_id` and `miden::get_native_id` procedures return IDs of
 /// the current and native account respectively while being called from the foreign account.
 #[tokio::test]
@@ -1705,6 +1811,7 @@ async fn test_fpi_get_account_id() -> anyhow::Result<()> {
     let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
@@ -1785,6 +1892,190 @@ async fn test_fpi_get_account_id() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that executing a transaction which accesses a private foreign account is rejected with
+/// [`TransactionExecutorError::ForeignAccountNotPublic`] before the account is loaded.
+#[tokio::test]
+async fn test_fpi_private_foreign_account_rejected() -> anyhow::Result<()> {
+    let foreign_account_code_source = "
+        pub proc get_one
+            push.1 swap drop
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::new(
+        CodeBuilder::default()
+            .compile_component_code("foreign_account", foreign_account_code_source)?,
+        Vec::new(),
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Private)
+        .build_existing()?;
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChainBuilder::with_accounts([native_account.clone(), foreign_account.clone()])?
+            .build()?;
+    mock_chain.prove_next_block()?;
+
+    let code = format!(
+        r#"
+        use miden::core::sys
+
+        use miden::protocol::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0.0
+            # => [pad(15)]
+
+            # get the hash of the `get_one` foreign account procedure
+            procref.::foreign_account::get_one
+
+            # push the foreign account ID
+            push.{foreign_suffix} push.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, pad(15)]
+
+            exec.tx::execute_foreign_procedure
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        "#,
+        foreign_suffix = foreign_account.id().suffix(),
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+    );
+
+    let tx_script = CodeBuilder::default()
+        .with_dynamically_linked_library(foreign_account_component.component_code())?
+        .compile_tx_script(code)?;
+
+    let foreign_account_inputs = mock_chain
+        .get_foreign_account_inputs(foreign_account.id())
+        .expect("failed to get foreign account inputs");
+
+    let result = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .expect("failed to build tx context")
+        .foreign_accounts(vec![foreign_account_inputs])
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await;
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::ForeignAccountNotPublic(account_id))
+            if account_id == foreign_account.id()
+    );
+
+    Ok(())
+}
+
+/// Tests that executing a transaction whose foreign account inputs are not anchored in the
+/// reference block is rejected with
+/// [`TransactionExecutorError::ForeignAccountNotAnchoredInReference`] which reports the reason the
+/// inputs are invalid.
+#[tokio::test]
+async fn test_fpi_unanchored_foreign_account_rejected() -> anyhow::Result<()> {
+    let foreign_account_code_source = "
+        pub proc get_one
+            push.1 swap drop
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::new(
+        CodeBuilder::default()
+            .compile_component_code("foreign_account", foreign_account_code_source)?,
+        Vec::new(),
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChainBuilder::with_accounts([native_account.clone(), foreign_account.clone()])?
+            .build()?;
+    mock_chain.prove_next_block()?;
+
+    let code = format!(
+        r#"
+        use miden::core::sys
+
+        use miden::protocol::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0.0
+            # => [pad(15)]
+
+            # get the hash of the `get_one` foreign account procedure
+            procref.::foreign_account::get_one
+
+            # push the foreign account ID
+            push.{foreign_suffix} push.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, pad(15)]
+
+            exec.tx::execute_foreign_procedure
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        "#,
+        foreign_suffix = foreign_account.id().suffix(),
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+    );
+
+    let tx_script = CodeBuilder::default()
+        .with_dynamically_linked_library(foreign_account_component.component_code())?
+        .compile_tx_script(code)?;
+
+    // Provide the foreign account together with the witness of the native account.
+    let (_, native_account_witness) = mock_chain
+        .get_foreign_account_inputs(native_account.id())
+        .expect("failed to get native account inputs");
+
+    let result = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .expect("failed to build tx context")
+        .foreign_accounts(vec![(foreign_account.clone(), native_account_witness)])
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await;
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+            account_id,
+            source: AccountInputsError::AccountIdMismatch { witness_account_id, .. },
+        }) => {
+            assert_eq!(account_id, foreign_account.id());
+            assert_eq!(witness_account_id, native_account.id());
+        }
+    );
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1907,6 +2198,7 @@ async fn test_get_initial_item_and_get_initial_map_item_with_foreign_account() -
     let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
         .with_auth_component(Auth::IncrNonce)
         .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
         .build_existing()?;
 
     // Create the mock chain with both accounts
@@ -1969,3 +2261,194 @@ async fn test_get_initial_item_and_get_initial_map_item_with_foreign_account() -
 
     Ok(())
 }
+
+/// A [`DataStore`] which forwards all requests to a [`TransactionContext`] and records the storage
+/// map witnesses that were requested.
+struct MapWitnessRecordingStore<'ctx> {
+    inner: &'ctx TransactionContext,
+    map_witnesses: Mutex<Vec<(AccountId, Word, StorageMapWitness)>>,
+}
+
+impl DataStore for MapWitnessRecordingStore<'_> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        self.inner.get_transaction_inputs(account_id, ref_blocks)
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        self.inner.get_foreign_account_inputs(foreign_account_id, ref_block)
+    }
+
+    fn get_vault_asset_witnesses(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_keys: BTreeSet<AssetVaultKey>,
+    ) -> impl FutureMaybeSend<Result<Vec<AssetWitness>, DataStoreError>> {
+        self.inner.get_vault_asset_witnesses(account_id, vault_root, vault_keys)
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        async move {
+            let witness = self.inner.get_storage_map_witness(account_id, map_root, map_key).await?;
+            self.map_witnesses.lock().unwrap().push((account_id, map_key, witness.clone()));
+            Ok(witness)
+        }
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<Option<NoteScript>, DataStoreError>> {
+        self.inner.get_note_script(script_root)
+    }
+}
+
+impl MastForestStore for MapWitnessRecordingStore<'_> {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        MastForestStore::get(self.inner, procedure_hash)
+    }
+}
+
+/// Tests that reading a map item of a foreign account via FPI requests the storage map witness
+/// with the raw map key, while the SMT proof of the witness is keyed by the hashed map key.
+#[tokio::test]
+async fn foreign_account_map_read_uses_hashed_key_in_witness() -> anyhow::Result<()> {
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mock_map_slot = AccountStorage::mock_map_slot();
+    let (map_key, map_value) = STORAGE_LEAVES_2[0];
+
+    let foreign_account_code_source = "
+        use miden::protocol::active_account
+        use miden::core::sys
+
+        pub proc get_map_item_foreign
+            exec.active_account::get_map_item
+            exec.sys::truncate_stack
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::new(
+        CodeBuilder::default()
+            .compile_component_code("foreign_account", foreign_account_code_source)?,
+        vec![mock_map_slot.clone()],
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component.clone())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChainBuilder::with_accounts([native_account.clone(), foreign_account.clone()])?
+            .build()?;
+    mock_chain.prove_next_block()?;
+
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+
+    let code = format!(
+        r#"
+        use miden::core::sys
+        use miden::protocol::tx
+
+        const MOCK_MAP_SLOT = word("{mock_map_slot}")
+
+        begin
+            padw padw push.0.0
+            push.{map_key}
+            push.MOCK_MAP_SLOT[0..2]
+            procref.::foreign_account::get_map_item_foreign
+            push.{foreign_account_id_suffix} push.{foreign_account_id_prefix}
+            exec.tx::execute_foreign_procedure
+            push.{map_value}
+            assert_eqw.err="foreign account get_map_item should return the map value"
+
+            exec.sys::truncate_stack
+        end
+        "#,
+        mock_map_slot = mock_map_slot.name(),
+        foreign_account_id_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_account_id_suffix = foreign_account.id().suffix(),
+        map_key = &map_key,
+        map_value = &map_value,
+    );
+
+    let tx_script = CodeBuilder::with_mock_libraries()
+        .with_dynamically_linked_library(foreign_account_component.component_code())?
+        .compile_tx_script(code)?;
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .foreign_accounts(vec![foreign_account_inputs])
+        .tx_script(tx_script)
+        .build()?;
+
+    let store = MapWitnessRecordingStore {
+        inner: &tx_context,
+        map_witnesses: Mutex::new(Vec::new()),
+    };
+    TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&store)
+        .with_source_manager(tx_context.source_manager())
+        .execute_transaction(
+            native_account.id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .await?;
+
+    let hashed_key = StorageMap::hash_key(map_key);
+    let map_witnesses = store.map_witnesses.into_inner().unwrap();
+    let foreign_map_witnesses = map_witnesses
+        .iter()
+        .filter(|(account_id, ..)| *account_id == foreign_account.id())
+        .collect::<Vec<_>>();
+    assert!(
+        !foreign_map_witnesses.is_empty(),
+        "foreign map witness should have been requested"
+    );
+
+    for (_, requested_key, witness) in foreign_map_witnesses {
+        // the witness is requested by the raw key and exposes the value by the raw key
+        assert_eq!(*requested_key, map_key);
+        assert_eq!(witness.get(&map_key), Some(map_value));
+
+        // the SMT proof provided to the kernel is keyed by the hashed key
+        assert_eq!(witness.proof().get(&hashed_key), Some(map_value));
+        assert_eq!(witness.proof().get(&map_key), None);
+    }
+
+    // the Rust-side API is keyed by the raw key, and the hashed key must be used explicitly
+    let foreign_storage = foreign_account.storage();
+    assert_eq!(foreign_storage.get_map_item(mock_map_slot.name(), map_key)?, map_value);
+    assert_eq!(
+        foreign_storage.get_map_item_hashed(mock_map_slot.name(), hashed_key)?,
+        map_value
+    );
+    assert_eq!(
+        foreign_storage.get_map_item_hashed(mock_map_slot.name(), map_key)?,
+        Word::empty()
+    );
+
+    Ok(())
+}
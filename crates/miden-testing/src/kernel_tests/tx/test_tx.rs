@@ -1,23 +1,41 @@
+use alloc::collections::BTreeSet;
+use alloc::string::ToString;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::slice;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Context;
 use assert_matches::assert_matches;
 use miden_processor::crypto::RpoRandomCoin;
+use miden_processor::{AdviceInputs, ExecutionError, FutureMaybeSend, MastForest};
+use miden_protocol::account::auth::{PublicKey, PublicKeyCommitment, Signature};
+use miden_protocol::account::delta::AccountUpdateDetails;
 use miden_protocol::account::{
     Account,
     AccountBuilder,
     AccountCode,
     AccountComponent,
+    AccountId,
     AccountStorage,
     AccountStorageMode,
     AccountType,
+    PartialAccount,
+    StorageMapWitness,
     StorageSlot,
     StorageSlotName,
 };
 use miden_protocol::assembly::DefaultSourceManager;
 use miden_protocol::assembly::diagnostics::NamedSource;
-use miden_protocol::asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset};
-use miden_protocol::block::BlockNumber;
+use miden_protocol::asset::{
+    Asset,
+    AssetVault,
+    AssetVaultKey,
+    AssetWitness,
+    FungibleAsset,
+    NonFungibleAsset,
+};
+use miden_protocol::block::{BlockHeader, BlockNumber};
 use miden_protocol::note::{
     Note,
     NoteAssets,
@@ -28,6 +46,7 @@ use miden_protocol::note::{
     NoteId,
     NoteMetadata,
     NoteRecipient,
+    NoteScript,
     NoteStorage,
     NoteTag,
     NoteType,
@@ -42,28 +61,69 @@ use miden_protocol::testing::account_id::{
 };
 use miden_protocol::testing::constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA};
 use miden_protocol::testing::note::DEFAULT_NOTE_CODE;
+use miden_protocol::testing::storage::{MOCK_VALUE_SLOT0, STORAGE_VALUE_0};
 use miden_protocol::transaction::{
+    AccountInputs,
     InputNotes,
     OutputNote,
     OutputNotes,
+    PartialBlockchain,
+    ReplayBundle,
     TransactionArgs,
+    TransactionId,
     TransactionKernel,
     TransactionSummary,
 };
-use miden_protocol::{Felt, Hasher, ONE, Word};
+use miden_protocol::utils::serde::{Deserializable, Serializable};
+use miden_protocol::vm::ProgramInfo;
+use miden_protocol::{
+    Felt,
+    Hasher,
+    MAX_TX_EXECUTION_CYCLES,
+    MIN_PROOF_SECURITY_LEVEL,
+    MIN_TX_EXECUTION_CYCLES,
+    ONE,
+    PrettyPrint,
+    Word,
+};
 use miden_standards::AuthScheme;
 use miden_standards::account::interface::{AccountInterface, AccountInterfaceExt};
 use miden_standards::account::wallets::BasicWallet;
 use miden_standards::code_builder::CodeBuilder;
 use miden_standards::note::P2idNote;
-use miden_standards::testing::account_component::IncrNonceAuthComponent;
+use miden_standards::testing::account_component::{IncrNonceAuthComponent, MockAccountComponent};
 use miden_standards::testing::mock_account::MockAccountExt;
-use miden_tx::auth::UnreachableAuth;
-use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use miden_standards::testing::note::NoteBuilder;
+use miden_tx::auth::{
+    BasicAuthenticator,
+    CachingAuthenticator,
+    SigningInputs,
+    TransactionAuthenticator,
+    UnreachableAuth,
+};
+use miden_tx::{
+    AccountMutation,
+    AuthenticationError,
+    DataStore,
+    DataStoreError,
+    ExecutionOptions,
+    ExecutionPreset,
+    LocalTransactionProver,
+    MastForestStore,
+    ReplayBundleExt,
+    ReplayDivergence,
+    TransactionExecutor,
+    TransactionExecutorError,
+    TransactionKernelError,
+    TransactionReplayError,
+    TransactionVerifier,
+    TransactionVerifierError,
+    TxRequest,
+};
 
 use crate::kernel_tests::tx::ExecutionOutputExt;
 use crate::utils::{create_public_p2any_note, create_spawn_note};
-use crate::{Auth, MockChain, TransactionContextBuilder};
+use crate::{AccountState, Auth, MockChain, TransactionContext, TransactionContextBuilder};
 
 /// Tests that consuming a note created in a block that is newer than the reference block of the
 /// transaction fails.
@@ -667,6 +727,942 @@ async fn execute_tx_view_script() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that execute_read_only returns the stack outputs of a script which only reads the
+/// account storage.
+#[tokio::test]
+async fn execute_read_only_allows_storage_reads() -> anyhow::Result<()> {
+    let source = format!(
+        r#"
+        use mock::account
+        use miden::core::sys
+
+        const SLOT_NAME = word("{slot_name}")
+
+        begin
+            push.SLOT_NAME[0..2]
+            call.account::get_item
+            exec.sys::truncate_stack
+        end
+        "#,
+        slot_name = &*MOCK_VALUE_SLOT0,
+    );
+
+    let tx_script = CodeBuilder::with_mock_libraries().compile_tx_script(source)?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
+    let stack_outputs = executor
+        .execute_read_only(account_id, block_ref, tx_script, AdviceInputs::default())
+        .await?;
+
+    let mut expected_value = STORAGE_VALUE_0.to_vec();
+    expected_value.reverse();
+    assert_eq!(stack_outputs[..4], expected_value);
+
+    Ok(())
+}
+
+/// Tests that execute_read_only fails when the script attempts to add an asset to the account
+/// vault.
+#[tokio::test]
+async fn execute_read_only_rejects_vault_mutation() -> anyhow::Result<()> {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)?;
+    let asset = FungibleAsset::new(faucet_id, 100)?;
+
+    let source = format!(
+        "
+        use mock::account
+
+        begin
+            push.{asset}
+            call.account::receive_asset
+            dropw
+        end
+        ",
+        asset = Word::from(asset),
+    );
+
+    let tx_script = CodeBuilder::with_mock_libraries().compile_tx_script(source)?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
+    let result = executor
+        .execute_read_only(account_id, block_ref, tx_script, AdviceInputs::default())
+        .await;
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::ReadOnlyViolation(AccountMutation::VaultMutation))
+    );
+
+    Ok(())
+}
+
+// EXECUTION OPTIONS TESTS
+// ================================================================================================
+
+/// Tests that all execution presets are accepted by the executor and that out-of-range cycle
+/// values are rejected.
+#[test]
+fn execution_presets_and_max_cycles_are_validated() -> anyhow::Result<()> {
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+
+    for preset in [ExecutionPreset::Fast, ExecutionPreset::Standard, ExecutionPreset::Large] {
+        assert!(preset.expected_cycles() <= preset.max_cycles());
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+            .with_options(preset.into())
+            .context("preset should be valid execution options")?;
+    }
+
+    TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_max_cycles(MIN_TX_EXECUTION_CYCLES)
+        .context("min cycles should be a valid max cycles value")?;
+
+    let error = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_max_cycles(MIN_TX_EXECUTION_CYCLES - 1)
+        .err();
+    assert_matches!(
+        error,
+        Some(TransactionExecutorError::InvalidExecutionOptionsCycles { actual, .. })
+            if actual == MIN_TX_EXECUTION_CYCLES - 1
+    );
+
+    Ok(())
+}
+
+/// Tests that the per-call execution options override is validated and can be used to execute a
+/// transaction.
+#[tokio::test]
+async fn execute_transaction_with_options() -> anyhow::Result<()> {
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager())
+        .with_preset(ExecutionPreset::Fast);
+
+    let invalid_options = ExecutionOptions::new(
+        Some(MAX_TX_EXECUTION_CYCLES),
+        MIN_TX_EXECUTION_CYCLES / 2,
+        false,
+        false,
+    )
+    .unwrap();
+    let error = executor
+        .execute_transaction_with_options(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            invalid_options,
+        )
+        .await;
+    assert_matches!(error, Err(TransactionExecutorError::InvalidExecutionOptionsCycles { .. }));
+
+    executor
+        .execute_transaction_with_options(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            ExecutionPreset::Large.into(),
+        )
+        .await
+        .context("failed to execute transaction with large preset")?;
+
+    Ok(())
+}
+
+/// Tests that the max cycles of the per-call execution options are enforced during execution and
+/// that the same transaction succeeds under the default execution options of the executor.
+#[tokio::test]
+async fn execute_transaction_with_options_enforces_max_cycles() -> anyhow::Result<()> {
+    // loop for a large number of cycles before the epilogue of the transaction
+    let tx_script = CodeBuilder::default().compile_tx_script(
+        "
+        begin
+            push.100000 push.1
+            while.true
+                sub.1 dup neq.0
+            end
+            drop
+        end
+        ",
+    )?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager());
+
+    let max_cycles = 1 << 17;
+    let options =
+        ExecutionOptions::new(Some(max_cycles), MIN_TX_EXECUTION_CYCLES, false, false).unwrap();
+    let error = executor
+        .execute_transaction_with_options(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+            options,
+        )
+        .await;
+    assert_matches!(
+        error,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::CycleLimitExceeded(limit)
+        )) if limit == max_cycles
+    );
+
+    executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .await
+        .context("failed to execute transaction with default execution options")?;
+
+    Ok(())
+}
+
+/// Tests that the max cycles of the executor abort a script which loops forever without raising
+/// any events.
+///
+/// The loop only executes a trace decorator once every outer iteration, which in debug mode is the
+/// only point at which the host regains control during the loop.
+#[tokio::test]
+async fn max_cycles_aborts_non_terminating_script_without_events() -> anyhow::Result<()> {
+    let tx_script = CodeBuilder::default().compile_tx_script(
+        "
+        begin
+            push.1
+            while.true
+                trace.240
+                push.10000 push.1
+                while.true
+                    sub.1 dup neq.0
+                end
+                drop push.1
+            end
+        end
+        ",
+    )?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script)
+        .build()?;
+
+    let max_cycles = 1 << 17;
+    let error = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager())
+        .with_debug_mode()
+        .with_max_cycles(max_cycles)?
+        .execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .await;
+    assert_matches!(
+        error,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::CycleLimitExceeded(limit)
+        )) if limit == max_cycles
+    );
+
+    Ok(())
+}
+
+/// Tests that the cycle estimate of a transaction is within the valid range and matches the cycle
+/// count of the full execution of the same transaction.
+#[tokio::test]
+async fn estimate_cycles_matches_executed_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_source_manager(tx_context.source_manager());
+    let estimated_cycles = executor
+        .estimate_cycles(
+            account.id(),
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .await
+        .context("failed to estimate transaction cycles")?;
+
+    assert!(estimated_cycles > 0);
+    assert!(estimated_cycles <= u64::from(MAX_TX_EXECUTION_CYCLES));
+
+    let executed_tx = tx_context.execute().await.context("failed to execute transaction")?;
+    assert_eq!(estimated_cycles, executed_tx.measurements().total_cycles() as u64);
+
+    Ok(())
+}
+
+/// Tests that the per-stage cycle counts of the transaction measurements add up to the total cycle
+/// count.
+#[tokio::test]
+async fn transaction_measurements_stage_cycles_sum_to_total() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let tx_script = CodeBuilder::default().compile_tx_script("begin push.1 drop end")?;
+    let executed_tx = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+    let measurements = executed_tx.measurements();
+
+    assert!(measurements.prologue_cycles() > 0);
+    assert!(measurements.notes_cycles() > 0);
+    assert!(measurements.tx_script_cycles() > 0);
+    assert!(measurements.auth_cycles() > 0);
+    assert!(measurements.auth_cycles() < measurements.epilogue_cycles());
+    assert_eq!(
+        measurements.prologue_cycles()
+            + measurements.notes_cycles()
+            + measurements.tx_script_cycles()
+            + measurements.epilogue_cycles(),
+        measurements.total_cycles()
+    );
+
+    // the execution of all notes is part of the notes processing
+    let note_execution_cycles: usize =
+        measurements.note_execution.iter().map(|(_, cycles)| cycles).sum();
+    assert!(note_execution_cycles <= measurements.notes_cycles());
+
+    Ok(())
+}
+
+/// Tests that the transaction ID computed from the transaction commitments before proving matches
+/// the ID of the executed and proven transaction.
+#[tokio::test]
+async fn transaction_id_can_be_computed_from_commitments() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let executed_tx = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    let tx_id = TransactionId::new(
+        account.commitment(),
+        executed_tx.final_account().commitment(),
+        executed_tx.input_notes().commitment(),
+        executed_tx.output_notes().commitment(),
+    );
+    assert_eq!(tx_id, executed_tx.id());
+
+    let proven_tx = LocalTransactionProver::default().prove_dummy(executed_tx)?;
+    assert_eq!(tx_id, proven_tx.id());
+
+    // the ID can be correlated across logs via its hex representation
+    assert_eq!(tx_id.to_string().parse::<TransactionId>()?, tx_id);
+
+    Ok(())
+}
+
+/// Tests that verifying a transaction with a forged proof fails with an error whose summary
+/// mentions the attempted security level.
+#[tokio::test]
+async fn transaction_verifier_error_summary_for_forged_proof() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let mock_chain = builder.build()?;
+
+    let executed_tx =
+        mock_chain.build_tx_context(account.id(), &[], &[])?.build()?.execute().await?;
+    // a dummy proof does not prove the execution of the transaction kernel
+    let forged_tx = LocalTransactionProver::default().prove_dummy(executed_tx)?;
+
+    let error = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify(&forged_tx)
+        .unwrap_err();
+    assert_matches!(
+        error,
+        TransactionVerifierError::ProofVerificationFailed { proof_security_level, .. }
+            if proof_security_level == MIN_PROOF_SECURITY_LEVEL
+    );
+
+    let summary = error.summary();
+    assert!(!summary.contains('\n'));
+    assert!(summary.contains(&format!("security level {MIN_PROOF_SECURITY_LEVEL}")));
+
+    Ok(())
+}
+
+/// Tests that the verifier accepts a valid proof only if its security level is sufficient.
+#[tokio::test]
+async fn transaction_verifier_checks_security_level() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let mock_chain = builder.build()?;
+
+    let executed_tx =
+        mock_chain.build_tx_context(account.id(), &[], &[])?.build()?.execute().await?;
+    let proven_tx = LocalTransactionProver::default().prove(executed_tx)?;
+    let proof_security_level = proven_tx.proof().security_level();
+
+    TransactionVerifier::new(proof_security_level).verify(&proven_tx)?;
+
+    let error = TransactionVerifier::new(proof_security_level + 1)
+        .verify(&proven_tx)
+        .unwrap_err();
+    assert_matches!(
+        error,
+        TransactionVerifierError::InsufficientSecurityLevel { required, actual }
+            if required == proof_security_level + 1 && actual == proof_security_level
+    );
+
+    Ok(())
+}
+
+/// Tests that the verifier accepts a proof against its kernel, and that a proof against another
+/// kernel is reported as a kernel mismatch only if that kernel is known to the verifier.
+#[tokio::test]
+async fn transaction_verifier_checks_kernel_program() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let mock_chain = builder.build()?;
+
+    let executed_tx =
+        mock_chain.build_tx_context(account.id(), &[], &[])?.build()?.execute().await?;
+    let proven_tx = LocalTransactionProver::default().prove(executed_tx)?;
+
+    let tx_program_info = TransactionKernel::program_info();
+    let other_program_info = ProgramInfo::from(TransactionKernel::tx_script_main());
+
+    // the proof verifies against its kernel, also if other kernels are known
+    TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .with_kernel_program(tx_program_info.clone())
+        .with_known_kernel_programs([other_program_info.clone()])
+        .verify(&proven_tx)?;
+
+    // a verifier expecting a different kernel identifies the known kernel the proof was produced
+    // against
+    let error = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .with_kernel_program(other_program_info.clone())
+        .with_known_kernel_programs([tx_program_info.clone()])
+        .verify(&proven_tx)
+        .unwrap_err();
+    assert_matches!(
+        error,
+        TransactionVerifierError::KernelProgramMismatch { expected, actual }
+            if expected == *other_program_info.program_hash()
+                && actual == *tx_program_info.program_hash()
+    );
+
+    // without the kernel being known, the same proof is reported as invalid
+    let error = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .with_kernel_program(other_program_info)
+        .verify(&proven_tx)
+        .unwrap_err();
+    assert_matches!(error, TransactionVerifierError::ProofVerificationFailed { .. });
+
+    Ok(())
+}
+
+/// Tests that the account update details of a proven transaction reflect the storage mode of the
+/// updated account.
+#[tokio::test]
+async fn proven_transaction_account_update_details() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let public_account = builder.add_existing_mock_account(Auth::IncrNonce)?;
+    let private_account = builder.add_account_from_builder(
+        Auth::IncrNonce,
+        Account::builder([7; 32])
+            .storage_mode(AccountStorageMode::Private)
+            .with_component(MockAccountComponent::with_empty_slots()),
+        AccountState::Exists,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let executed_tx = mock_chain
+        .build_tx_context(public_account.id(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+    let proven_tx = LocalTransactionProver::default().prove_dummy(executed_tx)?;
+
+    assert_eq!(proven_tx.account_id(), public_account.id());
+    assert_eq!(proven_tx.account_update_details(), proven_tx.account_update().details());
+    assert_matches!(
+        proven_tx.account_update_details(),
+        AccountUpdateDetails::Delta(delta) => assert_eq!(delta.id(), public_account.id())
+    );
+
+    let executed_tx = mock_chain
+        .build_tx_context(private_account.clone(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+    let proven_tx = LocalTransactionProver::default().prove_dummy(executed_tx)?;
+
+    assert_eq!(proven_tx.account_id(), private_account.id());
+    assert_matches!(proven_tx.account_update_details(), AccountUpdateDetails::Private);
+
+    Ok(())
+}
+
+/// Tests the human-readable rendering of executed and proven transactions against a snapshot.
+///
+/// The transaction consumes an authenticated and an unauthenticated note so that both branches of
+/// the input note rendering are covered.
+#[tokio::test]
+async fn executed_and_proven_transaction_pretty_print() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let authenticated_note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let mut rng = RpoRandomCoin::new(Word::empty());
+    let unauthenticated_note = P2idNote::create(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        vec![],
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut rng,
+    )?;
+
+    let executed_tx = mock_chain
+        .build_tx_context(
+            account.id(),
+            &[authenticated_note.id()],
+            slice::from_ref(&unauthenticated_note),
+        )?
+        .build()?
+        .execute()
+        .await?;
+    let proven_tx = LocalTransactionProver::default().prove_dummy(executed_tx.clone())?;
+
+    let expected = "\
+executed transaction 0x48736c9a73b6b1d921c61a5973939f7b15a78cf354e92803bd4fff4a422b2c93
+    account id: 0xf0a52496ee530310675bc0d40ee8cf
+    initial account commitment: 0xafdaef154a372c0adf03be336c25cc3aead10ac2f008b69e8163028fd582e635
+    final account commitment: 0xc8f095edd95683c1d54e8ed4acc3170445162d15ff86438ada02c959142d4f44
+    reference block: 0
+    expiration block: 4294967295
+    fee: fungible 0xab0000000000cd200000ac000000de: 0
+    input notes (2), commitment 0xd75c163f0a84be5350997fe3f92c17db523a7e10f75469dec569836d274ac84d
+        note 0x14872b99b2a827250a03a17793d595ac48418091d305609b3a460d8e735250a3, nullifier 0xa180781a81c2bd0c0c5000d2b1af34083f98d857049104bef9a23b7e47dce43e
+        note 0x1a62e92e8e67d451e24ff37821881ad967f39163840d721623b027d6f3fa44c3, nullifier 0x4a43d55c8783e957728dbd86e43d774f746714e73516ced4cac8fff547e013a8
+    account delta
+        account id: 0xf0a52496ee530310675bc0d40ee8cf
+        nonce delta: 1
+        vault
+            fungible 0xaa0000000000bc200000bc000000de: +100
+    output notes (0), commitment 0x0000000000000000000000000000000000000000000000000000000000000000";
+    assert_eq!(executed_tx.to_pretty_string(), expected);
+
+    // The genesis block commitment depends on the block timestamp, so it cannot be part of the
+    // snapshot.
+    let ref_block_commitment = mock_chain.latest_block_header().commitment();
+    let expected = format!(
+        "\
+proven transaction 0x48736c9a73b6b1d921c61a5973939f7b15a78cf354e92803bd4fff4a422b2c93
+    account id: 0xf0a52496ee530310675bc0d40ee8cf
+    initial account commitment: 0xafdaef154a372c0adf03be336c25cc3aead10ac2f008b69e8163028fd582e635
+    final account commitment: 0xc8f095edd95683c1d54e8ed4acc3170445162d15ff86438ada02c959142d4f44
+    account delta commitment: 0x80363a8f1fb0661c245a7b271be235c1ce82d64f712602b7be7d22ed71fc2b0e
+    reference block: 0, commitment {ref_block_commitment}
+    expiration block: 4294967295
+    fee: fungible 0xab0000000000cd200000ac000000de: 0
+    input notes (2), commitment 0xd75c163f0a84be5350997fe3f92c17db523a7e10f75469dec569836d274ac84d
+        nullifier 0xa180781a81c2bd0c0c5000d2b1af34083f98d857049104bef9a23b7e47dce43e
+        nullifier 0x4a43d55c8783e957728dbd86e43d774f746714e73516ced4cac8fff547e013a8, unauthenticated note 0x1a62e92e8e67d451e24ff37821881ad967f39163840d721623b027d6f3fa44c3
+    account delta
+        account id: 0xf0a52496ee530310675bc0d40ee8cf
+        nonce delta: 1
+        vault
+            fungible 0xaa0000000000bc200000bc000000de: +100
+    output notes (0), commitment 0x0000000000000000000000000000000000000000000000000000000000000000"
+    );
+    assert_eq!(proven_tx.to_pretty_string(), expected);
+
+    Ok(())
+}
+
+/// A [`DataStore`] which forwards all requests to a [`TransactionContext`] and counts how often
+/// transaction inputs were fetched.
+struct CountingDataStore<'ctx> {
+    inner: &'ctx TransactionContext,
+    num_tx_inputs_fetches: AtomicUsize,
+}
+
+impl DataStore for CountingDataStore<'_> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        self.num_tx_inputs_fetches.fetch_add(1, Ordering::Relaxed);
+        self.inner.get_transaction_inputs(account_id, ref_blocks)
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        self.inner.get_foreign_account_inputs(foreign_account_id, ref_block)
+    }
+
+    fn get_vault_asset_witnesses(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_keys: BTreeSet<AssetVaultKey>,
+    ) -> impl FutureMaybeSend<Result<Vec<AssetWitness>, DataStoreError>> {
+        self.inner.get_vault_asset_witnesses(account_id, vault_root, vault_keys)
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        self.inner.get_storage_map_witness(account_id, map_root, map_key)
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<Option<NoteScript>, DataStoreError>> {
+        self.inner.get_note_script(script_root)
+    }
+}
+
+impl MastForestStore for CountingDataStore<'_> {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        MastForestStore::get(self.inner, procedure_hash)
+    }
+}
+
+/// Tests that executing a batch of transactions which share the native account and reference
+/// block fetches the transaction inputs only once, while each transaction consumes its own notes.
+#[tokio::test]
+async fn execute_transactions_deduplicates_input_fetches() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let notes = (1..=3)
+        .map(|amount| {
+            builder.add_p2id_note(
+                ACCOUNT_ID_SENDER.try_into().unwrap(),
+                account.id(),
+                &[FungibleAsset::mock(amount)],
+                NoteType::Public,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    let note_ids = notes.iter().map(Note::id).collect::<Vec<_>>();
+    let tx_context = mock_chain.build_tx_context(account.id(), &note_ids, &[])?.build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let store = CountingDataStore {
+        inner: &tx_context,
+        num_tx_inputs_fetches: AtomicUsize::new(0),
+    };
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&store)
+        .with_source_manager(tx_context.source_manager());
+
+    // each transaction consumes a different one of the notes
+    let requests = tx_context
+        .input_notes()
+        .iter()
+        .map(|input_note| {
+            let notes = InputNotes::new(vec![input_note.clone()])?;
+            Ok(TxRequest::new(account.id(), block_ref, notes, TransactionArgs::default()))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let results = executor.execute_transactions(requests.clone()).await;
+
+    assert_eq!(results.len(), 3);
+    for (request, result) in requests.iter().zip(results) {
+        let executed_tx = result.context("failed to execute transaction in batch")?;
+        assert_eq!(executed_tx.input_notes(), request.notes());
+        assert_eq!(executed_tx.initial_account().commitment(), account.commitment());
+    }
+    assert_eq!(store.num_tx_inputs_fetches.load(Ordering::Relaxed), 1);
+
+    Ok(())
+}
+
+/// A [`DataStore`] which forwards all requests to a [`TransactionContext`], but fails to provide
+/// witnesses for a specific vault key.
+struct MissingAssetWitnessStore<'ctx> {
+    inner: &'ctx TransactionContext,
+    missing_key: AssetVaultKey,
+}
+
+impl DataStore for MissingAssetWitnessStore<'_> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        self.inner.get_transaction_inputs(account_id, ref_blocks)
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        self.inner.get_foreign_account_inputs(foreign_account_id, ref_block)
+    }
+
+    fn get_vault_asset_witnesses(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_keys: BTreeSet<AssetVaultKey>,
+    ) -> impl FutureMaybeSend<Result<Vec<AssetWitness>, DataStoreError>> {
+        async move {
+            if vault_keys.contains(&self.missing_key) {
+                return Err(DataStoreError::other(format!(
+                    "witness for vault key {} is missing",
+                    self.missing_key
+                )));
+            }
+            self.inner.get_vault_asset_witnesses(account_id, vault_root, vault_keys).await
+        }
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        self.inner.get_storage_map_witness(account_id, map_root, map_key)
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<Option<NoteScript>, DataStoreError>> {
+        self.inner.get_note_script(script_root)
+    }
+}
+
+impl MastForestStore for MissingAssetWitnessStore<'_> {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        MastForestStore::get(self.inner, procedure_hash)
+    }
+}
+
+/// Tests that an error raised by an event handler while executing an input note names the note
+/// that was being executed.
+#[tokio::test]
+async fn event_handler_error_names_active_note() -> anyhow::Result<()> {
+    let missing_asset =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2.try_into().unwrap(), 50)?;
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet_with_assets(
+        Auth::IncrNonce,
+        [FungibleAsset::mock(100), missing_asset.into()],
+    )?;
+    let mock_chain = builder.build()?;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let noop_note = NoteBuilder::new(sender, rand::rng()).build()?;
+    // the vault witness for the asset is not prefetched since the note does not contain it, so it
+    // is requested from the data store when the note reads the balance; the account procedure
+    // called before must not be attributed to the balance read, which does not authenticate it
+    let balance_note = NoteBuilder::new(sender, rand::rng())
+        .code(format!(
+            "
+            use miden::protocol::active_account
+            use miden::standards::wallets::basic->wallet
+
+            begin
+                push.{asset}
+                call.wallet::receive_asset
+                dropw
+
+                push.{suffix}.{prefix}
+                exec.active_account::get_balance
+                drop
+            end
+            ",
+            asset = Word::from(FungibleAsset::mock(1)),
+            suffix = missing_asset.faucet_id().suffix(),
+            prefix = missing_asset.faucet_id().prefix().as_felt(),
+        ))
+        .build()?;
+
+    let tx_context = mock_chain
+        .build_tx_context(account.id(), &[], &[noop_note, balance_note.clone()])?
+        .build()?;
+    let store = MissingAssetWitnessStore {
+        inner: &tx_context,
+        missing_key: missing_asset.vault_key(),
+    };
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&store)
+        .with_source_manager(tx_context.source_manager());
+
+    let err = executor
+        .execute_transaction(
+            account.id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.input_notes().clone(),
+            TransactionArgs::default(),
+        )
+        .await
+        .unwrap_err();
+
+    let TransactionExecutorError::TransactionProgramExecutionFailed(ExecutionError::EventError {
+        error,
+        ..
+    }) = err
+    else {
+        panic!("expected event error but got {err:?}");
+    };
+    let kernel_error = error
+        .downcast_ref::<TransactionKernelError>()
+        .expect("event error should be a transaction kernel error");
+
+    assert_matches!(
+        kernel_error,
+        TransactionKernelError::GetVaultAssetWitness { context, .. }
+            if context.note_id() == Some(balance_note.id())
+                && context.foreign_account_id().is_none()
+                && context.procedure_root().is_none()
+    );
+    assert!(kernel_error.to_string().contains(&balance_note.id().to_string()));
+
+    Ok(())
+}
+
+/// A [`TransactionAuthenticator`] which forwards all requests to a [`BasicAuthenticator`] and
+/// counts how often signatures were requested.
+struct CountingAuthenticator<'auth> {
+    inner: &'auth BasicAuthenticator,
+    num_signature_requests: AtomicUsize,
+}
+
+impl TransactionAuthenticator for CountingAuthenticator<'_> {
+    fn get_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &SigningInputs,
+    ) -> impl FutureMaybeSend<Result<Signature, AuthenticationError>> {
+        self.num_signature_requests.fetch_add(1, Ordering::Relaxed);
+        self.inner.get_signature(pub_key_commitment, signing_inputs)
+    }
+
+    fn get_public_key(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+    ) -> impl FutureMaybeSend<Option<Arc<PublicKey>>> {
+        self.inner.get_public_key(pub_key_commitment)
+    }
+}
+
+/// Tests that executing the same transaction twice with a [`CachingAuthenticator`] requests the
+/// signature only once, while both executed transactions contain it in their advice inputs.
+#[tokio::test]
+async fn caching_authenticator_requests_signature_once() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+    let tx_context = mock_chain.build_tx_context(account.id(), &[note.id()], &[])?.build()?;
+
+    let authenticator = CachingAuthenticator::new(
+        CountingAuthenticator {
+            inner: tx_context.authenticator().unwrap(),
+            num_signature_requests: AtomicUsize::new(0),
+        },
+        16,
+    );
+    let executor = TransactionExecutor::new(&tx_context)
+        .with_authenticator(&authenticator)
+        .with_source_manager(tx_context.source_manager());
+
+    let mut executed_transactions = Vec::new();
+    for _ in 0..2 {
+        let executed_tx = executor
+            .execute_transaction(
+                account.id(),
+                tx_context.tx_inputs().block_header().block_num(),
+                tx_context.input_notes().clone(),
+                tx_context.tx_args().clone(),
+            )
+            .await?;
+        executed_transactions.push(executed_tx);
+    }
+
+    assert_eq!(authenticator.inner().num_signature_requests.load(Ordering::Relaxed), 1);
+    assert_eq!(authenticator.num_cached_signatures(), 1);
+    // signatures are randomized, so the advice inputs only match if the cached signature was used
+    assert_eq!(
+        executed_transactions[0].advice_witness(),
+        executed_transactions[1].advice_witness()
+    );
+
+    Ok(())
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -847,3 +1843,116 @@ async fn tx_can_be_reexecuted() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that an executed transaction can be replayed from its replay bundle and that mutating an
+/// advice value which the transaction depends on is reported as a divergence.
+#[tokio::test]
+async fn executed_transaction_can_be_replayed() -> anyhow::Result<()> {
+    let expiration_delta_key = Word::from([11, 22, 33, 44u32]);
+    let tx_script_src = format!(
+        r#"
+        use miden::protocol::tx
+
+        begin
+            # load the expiration delta from the advice map
+            push.{expiration_delta_key}
+            adv.push_mapval adv_push.1
+            # => [expiration_delta, EXPIRATION_DELTA_KEY]
+
+            exec.tx::update_expiration_block_delta
+            # => [EXPIRATION_DELTA_KEY]
+
+            dropw
+        end
+        "#
+    );
+    let tx_script = CodeBuilder::default().compile_tx_script(tx_script_src)?;
+
+    let mut builder = MockChain::builder();
+    // Use basic auth so the replay bundle must contain a signature.
+    let account = builder.add_existing_mock_account(Auth::BasicAuth)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(3)],
+        NoteType::Public,
+    )?;
+    let chain = builder.build()?;
+
+    let tx = chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .tx_script(tx_script)
+        .extend_advice_map([(expiration_delta_key, vec![Felt::new(10)])])
+        .build()?
+        .execute()
+        .await?;
+
+    let bundle = ReplayBundle::read_from_bytes(&tx.to_replay_bundle().to_bytes())?;
+    let replayed_outputs = bundle.execute().await.context("failed to replay transaction")?;
+    assert_eq!(&replayed_outputs, bundle.tx_outputs());
+
+    // Mutate the expiration delta in the advice inputs of the bundle.
+    let (tx_inputs, kernel_program_hash, tx_outputs) = bundle.into_parts();
+    let mut advice_inputs = tx_inputs.advice_inputs().clone();
+    advice_inputs.map.extend([(expiration_delta_key, vec![Felt::new(5)])]);
+    let tx_inputs = tx_inputs.with_advice_inputs(advice_inputs);
+    let mutated_bundle = ReplayBundle::new(tx_inputs, kernel_program_hash, tx_outputs);
+
+    let ref_block_num = tx.block_header().block_num();
+    assert_matches!(
+        mutated_bundle.execute().await,
+        Err(TransactionReplayError::OutputsDiverged(report)) => {
+            assert_eq!(
+                report.divergences(),
+                [ReplayDivergence::ExpirationBlockNum {
+                    recorded: ref_block_num + 10,
+                    replayed: ref_block_num + 5,
+                }]
+            );
+        }
+    );
+
+    Ok(())
+}
+
+/// Tests that a transaction executes identically against a partial account tracking only the
+/// minimal account state and one created by [`PartialAccount::from_full`].
+#[tokio::test]
+async fn execute_tx_against_partial_account_from_full() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet_with_assets(
+        Auth::BasicAuth,
+        [FungibleAsset::mock(100), NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA)],
+    )?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(50)],
+        NoteType::Public,
+    )?;
+    let chain = builder.build()?;
+
+    let partial_account = PartialAccount::from_full(&account);
+    assert_eq!(partial_account.commitment(), account.commitment());
+    assert_eq!(partial_account.vault().leaves().count(), account.vault().assets().count());
+
+    let minimal_tx = chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    let tx_inputs = chain.get_transaction_inputs(partial_account, &[note.id()], &[])?;
+    let full_tx = chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .tx_inputs(tx_inputs)
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(full_tx.id(), minimal_tx.id());
+    assert_eq!(full_tx.final_account(), minimal_tx.final_account());
+    assert_eq!(full_tx.account_delta(), minimal_tx.account_delta());
+
+    Ok(())
+}
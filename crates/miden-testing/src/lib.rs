@@ -13,7 +13,9 @@ pub use mock_chain::{
     MockChain,
     MockChainBuilder,
     MockChainNote,
+    NetworkFaucetHandle,
     TxContextInput,
+    WalletFunding,
 };
 
 mod tx_context;
@@ -1153,6 +1153,7 @@ impl From<Account> for TxContextInput {
 
 #[cfg(test)]
 mod tests {
+    use miden_protocol::Felt;
     use miden_protocol::account::{AccountBuilder, AccountStorageMode};
     use miden_protocol::asset::{Asset, FungibleAsset};
     use miden_protocol::note::NoteType;
@@ -1161,10 +1162,11 @@ mod tests {
         ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
         ACCOUNT_ID_SENDER,
     };
+    use miden_standards::account::faucets::FungibleFaucetExt;
     use miden_standards::account::wallets::BasicWallet;
 
     use super::*;
-    use crate::Auth;
+    use crate::{Auth, WalletFunding};
 
     #[test]
     fn prove_until_block() -> anyhow::Result<()> {
@@ -1310,4 +1312,86 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn add_funded_wallet_seeds_vault_and_issuance() -> anyhow::Result<()> {
+        let mut builder = MockChain::builder();
+        let faucet =
+            builder.add_existing_basic_faucet(Auth::BasicAuth, "TKN", 10_000, Some(100))?;
+        let default_faucet_id = ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into()?;
+
+        let wallet = builder.add_funded_wallet(
+            Auth::IncrNonce,
+            &[
+                FungibleAsset::new(faucet.id(), 500)?,
+                FungibleAsset::new(default_faucet_id, 200)?,
+                FungibleAsset::new(default_faucet_id, 300)?,
+            ],
+        )?;
+
+        // the total issuance of an existing faucet cannot exceed its max supply
+        assert!(
+            builder
+                .add_funded_wallet(Auth::IncrNonce, &[FungibleAsset::new(faucet.id(), 9_401)?])
+                .is_err()
+        );
+
+        let chain = builder.build()?;
+
+        let wallet = chain.committed_account(wallet.id())?;
+        assert_eq!(wallet.vault().get_balance(faucet.id())?, 500);
+        assert_eq!(wallet.vault().get_balance(default_faucet_id)?, 500);
+
+        // the issuance of the existing faucet is increased and the missing faucet was added
+        let faucet = chain.committed_account(faucet.id())?;
+        assert_eq!(faucet.get_token_issuance()?, Felt::new(600));
+        let default_faucet = chain.committed_account(default_faucet_id)?;
+        assert_eq!(default_faucet.get_token_issuance()?, Felt::new(500));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_funded_wallet_with_notes() -> anyhow::Result<()> {
+        let mut builder = MockChain::builder().wallet_funding(WalletFunding::Notes);
+        let faucet_id = ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into()?;
+
+        let wallet =
+            builder.add_funded_wallet(Auth::BasicAuth, &[FungibleAsset::new(faucet_id, 1000)?])?;
+        assert!(wallet.vault().is_empty());
+
+        let mut chain = builder.build()?;
+        assert_eq!(chain.committed_account(faucet_id)?.get_token_issuance()?, Felt::new(1000));
+
+        let note_ids: Vec<NoteId> = chain.committed_notes().keys().copied().collect();
+        assert_eq!(note_ids.len(), 1);
+
+        let tx = chain.build_tx_context(wallet.id(), &note_ids, &[])?.build()?.execute().await?;
+        chain.add_pending_executed_transaction(&tx)?;
+        chain.prove_next_block()?;
+
+        let wallet = chain.committed_account(wallet.id())?;
+        assert_eq!(wallet.vault().get_balance(faucet_id)?, 1000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn network_faucet_mint_to() -> anyhow::Result<()> {
+        let mut builder = MockChain::builder();
+        let faucet = builder.add_network_faucet("NET", 6, 1000)?;
+        let wallet = builder.add_existing_wallet(Auth::BasicAuth)?;
+        let mut chain = builder.build()?;
+
+        faucet.mint_to(&mut chain, wallet.id(), 75).await?;
+        faucet.mint_to(&mut chain, wallet.id(), 25).await?;
+
+        let wallet = chain.committed_account(wallet.id())?;
+        assert_eq!(wallet.vault().get_balance(faucet.faucet_id())?, 100);
+
+        let faucet_account = chain.committed_account(faucet.faucet_id())?;
+        assert_eq!(faucet_account.get_token_issuance()?, Felt::new(100));
+
+        Ok(())
+    }
 }
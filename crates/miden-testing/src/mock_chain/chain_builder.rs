@@ -9,6 +9,9 @@ use anyhow::Context;
 /// Default number of decimals for faucets created in tests.
 const DEFAULT_FAUCET_DECIMALS: u8 = 10;
 
+/// Token symbol of the default faucets created by [`MockChainBuilder::add_funded_wallet`].
+const DEFAULT_FAUCET_SYMBOL: &str = "TST";
+
 // IMPORTS
 // ================================================================================================
 
@@ -48,13 +51,18 @@ use miden_protocol::testing::account_id::ACCOUNT_ID_NATIVE_ASSET_FAUCET;
 use miden_protocol::testing::random_signer::RandomBlockSigner;
 use miden_protocol::transaction::{OrderedTransactionHeaders, OutputNote, TransactionKernel};
 use miden_protocol::{Felt, MAX_OUTPUT_NOTES_PER_BATCH, Word, ZERO};
-use miden_standards::account::faucets::{BasicFungibleFaucet, NetworkFungibleFaucet};
+use miden_standards::account::faucets::{
+    BasicFungibleFaucet,
+    FungibleFaucetExt,
+    NetworkFungibleFaucet,
+};
 use miden_standards::account::wallets::BasicWallet;
-use miden_standards::note::{P2idNote, P2ideNote, SwapNote};
+use miden_standards::note::{P2idNote, P2ideNote, P2mNote, SwapNote};
 use miden_standards::testing::account_component::MockAccountComponent;
 use rand::Rng;
 
 use crate::mock_chain::chain::AccountAuthenticator;
+use crate::mock_chain::network_faucet::NetworkFaucetHandle;
 use crate::utils::{create_p2any_note, create_spawn_note};
 use crate::{AccountState, Auth, MockChain};
 
@@ -110,6 +118,7 @@ pub struct MockChainBuilder {
     // Fee parameters.
     native_asset_id: AccountId,
     verification_base_fee: u32,
+    wallet_funding: WalletFunding,
 }
 
 impl MockChainBuilder {
@@ -133,6 +142,7 @@ impl MockChainBuilder {
             rng: RpoRandomCoin::new(Default::default()),
             native_asset_id,
             verification_base_fee: 0,
+            wallet_funding: WalletFunding::default(),
         }
     }
 
@@ -174,6 +184,14 @@ impl MockChainBuilder {
         self
     }
 
+    /// Sets how [`Self::add_funded_wallet`] provides the assets to the wallets it adds.
+    ///
+    /// Defaults to [`WalletFunding::Vault`].
+    pub fn wallet_funding(mut self, wallet_funding: WalletFunding) -> Self {
+        self.wallet_funding = wallet_funding;
+        self
+    }
+
     /// Consumes the builder, creates the genesis block of the chain and returns the [`MockChain`].
     pub fn build(self) -> anyhow::Result<MockChain> {
         // Create the genesis block, consisting of the provided accounts and notes.
@@ -351,13 +369,7 @@ impl MockChainBuilder {
         // The faucet's sysdata slot is initialized to an empty word by default.
         // If total_issuance is set, overwrite it and reinsert the account.
         if let Some(issuance) = total_issuance {
-            account
-                .storage_mut()
-                .set_item(
-                    AccountStorage::faucet_sysdata_slot(),
-                    Word::from([ZERO, ZERO, ZERO, Felt::new(issuance)]),
-                )
-                .context("failed to set faucet storage")?;
+            set_token_issuance(&mut account, issuance)?;
             self.accounts.insert(account.id(), account.clone());
         }
 
@@ -395,19 +407,104 @@ impl MockChainBuilder {
         // The faucet's sysdata slot is initialized to an empty word by default.
         // If total_issuance is set, overwrite it and reinsert the account.
         if let Some(issuance) = total_issuance {
-            account
-                .storage_mut()
-                .set_item(
-                    AccountStorage::faucet_sysdata_slot(),
-                    Word::from([ZERO, ZERO, ZERO, Felt::new(issuance)]),
-                )
-                .context("failed to set faucet storage")?;
+            set_token_issuance(&mut account, issuance)?;
             self.accounts.insert(account.id(), account.clone());
         }
 
         Ok(account)
     }
 
+    /// Adds an existing [`NetworkFungibleFaucet`] account with the provided metadata to the initial
+    /// chain state and returns a [`NetworkFaucetHandle`] to mint its tokens in the built chain.
+    ///
+    /// The faucet is owned by a new existing [`BasicWallet`] account, which creates the MINT notes
+    /// consumed by the faucet in [`NetworkFaucetHandle::mint_to`].
+    pub fn add_network_faucet(
+        &mut self,
+        token_symbol: &str,
+        decimals: u8,
+        max_supply: u64,
+    ) -> anyhow::Result<NetworkFaucetHandle> {
+        let owner_account_id = self.add_existing_wallet(Auth::IncrNonce)?.id();
+        let token_symbol = TokenSymbol::new(token_symbol).context("invalid argument")?;
+        let network_faucet = NetworkFungibleFaucet::new(
+            token_symbol,
+            decimals,
+            Felt::new(max_supply),
+            owner_account_id,
+        )
+        .context("invalid argument")?;
+
+        let account_builder = AccountBuilder::new(self.rng.random())
+            .storage_mode(AccountStorageMode::Network)
+            .with_component(network_faucet)
+            .account_type(AccountType::FungibleFaucet);
+
+        let faucet =
+            self.add_account_from_builder(Auth::IncrNonce, account_builder, AccountState::Exists)?;
+
+        Ok(NetworkFaucetHandle::new(faucet.id(), owner_account_id))
+    }
+
+    /// Adds an existing public [`BasicWallet`] account funded with the provided assets to the
+    /// initial chain state and registers the authenticator (if any).
+    ///
+    /// For every faucet of the provided assets that is not part of the initial chain state yet, a
+    /// default [`BasicFungibleFaucet`] with that ID is added. The token issuance of each faucet is
+    /// increased by the amount of its assets, so that the issuance matches the funded assets.
+    ///
+    /// Depending on the [`WalletFunding`] set via [`Self::wallet_funding`], the assets are either
+    /// added directly to the wallet's vault, or provided in P2ID notes which the wallet has to
+    /// consume. Multiple assets of the same faucet are merged into a single asset in the vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the total amount of the assets of a faucet exceeds [`FungibleAsset::MAX_AMOUNT`].
+    /// - an existing account with the ID of one of the asset faucets is not a fungible faucet.
+    /// - the token issuance of a faucet would exceed its max supply.
+    pub fn add_funded_wallet(
+        &mut self,
+        auth_method: Auth,
+        assets: &[FungibleAsset],
+    ) -> anyhow::Result<Account> {
+        // The assets are merged per faucet, since the vault can hold only one asset per faucet.
+        let mut merged_assets = BTreeMap::<AccountId, FungibleAsset>::new();
+        for asset in assets {
+            let merged_asset = match merged_assets.remove(&asset.faucet_id()) {
+                Some(merged_asset) => merged_asset
+                    .add(*asset)
+                    .context("total amount of assets of faucet exceeds the max amount")?,
+                None => *asset,
+            };
+            merged_assets.insert(asset.faucet_id(), merged_asset);
+        }
+
+        for asset in merged_assets.values() {
+            self.issue_tokens(asset.faucet_id(), asset.amount())?;
+        }
+
+        match self.wallet_funding {
+            WalletFunding::Vault => self.add_existing_wallet_with_assets(
+                auth_method,
+                merged_assets.into_values().map(Asset::from),
+            ),
+            WalletFunding::Notes => {
+                let wallet = self.add_existing_wallet(auth_method)?;
+                for asset in assets {
+                    self.add_p2id_note(
+                        asset.faucet_id(),
+                        wallet.id(),
+                        &[Asset::from(*asset)],
+                        NoteType::Public,
+                    )?;
+                }
+
+                Ok(wallet)
+            },
+        }
+    }
+
     /// Creates a new public account with an [`MockAccountComponent`] and registers the
     /// authenticator (if any).
     pub fn create_new_mock_account(&mut self, auth_method: Auth) -> anyhow::Result<Account> {
@@ -592,6 +689,31 @@ impl MockChainBuilder {
         Ok(note)
     }
 
+    /// Creates a new P2M note from the provided parameters and adds it to the list of genesis
+    /// notes.
+    ///
+    /// In the created [`MockChain`], the note will be immediately spendable by any of the
+    /// `target_account_ids`.
+    pub fn add_p2m_note(
+        &mut self,
+        sender_account_id: AccountId,
+        target_account_ids: Vec<AccountId>,
+        asset: &[Asset],
+        note_type: NoteType,
+    ) -> Result<Note, NoteError> {
+        let note = P2mNote::create(
+            sender_account_id,
+            target_account_ids,
+            asset.to_vec(),
+            note_type,
+            NoteAttachment::default(),
+            &mut self.rng,
+        )?;
+        self.add_output_note(OutputNote::Full(note.clone()));
+
+        Ok(note)
+    }
+
     /// Adds a public SWAP [`OutputNote`] to the list of genesis notes.
     pub fn add_swap_note(
         &mut self,
@@ -675,6 +797,95 @@ impl MockChainBuilder {
     fn native_fee_asset(&self, amount: u64) -> anyhow::Result<FungibleAsset> {
         FungibleAsset::new(self.native_asset_id, amount).context("failed to create fee asset")
     }
+
+    /// Increases the token issuance of the genesis faucet with the provided ID by `amount`.
+    ///
+    /// If the faucet is not part of the initial chain state, a default [`BasicFungibleFaucet`] with
+    /// the provided ID is added first.
+    fn issue_tokens(&mut self, faucet_id: AccountId, amount: u64) -> anyhow::Result<()> {
+        if !self.accounts.contains_key(&faucet_id) {
+            let faucet = self.build_default_faucet(faucet_id)?;
+            self.account_authenticators.insert(faucet_id, AccountAuthenticator::new(None));
+            self.accounts.insert(faucet_id, faucet);
+        }
+
+        let faucet = self.accounts.get_mut(&faucet_id).expect("faucet should have been added");
+        let issuance = faucet
+            .get_token_issuance()
+            .with_context(|| format!("account {faucet_id} is not a fungible faucet"))?
+            .as_int()
+            .checked_add(amount)
+            .context("token issuance overflowed")?;
+        let max_supply = faucet
+            .storage()
+            .get_item(BasicFungibleFaucet::metadata_slot())
+            .with_context(|| format!("faucet {faucet_id} does not store its metadata"))?[0]
+            .as_int();
+        if issuance > max_supply {
+            anyhow::bail!("token issuance {issuance} of faucet {faucet_id} exceeds max supply");
+        }
+
+        set_token_issuance(faucet, issuance)
+    }
+
+    /// Builds an existing [`BasicFungibleFaucet`] account with the provided ID, the
+    /// [`DEFAULT_FAUCET_SYMBOL`] and the maximum possible max supply.
+    fn build_default_faucet(&mut self, faucet_id: AccountId) -> anyhow::Result<Account> {
+        let token_symbol =
+            TokenSymbol::new(DEFAULT_FAUCET_SYMBOL).expect("default token symbol should be valid");
+        let basic_faucet = BasicFungibleFaucet::new(
+            token_symbol,
+            DEFAULT_FAUCET_DECIMALS,
+            Felt::new(FungibleAsset::MAX_AMOUNT),
+        )
+        .context("failed to create BasicFungibleFaucet")?;
+        let (auth_component, _) = Auth::IncrNonce.build_component();
+
+        let faucet = AccountBuilder::new(self.rng.random())
+            .storage_mode(faucet_id.storage_mode())
+            .account_type(AccountType::FungibleFaucet)
+            .with_auth_component(auth_component)
+            .with_component(basic_faucet)
+            .build_existing()
+            .context("failed to build default faucet")?;
+
+        // The ID of the built faucet is derived from the random seed, so it is replaced by the
+        // requested ID. This is fine for an existing account, since its ID is not validated
+        // against its seed anymore.
+        let (_, vault, storage, code, nonce, _) = faucet.into_parts();
+        Ok(Account::new_unchecked(faucet_id, vault, storage, code, nonce, None))
+    }
+}
+
+// WALLET FUNDING
+// ================================================================================================
+
+/// Determines how [`MockChainBuilder::add_funded_wallet`] provides the assets to the wallets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalletFunding {
+    /// The assets are added directly to the vault of the wallet in the genesis state.
+    #[default]
+    Vault,
+    /// For each asset, a public P2ID note sent by the asset's faucet to the wallet is added to the
+    /// genesis state. The wallet only holds the assets once it consumed the notes in a
+    /// transaction.
+    Notes,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Overwrites the token issuance stored in the sysdata slot of the provided faucet account.
+fn set_token_issuance(faucet: &mut Account, issuance: u64) -> anyhow::Result<()> {
+    faucet
+        .storage_mut()
+        .set_item(
+            AccountStorage::faucet_sysdata_slot(),
+            Word::from([ZERO, ZERO, ZERO, Felt::new(issuance)]),
+        )
+        .context("failed to set faucet storage")?;
+
+    Ok(())
 }
 
 impl Default for MockChainBuilder {
@@ -1,9 +1,11 @@
 mod auth;
 mod chain;
 mod chain_builder;
+mod network_faucet;
 mod note;
 
 pub use auth::Auth;
 pub use chain::{AccountState, MockChain, TxContextInput};
-pub use chain_builder::MockChainBuilder;
+pub use chain_builder::{MockChainBuilder, WalletFunding};
+pub use network_faucet::NetworkFaucetHandle;
 pub use note::MockChainNote;
@@ -0,0 +1,133 @@
+use alloc::vec;
+
+use anyhow::Context;
+use miden_processor::crypto::RpoRandomCoin;
+use miden_protocol::account::AccountId;
+use miden_protocol::asset::FungibleAsset;
+use miden_protocol::note::{NoteAttachment, NoteType};
+use miden_protocol::transaction::OutputNote;
+use miden_protocol::{Felt, Word};
+use miden_standards::account::interface::{AccountInterface, AccountInterfaceExt};
+use miden_standards::note::{MintNote, MintNoteStorage, P2idNote};
+
+use crate::MockChain;
+
+// NETWORK FAUCET HANDLE
+// ================================================================================================
+
+/// A handle to a
+/// [`NetworkFungibleFaucet`](miden_standards::account::faucets::NetworkFungibleFaucet) added to the
+/// initial chain state via
+/// [`MockChainBuilder::add_network_faucet`](crate::MockChainBuilder::add_network_faucet).
+///
+/// The handle allows minting tokens of the faucet to accounts of the built [`MockChain`] through
+/// real transactions, see [`NetworkFaucetHandle::mint_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkFaucetHandle {
+    faucet_id: AccountId,
+    owner_account_id: AccountId,
+}
+
+impl NetworkFaucetHandle {
+    /// Creates a new handle for the network faucet with the provided ID and owner.
+    pub(super) fn new(faucet_id: AccountId, owner_account_id: AccountId) -> Self {
+        Self { faucet_id, owner_account_id }
+    }
+
+    /// Returns the ID of the faucet.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the ID of the faucet's owner, which is allowed to mint tokens.
+    pub fn owner_account_id(&self) -> AccountId {
+        self.owner_account_id
+    }
+
+    /// Mints `amount` tokens of the faucet to the account with the provided ID.
+    ///
+    /// This executes three transactions and proves a block after each of them:
+    /// - the faucet's owner creates a MINT note for the faucet.
+    /// - the faucet consumes the MINT note and creates a private P2ID note for the account.
+    /// - the account consumes the P2ID note.
+    ///
+    /// Afterwards, the minted tokens are part of the committed state of the account and the
+    /// faucet's token issuance is increased by `amount`.
+    ///
+    /// The account must be part of the committed chain state and its authenticator (if any) must
+    /// be registered with the chain.
+    pub async fn mint_to(
+        &self,
+        chain: &mut MockChain,
+        account_id: AccountId,
+        amount: u64,
+    ) -> anyhow::Result<()> {
+        let asset = FungibleAsset::new(self.faucet_id, amount).context("invalid mint amount")?;
+
+        // The serial number is derived from the current block number, which is incremented by
+        // every mint, so that repeated mints of the same amount create distinct notes.
+        let block_num = chain.latest_block_header().block_num();
+        let serial_num = Word::from([
+            Felt::from(block_num),
+            Felt::new(amount),
+            account_id.suffix(),
+            account_id.prefix().as_felt(),
+        ]);
+
+        let p2id_note = P2idNote::create(
+            self.faucet_id,
+            account_id,
+            vec![asset.into()],
+            NoteType::Private,
+            NoteAttachment::default(),
+            &mut RpoRandomCoin::new(serial_num),
+        )?;
+
+        let mint_storage = MintNoteStorage::new_private(
+            p2id_note.recipient().digest(),
+            Felt::new(amount),
+            p2id_note.metadata().tag().into(),
+        );
+        let mint_note = MintNote::create(
+            self.faucet_id,
+            self.owner_account_id,
+            mint_storage,
+            NoteAttachment::default(),
+            &mut RpoRandomCoin::new(serial_num),
+        )?;
+
+        let owner_account = chain.committed_account(self.owner_account_id)?;
+        let send_mint_note_script = AccountInterface::from_account(owner_account)
+            .build_send_notes_script(&[mint_note.clone().into()], None)?;
+        let send_mint_note_tx = chain
+            .build_tx_context(self.owner_account_id, &[], &[])?
+            .tx_script(send_mint_note_script)
+            .extend_expected_output_notes(vec![OutputNote::Full(mint_note.clone())])
+            .build()?
+            .execute()
+            .await
+            .context("failed to create mint note")?;
+        chain.add_pending_executed_transaction(&send_mint_note_tx)?;
+        chain.prove_next_block()?;
+
+        let mint_tx = chain
+            .build_tx_context(self.faucet_id, &[mint_note.id()], &[])?
+            .build()?
+            .execute()
+            .await
+            .context("failed to execute mint transaction")?;
+        chain.add_pending_executed_transaction(&mint_tx)?;
+        chain.prove_next_block()?;
+
+        let consume_tx = chain
+            .build_tx_context(account_id, &[], &[p2id_note])?
+            .build()?
+            .execute()
+            .await
+            .context("failed to consume minted note")?;
+        chain.add_pending_executed_transaction(&consume_tx)?;
+        chain.prove_next_block()?;
+
+        Ok(())
+    }
+}
@@ -14,8 +14,8 @@ use miden_processor::{
 use miden_protocol::transaction::TransactionEventId;
 use miden_protocol::vm::EventId;
 use miden_protocol::{CoreLibrary, Word};
-use miden_tx::TransactionExecutorHost;
 use miden_tx::auth::UnreachableAuth;
+use miden_tx::{DataStore, TransactionExecutorHost};
 
 use crate::TransactionContext;
 
@@ -34,9 +34,12 @@ use crate::TransactionContext;
 ///
 /// Some event handlers, such as delta or output note tracking, will similarly interfere with
 /// testing a procedure in isolation and these are also turned off in this host.
-pub(crate) struct MockHost<'store> {
+pub(crate) struct MockHost<'store, STORE = TransactionContext>
+where
+    STORE: DataStore,
+{
     /// The underlying [`TransactionExecutorHost`] that the mock host will forward requests to.
-    exec_host: TransactionExecutorHost<'store, 'static, TransactionContext, UnreachableAuth>,
+    exec_host: TransactionExecutorHost<'store, 'static, STORE, UnreachableAuth>,
 
     /// The set of event IDs that the mock host will forward to the [`TransactionExecutorHost`].
     ///
@@ -45,10 +48,13 @@ pub(crate) struct MockHost<'store> {
     handled_events: BTreeSet<EventId>,
 }
 
-impl<'store> MockHost<'store> {
+impl<'store, STORE> MockHost<'store, STORE>
+where
+    STORE: DataStore + Sync,
+{
     /// Returns a new [`MockHost`] instance with the provided inputs.
     pub fn new(
-        exec_host: TransactionExecutorHost<'store, 'static, TransactionContext, UnreachableAuth>,
+        exec_host: TransactionExecutorHost<'store, 'static, STORE, UnreachableAuth>,
     ) -> Self {
         // CoreLibrary events are always handled.
         let core_lib_handlers = CoreLibrary::default()
@@ -90,7 +96,10 @@ impl<'store> MockHost<'store> {
     }
 }
 
-impl<'store> BaseHost for MockHost<'store> {
+impl<STORE> BaseHost for MockHost<'_, STORE>
+where
+    STORE: DataStore,
+{
     fn get_label_and_source_file(
         &self,
         location: &miden_protocol::assembly::debuginfo::Location,
@@ -102,7 +111,10 @@ impl<'store> BaseHost for MockHost<'store> {
     }
 }
 
-impl<'store> AsyncHost for MockHost<'store> {
+impl<STORE> AsyncHost for MockHost<'_, STORE>
+where
+    STORE: DataStore + Sync,
+{
     fn get_mast_forest(&self, node_digest: &Word) -> impl FutureMaybeSend<Option<Arc<MastForest>>> {
         self.exec_host.get_mast_forest(node_digest)
     }
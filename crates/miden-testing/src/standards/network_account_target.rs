@@ -1,10 +1,12 @@
 //! Tests for the `miden::standards::attachments::network_account_target` module.
 
-use miden_protocol::Felt;
 use miden_protocol::account::AccountStorageMode;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::crypto::rand::RpoRandomCoin;
 use miden_protocol::note::{NoteAttachment, NoteExecutionHint, NoteMetadata, NoteTag, NoteType};
 use miden_protocol::testing::account_id::AccountIdBuilder;
-use miden_standards::note::NetworkAccountTarget;
+use miden_protocol::{Felt, Word};
+use miden_standards::note::{NetworkAccountTarget, NoteExt, P2idNote};
 
 use crate::executor::CodeExecutor;
 
@@ -128,3 +130,31 @@ async fn network_account_target_attachment_round_trip() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that a note with a network account target attachment is reported as consumable only by
+/// the target and only once its execution hint is satisfied.
+#[test]
+fn network_account_target_note_is_consumable_by() -> anyhow::Result<()> {
+    let target_id = AccountIdBuilder::new()
+        .storage_mode(AccountStorageMode::Network)
+        .build_with_rng(&mut rand::rng());
+    let other_id = AccountIdBuilder::new()
+        .storage_mode(AccountStorageMode::Network)
+        .build_with_rng(&mut rand::rng());
+    let exec_hint = NoteExecutionHint::after_block(BlockNumber::from(10));
+
+    let note = P2idNote::create(
+        other_id,
+        target_id,
+        vec![],
+        NoteType::Public,
+        NetworkAccountTarget::new(target_id, exec_hint)?.into(),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+
+    assert!(!note.is_consumable_by(target_id, BlockNumber::from(9)));
+    assert!(note.is_consumable_by(target_id, BlockNumber::from(10)));
+    assert!(!note.is_consumable_by(other_id, BlockNumber::from(10)));
+
+    Ok(())
+}
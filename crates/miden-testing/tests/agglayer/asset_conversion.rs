@@ -246,3 +246,29 @@ fn test_felts_to_u256_bytes_edge_cases() {
     assert_eq!(result.len(), 32);
     assert!(result.iter().all(|&b| b == 255));
 }
+
+#[test]
+fn test_felts_to_bytes32_roundtrip() {
+    let mut bytes32 = [0u8; 32];
+    for (i, byte) in bytes32.iter_mut().enumerate() {
+        *byte = (i as u8).wrapping_mul(37).wrapping_add(11);
+    }
+
+    let felts = utils::bytes32_to_felts(&bytes32);
+    assert_eq!(utils::felts_to_bytes32(&felts).unwrap(), bytes32);
+
+    let max_bytes32 = [u8::MAX; 32];
+    let felts = utils::bytes32_to_felts(&max_bytes32);
+    assert_eq!(utils::felts_to_bytes32(&felts).unwrap(), max_bytes32);
+}
+
+#[test]
+fn test_felts_to_bytes32_rejects_non_u32_felts() {
+    let mut felts = [Felt::new(0); 8];
+    felts[3] = Felt::new(u32::MAX as u64 + 1);
+
+    assert_eq!(
+        utils::felts_to_bytes32(&felts),
+        Err(utils::FeltConversionError::NotU32 { index: 3, value: u32::MAX as u64 + 1 })
+    );
+}
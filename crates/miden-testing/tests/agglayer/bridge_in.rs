@@ -3,16 +3,18 @@ extern crate alloc;
 use core::slice;
 
 use miden_agglayer::{
+    ClaimNote,
     ClaimNoteParams,
+    agglayer_faucet_component_checked,
     claim_note_test_inputs,
+    create_bridge_account_builder,
     create_claim_note,
     create_existing_agglayer_faucet,
     create_existing_bridge_account,
 };
-use miden_protocol::Felt;
-use miden_protocol::account::Account;
+use miden_protocol::account::{Account, AccountId, AccountStorageMode};
 use miden_protocol::asset::{Asset, FungibleAsset};
-use miden_protocol::crypto::rand::FeltRng;
+use miden_protocol::crypto::rand::{FeltRng, RpoRandomCoin};
 use miden_protocol::note::{
     Note,
     NoteAssets,
@@ -22,7 +24,14 @@ use miden_protocol::note::{
     NoteTag,
     NoteType,
 };
+use miden_protocol::testing::account_id::{
+    ACCOUNT_ID_NETWORK_FUNGIBLE_FAUCET,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    ACCOUNT_ID_SENDER,
+};
 use miden_protocol::transaction::OutputNote;
+use miden_protocol::{Felt, Word};
+use miden_standards::account::auth::NoAuth;
 use miden_standards::account::wallets::BasicWallet;
 use miden_standards::note::StandardNote;
 use miden_testing::{AccountState, Auth, MockChain};
@@ -106,6 +115,7 @@ async fn test_bridge_in_claim_to_p2id() -> anyhow::Result<()> {
         output_note_tag: NoteTag::with_account_target(user_account.id()),
         p2id_serial_number: serial_num,
         destination_account_id: user_account.id(),
+        allow_same_network: false,
         rng: builder.rng_mut(),
     };
 
@@ -194,3 +204,196 @@ async fn test_bridge_in_claim_to_p2id() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that every field of a CLAIM note can be read back via [`ClaimNote`].
+#[test]
+fn test_claim_note_fields_can_be_read_back() -> anyhow::Result<()> {
+    let destination_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+    let agglayer_faucet_account_id = AccountId::try_from(ACCOUNT_ID_NETWORK_FUNGIBLE_FAUCET)?;
+    let claim_note_creator_account_id = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+
+    let (
+        smt_proof_local_exit_root,
+        smt_proof_rollup_exit_root,
+        global_index,
+        mainnet_exit_root,
+        rollup_exit_root,
+        origin_network,
+        origin_token_address,
+        destination_network,
+        destination_address,
+        amount_u256,
+        metadata,
+    ) = claim_note_test_inputs(Felt::new(100), destination_account_id);
+
+    let p2id_serial_number = Word::from([1u32, 2, 3, 4]);
+    let output_note_tag = NoteTag::with_account_target(destination_account_id);
+
+    let claim_params = ClaimNoteParams {
+        smt_proof_local_exit_root: smt_proof_local_exit_root.clone(),
+        smt_proof_rollup_exit_root: smt_proof_rollup_exit_root.clone(),
+        global_index,
+        mainnet_exit_root: &mainnet_exit_root,
+        rollup_exit_root: &rollup_exit_root,
+        origin_network,
+        origin_token_address: &origin_token_address,
+        destination_network,
+        destination_address: &destination_address,
+        amount: amount_u256,
+        metadata,
+        claim_note_creator_account_id,
+        agglayer_faucet_account_id,
+        output_note_tag,
+        p2id_serial_number,
+        destination_account_id,
+        allow_same_network: false,
+        rng: &mut RpoRandomCoin::new(Word::empty()),
+    };
+
+    let note = create_claim_note(claim_params)?;
+    let claim_note = ClaimNote::try_from(note.clone())?;
+
+    assert_eq!(claim_note.smt_proof_local_exit_root(), smt_proof_local_exit_root.as_slice());
+    assert_eq!(claim_note.smt_proof_rollup_exit_root(), smt_proof_rollup_exit_root.as_slice());
+    assert_eq!(claim_note.global_index(), global_index);
+    assert_eq!(claim_note.mainnet_exit_root(), &mainnet_exit_root);
+    assert_eq!(claim_note.rollup_exit_root(), &rollup_exit_root);
+    assert_eq!(Felt::from(claim_note.origin_network()), origin_network);
+    // the most-significant 4 bytes of the origin token address are not stored in the note
+    let mut expected_origin_token_address = origin_token_address;
+    expected_origin_token_address[..4].fill(0);
+    assert_eq!(claim_note.origin_token_address().into_bytes(), expected_origin_token_address);
+    assert_eq!(Felt::from(claim_note.destination_network()), destination_network);
+    assert_eq!(claim_note.destination_account_id(), destination_account_id);
+    assert_eq!(claim_note.amount(), amount_u256);
+    assert_eq!(claim_note.metadata(), metadata);
+    assert_eq!(claim_note.p2id_serial_number(), p2id_serial_number);
+    assert_eq!(claim_note.agglayer_faucet_account_id(), agglayer_faucet_account_id);
+    assert_eq!(claim_note.output_note_tag(), output_note_tag);
+    assert_eq!(claim_note.into_note(), note);
+
+    // a note with a different script is rejected
+    let p2id_note = Note::new(
+        NoteAssets::default(),
+        NoteMetadata::new(claim_note_creator_account_id, NoteType::Public, output_note_tag),
+        NoteRecipient::new(
+            p2id_serial_number,
+            StandardNote::P2ID.script(),
+            NoteStorage::new(vec![
+                destination_account_id.suffix(),
+                destination_account_id.prefix().as_felt(),
+            ])?,
+        ),
+    );
+    assert!(ClaimNote::try_from(p2id_note).is_err());
+
+    Ok(())
+}
+
+/// Tests that CLAIM notes bridging between identical networks are rejected unless explicitly
+/// allowed, and that the destination network must not be zero.
+#[test]
+fn test_claim_note_network_validation() -> anyhow::Result<()> {
+    let destination_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+
+    let (
+        smt_proof_local_exit_root,
+        smt_proof_rollup_exit_root,
+        global_index,
+        mainnet_exit_root,
+        rollup_exit_root,
+        _origin_network,
+        origin_token_address,
+        _destination_network,
+        destination_address,
+        amount_u256,
+        metadata,
+    ) = claim_note_test_inputs(Felt::new(100), destination_account_id);
+
+    let create_note = |origin_network: u32, destination_network: u32, allow_same_network: bool| {
+        create_claim_note(ClaimNoteParams {
+            smt_proof_local_exit_root: smt_proof_local_exit_root.clone(),
+            smt_proof_rollup_exit_root: smt_proof_rollup_exit_root.clone(),
+            global_index,
+            mainnet_exit_root: &mainnet_exit_root,
+            rollup_exit_root: &rollup_exit_root,
+            origin_network: Felt::from(origin_network),
+            origin_token_address: &origin_token_address,
+            destination_network: Felt::from(destination_network),
+            destination_address: &destination_address,
+            amount: amount_u256,
+            metadata,
+            claim_note_creator_account_id: AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+            agglayer_faucet_account_id: AccountId::try_from(ACCOUNT_ID_NETWORK_FUNGIBLE_FAUCET)
+                .unwrap(),
+            output_note_tag: NoteTag::with_account_target(destination_account_id),
+            p2id_serial_number: Word::from([1u32, 2, 3, 4]),
+            destination_account_id,
+            allow_same_network,
+            rng: &mut RpoRandomCoin::new(Word::empty()),
+        })
+    };
+
+    // bridging from mainnet to a rollup is valid
+    create_note(0, 2, false)?;
+
+    // identical networks are rejected unless explicitly allowed
+    let err = create_note(2, 2, false).unwrap_err();
+    assert!(err.to_string().contains("origin and destination network"));
+    let claim_note = ClaimNote::try_from(create_note(2, 2, true)?)?;
+    assert_eq!(claim_note.origin_network(), 2);
+    assert_eq!(claim_note.destination_network(), 2);
+
+    // the destination network must never be mainnet, even if identical networks are allowed
+    assert!(create_note(1, 0, false).is_err());
+    assert!(create_note(0, 0, true).is_err());
+
+    Ok(())
+}
+
+/// Tests that the checked agglayer faucet component accepts a public bridge account and rejects
+/// bridge references which cannot be used for FPI.
+#[test]
+fn test_agglayer_faucet_component_checked() -> anyhow::Result<()> {
+    let max_supply = Felt::new(1000000);
+
+    // a public bridge account is a valid bridge reference
+    let bridge_account = create_existing_bridge_account(Word::from([1u32, 2, 3, 4]));
+    let component = agglayer_faucet_component_checked("AGG", 8, max_supply, &bridge_account)?;
+    let bridge_slot = component
+        .storage_slots()
+        .iter()
+        .find(|slot| slot.name().as_str() == "miden::agglayer::faucet")
+        .expect("component should contain the bridge slot");
+    assert_eq!(
+        bridge_slot.value(),
+        Word::new([
+            Felt::new(0),
+            Felt::new(0),
+            bridge_account.id().suffix(),
+            bridge_account.id().prefix().as_felt(),
+        ])
+    );
+
+    // a private bridge account cannot be loaded as a foreign account
+    let private_bridge_account = create_bridge_account_builder(Word::from([5u32, 6, 7, 8]))
+        .storage_mode(AccountStorageMode::Private)
+        .with_auth_component(NoAuth)
+        .build_existing()?;
+    let err = agglayer_faucet_component_checked("AGG", 8, max_supply, &private_bridge_account)
+        .unwrap_err();
+    assert!(err.to_string().contains("does not support FPI"));
+
+    // a public account without the bridge component is not a bridge account
+    let wallet_account = Account::builder([9; 32])
+        .storage_mode(AccountStorageMode::Public)
+        .with_component(BasicWallet)
+        .with_auth_component(NoAuth)
+        .build_existing()?;
+    let err = agglayer_faucet_component_checked("AGG", 8, max_supply, &wallet_account).unwrap_err();
+    assert!(err.to_string().contains("does not contain the bridge storage slot"));
+
+    Ok(())
+}
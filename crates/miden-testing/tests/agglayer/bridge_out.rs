@@ -1,6 +1,14 @@
 extern crate alloc;
 
-use miden_agglayer::{EthAddressFormat, b2agg_script, bridge_out_component};
+use assert_matches::assert_matches;
+use miden_agglayer::{
+    EthAddressFormat,
+    agglayer_library,
+    b2agg_script,
+    bridge_out_component,
+    bridge_out_with_local_exit_tree_merged,
+};
+use miden_processor::MastNodeExt;
 use miden_protocol::account::{
     Account,
     AccountId,
@@ -10,7 +18,9 @@ use miden_protocol::account::{
     StorageSlot,
     StorageSlotName,
 };
+use miden_protocol::assembly::LibraryExport;
 use miden_protocol::asset::{Asset, FungibleAsset};
+use miden_protocol::errors::AccountError;
 use miden_protocol::note::{
     Note,
     NoteAssets,
@@ -23,6 +33,7 @@ use miden_protocol::note::{
 };
 use miden_protocol::transaction::OutputNote;
 use miden_protocol::{Felt, Word};
+use miden_standards::account::auth::NoAuth;
 use miden_standards::account::faucets::FungibleFaucetExt;
 use miden_standards::note::StandardNote;
 use miden_testing::{AccountState, Auth, MockChain};
@@ -296,3 +307,53 @@ async fn test_b2agg_note_reclaim_scenario() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that the merged bridge_out and local_exit_tree component exposes the procedures of both
+/// modules and rejects duplicate storage slot names.
+#[test]
+fn test_bridge_out_with_local_exit_tree_merged() -> anyhow::Result<()> {
+    let storage_slot_name = StorageSlotName::new("miden::agglayer::let")?;
+    let component = bridge_out_with_local_exit_tree_merged(vec![StorageSlot::with_empty_map(
+        storage_slot_name.clone(),
+    )])?;
+
+    let account = Account::builder([7; 32])
+        .storage_mode(AccountStorageMode::Network)
+        .with_component(component)
+        .with_auth_component(NoAuth)
+        .build_existing()?;
+    assert!(account.storage().get(&storage_slot_name).is_some());
+
+    // the procedures of both modules are part of the account's interface
+    let library = agglayer_library();
+    for module in ["bridge_out", "local_exit_tree"] {
+        let module_path = format!("agglayer::{module}::");
+        let procedure_roots: Vec<Word> = library
+            .exports()
+            .filter(|export| matches!(export, LibraryExport::Procedure(_)))
+            .filter(|export| export.path().to_string().contains(&module_path))
+            .map(|export| {
+                library
+                    .mast_forest()
+                    .get_node_by_id(export.unwrap_procedure().node)
+                    .expect("export node should be in the forest")
+                    .digest()
+            })
+            .collect();
+
+        assert!(!procedure_roots.is_empty(), "library should export procedures of {module}");
+        for root in procedure_roots {
+            assert!(account.code().has_procedure(root), "{module} procedure should be callable");
+        }
+    }
+
+    // duplicate storage slot names are rejected
+    let err = bridge_out_with_local_exit_tree_merged(vec![
+        StorageSlot::with_empty_map(storage_slot_name.clone()),
+        StorageSlot::with_empty_value(storage_slot_name.clone()),
+    ])
+    .unwrap_err();
+    assert_matches!(err, AccountError::DuplicateStorageSlotName(name) if name == storage_slot_name);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use miden_agglayer::LocalExitTree;
+use miden_protocol::utils::hex_to_bytes;
+
+/// The root of the empty local exit tree, which is the root of an empty Keccak256 Merkle tree of
+/// depth 32 as returned by `getRoot()` of the AggLayer deposit contract.
+const EMPTY_ROOT: &str = "0x27ae5ba08d7291c96c8cbddcc148bf48a6d68c7974b94356f53754ef6171d757";
+
+/// The leaves inserted in order and the roots of the tree after each insertion.
+///
+/// These are the first vectors of `test/vectors/src/mt-bridge/root-vectors.json` in the
+/// `0xPolygonHermez/zkevm-bridge-service` repository, which are the reference vectors for the
+/// AggLayer deposit contract's Merkle tree.
+const REFERENCE_VECTORS: [(&str, &str); 2] = [
+    (
+        "0xa4bfa0908dc7b06d98da4309f859023d6947561bc19bc00d77f763dea1a0b9f5",
+        "0xbf7ddbb59aa018a4c74e061f5172973ff09e4cb7f58405af117fc521f1ca46aa",
+    ),
+    (
+        "0x315fee1aa202bf4a6bd0fde560c89be90b6e6e2aaf92dc5e8d118209abc3410f",
+        "0xa7042a3ce14f384bbff63f1cee6ee5579193c2d7002e0034854963322cda6128",
+    ),
+];
+
+#[test]
+fn test_local_exit_tree_roots_match_reference_vectors() -> anyhow::Result<()> {
+    let mut tree = LocalExitTree::new();
+    assert_eq!(tree.num_leaves(), 0);
+    assert_eq!(tree.root(), hex_to_bytes::<32>(EMPTY_ROOT)?);
+
+    for (idx, (leaf, expected_root)) in REFERENCE_VECTORS.iter().enumerate() {
+        assert_eq!(tree.insert_leaf(hex_to_bytes::<32>(leaf)?), idx as u32);
+        assert_eq!(tree.num_leaves(), idx as u32 + 1);
+        assert_eq!(tree.root(), hex_to_bytes::<32>(expected_root)?);
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 pub mod asset_conversion;
 mod bridge_in;
 mod bridge_out;
+mod local_exit_tree;
 mod solidity_miden_address_conversion;
@@ -2,7 +2,7 @@ extern crate alloc;
 
 use alloc::sync::Arc;
 
-use miden_agglayer::{EthAddressFormat, agglayer_library};
+use miden_agglayer::{EthAddressFormat, agglayer_library, utils};
 use miden_assembly::{Assembler, DefaultSourceManager};
 use miden_core_lib::CoreLibrary;
 use miden_processor::fast::{ExecutionOutput, FastProcessor};
@@ -51,6 +51,21 @@ fn test_account_id_to_ethereum_roundtrip() {
     assert_eq!(original_account_id, recovered_account_id);
 }
 
+#[test]
+fn test_ethereum_address_elements_roundtrip() {
+    // `to_elements` only encodes addresses in the embedded AccountId (zero-prefixed) format
+    let eth_address = EthAddressFormat::from_hex("0x00000000b0e79c68cafc54802726c6f102cca300")
+        .expect("valid hex address");
+
+    let felts = eth_address.to_elements();
+    let bytes = utils::felts_to_ethereum_address(&felts).unwrap();
+    assert_eq!(bytes, eth_address.into_bytes());
+
+    let mut invalid_felts = felts;
+    invalid_felts[4] = Felt::new(u64::from(u32::MAX) + 1);
+    assert!(utils::felts_to_ethereum_address(&invalid_felts).is_err());
+}
+
 #[test]
 fn test_bech32_to_ethereum_roundtrip() {
     let test_addresses = [
@@ -0,0 +1,179 @@
+use miden_protocol::account::{Account, AccountComponent, AccountId, StorageSlot, StorageSlotName};
+use miden_protocol::asset::{Asset, AssetVault, FungibleAsset};
+use miden_protocol::note::{Note, NoteAttachment, NoteType};
+use miden_protocol::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_protocol::transaction::OutputNote;
+use miden_protocol::{Felt, Word};
+use miden_standards::errors::standards::ERR_CONDITIONAL_NOTE_CONDITION_NOT_SATISFIED;
+use miden_standards::note::{
+    ConditionOperator,
+    ConditionalNote,
+    ForeignAccountRequirement,
+    OracleCondition,
+};
+use miden_standards::testing::account_component::MockAccountComponent;
+use miden_testing::{Auth, MockChain, MockChainBuilder, assert_transaction_executor_error};
+
+// HELPERS
+// ================================================================================================
+
+/// The value stored in the price slot of the oracle account.
+const ORACLE_PRICE: u32 = 1000;
+
+fn price_slot_name() -> StorageSlotName {
+    StorageSlotName::new("test::oracle::price").expect("slot name should be valid")
+}
+
+/// Adds an oracle account which stores [`ORACLE_PRICE`] in its price slot.
+fn add_oracle_account(builder: &mut MockChainBuilder) -> anyhow::Result<Account> {
+    builder.add_existing_mock_account_with_storage(
+        Auth::IncrNonce,
+        [StorageSlot::with_value(price_slot_name(), Word::from([ORACLE_PRICE, 0, 0, 0]))],
+    )
+}
+
+/// Creates a conditional note comparing the oracle price against `threshold` and adds it to the
+/// genesis notes.
+fn add_conditional_note(
+    builder: &mut MockChainBuilder,
+    target: AccountId,
+    oracle: AccountId,
+    operator: ConditionOperator,
+    threshold: u32,
+    asset: Asset,
+) -> anyhow::Result<(Note, ForeignAccountRequirement)> {
+    let get_item_proc_root = AccountComponent::from(MockAccountComponent::with_empty_slots())
+        .get_procedure_root_by_path("mock::account::get_item")
+        .expect("get_item procedure should exist");
+
+    let condition = OracleCondition::new(
+        oracle,
+        get_item_proc_root,
+        price_slot_name(),
+        operator,
+        Word::from([threshold, 0, 0, 0]),
+    );
+
+    let (note, requirement) = ConditionalNote::create(
+        ACCOUNT_ID_SENDER.try_into()?,
+        target,
+        condition,
+        vec![asset],
+        NoteType::Public,
+        NoteAttachment::default(),
+        builder.rng_mut(),
+    )?;
+    builder.add_output_note(OutputNote::Full(note.clone()));
+
+    Ok((note, requirement))
+}
+
+// TESTS
+// ================================================================================================
+
+/// Tests that a conditional note releases its assets to the target account if the oracle value
+/// satisfies the condition.
+#[tokio::test]
+async fn conditional_note_releases_assets_if_condition_is_satisfied() -> anyhow::Result<()> {
+    let fungible_asset: Asset = FungibleAsset::mock(100);
+
+    let mut builder = MockChain::builder();
+    let target_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let oracle_account = add_oracle_account(&mut builder)?;
+    let (note, requirement) = add_conditional_note(
+        &mut builder,
+        target_account.id(),
+        oracle_account.id(),
+        ConditionOperator::Gte,
+        ORACLE_PRICE - 100,
+        fungible_asset,
+    )?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    assert_eq!(requirement.account_id(), oracle_account.id());
+    assert_eq!(requirement.slot_name(), &price_slot_name());
+
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(requirement.account_id())?;
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])?
+        .foreign_accounts([foreign_account_inputs])
+        .build()?
+        .execute()
+        .await?;
+
+    let target_account_after: Account = Account::new_existing(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset])?,
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(
+        executed_transaction.final_account().commitment(),
+        target_account_after.commitment()
+    );
+
+    Ok(())
+}
+
+/// Tests that each comparison operator is evaluated correctly against the oracle value and that a
+/// conditional note cannot be consumed if the condition is not satisfied.
+#[tokio::test]
+async fn conditional_note_operators() -> anyhow::Result<()> {
+    let cases = [
+        (ConditionOperator::Eq, ORACLE_PRICE, true),
+        (ConditionOperator::Eq, ORACLE_PRICE + 1, false),
+        (ConditionOperator::Lt, ORACLE_PRICE + 1, true),
+        (ConditionOperator::Lt, ORACLE_PRICE, false),
+        (ConditionOperator::Lte, ORACLE_PRICE, true),
+        (ConditionOperator::Lte, ORACLE_PRICE - 1, false),
+        (ConditionOperator::Gt, ORACLE_PRICE - 1, true),
+        (ConditionOperator::Gt, ORACLE_PRICE, false),
+        (ConditionOperator::Gte, ORACLE_PRICE, true),
+        (ConditionOperator::Gte, ORACLE_PRICE + 1, false),
+    ];
+
+    let mut builder = MockChain::builder();
+    let target_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let oracle_account = add_oracle_account(&mut builder)?;
+    let notes = cases
+        .iter()
+        .map(|(operator, threshold, _)| {
+            add_conditional_note(
+                &mut builder,
+                target_account.id(),
+                oracle_account.id(),
+                *operator,
+                *threshold,
+                FungibleAsset::mock(10),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    for ((operator, threshold, is_satisfied), (note, requirement)) in cases.iter().zip(notes) {
+        let foreign_account_inputs =
+            mock_chain.get_foreign_account_inputs(requirement.account_id())?;
+        let result = mock_chain
+            .build_tx_context(target_account.id(), &[note.id()], &[])?
+            .foreign_accounts([foreign_account_inputs])
+            .build()?
+            .execute()
+            .await;
+
+        if *is_satisfied {
+            result.unwrap_or_else(|err| {
+                panic!("oracle price {operator:?} {threshold} should be satisfied: {err}")
+            });
+        } else {
+            assert_transaction_executor_error!(
+                result,
+                ERR_CONDITIONAL_NOTE_CONDITION_NOT_SATISFIED
+            );
+        }
+    }
+
+    Ok(())
+}
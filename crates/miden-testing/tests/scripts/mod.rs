@@ -1,6 +1,9 @@
+mod conditional;
 mod faucet;
 mod fee;
 mod p2id;
 mod p2ide;
+mod p2m;
 mod send_note;
 mod swap;
+mod tx_script_template;
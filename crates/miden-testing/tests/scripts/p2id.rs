@@ -1,5 +1,7 @@
-use miden_protocol::account::Account;
+use assert_matches::assert_matches;
+use miden_protocol::account::{Account, AccountId};
 use miden_protocol::asset::{Asset, AssetVault, FungibleAsset};
+use miden_protocol::block::BlockNumber;
 use miden_protocol::crypto::rand::RpoRandomCoin;
 use miden_protocol::note::{NoteAttachment, NoteType};
 use miden_protocol::testing::account_id::{
@@ -9,12 +11,15 @@ use miden_protocol::testing::account_id::{
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2,
     ACCOUNT_ID_SENDER,
 };
-use miden_protocol::transaction::OutputNote;
-use miden_protocol::{Felt, Word};
+use miden_protocol::transaction::{OutputNote, TransactionHeader, TransactionWitness};
+use miden_protocol::utils::serde::{Deserializable, Serializable};
+use miden_protocol::vm::StackOutputs;
+use miden_protocol::{Felt, MIN_PROOF_SECURITY_LEVEL, Word};
 use miden_standards::code_builder::CodeBuilder;
 use miden_standards::errors::standards::ERR_P2ID_TARGET_ACCT_MISMATCH;
-use miden_standards::note::P2idNote;
+use miden_standards::note::{NoteExt, P2idNote};
 use miden_testing::{Auth, MockChain, assert_transaction_executor_error};
+use miden_tx::{LocalTransactionProver, TransactionProverError, TransactionVerifier};
 
 use crate::prove_and_verify_transaction;
 
@@ -132,6 +137,93 @@ async fn prove_consume_note_with_new_account() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Proves a P2ID consume from a [`TransactionWitness`] and checks that the resulting proof
+/// verifies identically to a proof generated from the executed transaction, while the witness
+/// encoding stays within a fixed size budget.
+#[tokio::test]
+async fn prove_p2id_consume_from_witness() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let sender_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let target_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let note = builder.add_p2id_note(
+        sender_account.id(),
+        target_account.id(),
+        &[FungibleAsset::mock(123)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    // The executed transaction encodes to about 115 KB, since it contains the full transaction
+    // inputs and outputs, while the witness only consists of the kernel program inputs and outputs.
+    const WITNESS_SIZE_BUDGET: usize = 64 * 1024;
+
+    let witness = executed_transaction.clone().into_witness();
+    let witness_bytes = witness.to_bytes();
+    assert!(
+        witness_bytes.len() <= WITNESS_SIZE_BUDGET,
+        "witness of {} bytes exceeds the budget of {WITNESS_SIZE_BUDGET} bytes",
+        witness_bytes.len()
+    );
+    assert_eq!(TransactionWitness::read_from_bytes(&witness_bytes)?.to_bytes(), witness_bytes);
+
+    let prover = LocalTransactionProver::default();
+    let proven_tx = prover.prove(executed_transaction)?;
+    let proven_tx_from_witness = prover.prove_witness(witness)?;
+
+    assert_eq!(proven_tx_from_witness.id(), proven_tx.id());
+    assert_eq!(
+        TransactionHeader::from(&proven_tx_from_witness),
+        TransactionHeader::from(&proven_tx)
+    );
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier.verify(&proven_tx)?;
+    verifier.verify(&proven_tx_from_witness)?;
+
+    Ok(())
+}
+
+/// Checks that a witness whose expected stack outputs do not match the outputs of the transaction
+/// kernel is rejected by the prover.
+#[tokio::test]
+async fn prove_witness_rejects_mismatching_stack_outputs() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let sender_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let target_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let note = builder.add_p2id_note(
+        sender_account.id(),
+        target_account.id(),
+        &[FungibleAsset::mock(123)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let witness = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?
+        .into_witness();
+
+    let tampered_witness = TransactionWitness::new(
+        witness.stack_inputs().clone(),
+        witness.advice_inputs().clone(),
+        witness.program_hash(),
+        StackOutputs::default(),
+    );
+
+    let error = LocalTransactionProver::default().prove_witness(tampered_witness).unwrap_err();
+    assert_matches!(error, TransactionProverError::WitnessStackOutputsMismatch);
+
+    Ok(())
+}
+
 /// Consumes two existing notes (with an asset from a faucet for a combined total of 123 tokens)
 /// with a basic account
 #[tokio::test]
@@ -274,3 +366,25 @@ async fn test_create_consume_multiple_notes() -> anyhow::Result<()> {
     assert_eq!(account.vault().get_balance(FungibleAsset::mock_issuer())?, 5);
     Ok(())
 }
+
+/// Tests that a P2ID note is reported as consumable by its target but not by another account.
+#[test]
+fn p2id_note_is_consumable_by_target() -> anyhow::Result<()> {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+    let other_account = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET)?;
+
+    let note = P2idNote::create(
+        sender,
+        target,
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+
+    assert!(note.is_consumable_by(target, BlockNumber::from(1)));
+    assert!(!note.is_consumable_by(other_account, BlockNumber::from(1)));
+
+    Ok(())
+}
@@ -1,17 +1,23 @@
 use core::slice;
 
 use anyhow::Context;
-use miden_protocol::Felt;
-use miden_protocol::account::Account;
+use miden_protocol::account::{Account, AccountId};
 use miden_protocol::asset::{Asset, AssetVault, FungibleAsset};
 use miden_protocol::block::BlockNumber;
-use miden_protocol::note::{Note, NoteType};
+use miden_protocol::crypto::rand::RpoRandomCoin;
+use miden_protocol::note::{Note, NoteAttachment, NoteType};
+use miden_protocol::testing::account_id::{
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    ACCOUNT_ID_SENDER,
+};
+use miden_protocol::{Felt, Word};
 use miden_standards::errors::standards::{
     ERR_P2IDE_RECLAIM_ACCT_IS_NOT_SENDER,
     ERR_P2IDE_RECLAIM_DISABLED,
     ERR_P2IDE_RECLAIM_HEIGHT_NOT_REACHED,
     ERR_P2IDE_TIMELOCK_HEIGHT_NOT_REACHED,
 };
+use miden_standards::note::{P2idNote, P2ideNote};
 use miden_testing::{Auth, MockChain, assert_transaction_executor_error};
 
 /// Test that the P2IDE note works like a regular P2ID note
@@ -353,6 +359,44 @@ async fn p2ide_script_reclaim_success_after_timelock() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the target, reclaim and timelock heights can be parsed from a P2IDE note, and that
+/// notes with a different script are not parsed.
+#[test]
+fn p2ide_note_storage_can_be_parsed() -> anyhow::Result<()> {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE)?;
+    let mut rng = RpoRandomCoin::new(Word::from([1, 2, 3, 4u32]));
+
+    let p2ide_note = P2ideNote::create(
+        sender,
+        target,
+        vec![FungibleAsset::mock(10)],
+        Some(BlockNumber::from(42)),
+        None,
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut rng,
+    )?;
+
+    let storage = P2ideNote::parse_storage(&p2ide_note).context("failed to parse P2IDE note")?;
+    assert_eq!(storage.target(), target);
+    assert_eq!(storage.reclaim_height(), Some(BlockNumber::from(42)));
+    assert_eq!(storage.timelock_height(), None);
+
+    let p2id_note = P2idNote::create(
+        sender,
+        target,
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut rng,
+    )?;
+
+    assert!(P2ideNote::parse_storage(&p2id_note).is_none());
+
+    Ok(())
+}
+
 struct P2ideTestSetup {
     mock_chain: MockChain,
     fungible_asset: Asset,
@@ -0,0 +1,115 @@
+use core::slice;
+
+use assert_matches::assert_matches;
+use miden_protocol::account::{Account, AccountId};
+use miden_protocol::asset::{Asset, AssetVault, FungibleAsset};
+use miden_protocol::crypto::rand::RpoRandomCoin;
+use miden_protocol::errors::NoteError;
+use miden_protocol::note::{NoteAttachment, NoteType};
+use miden_protocol::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_protocol::{Felt, Word};
+use miden_standards::errors::standards::ERR_P2M_TARGET_ACCT_MISMATCH;
+use miden_standards::note::{NoteConsumptionStatus, P2mNote, StandardNote};
+use miden_testing::{Auth, MockChain, assert_transaction_executor_error};
+
+/// Tests that a P2M note can be consumed by any of its targets, here the third one, but not by
+/// an account which is not one of its targets.
+#[tokio::test]
+async fn p2m_script_consumable_by_any_target() -> anyhow::Result<()> {
+    let fungible_asset: Asset = FungibleAsset::mock(123);
+
+    let mut builder = MockChain::builder();
+    let targets = (0..4)
+        .map(|_| builder.add_existing_wallet(Auth::BasicAuth))
+        .collect::<Result<Vec<_>, _>>()?;
+    let target_account = targets[2].clone();
+    let malicious_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    let note = builder.add_p2m_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        targets.iter().map(Account::id).collect(),
+        &[fungible_asset],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    // the static analysis of the note agrees with its execution
+    let block_num = mock_chain.latest_block_header().block_num();
+    let standard_note = StandardNote::from_note(&note).expect("note should be a P2M note");
+    assert_matches!(
+        standard_note.is_consumable(&note, target_account.id(), block_num),
+        Some(NoteConsumptionStatus::ConsumableWithAuthorization)
+    );
+    assert_matches!(
+        standard_note.is_consumable(&note, malicious_account.id(), block_num),
+        Some(NoteConsumptionStatus::NeverConsumable(_))
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - Malicious Account)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(malicious_account.id(), &[], slice::from_ref(&note))?
+        .build()?
+        .execute()
+        .await;
+
+    assert_transaction_executor_error!(executed_transaction, ERR_P2M_TARGET_ACCT_MISMATCH);
+
+    // CONSTRUCT AND EXECUTE TX (Success - Third Target Account)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    let target_account_after: Account = Account::new_existing(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset])?,
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(
+        executed_transaction.final_account().commitment(),
+        target_account_after.commitment()
+    );
+
+    Ok(())
+}
+
+/// Tests that a P2M note cannot be created without targets or with more than the maximum number
+/// of targets.
+#[test]
+fn p2m_note_target_count_is_bounded() -> anyhow::Result<()> {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER)?;
+    let mut rng = RpoRandomCoin::new(Word::from([1, 2, 3, 4u32]));
+
+    for num_targets in [0, P2mNote::MAX_TARGETS + 1] {
+        let result = P2mNote::create(
+            sender,
+            vec![sender; num_targets],
+            vec![FungibleAsset::mock(10)],
+            NoteType::Public,
+            NoteAttachment::default(),
+            &mut rng,
+        );
+
+        assert_matches!(
+            result,
+            Err(NoteError::InvalidNumberOfTargetAccounts { num_targets: actual, max })
+                if actual == num_targets && max == P2mNote::MAX_TARGETS
+        );
+    }
+
+    P2mNote::create(
+        sender,
+        vec![sender; P2mNote::MAX_TARGETS],
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut rng,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use assert_matches::assert_matches;
+use miden_protocol::account::{AccountComponent, StorageSlot, StorageSlotName};
+use miden_protocol::asset::{Asset, FungibleAsset};
+use miden_protocol::crypto::rand::RpoRandomCoin;
+use miden_protocol::note::{NoteAttachment, NoteType};
+use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+use miden_protocol::transaction::OutputNote;
+use miden_protocol::{Felt, Word};
+use miden_standards::code_builder::{CodeBuilder, TxScriptTemplate, TxScriptTemplateError};
+use miden_standards::note::{BurnNote, P2idNote};
+use miden_standards::testing::account_component::MockAccountComponent;
+use miden_testing::{Auth, MockChain};
+
+/// Tests that the mint template mints the asset of the note and sends it to the recipient.
+#[tokio::test]
+async fn tx_script_template_mint_fungible() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "POL", 200, None)?;
+    let mock_chain = builder.build()?;
+
+    let note = P2idNote::create(
+        faucet.id(),
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?,
+        vec![FungibleAsset::new(faucet.id(), 50)?.into()],
+        NoteType::Public,
+        NoteAttachment::default(),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+
+    let tx_script = TxScriptTemplate::MintFungible { note: note.clone().into() }
+        .compile(CodeBuilder::default())?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(note.clone())])
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0), &OutputNote::Full(note));
+
+    Ok(())
+}
+
+/// Tests that the send notes template creates all notes and moves their assets out of the vault.
+#[tokio::test]
+async fn tx_script_template_send_notes() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let wallet =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [FungibleAsset::mock(100)])?;
+    let mock_chain = builder.build()?;
+
+    let mut rng = RpoRandomCoin::new(Word::from([5, 6, 7, 8u32]));
+    let notes = [FungibleAsset::mock(10), FungibleAsset::mock(20)]
+        .into_iter()
+        .map(|asset| {
+            P2idNote::create(
+                wallet.id(),
+                ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+                vec![asset],
+                NoteType::Public,
+                NoteAttachment::default(),
+                &mut rng,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let tx_script = TxScriptTemplate::SendNotes(notes.iter().cloned().map(Into::into).collect())
+        .compile(CodeBuilder::default())?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(wallet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(notes.iter().cloned().map(OutputNote::Full).collect())
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+    for (idx, note) in notes.into_iter().enumerate() {
+        assert_eq!(executed_transaction.output_notes().get_note(idx), &OutputNote::Full(note));
+    }
+
+    let removed_assets: Vec<Asset> =
+        executed_transaction.account_delta().vault().removed_assets().collect();
+    assert_eq!(removed_assets, [FungibleAsset::mock(30)]);
+
+    Ok(())
+}
+
+/// Tests that the burn template sends the asset to its faucet in a BURN note.
+#[tokio::test]
+async fn tx_script_template_burn_asset() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let wallet =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [FungibleAsset::mock(100)])?;
+    let mock_chain = builder.build()?;
+
+    let asset = FungibleAsset::mock(30).unwrap_fungible();
+    let serial_num = Word::from([9, 10, 11, 12u32]);
+    let burn_note = BurnNote::create(
+        wallet.id(),
+        asset.faucet_id(),
+        asset.into(),
+        NoteAttachment::default(),
+        serial_num,
+    )?;
+
+    let tx_script = TxScriptTemplate::BurnAsset { sender: wallet.id(), asset, serial_num }
+        .compile(CodeBuilder::default())?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(wallet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(burn_note.clone())])
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_transaction.output_notes().get_note(0), &OutputNote::Full(burn_note));
+    let removed_assets: Vec<Asset> =
+        executed_transaction.account_delta().vault().removed_assets().collect();
+    assert_eq!(removed_assets, [Asset::from(asset)]);
+
+    Ok(())
+}
+
+/// Tests that the procedure call template calls the account procedure with the provided
+/// arguments.
+#[tokio::test]
+async fn tx_script_template_call_procedure() -> anyhow::Result<()> {
+    let slot_name = StorageSlotName::new("miden::testing::tx_script_template::value")?;
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_mock_account_with_storage(
+        Auth::IncrNonce,
+        [StorageSlot::with_empty_value(slot_name.clone())],
+    )?;
+    let mock_chain = builder.build()?;
+
+    let component: AccountComponent = MockAccountComponent::with_slots(vec![]).into();
+    let set_item_root = component
+        .get_procedure_root_by_path("mock::account::set_item")
+        .expect("set_item procedure should exist");
+
+    // set_item expects [slot_id_prefix, slot_id_suffix, VALUE]
+    let value = Word::from([7, 7, 7, 7u32]);
+    let mut args = vec![slot_name.id().prefix(), slot_name.id().suffix()];
+    args.extend(value.iter().copied());
+
+    let tx_script = TxScriptTemplate::CallProcedure { root: set_item_root, args }
+        .compile(CodeBuilder::default())?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[], &[])?
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+
+    let mut updated_account = account.clone();
+    updated_account.apply_delta(executed_transaction.account_delta())?;
+    assert_eq!(updated_account.storage().get_item(&slot_name)?, value);
+
+    Ok(())
+}
+
+/// Tests that invalid template parameters are rejected before compiling the script.
+#[test]
+fn tx_script_template_rejects_invalid_parameters() {
+    assert_matches!(
+        TxScriptTemplate::SendNotes(vec![]).compile(CodeBuilder::default()),
+        Err(TxScriptTemplateError::NoNotesToSend)
+    );
+
+    let args = vec![Felt::new(1); TxScriptTemplate::MAX_PROCEDURE_ARGS + 1];
+    assert_matches!(
+        TxScriptTemplate::CallProcedure { root: Word::empty(), args }
+            .compile(CodeBuilder::default()),
+        Err(TxScriptTemplateError::TooManyProcedureArgs(17))
+    );
+}
@@ -0,0 +1,32 @@
+//! Smoke test for executing transactions on `wasm32-unknown-unknown`.
+//!
+//! Run on Node.js with `wasm-bindgen-test-runner` by `make test-wasm`.
+#![cfg(target_arch = "wasm32")]
+
+use miden_protocol::transaction::TransactionInputs;
+use miden_protocol::utils::{Deserializable, Serializable};
+use miden_testing::TransactionContextBuilder;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn execute_transaction_from_prebuilt_inputs() {
+    // the inputs are built once and then only passed around in their serialized form, the way a
+    // browser wallet would receive them
+    let tx_inputs_bytes = TransactionContextBuilder::with_existing_mock_account()
+        .build()
+        .expect("failed to build transaction context")
+        .tx_inputs()
+        .to_bytes();
+    let tx_inputs = TransactionInputs::read_from_bytes(&tx_inputs_bytes)
+        .expect("failed to deserialize transaction inputs");
+
+    let executed_tx = TransactionContextBuilder::with_existing_mock_account()
+        .tx_inputs(tx_inputs)
+        .build()
+        .expect("failed to build transaction context")
+        .execute()
+        .await
+        .expect("failed to execute transaction");
+
+    assert!(executed_tx.input_notes().is_empty());
+}
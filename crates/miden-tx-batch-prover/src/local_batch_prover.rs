@@ -31,6 +31,17 @@ impl LocalBatchProver {
     /// Returns an error if:
     /// - a proof of any transaction in the batch fails to verify.
     pub fn prove(&self, proposed_batch: ProposedBatch) -> Result<ProvenBatch, ProvenBatchError> {
+        self.verify_batch(&proposed_batch)?;
+        self.prove_inner(proposed_batch)
+    }
+
+    /// Verifies the proof of each transaction in the provided [`ProposedBatch`] individually.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - a proof of any transaction in the batch fails to verify.
+    pub fn verify_batch(&self, proposed_batch: &ProposedBatch) -> Result<(), ProvenBatchError> {
         let verifier = TransactionVerifier::new(self.proof_security_level);
 
         for tx in proposed_batch.transactions() {
@@ -42,19 +53,20 @@ impl LocalBatchProver {
             })?;
         }
 
-        self.prove_inner(proposed_batch)
+        Ok(())
     }
 
-    /// Proves the provided [`ProposedBatch`] into a [`ProvenBatch`], **without verifying batches
-    /// and proving the block**.
+    /// Proves the provided [`ProposedBatch`] into a [`ProvenBatch`], **without verifying the
+    /// transaction proofs of the batch**.
     ///
-    /// This is exposed for testing purposes.
+    /// See [`ProvenBatch::from_proposed_unchecked`] for details. This is exposed for testing
+    /// purposes.
     #[cfg(any(feature = "testing", test))]
     pub fn prove_dummy(
         &self,
         proposed_batch: ProposedBatch,
     ) -> Result<ProvenBatch, ProvenBatchError> {
-        self.prove_inner(proposed_batch)
+        ProvenBatch::from_proposed_unchecked(proposed_batch)
     }
 
     /// Converts a proposed batch into a proven batch.
@@ -0,0 +1,206 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::sync::Arc;
+
+use miden_processor::FutureMaybeSend;
+use miden_protocol::Word;
+use miden_protocol::account::auth::{PublicKey, PublicKeyCommitment, Signature};
+use miden_protocol::utils::sync::RwLock;
+
+use super::{SigningInputs, TransactionAuthenticator};
+use crate::errors::AuthenticationError;
+
+// CACHING AUTHENTICATOR
+// ================================================================================================
+
+/// A [`TransactionAuthenticator`] which caches the signatures generated by an inner authenticator.
+///
+/// Repeated executions of the same transaction, e.g. when checking note consumability or
+/// estimating fees, request signatures over the same messages. With this authenticator, each such
+/// signature is only requested once from the inner authenticator, which avoids repeatedly
+/// generating expensive signatures or prompting hardware signers.
+///
+/// Signatures are cached by public key commitment and message. The cache holds at most
+/// [`Self::capacity`] signatures, and the oldest signature is evicted when a signature is inserted
+/// into a full cache.
+///
+/// Cached signatures are returned to the transaction host like any other signature, and so they
+/// are still included in the advice inputs of the executed transaction.
+pub struct CachingAuthenticator<AUTH> {
+    inner: AUTH,
+    capacity: usize,
+    cache: RwLock<SignatureCache>,
+}
+
+impl<AUTH> CachingAuthenticator<AUTH> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`CachingAuthenticator`] which caches at most `capacity` signatures generated
+    /// by the provided authenticator.
+    ///
+    /// A capacity of zero disables caching.
+    pub fn new(inner: AUTH, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            cache: RwLock::new(SignatureCache::default()),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the inner authenticator.
+    pub fn inner(&self) -> &AUTH {
+        &self.inner
+    }
+
+    /// Returns the maximum number of signatures held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of signatures currently held by the cache.
+    pub fn num_cached_signatures(&self) -> usize {
+        self.cache.read().signatures.len()
+    }
+
+    /// Returns the cached signature over the provided message against the key with the provided
+    /// commitment, if any.
+    pub fn get_cached_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        message: Word,
+    ) -> Option<Signature> {
+        self.cache.read().signatures.get(&(pub_key_commitment, message)).cloned()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the provided signature over the provided message against the key with the provided
+    /// commitment into the cache.
+    ///
+    /// This allows seeding the cache with signatures which were obtained earlier. If the cache is
+    /// full, the oldest signature is evicted.
+    pub fn insert_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        message: Word,
+        signature: Signature,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.cache
+            .write()
+            .insert((pub_key_commitment, message), signature, self.capacity);
+    }
+
+    /// Removes all signatures from the cache.
+    pub fn clear(&self) {
+        *self.cache.write() = SignatureCache::default();
+    }
+
+    /// Consumes the authenticator and returns the inner authenticator.
+    pub fn into_inner(self) -> AUTH {
+        self.inner
+    }
+}
+
+impl<AUTH> TransactionAuthenticator for CachingAuthenticator<AUTH>
+where
+    AUTH: TransactionAuthenticator + Sync,
+{
+    /// Returns the cached signature over the message described by the signing inputs, or requests
+    /// it from the inner authenticator and caches it if it is not cached.
+    fn get_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &SigningInputs,
+    ) -> impl FutureMaybeSend<Result<Signature, AuthenticationError>> {
+        let message = signing_inputs.to_commitment();
+
+        async move {
+            if let Some(signature) = self.get_cached_signature(pub_key_commitment, message) {
+                return Ok(signature);
+            }
+
+            let signature = self.inner.get_signature(pub_key_commitment, signing_inputs).await?;
+            self.insert_signature(pub_key_commitment, message, signature.clone());
+
+            Ok(signature)
+        }
+    }
+
+    fn get_public_key(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+    ) -> impl FutureMaybeSend<Option<Arc<PublicKey>>> {
+        self.inner.get_public_key(pub_key_commitment)
+    }
+}
+
+// SIGNATURE CACHE
+// ================================================================================================
+
+/// The signatures of a [`CachingAuthenticator`] together with their insertion order.
+#[derive(Default)]
+struct SignatureCache {
+    signatures: BTreeMap<(PublicKeyCommitment, Word), Signature>,
+    insertion_order: VecDeque<(PublicKeyCommitment, Word)>,
+}
+
+impl SignatureCache {
+    /// Inserts the signature under the provided key, evicting the oldest signatures such that the
+    /// cache holds at most `capacity` signatures.
+    fn insert(&mut self, key: (PublicKeyCommitment, Word), signature: Signature, capacity: usize) {
+        if self.signatures.insert(key, signature).is_some() {
+            return;
+        }
+        self.insertion_order.push_back(key);
+
+        while self.signatures.len() > capacity {
+            let oldest = self.insertion_order.pop_front().expect("cache should not be empty");
+            self.signatures.remove(&oldest);
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use core::slice;
+
+    use miden_protocol::Word;
+    use miden_protocol::account::auth::AuthSecretKey;
+
+    use super::CachingAuthenticator;
+    use crate::auth::BasicAuthenticator;
+
+    #[test]
+    fn caching_authenticator_evicts_oldest_signature() {
+        let secret_key = AuthSecretKey::new_falcon512_rpo();
+        let pub_key_commitment = secret_key.public_key().to_commitment();
+        let authenticator =
+            CachingAuthenticator::new(BasicAuthenticator::new(slice::from_ref(&secret_key)), 2);
+
+        let messages = [1, 2, 3u32].map(|i| Word::from([i, 0, 0, 0]));
+        for message in messages {
+            authenticator.insert_signature(pub_key_commitment, message, secret_key.sign(message));
+        }
+
+        assert_eq!(authenticator.num_cached_signatures(), 2);
+        assert!(authenticator.get_cached_signature(pub_key_commitment, messages[0]).is_none());
+        assert!(authenticator.get_cached_signature(pub_key_commitment, messages[1]).is_some());
+        assert!(authenticator.get_cached_signature(pub_key_commitment, messages[2]).is_some());
+
+        let disabled =
+            CachingAuthenticator::new(BasicAuthenticator::new(slice::from_ref(&secret_key)), 0);
+        disabled.insert_signature(pub_key_commitment, messages[0], secret_key.sign(messages[0]));
+        assert_eq!(disabled.num_cached_signatures(), 0);
+    }
+}
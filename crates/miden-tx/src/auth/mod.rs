@@ -5,3 +5,6 @@ pub use tx_authenticator::{
     TransactionAuthenticator,
     UnreachableAuth,
 };
+
+mod caching_authenticator;
+pub use caching_authenticator::CachingAuthenticator;
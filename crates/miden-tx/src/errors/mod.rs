@@ -1,11 +1,12 @@
 use alloc::boxed::Box;
-use alloc::string::String;
+use alloc::format;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::error::Error;
 
 use miden_processor::{DeserializationError, ExecutionError};
-use miden_protocol::account::AccountId;
 use miden_protocol::account::auth::PublicKeyCommitment;
+use miden_protocol::account::{AccountDeltaSizeBreakdown, AccountId};
 use miden_protocol::assembly::diagnostics::reporting::PrintDiagnostic;
 use miden_protocol::asset::AssetVaultKey;
 use miden_protocol::block::BlockNumber;
@@ -13,12 +14,14 @@ use miden_protocol::crypto::merkle::smt::SmtProofError;
 use miden_protocol::errors::{
     AccountDeltaError,
     AccountError,
+    AccountInputsError,
     AssetError,
     NoteError,
     ProvenTransactionError,
     TransactionInputError,
     TransactionInputsExtractionError,
     TransactionOutputError,
+    TransactionWitnessError,
 };
 use miden_protocol::note::{NoteId, NoteMetadata};
 use miden_protocol::transaction::TransactionSummary;
@@ -26,6 +29,10 @@ use miden_protocol::{Felt, Word};
 use miden_verifier::VerificationError;
 use thiserror::Error;
 
+use crate::executor::AccountMutation;
+use crate::host::ExecutionContext;
+use crate::replay::ReplayDivergenceReport;
+
 // NOTE EXECUTION ERROR
 // ================================================================================================
 
@@ -81,8 +88,15 @@ pub enum TransactionExecutorError {
     FetchAssetWitnessFailed(#[source] DataStoreError),
     #[error("fee asset must be fungible but was non-fungible")]
     FeeAssetMustBeFungible,
-    #[error("foreign account inputs for ID {0} are not anchored on reference block")]
-    ForeignAccountNotAnchoredInReference(AccountId),
+    #[error("foreign account inputs for ID {account_id} are not anchored on reference block")]
+    ForeignAccountNotAnchoredInReference {
+        account_id: AccountId,
+        source: AccountInputsError,
+    },
+    #[error(
+        "foreign account {0} cannot be accessed via foreign procedure invocation because its state is not public"
+    )]
+    ForeignAccountNotPublic(AccountId),
     #[error(
         "execution options' cycles must be between {min_cycles} and {max_cycles}, but found {actual}"
     )]
@@ -115,6 +129,19 @@ pub enum TransactionExecutorError {
         "native asset amount {account_balance} in the account vault is not sufficient to cover the transaction fee of {tx_fee}"
     )]
     InsufficientFee { account_balance: u64, tx_fee: u64 },
+    #[error(
+        "account delta of account {account_id} has an encoded size of {} bytes which exceeds the limit of {limit} bytes",
+        breakdown.total()
+    )]
+    AccountDeltaSizeLimitExceeded {
+        account_id: AccountId,
+        limit: usize,
+        breakdown: Box<AccountDeltaSizeBreakdown>,
+    },
+    #[error("{0} of the native account is not allowed in read-only execution")]
+    ReadOnlyViolation(AccountMutation),
+    #[error("execution of note {note_id} exceeded the limit of {limit} cycles per note")]
+    NoteCycleLimitExceeded { note_id: NoteId, limit: u32 },
     #[error("account witness provided for account ID {0} is invalid")]
     InvalidAccountWitness(AccountId, #[source] SmtProofError),
     #[error(
@@ -137,6 +164,24 @@ pub enum TransactionExecutorError {
     MissingAuthenticator,
 }
 
+impl TransactionExecutorError {
+    /// Returns the ID of the input note this error relates to, if any.
+    ///
+    /// This allows mapping an execution failure back to a specific note without matching on every
+    /// variant.
+    pub fn note_id(&self) -> Option<NoteId> {
+        match self {
+            Self::NoteBlockPastReferenceBlock(note_id, _)
+            | Self::NoteCycleLimitExceeded { note_id, .. } => Some(*note_id),
+            Self::InvalidTransactionInputs(
+                TransactionInputError::InputNoteBlockNotInPartialBlockchain(note_id)
+                | TransactionInputError::InputNoteNotInBlock(note_id, _),
+            ) => Some(*note_id),
+            _ => None,
+        }
+    }
+}
+
 // TRANSACTION PROVER ERROR
 // ================================================================================================
 
@@ -150,6 +195,16 @@ pub enum TransactionProverError {
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to build proven transaction")]
     ProvenTransactionBuildFailed(#[source] ProvenTransactionError),
+    #[error(
+        "transaction witness was created for kernel program {expected} but the current kernel program is {actual}"
+    )]
+    WitnessKernelProgramHashMismatch { expected: Word, actual: Word },
+    #[error("failed to read the host inputs from the transaction witness")]
+    WitnessHostInputsReadFailed(#[source] TransactionWitnessError),
+    #[error(
+        "stack outputs of the transaction kernel program do not match the outputs expected by the witness"
+    )]
+    WitnessStackOutputsMismatch,
     // Print the diagnostic directly instead of returning the source error. In the source error
     // case, the diagnostic is lost if the execution error is not explicitly unwrapped.
     #[error("failed to execute transaction kernel program:\n{}", PrintDiagnostic::new(.0))]
@@ -185,15 +240,77 @@ impl TransactionProverError {
     }
 }
 
+// TRANSACTION REPLAY ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionReplayError {
+    /// The bundle was recorded against a different transaction kernel than the current one.
+    #[error(
+        "replay bundle was recorded against kernel program {expected} but the current kernel program is {actual}"
+    )]
+    KernelProgramHashMismatch { expected: Word, actual: Word },
+    /// The re-execution of the transaction kernel program failed.
+    //
+    // Print the diagnostic directly instead of returning the source error. In the source error
+    // case, the diagnostic is lost if the execution error is not explicitly unwrapped.
+    #[error("failed to execute transaction kernel program:\n{}", PrintDiagnostic::new(.0))]
+    TransactionProgramExecutionFailed(ExecutionError),
+    /// The transaction outputs could not be constructed from the results of the re-execution.
+    #[error("failed to construct transaction outputs")]
+    TransactionOutputConstructionFailed(#[source] TransactionOutputError),
+    /// The outputs of the re-execution differ from the outputs recorded in the bundle.
+    #[error("replayed transaction outputs diverged from the recorded outputs: {0}")]
+    OutputsDiverged(ReplayDivergenceReport),
+}
+
 // TRANSACTION VERIFIER ERROR
 // ================================================================================================
 
 #[derive(Debug, Error)]
 pub enum TransactionVerifierError {
-    #[error("failed to verify transaction")]
-    TransactionVerificationFailed(#[source] VerificationError),
-    #[error("transaction proof security level is {actual} but must be at least {expected_minimum}")]
-    InsufficientProofSecurityLevel { actual: u32, expected_minimum: u32 },
+    /// The transaction proof is invalid for the public inputs of the transaction.
+    ///
+    /// Since a proof does not commit to the kernel program separately from its public inputs, this
+    /// is also the error returned for proofs produced against a different transaction kernel which
+    /// is not known to the verifier.
+    #[error(
+        "failed to verify transaction proof with minimum proof security level {proof_security_level}"
+    )]
+    ProofVerificationFailed {
+        proof_security_level: u32,
+        #[source]
+        source: VerificationError,
+    },
+    /// The transaction proof was produced against one of the known transaction kernels of the
+    /// verifier instead of the expected kernel.
+    #[error(
+        "transaction proof was produced against kernel program {actual} but kernel program {expected} was expected"
+    )]
+    KernelProgramMismatch { expected: Word, actual: Word },
+    #[error("transaction proof security level is {actual} but must be at least {required}")]
+    InsufficientSecurityLevel { required: u32, actual: u32 },
+}
+
+impl TransactionVerifierError {
+    /// Returns a concise, single-line description of the error suitable for logging.
+    ///
+    /// Unlike the [`Display`](core::fmt::Display) implementation, the summary includes the
+    /// underlying verification error.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::ProofVerificationFailed { proof_security_level, source } => format!(
+                "transaction proof verification failed at minimum security level {proof_security_level}: {}",
+                source.to_string().replace('\n', " ")
+            ),
+            Self::KernelProgramMismatch { expected, actual } => format!(
+                "transaction proof was produced against kernel program {actual} instead of {expected}"
+            ),
+            Self::InsufficientSecurityLevel { required, actual } => format!(
+                "transaction proof has security level {actual} but at least {required} is required"
+            ),
+        }
+    }
 }
 
 // TRANSACTION KERNEL ERROR
@@ -201,12 +318,21 @@ pub enum TransactionVerifierError {
 
 #[derive(Debug, Error)]
 pub enum TransactionKernelError {
-    #[error("failed to add asset to account delta")]
-    AccountDeltaAddAssetFailed(#[source] AccountDeltaError),
-    #[error("failed to remove asset from account delta")]
-    AccountDeltaRemoveAssetFailed(#[source] AccountDeltaError),
-    #[error("failed to add asset to note")]
-    FailedToAddAssetToNote(#[source] NoteError),
+    #[error("failed to add asset to account delta{context}")]
+    AccountDeltaAddAssetFailed {
+        context: Box<ExecutionContext>,
+        source: AccountDeltaError,
+    },
+    #[error("failed to remove asset from account delta{context}")]
+    AccountDeltaRemoveAssetFailed {
+        context: Box<ExecutionContext>,
+        source: AccountDeltaError,
+    },
+    #[error("failed to add asset to note{context}")]
+    FailedToAddAssetToNote {
+        context: Box<ExecutionContext>,
+        source: NoteError,
+    },
     #[error("note storage has commitment {actual} but expected commitment {expected}")]
     InvalidNoteStorage { expected: Word, actual: Word },
     #[error(
@@ -273,20 +399,46 @@ pub enum TransactionKernelError {
         source: DataStoreError,
     },
     #[error(
-        "failed to get vault asset witness from data store for vault root {vault_root} and vault_key {asset_key}"
+        "foreign account {0} cannot be accessed via foreign procedure invocation because its state is not public"
+    )]
+    ForeignAccountNotPublic(AccountId),
+    #[error("foreign account inputs for ID {account_id} are not anchored on reference block")]
+    ForeignAccountNotAnchoredInReference {
+        account_id: AccountId,
+        source: AccountInputsError,
+    },
+    #[error(
+        "account delta of account {account_id} has an encoded size of {} bytes which exceeds the limit of {limit} bytes",
+        breakdown.total()
+    )]
+    AccountDeltaSizeLimitExceeded {
+        account_id: AccountId,
+        limit: usize,
+        breakdown: Box<AccountDeltaSizeBreakdown>,
+    },
+    #[error("{0} of the native account is not allowed in read-only execution")]
+    ReadOnlyViolation(AccountMutation),
+    #[error("execution exceeded the limit of {0} cycles")]
+    CycleLimitExceeded(u32),
+    #[error("execution of note {note_id} exceeded the limit of {limit} cycles per note")]
+    NoteCycleLimitExceeded { note_id: NoteId, limit: u32 },
+    #[error(
+        "failed to get vault asset witness from data store for vault root {vault_root} and vault_key {asset_key}{context}"
     )]
     GetVaultAssetWitness {
         vault_root: Word,
         asset_key: AssetVaultKey,
+        context: Box<ExecutionContext>,
         // thiserror will return this when calling Error::source on TransactionKernelError.
         source: DataStoreError,
     },
     #[error(
-        "failed to get storage map witness from data store for map root {map_root} and map_key {map_key}"
+        "failed to get storage map witness from data store for map root {map_root} and map_key {map_key}{context}"
     )]
     GetStorageMapWitness {
         map_root: Word,
         map_key: Word,
+        context: Box<ExecutionContext>,
         // thiserror will return this when calling Error::source on TransactionKernelError.
         source: DataStoreError,
     },
@@ -429,3 +581,38 @@ mod error_assertions {
         _assert_error_is_send_sync_static(err);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_executor_error_note_id() {
+        let note_id = NoteId::new(Word::from([1, 2, 3, 4u32]), Word::empty());
+        let block_num = BlockNumber::from(5);
+
+        let err = TransactionExecutorError::NoteBlockPastReferenceBlock(note_id, block_num);
+        assert_eq!(err.note_id(), Some(note_id));
+
+        let err = TransactionExecutorError::NoteCycleLimitExceeded { note_id, limit: 1 << 16 };
+        assert_eq!(err.note_id(), Some(note_id));
+
+        let err = TransactionExecutorError::InvalidTransactionInputs(
+            TransactionInputError::InputNoteNotInBlock(note_id, block_num),
+        );
+        assert_eq!(err.note_id(), Some(note_id));
+
+        let err = TransactionExecutorError::InvalidTransactionInputs(
+            TransactionInputError::InputNoteBlockNotInPartialBlockchain(note_id),
+        );
+        assert_eq!(err.note_id(), Some(note_id));
+
+        let err = TransactionExecutorError::InvalidTransactionInputs(
+            TransactionInputError::TooManyInputNotes(1024),
+        );
+        assert_eq!(err.note_id(), None);
+
+        assert_eq!(TransactionExecutorError::FeeAssetMustBeFungible.note_id(), None);
+        assert_eq!(TransactionExecutorError::MissingAuthenticator.note_id(), None);
+    }
+}
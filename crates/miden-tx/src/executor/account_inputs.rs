@@ -0,0 +1,43 @@
+use miden_protocol::block::BlockHeader;
+use miden_protocol::transaction::AccountInputs;
+
+use crate::errors::TransactionExecutorError;
+
+// ACCOUNT INPUTS EXTENSION
+// ================================================================================================
+
+/// An extension trait for [`AccountInputs`] which allows validating foreign account inputs before
+/// executing a transaction.
+pub trait AccountInputsExt {
+    /// Verifies that the account inputs are anchored in the provided block, i.e. that they are
+    /// valid inputs for a transaction with the provided reference block.
+    ///
+    /// This is a wrapper around [`AccountInputs::validate`] which reports failures as executor
+    /// errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TransactionExecutorError::ForeignAccountNotAnchoredInReference`] with the
+    /// [`AccountInputsError`](miden_protocol::errors::AccountInputsError) as its source if:
+    /// - the witness is for a different account.
+    /// - the witness does not prove the current commitment of the partial account.
+    /// - the account root computed from the witness does not match the account root of the block.
+    fn verify_against_block(
+        &self,
+        block_header: &BlockHeader,
+    ) -> Result<(), TransactionExecutorError>;
+}
+
+impl AccountInputsExt for AccountInputs {
+    fn verify_against_block(
+        &self,
+        block_header: &BlockHeader,
+    ) -> Result<(), TransactionExecutorError> {
+        self.validate(block_header).map_err(|source| {
+            TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+                account_id: self.id(),
+                source,
+            }
+        })
+    }
+}
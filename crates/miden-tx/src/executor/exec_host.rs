@@ -7,10 +7,14 @@ use miden_processor::{
     AdviceMutation,
     AsyncHost,
     BaseHost,
+    DebugError,
+    DebugHandler,
+    DefaultDebugHandler,
     EventError,
     FutureMaybeSend,
     MastForest,
     ProcessState,
+    TraceError,
 };
 use miden_protocol::account::auth::PublicKeyCommitment;
 use miden_protocol::account::{
@@ -24,30 +28,34 @@ use miden_protocol::account::{
 use miden_protocol::assembly::debuginfo::Location;
 use miden_protocol::assembly::{SourceFile, SourceManagerSync, SourceSpan};
 use miden_protocol::asset::{AssetVaultKey, AssetWitness, FungibleAsset};
-use miden_protocol::block::BlockNumber;
+use miden_protocol::block::{BlockHeader, BlockNumber};
 use miden_protocol::crypto::merkle::smt::SmtProof;
+use miden_protocol::errors::AccountDeltaError;
 use miden_protocol::note::{NoteMetadata, NoteRecipient, NoteStorage};
 use miden_protocol::transaction::{
     InputNote,
     InputNotes,
     OutputNote,
     TransactionAdviceInputs,
+    TransactionEventId,
     TransactionSummary,
 };
-use miden_protocol::vm::AdviceMap;
+use miden_protocol::vm::{AdviceMap, DebugOptions, EventId, RowIndex};
 use miden_protocol::{Felt, Hasher, Word};
 
 use crate::auth::{SigningInputs, TransactionAuthenticator};
-use crate::errors::TransactionKernelError;
+use crate::errors::{TransactionExecutorError, TransactionKernelError};
 use crate::host::{
+    ExecutionContext,
     RecipientData,
     ScriptMastForestStore,
     TransactionBaseHost,
     TransactionEvent,
     TransactionProgress,
     TransactionProgressEvent,
+    TxProgressSnapshot,
 };
-use crate::{AccountProcedureIndexMap, DataStore};
+use crate::{AccountInputsExt, AccountMutation, AccountProcedureIndexMap, DataStore};
 
 // TRANSACTION EXECUTOR HOST
 // ================================================================================================
@@ -72,6 +80,12 @@ where
     /// The progress is updated event handlers.
     tx_progress: TransactionProgress,
 
+    /// The root of the account procedure which called the currently executing kernel procedure,
+    /// if the kernel procedure authenticated its caller.
+    ///
+    /// This is only tracked to provide context for errors raised by event handlers.
+    active_procedure_root: Option<Word>,
+
     /// Serves signature generation requests from the transaction runtime for signatures which are
     /// not present in the `generated_signatures` field.
     authenticator: Option<&'auth AUTH>,
@@ -79,6 +93,10 @@ where
     /// The reference block of the transaction.
     ref_block: BlockNumber,
 
+    /// The header of the reference block, if any, against which the inputs of foreign accounts
+    /// are verified before they are provided to the transaction kernel.
+    ref_block_header: Option<BlockHeader>,
+
     /// The foreign account code that was lazy loaded during transaction execution.
     ///
     /// This is required for re-executing the transaction, e.g. as part of transaction proving.
@@ -97,6 +115,22 @@ where
     /// The initial balance of the fee asset in the native account's vault.
     initial_fee_asset_balance: u64,
 
+    /// The maximum encoded size of the account delta in bytes, if any.
+    ///
+    /// This limit is advisory: it allows aborting transactions early whose account delta grows
+    /// too large, while the limit on the account update of proven transactions remains
+    /// authoritative.
+    account_delta_size_limit: Option<usize>,
+
+    /// The maximum number of cycles the execution of the transaction may take, if any.
+    max_cycles: Option<u32>,
+
+    /// The maximum number of cycles the execution of a single input note may take, if any.
+    max_cycles_per_note: Option<u32>,
+
+    /// Whether mutations of the native account's storage, vault or nonce are rejected.
+    read_only: bool,
+
     /// The source manager to track source code file span information, improving any MASM related
     /// error messages.
     source_manager: Arc<dyn SourceManagerSync>,
@@ -134,16 +168,93 @@ where
         Self {
             base_host,
             tx_progress: TransactionProgress::default(),
+            active_procedure_root: None,
             authenticator,
             ref_block,
+            ref_block_header: None,
             accessed_foreign_account_code: Vec::new(),
             foreign_account_slot_names: BTreeMap::new(),
             generated_signatures: BTreeMap::new(),
             initial_fee_asset_balance,
+            account_delta_size_limit: None,
+            max_cycles: None,
+            max_cycles_per_note: None,
+            read_only: false,
             source_manager,
         }
     }
 
+    /// Sets the maximum encoded size of the account delta in bytes and returns the resulting
+    /// host.
+    ///
+    /// If a limit is set, the size of the account delta is checked whenever the vault or storage
+    /// of the native account is updated, and execution is aborted with
+    /// [`TransactionKernelError::AccountDeltaSizeLimitExceeded`] if the limit is exceeded.
+    #[must_use]
+    pub fn with_account_delta_size_limit(mut self, limit: Option<usize>) -> Self {
+        self.account_delta_size_limit = limit;
+        self
+    }
+
+    /// Sets the maximum number of cycles the execution of the transaction may take and returns the
+    /// resulting host.
+    ///
+    /// If a limit is set, the clock cycle is checked on every host callback, i.e., whenever an
+    /// event is raised or, in debug mode, a debug or trace decorator is executed, and execution is
+    /// aborted with [`TransactionKernelError::CycleLimitExceeded`] if the limit is exceeded. This
+    /// enforces the limit of the execution options, which the processor does not respect yet.
+    ///
+    /// Since the kernel raises events at the end of every transaction, any terminating execution
+    /// exceeding the limit is rejected. A loop which never returns control to the host, however,
+    /// is not stopped until the processor enforces the limit itself.
+    #[must_use]
+    pub fn with_max_cycles(mut self, limit: Option<u32>) -> Self {
+        self.max_cycles = limit;
+        self
+    }
+
+    /// Sets the maximum number of cycles the execution of a single input note may take and returns
+    /// the resulting host.
+    ///
+    /// If a limit is set, the number of cycles of the executing note is checked on every host
+    /// callback and when its execution ends, and execution is aborted with
+    /// [`TransactionKernelError::NoteCycleLimitExceeded`] if the limit is exceeded. This prevents a
+    /// single note from using up the cycle budget of the whole transaction.
+    ///
+    /// Since the host is only invoked on events and decorators, the limit is not enforced in
+    /// between: a note exceeding the limit without invoking the host is only stopped when its
+    /// execution ends, and a non-terminating note which never invokes the host is not stopped by
+    /// this limit at all.
+    #[must_use]
+    pub fn with_max_cycles_per_note(mut self, limit: Option<u32>) -> Self {
+        self.max_cycles_per_note = limit;
+        self
+    }
+
+    /// Sets the header of the reference block and returns the resulting host.
+    ///
+    /// If a header is set, the inputs of foreign accounts returned by the data store are verified
+    /// against it, and execution is aborted with
+    /// [`TransactionKernelError::ForeignAccountNotAnchoredInReference`] if they are not anchored
+    /// in the reference block.
+    #[must_use]
+    pub fn with_ref_block_header(mut self, ref_block_header: Option<BlockHeader>) -> Self {
+        self.ref_block_header = ref_block_header;
+        self
+    }
+
+    /// Sets whether the host rejects mutations of the native account and returns the resulting
+    /// host.
+    ///
+    /// In read-only mode, any event signaling a storage write, a vault mutation or a nonce
+    /// increment of the native account aborts execution with
+    /// [`TransactionKernelError::ReadOnlyViolation`].
+    #[must_use]
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -152,19 +263,70 @@ where
         &self.tx_progress
     }
 
+    /// Returns a [`TxProgressSnapshot`] of the transaction progress recorded so far.
+    ///
+    /// Unlike [`Self::tx_progress`], the snapshot is owned and serializable, which allows
+    /// reporting the timing of the transaction stages while the transaction is being executed.
+    pub fn tx_progress_snapshot(&self) -> TxProgressSnapshot {
+        self.tx_progress.snapshot()
+    }
+
     /// Returns a reference to the foreign account slot names collected during execution.
     pub fn foreign_account_slot_names(&self) -> &BTreeMap<StorageSlotId, StorageSlotName> {
         &self.foreign_account_slot_names
     }
 
+    /// Returns the [`ExecutionContext`] of an event raised while the account with the provided ID
+    /// is active.
+    ///
+    /// The active note is derived from the note execution intervals recorded by the transaction
+    /// progress, i.e. it is the note whose execution started but has not yet ended.
+    fn execution_context(&self, active_account_id: AccountId) -> ExecutionContext {
+        let note_id = self
+            .tx_progress
+            .note_execution()
+            .last()
+            .filter(|(_, interval)| interval.end().is_none())
+            .map(|(note_id, _)| *note_id);
+        let foreign_account_id =
+            (active_account_id != self.base_host.native_account_id()).then_some(active_account_id);
+
+        ExecutionContext::new(note_id, foreign_account_id, self.active_procedure_root)
+    }
+
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
+    /// Checks the size of the account delta against the account delta size limit, if one is set.
+    fn check_account_delta_size(&self) -> Result<(), TransactionKernelError> {
+        let Some(limit) = self.account_delta_size_limit else {
+            return Ok(());
+        };
+
+        match self.base_host.account_delta_tracker().check_size_limit(limit) {
+            Err(AccountDeltaError::SizeLimitExceeded { account_id, limit, breakdown }) => {
+                Err(TransactionKernelError::AccountDeltaSizeLimitExceeded {
+                    account_id,
+                    limit,
+                    breakdown,
+                })
+            },
+            _ => Ok(()),
+        }
+    }
+
     /// Handles a request for a foreign account by querying the data store for its account inputs.
+    ///
+    /// Foreign accounts whose storage mode does not support foreign procedure invocation are
+    /// rejected before the data store is queried.
     async fn on_foreign_account_requested(
         &mut self,
         foreign_account_id: AccountId,
     ) -> Result<Vec<AdviceMutation>, TransactionKernelError> {
+        if !foreign_account_id.storage_mode().supports_fpi() {
+            return Err(TransactionKernelError::ForeignAccountNotPublic(foreign_account_id));
+        }
+
         let foreign_account_inputs = self
             .base_host
             .store()
@@ -176,6 +338,24 @@ where
                 source: err,
             })?;
 
+        if let Some(ref_block_header) = &self.ref_block_header {
+            foreign_account_inputs.verify_against_block(ref_block_header).map_err(
+                |err| match err {
+                    TransactionExecutorError::ForeignAccountNotAnchoredInReference {
+                        account_id,
+                        source,
+                    } => TransactionKernelError::ForeignAccountNotAnchoredInReference {
+                        account_id,
+                        source,
+                    },
+                    err => TransactionKernelError::other_with_source(
+                        "failed to verify foreign account inputs against the reference block",
+                        err,
+                    ),
+                },
+            )?;
+        }
+
         let mut tx_advice_inputs = TransactionAdviceInputs::default();
         tx_advice_inputs.add_foreign_accounts([&foreign_account_inputs]);
 
@@ -292,6 +472,7 @@ where
             .map_err(|err| TransactionKernelError::GetStorageMapWitness {
                 map_root,
                 map_key,
+                context: Box::new(self.execution_context(active_account_id)),
                 source: err,
             })?;
 
@@ -358,6 +539,7 @@ where
             .map_err(|err| TransactionKernelError::GetVaultAssetWitness {
                 vault_root,
                 asset_key,
+                context: Box::new(self.execution_context(active_account_id)),
                 source: err,
             })?;
 
@@ -446,6 +628,62 @@ where
     }
 }
 
+impl<STORE, AUTH> TransactionExecutorHost<'_, '_, STORE, AUTH>
+where
+    STORE: DataStore,
+    AUTH: TransactionAuthenticator,
+{
+    // CYCLE LIMITS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks the provided clock cycle against the cycle limit of the transaction and against the
+    /// per-note cycle limit, if they are set.
+    ///
+    /// This is called on every host callback which exposes the clock cycle, i.e., on events and, in
+    /// debug mode, on debug and trace decorators.
+    fn check_all_cycles(&self, clk: RowIndex) -> Result<(), TransactionKernelError> {
+        self.check_cycles(clk)?;
+        self.check_note_cycles(clk)
+    }
+
+    /// Checks the provided clock cycle against the cycle limit of the transaction, if one is set.
+    fn check_cycles(&self, clk: RowIndex) -> Result<(), TransactionKernelError> {
+        match self.max_cycles {
+            Some(limit) if clk.as_usize() > limit as usize => {
+                Err(TransactionKernelError::CycleLimitExceeded(limit))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the number of cycles of the currently executing note against the per-note cycle
+    /// limit, if one is set and a note is executing.
+    ///
+    /// Must be called before the end of the note execution is recorded, so that a note exceeding
+    /// the limit is reported as the note that failed.
+    fn check_note_cycles(&self, clk: RowIndex) -> Result<(), TransactionKernelError> {
+        let Some(limit) = self.max_cycles_per_note else {
+            return Ok(());
+        };
+
+        let Some((note_id, interval)) = self
+            .tx_progress
+            .note_execution()
+            .last()
+            .filter(|(_, interval)| interval.end().is_none())
+        else {
+            return Ok(());
+        };
+
+        match interval.start() {
+            Some(start) if clk - start > limit as usize => {
+                Err(TransactionKernelError::NoteCycleLimitExceeded { note_id: *note_id, limit })
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
 // HOST IMPLEMENTATION
 // ================================================================================================
 
@@ -463,6 +701,20 @@ where
         let span = source_manager.location_to_span(location.clone()).unwrap_or_default();
         (span, maybe_file)
     }
+
+    fn on_debug(
+        &mut self,
+        process: &mut ProcessState,
+        options: &DebugOptions,
+    ) -> Result<(), DebugError> {
+        self.check_all_cycles(process.clk())?;
+        DefaultDebugHandler::default().on_debug(process, options)
+    }
+
+    fn on_trace(&mut self, process: &mut ProcessState, trace_id: u32) -> Result<(), TraceError> {
+        self.check_all_cycles(process.clk())?;
+        DefaultDebugHandler::default().on_trace(process, trace_id)
+    }
 }
 
 impl<STORE, AUTH> AsyncHost for TransactionExecutorHost<'_, '_, STORE, AUTH>
@@ -479,8 +731,18 @@ where
         &mut self,
         process: &ProcessState,
     ) -> impl FutureMaybeSend<Result<Vec<AdviceMutation>, EventError>> {
+        let clk = process.clk();
         let core_lib_event_result = self.base_host.handle_core_lib_events(process);
 
+        // The active procedure root is only attributed to the events of the kernel procedure which
+        // authenticated it.
+        if let Ok(tx_event_id) =
+            TransactionEventId::try_from(EventId::from_felt(process.get_stack_item(0)))
+            && !retains_procedure_root(&tx_event_id)
+        {
+            self.active_procedure_root = None;
+        }
+
         // If the event was handled by a core lib handler (Ok(Some)), we will return the result from
         // within the async block below. So, we only need to extract th tx event if the event was
         // not yet handled (Ok(None)).
@@ -490,6 +752,8 @@ where
         };
 
         async move {
+            self.check_all_cycles(clk)?;
+
             if let Some(mutations) = core_lib_event_result? {
                 return Ok(mutations);
             }
@@ -503,16 +767,49 @@ where
                 return Ok(Vec::new());
             };
 
+            if self.read_only {
+                let mutation = match tx_event {
+                    TransactionEvent::AccountStorageAfterSetItem { .. }
+                    | TransactionEvent::AccountStorageAfterSetMapItem { .. } => {
+                        Some(AccountMutation::StorageWrite)
+                    },
+                    TransactionEvent::AccountVaultAfterRemoveAsset { .. }
+                    | TransactionEvent::AccountVaultAfterAddAsset { .. } => {
+                        Some(AccountMutation::VaultMutation)
+                    },
+                    TransactionEvent::AccountAfterIncrementNonce => {
+                        Some(AccountMutation::NonceIncrement)
+                    },
+                    _ => None,
+                };
+
+                if let Some(mutation) = mutation {
+                    return Err(EventError::from(TransactionKernelError::ReadOnlyViolation(
+                        mutation,
+                    )));
+                }
+            }
+
+            let updates_account_delta = matches!(
+                tx_event,
+                TransactionEvent::AccountVaultAfterRemoveAsset { .. }
+                    | TransactionEvent::AccountVaultAfterAddAsset { .. }
+                    | TransactionEvent::AccountStorageAfterSetItem { .. }
+                    | TransactionEvent::AccountStorageAfterSetMapItem { .. }
+            );
+
             let result = match tx_event {
                 TransactionEvent::AccountBeforeForeignLoad { foreign_account_id: account_id } => {
                     self.on_foreign_account_requested(account_id).await
                 },
 
                 TransactionEvent::AccountVaultAfterRemoveAsset { asset } => {
-                    self.base_host.on_account_vault_after_remove_asset(asset)
+                    let context = self.execution_context(self.base_host.native_account_id());
+                    self.base_host.on_account_vault_after_remove_asset(asset, context)
                 },
                 TransactionEvent::AccountVaultAfterAddAsset { asset } => {
-                    self.base_host.on_account_vault_after_add_asset(asset)
+                    let context = self.execution_context(self.base_host.native_account_id());
+                    self.base_host.on_account_vault_after_add_asset(asset, context)
                 },
 
                 TransactionEvent::AccountStorageAfterSetItem { slot_name, new_value } => {
@@ -562,6 +859,7 @@ where
                 },
 
                 TransactionEvent::AccountPushProcedureIndex { code_commitment, procedure_root } => {
+                    self.active_procedure_root = Some(procedure_root);
                     self.base_host.on_account_push_procedure_index(code_commitment, procedure_root)
                 },
 
@@ -597,7 +895,8 @@ where
                 },
 
                 TransactionEvent::NoteBeforeAddAsset { note_idx, asset } => {
-                    self.base_host.on_note_before_add_asset(note_idx, asset)
+                    let context = self.execution_context(self.base_host.native_account_id());
+                    self.base_host.on_note_before_add_asset(note_idx, asset, context)
                 },
 
                 TransactionEvent::NoteBeforeSetAttachment { note_idx, attachment } => self
@@ -680,6 +979,13 @@ where
                 },
             };
 
+            let result = match result {
+                Ok(mutations) if updates_account_delta => {
+                    self.check_account_delta_size().map(|_| mutations)
+                },
+                result => result,
+            };
+
             result.map_err(EventError::from)
         }
     }
@@ -688,6 +994,30 @@ where
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns `true` if the event with the provided ID does not change the active procedure root.
+///
+/// Kernel procedures which authenticate their caller emit
+/// [`TransactionEventId::AccountPushProcedureIndex`] before any of their other events, so the
+/// pushed procedure root remains active only for the events these procedures emit. Link map events
+/// are emitted from within any kernel procedure and do not change the active procedure root either.
+fn retains_procedure_root(event_id: &TransactionEventId) -> bool {
+    matches!(
+        event_id,
+        TransactionEventId::AccountPushProcedureIndex
+            | TransactionEventId::AccountVaultBeforeAddAsset
+            | TransactionEventId::AccountVaultAfterAddAsset
+            | TransactionEventId::AccountVaultBeforeRemoveAsset
+            | TransactionEventId::AccountVaultAfterRemoveAsset
+            | TransactionEventId::AccountStorageBeforeSetItem
+            | TransactionEventId::AccountStorageAfterSetItem
+            | TransactionEventId::AccountStorageBeforeGetMapItem
+            | TransactionEventId::AccountStorageBeforeSetMapItem
+            | TransactionEventId::AccountStorageAfterSetMapItem
+            | TransactionEventId::LinkMapSet
+            | TransactionEventId::LinkMapGet
+    )
+}
+
 /// Converts an [`AssetWitness`] into the set of advice mutations that need to be inserted in order
 /// to access the asset.
 fn asset_witness_to_advice_mutation(asset_witness: AssetWitness) -> [AdviceMutation; 2] {
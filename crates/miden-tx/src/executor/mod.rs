@@ -1,21 +1,26 @@
-use alloc::collections::BTreeSet;
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
 
 use miden_processor::fast::FastProcessor;
 use miden_processor::{AdviceInputs, ExecutionError, StackInputs};
 pub use miden_processor::{ExecutionOptions, MastForestStore};
-use miden_protocol::account::AccountId;
+use miden_protocol::account::{AccountId, PartialAccount};
 use miden_protocol::assembly::DefaultSourceManager;
 use miden_protocol::assembly::debuginfo::SourceManagerSync;
 use miden_protocol::asset::{Asset, AssetVaultKey};
-use miden_protocol::block::BlockNumber;
+use miden_protocol::block::{BlockHeader, BlockNumber};
 use miden_protocol::transaction::{
     ExecutedTransaction,
     InputNote,
     InputNotes,
+    PartialBlockchain,
     TransactionArgs,
     TransactionInputs,
     TransactionKernel,
+    TransactionMeasurements,
     TransactionScript,
 };
 use miden_protocol::vm::StackOutputs;
@@ -32,6 +37,9 @@ pub use exec_host::TransactionExecutorHost;
 mod data_store;
 pub use data_store::DataStore;
 
+mod account_inputs;
+pub use account_inputs::AccountInputsExt;
+
 mod notes_checker;
 pub use notes_checker::{
     FailedNote,
@@ -40,6 +48,126 @@ pub use notes_checker::{
     NoteConsumptionInfo,
 };
 
+// EXECUTION PRESET
+// ================================================================================================
+
+/// A set of predefined cycle budgets for transaction execution.
+///
+/// Each preset maps to a pair of expected and maximum cycle counts which are guaranteed to be
+/// within the range [`MIN_TX_EXECUTION_CYCLES`] and [`MAX_TX_EXECUTION_CYCLES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionPreset {
+    /// A small budget for simple transactions, e.g. consuming a few standard notes.
+    Fast,
+    /// A moderate budget which is sufficient for most transactions.
+    Standard,
+    /// The largest budget allowed by the protocol.
+    Large,
+}
+
+impl ExecutionPreset {
+    /// Returns the number of cycles the transaction is expected to take under this preset.
+    pub const fn expected_cycles(&self) -> u32 {
+        match self {
+            Self::Fast => MIN_TX_EXECUTION_CYCLES,
+            Self::Standard => 1 << 14,
+            Self::Large => 1 << 16,
+        }
+    }
+
+    /// Returns the maximum number of cycles the transaction is allowed to take under this preset.
+    pub const fn max_cycles(&self) -> u32 {
+        match self {
+            Self::Fast => 1 << 16,
+            Self::Standard => 1 << 20,
+            Self::Large => MAX_TX_EXECUTION_CYCLES,
+        }
+    }
+
+    /// Returns the [`ExecutionOptions`] for this preset with tracing and debugging disabled.
+    pub fn execution_options(&self) -> ExecutionOptions {
+        ExecutionOptions::new(Some(self.max_cycles()), self.expected_cycles(), false, false)
+            .expect("preset cycles should be valid execution options")
+    }
+}
+
+impl From<ExecutionPreset> for ExecutionOptions {
+    fn from(preset: ExecutionPreset) -> Self {
+        preset.execution_options()
+    }
+}
+
+// TRANSACTION REQUEST
+// ================================================================================================
+
+/// The arguments of a single transaction executed as part of a batch via
+/// [`TransactionExecutor::execute_transactions`].
+#[derive(Debug, Clone)]
+pub struct TxRequest {
+    account_id: AccountId,
+    block_ref: BlockNumber,
+    notes: InputNotes<InputNote>,
+    tx_args: TransactionArgs,
+}
+
+impl TxRequest {
+    /// Returns a new [`TxRequest`] for a transaction against the account with the provided ID,
+    /// using `block_ref` as the reference block and consuming the provided notes.
+    pub fn new(
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+    ) -> Self {
+        Self { account_id, block_ref, notes, tx_args }
+    }
+
+    /// Returns the ID of the native account of the transaction.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the reference block number of the transaction.
+    pub fn block_ref(&self) -> BlockNumber {
+        self.block_ref
+    }
+
+    /// Returns the notes consumed by the transaction.
+    pub fn notes(&self) -> &InputNotes<InputNote> {
+        &self.notes
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+}
+
+// ACCOUNT MUTATION
+// ================================================================================================
+
+/// A kind of mutation of the native account's state which is rejected when executing a script
+/// via [`TransactionExecutor::execute_read_only`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountMutation {
+    /// A write to a value or map slot of the account storage.
+    StorageWrite,
+    /// An asset being added to or removed from the account vault.
+    VaultMutation,
+    /// An increment of the account nonce.
+    NonceIncrement,
+}
+
+impl fmt::Display for AccountMutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StorageWrite => f.write_str("storage write"),
+            Self::VaultMutation => f.write_str("vault mutation"),
+            Self::NonceIncrement => f.write_str("nonce increment"),
+        }
+    }
+}
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -57,6 +185,7 @@ pub struct TransactionExecutor<'store, 'auth, STORE: 'store, AUTH: 'auth> {
     authenticator: Option<&'auth AUTH>,
     source_manager: Arc<dyn SourceManagerSync>,
     exec_options: ExecutionOptions,
+    account_delta_size_limit: Option<usize>,
 }
 
 impl<'store, 'auth, STORE, AUTH> TransactionExecutor<'store, 'auth, STORE, AUTH>
@@ -84,6 +213,7 @@ where
                 false,
             )
             .expect("Must not fail while max cycles is more than min trace length"),
+            account_delta_size_limit: None,
         }
     }
 
@@ -129,6 +259,46 @@ where
         Ok(self)
     }
 
+    /// Sets the maximum number of cycles the executor allows a transaction to take and returns the
+    /// resulting executor.
+    ///
+    /// Tracing and debug mode settings of the executor are preserved. If the currently expected
+    /// number of cycles exceeds `max_cycles`, it is lowered to `max_cycles`.
+    ///
+    /// # Errors
+    /// Returns an error if `max_cycles` is not within the range [`MIN_TX_EXECUTION_CYCLES`] and
+    /// [`MAX_TX_EXECUTION_CYCLES`].
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Result<Self, TransactionExecutorError> {
+        validate_num_cycles(max_cycles)?;
+
+        let expected_cycles = self.exec_options.expected_cycles().min(max_cycles);
+        self.exec_options = ExecutionOptions::new(
+            Some(max_cycles),
+            expected_cycles,
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        )
+        .expect("Must not fail while max cycles is more than min trace length");
+
+        Ok(self)
+    }
+
+    /// Sets the [ExecutionOptions] of the executor to the ones defined by the provided
+    /// [ExecutionPreset] and returns the resulting executor.
+    ///
+    /// Tracing and debug mode settings of the executor are preserved.
+    #[must_use]
+    pub fn with_preset(mut self, preset: ExecutionPreset) -> Self {
+        let mut exec_options =
+            preset.execution_options().with_debugging(self.exec_options.enable_debugging());
+        if self.exec_options.enable_tracing() {
+            exec_options = exec_options.with_tracing();
+        }
+
+        self.exec_options = exec_options;
+        self
+    }
+
     /// Puts the [TransactionExecutor] into debug mode and returns the resulting executor.
     ///
     /// When transaction executor is in debug mode, all transaction-related code (note scripts,
@@ -152,6 +322,21 @@ where
         self
     }
 
+    /// Sets the maximum encoded size in bytes of the account delta of executed transactions and
+    /// returns the resulting executor.
+    ///
+    /// When set, execution is aborted with
+    /// [`TransactionExecutorError::AccountDeltaSizeLimitExceeded`] as soon as the account delta
+    /// exceeds the limit, instead of failing only once the proven transaction is constructed. This
+    /// check is advisory and disabled by default; the limit on the account update of proven
+    /// transactions ([`ACCOUNT_UPDATE_MAX_SIZE`](miden_protocol::ACCOUNT_UPDATE_MAX_SIZE)) remains
+    /// authoritative.
+    #[must_use]
+    pub fn with_account_delta_size_limit(mut self, limit: usize) -> Self {
+        self.account_delta_size_limit = Some(limit);
+        self
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -171,6 +356,7 @@ where
     /// - If required data can not be fetched from the [`DataStore`].
     /// - If the transaction arguments contain foreign account data not anchored in the reference
     ///   block.
+    /// - If the transaction accesses a foreign account whose state is not public.
     /// - If any input notes were created in block numbers higher than the reference block.
     pub async fn execute_transaction(
         &self,
@@ -179,36 +365,159 @@ where
         notes: InputNotes<InputNote>,
         tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        self.execute_transaction_inner(account_id, block_ref, notes, tx_args, &self.exec_options)
+            .await
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments using the provided
+    /// [`ExecutionOptions`] instead of the ones configured on the executor, and returns an
+    /// [`ExecutedTransaction`].
+    ///
+    /// This is useful for one-off transactions which require a different cycle budget than the
+    /// executor was configured with, without having to rebuild the executor. Otherwise, this
+    /// method behaves like [`Self::execute_transaction`].
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If the cycle values (`max_cycles` and `expected_cycles`) in the provided
+    ///   [`ExecutionOptions`] are not within the range [`MIN_TX_EXECUTION_CYCLES`] and
+    ///   [`MAX_TX_EXECUTION_CYCLES`].
+    /// - Any of the conditions described in [`Self::execute_transaction`] apply.
+    pub async fn execute_transaction_with_options(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        exec_options: ExecutionOptions,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        validate_num_cycles(exec_options.max_cycles())?;
+        validate_num_cycles(exec_options.expected_cycles())?;
+
+        self.execute_transaction_inner(account_id, block_ref, notes, tx_args, &exec_options)
+            .await
+    }
+
+    /// Executes a batch of independent transactions and returns their results in the same order
+    /// as the provided requests.
+    ///
+    /// Requests against the same account and reference block share a single
+    /// [`DataStore::get_transaction_inputs`] call which fetches the account, the reference block
+    /// header and the partial blockchain for the notes of all transactions in the group. Each
+    /// transaction is then executed against the same initial account state, consuming only the
+    /// notes of its own request. Note that the transactions are not applied on top of each other.
+    ///
+    /// If fetching the shared inputs of a group fails, each transaction of that group falls back
+    /// to fetching its own inputs so that every request receives its own result.
+    ///
+    /// # Errors:
+    ///
+    /// Each result is an error if any of the conditions described in
+    /// [`Self::execute_transaction`] apply to the corresponding request.
+    pub async fn execute_transactions(
+        &self,
+        requests: Vec<TxRequest>,
+    ) -> Vec<Result<ExecutedTransaction, TransactionExecutorError>> {
+        let mut results: Vec<Option<Result<ExecutedTransaction, TransactionExecutorError>>> =
+            (0..requests.len()).map(|_| None).collect();
+
+        // group the requests by native account and reference block, collecting the blocks
+        // required by the notes of all requests in a group
+        let mut groups: BTreeMap<(AccountId, BlockNumber), TxRequestGroup> = BTreeMap::new();
+        for (idx, request) in requests.into_iter().enumerate() {
+            match validate_input_notes(&request.notes, request.block_ref) {
+                Ok((asset_vault_keys, ref_blocks)) => {
+                    let group = groups.entry((request.account_id, request.block_ref)).or_default();
+                    group.ref_blocks.extend(ref_blocks);
+                    group.requests.push((idx, request, asset_vault_keys));
+                },
+                Err(err) => results[idx] = Some(Err(err)),
+            }
+        }
+
+        for ((account_id, block_ref), mut group) in groups {
+            group.ref_blocks.insert(block_ref);
+
+            let shared_inputs =
+                self.data_store.get_transaction_inputs(account_id, group.ref_blocks).await;
+
+            for (idx, request, asset_vault_keys) in group.requests {
+                let result = match &shared_inputs {
+                    Ok((account, block_header, blockchain)) => {
+                        let tx_inputs = self
+                            .complete_tx_inputs(
+                                account.clone(),
+                                block_header.clone(),
+                                blockchain.clone(),
+                                request.notes,
+                                request.tx_args,
+                                asset_vault_keys,
+                            )
+                            .await;
+
+                        match tx_inputs {
+                            Ok(tx_inputs) => {
+                                self.execute_tx_inputs(tx_inputs, &self.exec_options).await
+                            },
+                            Err(err) => Err(err),
+                        }
+                    },
+                    Err(_) => {
+                        self.execute_transaction_inner(
+                            request.account_id,
+                            request.block_ref,
+                            request.notes,
+                            request.tx_args,
+                            &self.exec_options,
+                        )
+                        .await
+                    },
+                };
+
+                results[idx] = Some(result);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("every request should have a result"))
+            .collect()
+    }
+
+    /// Executes a transaction specified by the provided arguments and returns the number of
+    /// cycles its execution took, without building an [`ExecutedTransaction`].
+    ///
+    /// This is cheaper than [`Self::execute_transaction`] since the transaction outputs and the
+    /// account delta are neither constructed nor validated after execution, which makes it
+    /// suitable for estimating the fee of a transaction. The returned cycle count is the same as
+    /// [`TransactionMeasurements::total_cycles`] of the corresponding executed transaction.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if any of the conditions described in [`Self::execute_transaction`] apply,
+    /// except for the ones related to constructing the [`ExecutedTransaction`].
+    pub async fn estimate_cycles(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+    ) -> Result<u64, TransactionExecutorError> {
         let tx_inputs = self.prepare_tx_inputs(account_id, block_ref, notes, tx_args).await?;
 
         let (mut host, stack_inputs, advice_inputs) = self.prepare_transaction(&tx_inputs).await?;
 
-        // instantiate the processor in debug mode only when debug mode is specified via execution
-        // options; this is important because in debug mode execution is almost 100x slower
-        // TODO: the processor does not yet respect other execution options (e.g., max cycles);
-        // this will be fixed in v0.21 release of the VM
-        let processor = if self.exec_options.enable_debugging() {
-            FastProcessor::new_debug(stack_inputs.as_slice(), advice_inputs)
-        } else {
-            FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs)
-        };
-
-        let output = processor
+        let processor =
+            FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs);
+        processor
             .execute(&TransactionKernel::main(), &mut host)
             .await
             .map_err(map_execution_error)?;
-        let stack_outputs = output.stack;
-        let advice_provider = output.advice;
 
-        // The stack is not necessary since it is being reconstructed when re-executing.
-        let (_stack, advice_map, merkle_store, _pc_requests) = advice_provider.into_parts();
-        let advice_inputs = AdviceInputs {
-            map: advice_map,
-            store: merkle_store,
-            ..Default::default()
-        };
+        let measurements = TransactionMeasurements::from(host.tx_progress().clone());
 
-        build_executed_transaction(advice_inputs, tx_inputs, stack_outputs, host)
+        Ok(measurements.total_cycles() as u64)
     }
 
     // SCRIPT EXECUTION
@@ -228,6 +537,46 @@ where
         block_ref: BlockNumber,
         tx_script: TransactionScript,
         advice_inputs: AdviceInputs,
+    ) -> Result<[Felt; 16], TransactionExecutorError> {
+        self.execute_tx_view_script_inner(account_id, block_ref, tx_script, advice_inputs, false)
+            .await
+    }
+
+    /// Executes an arbitrary script against the given account in read-only mode and returns the
+    /// stack state at the end of execution.
+    ///
+    /// This behaves like [`Self::execute_tx_view_script`], except that execution is aborted as
+    /// soon as the script attempts to mutate the state of the native account, i.e. to write to
+    /// its storage, add or remove assets from its vault or increment its nonce.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - Any of the conditions described in [`Self::execute_tx_view_script`] apply.
+    /// - If the script attempts to mutate the native account, in which case
+    ///   [`TransactionExecutorError::ReadOnlyViolation`] is returned.
+    pub async fn execute_read_only(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        tx_script: TransactionScript,
+        advice_inputs: AdviceInputs,
+    ) -> Result<[Felt; 16], TransactionExecutorError> {
+        self.execute_tx_view_script_inner(account_id, block_ref, tx_script, advice_inputs, true)
+            .await
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes an arbitrary script against the given account, optionally rejecting any mutation
+    /// of the native account.
+    async fn execute_tx_view_script_inner(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        tx_script: TransactionScript,
+        advice_inputs: AdviceInputs,
+        read_only: bool,
     ) -> Result<[Felt; 16], TransactionExecutorError> {
         let mut tx_args = TransactionArgs::default().with_tx_script(tx_script);
         tx_args.extend_advice_inputs(advice_inputs);
@@ -235,21 +584,80 @@ where
         let notes = InputNotes::default();
         let tx_inputs = self.prepare_tx_inputs(account_id, block_ref, notes, tx_args).await?;
 
-        let (mut host, stack_inputs, advice_inputs) = self.prepare_transaction(&tx_inputs).await?;
+        let (host, stack_inputs, advice_inputs) = self.prepare_transaction(&tx_inputs).await?;
+        let mut host = host.with_read_only(read_only);
 
         let processor =
             FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs);
         let output = processor
             .execute(&TransactionKernel::tx_script_main(), &mut host)
             .await
-            .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+            .map_err(|err| {
+                if read_only {
+                    map_execution_error(err)
+                } else {
+                    TransactionExecutorError::TransactionProgramExecutionFailed(err)
+                }
+            })?;
         let stack_outputs = output.stack;
 
         Ok(*stack_outputs)
     }
 
-    // HELPER METHODS
-    // --------------------------------------------------------------------------------------------
+    /// Executes a transaction specified by the provided arguments using the provided execution
+    /// options.
+    async fn execute_transaction_inner(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        exec_options: &ExecutionOptions,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = self.prepare_tx_inputs(account_id, block_ref, notes, tx_args).await?;
+
+        self.execute_tx_inputs(tx_inputs, exec_options).await
+    }
+
+    /// Executes a transaction with the provided, fully prepared transaction inputs using the
+    /// provided execution options.
+    async fn execute_tx_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        exec_options: &ExecutionOptions,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let (host, stack_inputs, advice_inputs) = self.prepare_transaction(&tx_inputs).await?;
+        // the provided execution options may differ from the ones of the executor
+        let mut host = host.with_max_cycles(Some(exec_options.max_cycles()));
+
+        // instantiate the processor in debug mode only when debug mode is specified via execution
+        // options; this is important because in debug mode execution is almost 100x slower
+        // TODO: the processor does not yet respect other execution options (e.g., max cycles), so
+        // the max cycles are enforced by the host on every callback; this will be fixed in v0.21
+        // release of the VM
+        let processor = if exec_options.enable_debugging() {
+            FastProcessor::new_debug(stack_inputs.as_slice(), advice_inputs)
+        } else {
+            FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs)
+        };
+
+        let output = processor
+            .execute(&TransactionKernel::main(), &mut host)
+            .await
+            .map_err(map_execution_error)?;
+        let stack_outputs = output.stack;
+        let advice_provider = output.advice;
+
+        // The stack is not necessary since it is being reconstructed when re-executing.
+        let (_stack, advice_map, merkle_store, _pc_requests) = advice_provider.into_parts();
+        let advice_inputs = AdviceInputs {
+            map: advice_map,
+            store: merkle_store,
+            ..Default::default()
+        };
+
+        build_executed_transaction(advice_inputs, tx_inputs, stack_outputs, host)
+    }
 
     // Validates input notes and account inputs after retrieving transaction inputs from the store.
     //
@@ -263,7 +671,7 @@ where
         input_notes: InputNotes<InputNote>,
         tx_args: TransactionArgs,
     ) -> Result<TransactionInputs, TransactionExecutorError> {
-        let (mut asset_vault_keys, mut ref_blocks) = validate_input_notes(&input_notes, block_ref)?;
+        let (asset_vault_keys, mut ref_blocks) = validate_input_notes(&input_notes, block_ref)?;
         ref_blocks.insert(block_ref);
 
         let (account, block_header, blockchain) = self
@@ -272,6 +680,29 @@ where
             .await
             .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        self.complete_tx_inputs(
+            account,
+            block_header,
+            blockchain,
+            input_notes,
+            tx_args,
+            asset_vault_keys,
+        )
+        .await
+    }
+
+    /// Builds the transaction inputs from the data fetched from the store and fetches the asset
+    /// witnesses which are required but not yet part of the inputs.
+    async fn complete_tx_inputs(
+        &self,
+        account: PartialAccount,
+        block_header: BlockHeader,
+        blockchain: PartialBlockchain,
+        input_notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        mut asset_vault_keys: BTreeSet<AssetVaultKey>,
+    ) -> Result<TransactionInputs, TransactionExecutorError> {
+        let account_id = account.id();
         let native_account_vault_root = account.vault().root();
         let fee_asset_vault_key =
             AssetVaultKey::from_account_id(block_header.fee_parameters().native_asset_id())
@@ -364,7 +795,11 @@ where
             tx_inputs.block_header().block_num(),
             initial_fee_asset_balance,
             self.source_manager.clone(),
-        );
+        )
+        .with_account_delta_size_limit(self.account_delta_size_limit)
+        .with_max_cycles(Some(self.exec_options.max_cycles()))
+        .with_max_cycles_per_note(tx_inputs.tx_args().max_cycles_per_note())
+        .with_ref_block_header(Some(tx_inputs.block_header().clone()));
 
         let advice_inputs = tx_advice_inputs.into_advice_inputs();
 
@@ -372,6 +807,19 @@ where
     }
 }
 
+// HELPER TYPES
+// ================================================================================================
+
+/// A group of [`TxRequest`]s against the same account and reference block, together with the set
+/// of blocks required by the notes of all requests in the group.
+#[derive(Default)]
+struct TxRequestGroup {
+    ref_blocks: BTreeSet<BlockNumber>,
+    /// The index of the request in the batch, the request itself and the asset vault keys
+    /// required by its notes.
+    requests: Vec<(usize, TxRequest, BTreeSet<AssetVaultKey>)>,
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -502,27 +950,123 @@ fn validate_num_cycles(num_cycles: u32) -> Result<(), TransactionExecutorError>
 ///
 /// - If the inner error is [`TransactionKernelError::Unauthorized`], it is remapped to
 ///   [`TransactionExecutorError::Unauthorized`].
+/// - If the inner error is [`TransactionKernelError::ForeignAccountNotPublic`], it is remapped to
+///   [`TransactionExecutorError::ForeignAccountNotPublic`].
+/// - If the inner error is [`TransactionKernelError::ForeignAccountNotAnchoredInReference`], it is
+///   remapped to [`TransactionExecutorError::ForeignAccountNotAnchoredInReference`].
+/// - If the inner error is [`TransactionKernelError::AccountDeltaSizeLimitExceeded`], it is
+///   remapped to [`TransactionExecutorError::AccountDeltaSizeLimitExceeded`].
+/// - If the inner error is [`TransactionKernelError::CycleLimitExceeded`], it is remapped to
+///   [`TransactionExecutorError::TransactionProgramExecutionFailed`] with an
+///   [`ExecutionError::CycleLimitExceeded`], as it would be reported by the processor.
+/// - If the inner error is [`TransactionKernelError::NoteCycleLimitExceeded`], it is remapped to
+///   [`TransactionExecutorError::NoteCycleLimitExceeded`].
+/// - If the inner error is [`TransactionKernelError::ReadOnlyViolation`], it is remapped to
+///   [`TransactionExecutorError::ReadOnlyViolation`].
 /// - Otherwise, the execution error is wrapped in
 ///   [`TransactionExecutorError::TransactionProgramExecutionFailed`].
 fn map_execution_error(exec_err: ExecutionError) -> TransactionExecutorError {
-    match exec_err {
-        ExecutionError::EventError { ref error, .. } => {
-            match error.downcast_ref::<TransactionKernelError>() {
-                Some(TransactionKernelError::Unauthorized(summary)) => {
-                    TransactionExecutorError::Unauthorized(summary.clone())
-                },
-                Some(TransactionKernelError::InsufficientFee { account_balance, tx_fee }) => {
-                    TransactionExecutorError::InsufficientFee {
-                        account_balance: *account_balance,
-                        tx_fee: *tx_fee,
-                    }
-                },
-                Some(TransactionKernelError::MissingAuthenticator) => {
-                    TransactionExecutorError::MissingAuthenticator
+    let (label, source_file, event_id, event_name, error) = match exec_err {
+        ExecutionError::EventError {
+            label,
+            source_file,
+            event_id,
+            event_name,
+            error,
+        } => (label, source_file, event_id, event_name, error),
+        // The cycle limits are also checked on debug and trace decorators.
+        ExecutionError::DebugHandlerError { clk, err } => {
+            return map_cycle_limit_error(err).unwrap_or_else(|err| {
+                TransactionExecutorError::TransactionProgramExecutionFailed(
+                    ExecutionError::DebugHandlerError { clk, err },
+                )
+            });
+        },
+        ExecutionError::TraceHandlerError { clk, trace_id, err } => {
+            return map_cycle_limit_error(err).unwrap_or_else(|err| {
+                TransactionExecutorError::TransactionProgramExecutionFailed(
+                    ExecutionError::TraceHandlerError { clk, trace_id, err },
+                )
+            });
+        },
+        exec_err => return TransactionExecutorError::TransactionProgramExecutionFailed(exec_err),
+    };
+
+    // The kernel error is taken by value, since some of its variants carry sources which cannot be
+    // cloned.
+    let kernel_error = match error.downcast::<TransactionKernelError>() {
+        Ok(kernel_error) => *kernel_error,
+        Err(error) => {
+            return TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::EventError {
+                    label,
+                    source_file,
+                    event_id,
+                    event_name,
+                    error,
                 },
-                _ => TransactionExecutorError::TransactionProgramExecutionFailed(exec_err),
-            }
+            );
+        },
+    };
+
+    match kernel_error {
+        TransactionKernelError::Unauthorized(summary) => {
+            TransactionExecutorError::Unauthorized(summary)
+        },
+        TransactionKernelError::InsufficientFee { account_balance, tx_fee } => {
+            TransactionExecutorError::InsufficientFee { account_balance, tx_fee }
+        },
+        TransactionKernelError::MissingAuthenticator => {
+            TransactionExecutorError::MissingAuthenticator
+        },
+        TransactionKernelError::ForeignAccountNotPublic(account_id) => {
+            TransactionExecutorError::ForeignAccountNotPublic(account_id)
+        },
+        TransactionKernelError::ForeignAccountNotAnchoredInReference { account_id, source } => {
+            TransactionExecutorError::ForeignAccountNotAnchoredInReference { account_id, source }
+        },
+        TransactionKernelError::ReadOnlyViolation(mutation) => {
+            TransactionExecutorError::ReadOnlyViolation(mutation)
+        },
+        TransactionKernelError::AccountDeltaSizeLimitExceeded { account_id, limit, breakdown } => {
+            TransactionExecutorError::AccountDeltaSizeLimitExceeded { account_id, limit, breakdown }
+        },
+        TransactionKernelError::CycleLimitExceeded(limit) => {
+            TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::CycleLimitExceeded(limit),
+            )
+        },
+        TransactionKernelError::NoteCycleLimitExceeded { note_id, limit } => {
+            TransactionExecutorError::NoteCycleLimitExceeded { note_id, limit }
+        },
+        kernel_error => TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::EventError {
+                label,
+                source_file,
+                event_id,
+                event_name,
+                error: Box::new(kernel_error),
+            },
+        ),
+    }
+}
+
+/// Remaps the provided handler error to the corresponding [`TransactionExecutorError`] if it is a
+/// cycle limit error raised by the transaction executor host, or returns the error unchanged
+/// otherwise.
+fn map_cycle_limit_error(
+    error: Box<dyn core::error::Error + Send + Sync + 'static>,
+) -> Result<TransactionExecutorError, Box<dyn core::error::Error + Send + Sync + 'static>> {
+    match error.downcast::<TransactionKernelError>().map(|kernel_error| *kernel_error) {
+        Ok(TransactionKernelError::CycleLimitExceeded(limit)) => {
+            Ok(TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::CycleLimitExceeded(limit),
+            ))
+        },
+        Ok(TransactionKernelError::NoteCycleLimitExceeded { note_id, limit }) => {
+            Ok(TransactionExecutorError::NoteCycleLimitExceeded { note_id, limit })
         },
-        _ => TransactionExecutorError::TransactionProgramExecutionFailed(exec_err),
+        Ok(kernel_error) => Err(Box::new(kernel_error)),
+        Err(error) => Err(error),
     }
 }
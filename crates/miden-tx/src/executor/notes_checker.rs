@@ -47,10 +47,16 @@ impl FailedNote {
 }
 
 /// Contains information about the successful and failed consumption of notes.
+///
+/// If the successful notes could only be executed up to the authentication of the transaction,
+/// e.g. because the executor has no authenticator for the account, the authentication error is
+/// stored in `epilogue_error`. The notes are still considered successful since the transaction
+/// consuming them would succeed once it is authorized.
 #[derive(Default, Debug)]
 pub struct NoteConsumptionInfo {
     pub successful: Vec<Note>,
     pub failed: Vec<FailedNote>,
+    pub epilogue_error: Option<TransactionExecutorError>,
 }
 
 impl NoteConsumptionInfo {
@@ -61,7 +67,14 @@ impl NoteConsumptionInfo {
 
     /// Creates a new [`NoteConsumptionInfo`] instance with the given successful and failed notes.
     pub fn new(successful: Vec<Note>, failed: Vec<FailedNote>) -> Self {
-        Self { successful, failed }
+        Self { successful, failed, epilogue_error: None }
+    }
+
+    /// Sets the error with which the authentication of the transaction consuming the successful
+    /// notes failed.
+    pub fn with_epilogue_error(mut self, epilogue_error: Option<TransactionExecutorError>) -> Self {
+        self.epilogue_error = epilogue_error;
+        self
     }
 }
 
@@ -104,9 +117,14 @@ where
     /// - Try [A, B, D, E] → A, B, D succeed, E fails → Remove E, try again.
     /// - Try [A, B, D] → All succeed → Return successful=[A, B, D], failed=[C, E].
     ///
-    /// If a failure occurs at the epilogue phase of the transaction execution, the relevant set of
-    /// otherwise-successful notes are retried in various combinations in an attempt to find a
-    /// combination that passes the epilogue phase successfully.
+    /// If the transaction fails in the epilogue phase only because it could not be authorized,
+    /// e.g. because no authenticator was provided to the executor, the notes are considered
+    /// successful and the authorization error is returned in
+    /// [`NoteConsumptionInfo::epilogue_error`].
+    ///
+    /// If any other failure occurs at the epilogue phase of the transaction execution, the
+    /// relevant set of otherwise-successful notes are retried in various combinations in an attempt
+    /// to find a combination that passes the epilogue phase successfully.
     ///
     /// Returns a list of successfully consumed notes and a list of failed notes.
     pub async fn check_notes_consumability(
@@ -240,6 +258,15 @@ where
                     }
                     // Continue and process the next set of candidates.
                 },
+                Err(TransactionCheckerError::EpilogueExecution(error))
+                    if is_authorization_error(&error) =>
+                {
+                    // The notes were consumed successfully and only the authorization of the
+                    // transaction failed.
+                    let successful = candidate_notes;
+                    return Ok(NoteConsumptionInfo::new(successful, failed_notes)
+                        .with_epilogue_error(Some(error)));
+                },
                 Err(TransactionCheckerError::EpilogueExecution(_)) => {
                     let consumption_info = self
                         .find_largest_executable_combination(
@@ -274,6 +301,7 @@ where
     ) -> NoteConsumptionInfo {
         let mut successful_notes = Vec::new();
         let mut failed_note_index = BTreeMap::new();
+        let mut epilogue_error = None;
 
         // Iterate by note count: try 1 note, then 2, then 3, etc.
         for size in 1..=remaining_notes.len() {
@@ -287,7 +315,18 @@ where
                 successful_notes.push(note.clone());
 
                 tx_inputs.set_input_notes(successful_notes.clone());
-                match self.try_execute_notes(&mut tx_inputs).await {
+                let result = match self.try_execute_notes(&mut tx_inputs).await {
+                    // Only the authorization of the transaction failed, so the combination is
+                    // considered successful.
+                    Err(TransactionCheckerError::EpilogueExecution(error))
+                        if is_authorization_error(&error) =>
+                    {
+                        epilogue_error = Some(error);
+                        Ok(())
+                    },
+                    result => result,
+                };
+                match result {
                     Ok(()) => {
                         // The successfully added note might have failed earlier. Remove it from the
                         // failed list.
@@ -313,7 +352,7 @@ where
 
         // Append failed notes to the list of failed notes provided as input.
         failed_notes.extend(failed_note_index.into_values());
-        NoteConsumptionInfo::new(successful_notes, failed_notes)
+        NoteConsumptionInfo::new(successful_notes, failed_notes).with_epilogue_error(epilogue_error)
     }
 
     /// Attempts to execute a transaction with the provided input notes.
@@ -390,18 +429,26 @@ where
 /// The goal of this helper function is to handle the cases where the account couldn't consume the
 /// note because of some epilogue check failure, e.g. absence of the authenticator.
 fn handle_epilogue_error(epilogue_error: TransactionExecutorError) -> NoteConsumptionStatus {
-    match epilogue_error {
+    if is_authorization_error(&epilogue_error) {
+        // There is a probability that the provided note could be consumed if the authentication
+        // is provided.
+        NoteConsumptionStatus::ConsumableWithAuthorization
+    } else {
+        // TODO: apply additional checks to get the verbose error reason
+        NoteConsumptionStatus::UnconsumableConditions
+    }
+}
+
+/// Returns `true` if the provided epilogue error signals that the transaction could not be
+/// authorized, rather than that the notes could not be consumed.
+fn is_authorization_error(epilogue_error: &TransactionExecutorError) -> bool {
+    matches!(
+        epilogue_error,
         // `Unauthorized` is returned for the multisig accounts if the transaction doesn't have
         // enough signatures.
         TransactionExecutorError::Unauthorized(_)
         // `MissingAuthenticator` is returned for the account with the basic auth if the
         // authenticator was not provided to the executor (UnreachableAuth).
-        | TransactionExecutorError::MissingAuthenticator => {
-            // Both these cases signal that there is a probability that the provided note could be
-            // consumed if the authentication is provided.
-            NoteConsumptionStatus::ConsumableWithAuthorization
-        },
-        // TODO: apply additional checks to get the verbose error reason
-        _ => NoteConsumptionStatus::UnconsumableConditions,
-    }
+        | TransactionExecutorError::MissingAuthenticator
+    )
 }
@@ -1,10 +1,15 @@
+use alloc::boxed::Box;
+
 use miden_protocol::account::{
     AccountCode,
     AccountDelta,
+    AccountDeltaSizeBreakdown,
     AccountId,
     AccountVaultDelta,
     PartialAccount,
 };
+use miden_protocol::errors::AccountDeltaError;
+use miden_protocol::utils::Serializable;
 use miden_protocol::{Felt, FieldElement, ZERO};
 
 use crate::host::storage_delta_tracker::StorageDeltaTracker;
@@ -27,6 +32,8 @@ pub struct AccountDeltaTracker {
     storage: StorageDeltaTracker,
     vault: AccountVaultDelta,
     code: Option<AccountCode>,
+    /// The encoded size of `code`, computed once since encoding the code is expensive.
+    code_size: usize,
     nonce_delta: Felt,
 }
 
@@ -39,11 +46,14 @@ impl AccountDeltaTracker {
             None
         };
 
+        let code_size = code.get_size_hint();
+
         Self {
             account_id: account.id(),
             storage: StorageDeltaTracker::new(account),
             vault: AccountVaultDelta::default(),
             code,
+            code_size,
             nonce_delta: ZERO,
         }
     }
@@ -68,6 +78,49 @@ impl AccountDeltaTracker {
         &mut self.vault
     }
 
+    /// Returns the encoded size of the delta returned by [`Self::into_delta`] in bytes.
+    ///
+    /// The size of the normalized storage delta is tracked as storage is written, so this does not
+    /// require normalizing the storage delta.
+    pub fn size_hint(&self) -> usize {
+        self.account_id.get_size_hint()
+            + self.storage.size_hint()
+            + self.vault.get_size_hint()
+            + self.code_size
+            + self.nonce_delta.get_size_hint()
+    }
+
+    /// Checks that the encoded size of the delta returned by [`Self::into_delta`] does not exceed
+    /// the provided limit in bytes.
+    ///
+    /// Unlike [`AccountDelta::check_size_limit`], this does not require building a valid
+    /// [`AccountDelta`], so it can be called at any point during execution, e.g. before the nonce
+    /// was incremented. The storage delta is only normalized to report the size breakdown if the
+    /// limit is exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccountDeltaError::SizeLimitExceeded`] if the encoded size of the normalized delta
+    /// is greater than `limit`.
+    pub fn check_size_limit(&self, limit: usize) -> Result<(), AccountDeltaError> {
+        let encoded_size = self.size_hint();
+        if encoded_size <= limit {
+            return Ok(());
+        }
+
+        let storage = self.storage.clone().into_delta();
+
+        Err(AccountDeltaError::SizeLimitExceeded {
+            account_id: self.account_id,
+            limit,
+            breakdown: Box::new(AccountDeltaSizeBreakdown::new(
+                encoded_size,
+                &storage,
+                &self.vault,
+            )),
+        })
+    }
+
     /// Returns a mutable reference to the current storage delta tracker.
     pub fn storage(&mut self) -> &mut StorageDeltaTracker {
         &mut self.storage
@@ -47,6 +47,22 @@ impl AccountProcedureIndexMap {
         self.0.insert(code.commitment(), procedure_map);
     }
 
+    /// Returns the number of account codes whose procedures are contained in this map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this map does not contain the procedures of any account code.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the procedure with the provided root is contained in any of the account
+    /// codes in this map.
+    pub fn contains(&self, procedure_root: Word) -> bool {
+        self.0.values().any(|procedures| procedures.contains_key(&procedure_root))
+    }
+
     /// Returns the index of the requested procedure root in the account code identified by the
     /// provided commitment.
     ///
@@ -67,3 +83,26 @@ impl AccountProcedureIndexMap {
             .ok_or(TransactionKernelError::UnknownAccountProcedure(procedure_root))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use miden_protocol::Word;
+    use miden_protocol::account::AccountCode;
+
+    use super::AccountProcedureIndexMap;
+
+    #[test]
+    fn procedure_index_map_contains_native_account_procedures() {
+        let code = AccountCode::mock();
+        let index_map = AccountProcedureIndexMap::new([&code]);
+
+        assert_eq!(index_map.len(), 1);
+        assert!(!index_map.is_empty());
+        for procedure in code.procedures() {
+            assert!(index_map.contains(*procedure.mast_root()));
+        }
+        assert!(!index_map.contains(Word::from([1, 2, 3, 4u32])));
+
+        assert!(AccountProcedureIndexMap::default().is_empty());
+    }
+}
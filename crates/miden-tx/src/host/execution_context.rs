@@ -0,0 +1,111 @@
+use core::fmt;
+
+use miden_protocol::Word;
+use miden_protocol::account::AccountId;
+use miden_protocol::note::NoteId;
+
+// EXECUTION CONTEXT
+// ================================================================================================
+
+/// The context of the transaction execution in which an event was handled.
+///
+/// The context is attached to errors raised by event handlers, so that they can be attributed to
+/// the note or procedure which triggered them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionContext {
+    note_id: Option<NoteId>,
+    foreign_account_id: Option<AccountId>,
+    procedure_root: Option<Word>,
+}
+
+impl ExecutionContext {
+    /// Returns a new [`ExecutionContext`] instantiated from the provided parameters.
+    pub fn new(
+        note_id: Option<NoteId>,
+        foreign_account_id: Option<AccountId>,
+        procedure_root: Option<Word>,
+    ) -> Self {
+        Self {
+            note_id,
+            foreign_account_id,
+            procedure_root,
+        }
+    }
+
+    /// Returns the ID of the input note which was being executed, or `None` if the event was not
+    /// handled during note processing.
+    pub fn note_id(&self) -> Option<NoteId> {
+        self.note_id
+    }
+
+    /// Returns the ID of the foreign account which was active, or `None` if the native account was
+    /// active.
+    pub fn foreign_account_id(&self) -> Option<AccountId> {
+        self.foreign_account_id
+    }
+
+    /// Returns the root of the account procedure which most recently called into the transaction
+    /// kernel within the current note or transaction script, if any.
+    pub fn procedure_root(&self) -> Option<Word> {
+        self.procedure_root
+    }
+}
+
+impl fmt::Display for ExecutionContext {
+    /// Formats the context as a parenthesized list of its known parts, preceded by a space, or as
+    /// an empty string if no part is known.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = " (";
+        if let Some(note_id) = self.note_id {
+            write!(f, "{separator}in note {note_id}")?;
+            separator = ", ";
+        }
+        if let Some(account_id) = self.foreign_account_id {
+            write!(f, "{separator}in foreign account {account_id}")?;
+            separator = ", ";
+        }
+        if let Some(procedure_root) = self.procedure_root {
+            write!(f, "{separator}in account procedure {procedure_root}")?;
+            separator = ", ";
+        }
+
+        if separator == ", " { f.write_str(")") } else { Ok(()) }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use miden_protocol::Word;
+    use miden_protocol::account::AccountId;
+    use miden_protocol::note::NoteId;
+    use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+
+    use super::ExecutionContext;
+
+    #[test]
+    fn execution_context_display() {
+        assert_eq!(ExecutionContext::default().to_string(), "");
+
+        let note_id = NoteId::new(Word::from([1, 2, 3, 4u32]), Word::from([5, 6, 7, 8u32]));
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let procedure_root = Word::from([9, 10, 11, 12u32]);
+
+        assert_eq!(
+            ExecutionContext::new(Some(note_id), None, None).to_string(),
+            format!(" (in note {note_id})")
+        );
+        assert_eq!(
+            ExecutionContext::new(Some(note_id), Some(account_id), Some(procedure_root))
+                .to_string(),
+            format!(
+                " (in note {note_id}, in foreign account {account_id}, in account procedure {procedure_root})"
+            )
+        );
+    }
+}
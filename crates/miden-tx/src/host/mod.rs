@@ -22,6 +22,9 @@ pub use script_mast_forest_store::ScriptMastForestStore;
 
 mod tx_progress;
 
+mod execution_context;
+pub use execution_context::ExecutionContext;
+
 mod tx_event;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
@@ -61,7 +64,7 @@ use miden_protocol::transaction::{
 };
 use miden_protocol::vm::RowIndex;
 pub(crate) use tx_event::{RecipientData, TransactionEvent, TransactionProgressEvent};
-pub use tx_progress::TransactionProgress;
+pub use tx_progress::{CycleInterval, TransactionProgress, TxProgressSnapshot};
 
 use crate::errors::TransactionKernelError;
 
@@ -286,16 +289,21 @@ impl<'store, STORE> TransactionBaseHost<'store, STORE> {
     }
 
     /// Adds an asset to the output note identified by the note index.
+    ///
+    /// The provided context is attached to the error if the asset cannot be added to the note.
     pub fn on_note_before_add_asset(
         &mut self,
         note_idx: usize,
         asset: Asset,
+        context: ExecutionContext,
     ) -> Result<Vec<AdviceMutation>, TransactionKernelError> {
         let note_builder = self.output_notes.get_mut(&note_idx).ok_or_else(|| {
             TransactionKernelError::other(format!("failed to find output note {note_idx}"))
         })?;
 
-        note_builder.add_asset(asset)?;
+        note_builder.add_asset(asset).map_err(|source| {
+            TransactionKernelError::FailedToAddAssetToNote { context: Box::new(context), source }
+        })?;
 
         Ok(Vec::new())
     }
@@ -373,27 +381,37 @@ impl<'store, STORE> TransactionBaseHost<'store, STORE> {
     // --------------------------------------------------------------------------------------------
 
     /// Tracks the addition of an asset to the account vault in the account delta.
+    ///
+    /// The provided context is attached to the error if the asset cannot be tracked.
     pub fn on_account_vault_after_add_asset(
         &mut self,
         asset: Asset,
+        context: ExecutionContext,
     ) -> Result<Vec<AdviceMutation>, TransactionKernelError> {
-        self.account_delta
-            .vault_delta_mut()
-            .add_asset(asset)
-            .map_err(TransactionKernelError::AccountDeltaAddAssetFailed)?;
+        self.account_delta.vault_delta_mut().add_asset(asset).map_err(|source| {
+            TransactionKernelError::AccountDeltaAddAssetFailed {
+                context: Box::new(context),
+                source,
+            }
+        })?;
 
         Ok(Vec::new())
     }
 
     /// Tracks the removal of an asset from the account vault in the account delta.
+    ///
+    /// The provided context is attached to the error if the asset cannot be tracked.
     pub fn on_account_vault_after_remove_asset(
         &mut self,
         asset: Asset,
+        context: ExecutionContext,
     ) -> Result<Vec<AdviceMutation>, TransactionKernelError> {
-        self.account_delta
-            .vault_delta_mut()
-            .remove_asset(asset)
-            .map_err(TransactionKernelError::AccountDeltaRemoveAssetFailed)?;
+        self.account_delta.vault_delta_mut().remove_asset(asset).map_err(|source| {
+            TransactionKernelError::AccountDeltaRemoveAssetFailed {
+                context: Box::new(context),
+                source,
+            }
+        })?;
 
         Ok(Vec::new())
     }
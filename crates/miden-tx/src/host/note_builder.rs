@@ -1,4 +1,5 @@
 use miden_protocol::asset::Asset;
+use miden_protocol::errors::NoteError;
 use miden_protocol::note::{
     Note,
     NoteAssets,
@@ -77,11 +78,8 @@ impl OutputNoteBuilder {
     ///   assets together results in an invalid asset.
     /// - Adding the asset to the note will push the list beyond the [NoteAssets::MAX_NUM_ASSETS]
     ///   limit.
-    pub fn add_asset(&mut self, asset: Asset) -> Result<(), TransactionKernelError> {
-        self.assets
-            .add_asset(asset)
-            .map_err(TransactionKernelError::FailedToAddAssetToNote)?;
-        Ok(())
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), NoteError> {
+        self.assets.add_asset(asset)
     }
 
     /// Overwrites the attachment in the note's metadata.
@@ -39,6 +39,25 @@ impl ScriptMastForestStore {
         mast_store
     }
 
+    /// Registers all procedures of the provided [NoteScript] with this store.
+    ///
+    /// This allows adding scripts which are discovered after the store was created without
+    /// rebuilding the whole store.
+    pub fn insert_script(&mut self, script: &NoteScript) {
+        self.insert(script.mast());
+    }
+
+    /// Merges all procedures and advice data of `other` into this store.
+    ///
+    /// Procedures which are already registered with this store are not replaced.
+    pub fn merge(&mut self, other: ScriptMastForestStore) {
+        for (proc_digest, mast_forest) in other.mast_forests {
+            self.mast_forests.entry(proc_digest).or_insert(mast_forest);
+        }
+
+        self.advice_map.extend(other.advice_map);
+    }
+
     /// Registers all procedures of the provided [MastForest] with this store.
     fn insert(&mut self, mast_forest: Arc<MastForest>) {
         // only register procedures that are local to this forest
@@ -66,3 +85,42 @@ impl MastForestStore for ScriptMastForestStore {
         self.mast_forests.get(procedure_root).cloned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use miden_processor::MastForestStore;
+    use miden_protocol::note::NoteScript;
+    use miden_standards::note::{P2idNote, P2mNote};
+
+    use super::ScriptMastForestStore;
+
+    #[test]
+    fn merge_script_mast_forest_stores() {
+        let p2id_script = P2idNote::script();
+        let p2m_script = P2mNote::script();
+
+        let mut store = ScriptMastForestStore::new(None, [&p2id_script].into_iter());
+        let other = ScriptMastForestStore::new(None, [&p2id_script, &p2m_script].into_iter());
+        assert!(store.get(&P2mNote::script_root()).is_none());
+
+        store.merge(other);
+
+        assert!(store.get(&P2idNote::script_root()).is_some());
+        let p2m_forest = store.get(&P2mNote::script_root()).expect("P2M root should be resolved");
+        assert_eq!(
+            NoteScript::from_parts(p2m_forest, p2m_script.entrypoint()).root(),
+            P2mNote::script_root()
+        );
+    }
+
+    #[test]
+    fn insert_script_into_script_mast_forest_store() {
+        let mut store = ScriptMastForestStore::default();
+        assert!(store.get(&P2idNote::script_root()).is_none());
+
+        store.insert_script(&P2idNote::script());
+        store.insert_script(&P2idNote::script());
+
+        assert!(store.get(&P2idNote::script_root()).is_some());
+    }
+}
@@ -1,7 +1,6 @@
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
-use miden_protocol::Word;
 use miden_protocol::account::{
     AccountStorageDelta,
     AccountStorageHeader,
@@ -11,6 +10,8 @@ use miden_protocol::account::{
     StorageSlotName,
     StorageSlotType,
 };
+use miden_protocol::utils::Serializable;
+use miden_protocol::{EMPTY_WORD, Word};
 
 /// Keeps track of the initial storage of an account during transaction execution.
 ///
@@ -35,6 +36,63 @@ pub struct StorageDeltaTracker {
     init_maps: BTreeMap<StorageSlotName, BTreeMap<Word, Word>>,
     /// The account storage delta.
     delta: AccountStorageDelta,
+    /// A map from slot name to the number of keys in that map slot whose current value differs
+    /// from their initial value, i.e. the keys that remain in the map delta after normalization.
+    ///
+    /// This is updated whenever a map item is written so that the size of the normalized delta
+    /// can be computed without normalizing it.
+    changed_map_keys: BTreeMap<StorageSlotName, ChangedMapKeys>,
+}
+
+/// The number of keys in a map slot whose value differs from their initial value, split by whether
+/// the key was cleared or updated to a non-empty value.
+#[derive(Debug, Clone, Copy, Default)]
+struct ChangedMapKeys {
+    num_cleared: usize,
+    num_updated: usize,
+}
+
+impl ChangedMapKeys {
+    /// Records that a key with the given initial value no longer holds `value`.
+    fn remove(&mut self, init_value: &Word, value: &Word) {
+        if let Some(counter) = self.counter(init_value, value) {
+            *counter -= 1;
+        }
+    }
+
+    /// Records that a key with the given initial value now holds `value`.
+    fn insert(&mut self, init_value: &Word, value: &Word) {
+        if let Some(counter) = self.counter(init_value, value) {
+            *counter += 1;
+        }
+    }
+
+    /// Returns the counter a key holding `value` is counted in, or `None` if `value` equals the
+    /// initial value of the key, in which case the key is removed by normalization.
+    fn counter(&mut self, init_value: &Word, value: &Word) -> Option<&mut usize> {
+        if value == init_value {
+            None
+        } else if value.is_empty() {
+            Some(&mut self.num_cleared)
+        } else {
+            Some(&mut self.num_updated)
+        }
+    }
+
+    /// Returns the encoded size of a map delta containing the changed keys.
+    ///
+    /// This mirrors the serialization of `StorageMapDelta`.
+    fn size_hint(&self) -> usize {
+        self.num_cleared.get_size_hint()
+            + self.num_cleared * Word::SERIALIZED_SIZE
+            + self.num_updated.get_size_hint()
+            + self.num_updated * (Word::SERIALIZED_SIZE + EMPTY_WORD.get_size_hint())
+    }
+
+    /// Returns `true` if no key in the map differs from its initial value.
+    fn is_empty(&self) -> bool {
+        self.num_cleared == 0 && self.num_updated == 0
+    }
 }
 
 impl StorageDeltaTracker {
@@ -57,6 +115,7 @@ impl StorageDeltaTracker {
             storage_header: initial_storage_header,
             init_maps: BTreeMap::new(),
             delta: AccountStorageDelta::new(),
+            changed_map_keys: BTreeMap::new(),
         };
 
         // Insert account storage into delta if it is new to match the kernel behavior.
@@ -97,6 +156,42 @@ impl StorageDeltaTracker {
         storage_delta_tracker
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the encoded size of the normalized delta returned by [`Self::into_delta`] in bytes.
+    ///
+    /// The map entries that are removed by normalization are tracked as map items are written, so
+    /// this only iterates over the slots of the delta rather than over all written map entries.
+    pub fn size_hint(&self) -> usize {
+        let u8_size = 0u8.get_size_hint();
+
+        // Length prefixes of the cleared values, updated values and maps.
+        let mut size = u8_size * 3;
+
+        for (slot_name, new_value) in self.delta.values() {
+            if self.is_value_retained(slot_name, new_value) {
+                size += slot_name.get_size_hint();
+                if !new_value.is_empty() {
+                    size += new_value.get_size_hint();
+                }
+            }
+        }
+
+        for (slot_name, _) in self.delta.maps() {
+            let changed_keys = self.changed_map_keys.get(slot_name);
+            let is_retained =
+                self.is_account_new || changed_keys.is_some_and(|keys| !keys.is_empty());
+
+            if is_retained {
+                size += slot_name.get_size_hint()
+                    + changed_keys.copied().unwrap_or_default().size_hint();
+            }
+        }
+
+        size
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -117,7 +212,12 @@ impl StorageDeltaTracker {
     ) {
         // Don't update the delta if the new value matches the old one.
         if prev_value != new_value {
-            self.set_init_map_item(slot_name.clone(), key, prev_value);
+            let init_value = self.set_init_map_item(slot_name.clone(), key, prev_value);
+
+            let changed_keys = self.changed_map_keys.entry(slot_name.clone()).or_default();
+            changed_keys.remove(&init_value, &prev_value);
+            changed_keys.insert(&init_value, &new_value);
+
             self.delta
                 .set_map_item(slot_name, key, new_value)
                 .expect("transaction kernel should not change slot types");
@@ -133,10 +233,28 @@ impl StorageDeltaTracker {
     // --------------------------------------------------------------------------------------------
 
     /// Sets the initial value of the given key in the given slot to the given value, if no value is
-    /// already tracked for that key.
-    fn set_init_map_item(&mut self, slot_name: StorageSlotName, key: Word, prev_value: Word) {
+    /// already tracked for that key, and returns the initial value of that key.
+    fn set_init_map_item(
+        &mut self,
+        slot_name: StorageSlotName,
+        key: Word,
+        prev_value: Word,
+    ) -> Word {
         let slot_map = self.init_maps.entry(slot_name).or_default();
-        slot_map.entry(key).or_insert(prev_value);
+        *slot_map.entry(key).or_insert(prev_value)
+    }
+
+    /// Returns `true` if the value slot update is retained by normalization, i.e. if the account
+    /// is new or the new value differs from the initial value of the slot.
+    fn is_value_retained(&self, slot_name: &StorageSlotName, new_value: &Word) -> bool {
+        // SAFETY: The header in the initial storage is the one from the account against which the
+        // transaction is executed, so accessing that slot name should be fine.
+        let slot_header = self
+            .storage_header
+            .find_slot_header_by_name(slot_name)
+            .expect("slot name should exist");
+
+        self.is_account_new || *new_value != slot_header.value()
     }
 
     /// Normalizes the storage delta by:
@@ -151,6 +269,7 @@ impl StorageDeltaTracker {
             storage_header,
             init_maps,
             delta,
+            changed_map_keys: _,
         } = self;
         let mut deltas = delta.into_map();
 
@@ -213,3 +332,80 @@ fn empty_storage_header_from_account(account: &PartialAccount) -> AccountStorage
 
     AccountStorageHeader::new(slots).expect("storage header should be valid")
 }
+
+#[cfg(test)]
+mod tests {
+    use miden_protocol::account::{
+        Account,
+        AccountCode,
+        AccountId,
+        AccountStorage,
+        PartialAccount,
+        StorageMap,
+        StorageSlot,
+        StorageSlotName,
+    };
+    use miden_protocol::asset::AssetVault;
+    use miden_protocol::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE;
+    use miden_protocol::utils::Serializable;
+    use miden_protocol::{Felt, FieldElement, Word};
+
+    use super::StorageDeltaTracker;
+
+    #[test]
+    fn size_hint_matches_normalized_delta() {
+        let value_slot = StorageSlotName::mock(0);
+        let map_slot = StorageSlotName::mock(1);
+        let existing_key = Word::from([1, 0, 0, 0u32]);
+        let existing_value = Word::from([7, 7, 7, 7u32]);
+
+        let storage = AccountStorage::new(vec![
+            StorageSlot::with_value(value_slot.clone(), Word::from([1, 2, 3, 4u32])),
+            StorageSlot::with_map(
+                map_slot.clone(),
+                StorageMap::with_entries([(existing_key, existing_value)]).unwrap(),
+            ),
+        ])
+        .unwrap();
+        let account = Account::new_existing(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE).unwrap(),
+            AssetVault::default(),
+            storage,
+            AccountCode::mock(),
+            Felt::ONE,
+        );
+        let mut tracker = StorageDeltaTracker::new(&PartialAccount::from(&account));
+
+        let assert_size_hint = |tracker: &StorageDeltaTracker| {
+            assert_eq!(tracker.size_hint(), tracker.clone().into_delta().get_size_hint());
+        };
+        assert_size_hint(&tracker);
+
+        // Write many keys and restore them, so the normalized delta is empty again.
+        for i in 2..50u32 {
+            let key = Word::from([i, 0, 0, 0]);
+            tracker.set_map_item(map_slot.clone(), key, Word::empty(), Word::from([i; 4]));
+            assert_size_hint(&tracker);
+        }
+        for i in 2..50u32 {
+            let key = Word::from([i, 0, 0, 0]);
+            tracker.set_map_item(map_slot.clone(), key, Word::from([i; 4]), Word::empty());
+        }
+        assert_size_hint(&tracker);
+        assert!(tracker.clone().into_delta().is_empty());
+
+        // Clear an existing key, update it and clear it again.
+        tracker.set_map_item(map_slot.clone(), existing_key, existing_value, Word::empty());
+        assert_size_hint(&tracker);
+        tracker.set_map_item(map_slot.clone(), existing_key, Word::empty(), existing_value);
+        assert_size_hint(&tracker);
+        tracker.set_map_item(map_slot.clone(), existing_key, existing_value, Word::empty());
+        assert_size_hint(&tracker);
+
+        // Update the value slot and restore it.
+        tracker.set_item(value_slot.clone(), Word::empty());
+        assert_size_hint(&tracker);
+        tracker.set_item(value_slot, Word::from([1, 2, 3, 4u32]));
+        assert_size_hint(&tracker);
+    }
+}
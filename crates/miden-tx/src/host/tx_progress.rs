@@ -1,5 +1,13 @@
 pub use alloc::vec::Vec;
 
+use miden_protocol::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+
 use super::{NoteId, RowIndex, TransactionMeasurements};
 
 // TRANSACTION PROGRESS
@@ -67,6 +75,18 @@ impl TransactionProgress {
         &self.auth_procedure
     }
 
+    /// Returns a [`TxProgressSnapshot`] of the intervals recorded so far.
+    pub fn snapshot(&self) -> TxProgressSnapshot {
+        TxProgressSnapshot {
+            prologue: self.prologue.clone(),
+            notes_processing: self.notes_processing.clone(),
+            note_execution: self.note_execution.clone(),
+            tx_script_processing: self.tx_script_processing.clone(),
+            epilogue: self.epilogue.clone(),
+            auth_procedure: self.auth_procedure.clone(),
+        }
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -171,8 +191,108 @@ impl From<TransactionProgress> for TransactionMeasurements {
     }
 }
 
+// TRANSACTION PROGRESS SNAPSHOT
+// ================================================================================================
+
+/// A point-in-time copy of the cycle intervals of the transaction execution stages.
+///
+/// Unlike [`TransactionProgress`], a snapshot can be serialized, so that tooling can report the
+/// timing of a transaction while or after it is executed. Intervals of stages which have not
+/// started or ended yet at the time of the snapshot have no start or end cycle, respectively.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxProgressSnapshot {
+    prologue: CycleInterval,
+    notes_processing: CycleInterval,
+    note_execution: Vec<(NoteId, CycleInterval)>,
+    tx_script_processing: CycleInterval,
+    epilogue: CycleInterval,
+    auth_procedure: CycleInterval,
+}
+
+impl TxProgressSnapshot {
+    /// Returns the interval of the prologue.
+    pub fn prologue(&self) -> &CycleInterval {
+        &self.prologue
+    }
+
+    /// Returns the interval of processing all input notes.
+    pub fn notes_processing(&self) -> &CycleInterval {
+        &self.notes_processing
+    }
+
+    /// Returns the execution interval of each input note whose execution has started, in
+    /// execution order.
+    pub fn note_execution(&self) -> &[(NoteId, CycleInterval)] {
+        &self.note_execution
+    }
+
+    /// Returns the number of input notes whose execution has finished.
+    pub fn num_executed_notes(&self) -> usize {
+        self.note_execution
+            .iter()
+            .filter(|(_, interval)| interval.end().is_some())
+            .count()
+    }
+
+    /// Returns the interval of processing the transaction script.
+    pub fn tx_script_processing(&self) -> &CycleInterval {
+        &self.tx_script_processing
+    }
+
+    /// Returns the interval of the epilogue.
+    pub fn epilogue(&self) -> &CycleInterval {
+        &self.epilogue
+    }
+
+    /// Returns the interval of the authentication procedure.
+    pub fn auth_procedure(&self) -> &CycleInterval {
+        &self.auth_procedure
+    }
+}
+
+impl Serializable for TxProgressSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.prologue.write_into(target);
+        self.notes_processing.write_into(target);
+        target.write_usize(self.note_execution.len());
+        for (note_id, interval) in self.note_execution.iter() {
+            note_id.write_into(target);
+            interval.write_into(target);
+        }
+        self.tx_script_processing.write_into(target);
+        self.epilogue.write_into(target);
+        self.auth_procedure.write_into(target);
+    }
+}
+
+impl Deserializable for TxProgressSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let prologue = CycleInterval::read_from(source)?;
+        let notes_processing = CycleInterval::read_from(source)?;
+        let num_notes = source.read_usize()?;
+        let note_execution = (0..num_notes)
+            .map(|_| Ok((NoteId::read_from(source)?, CycleInterval::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let tx_script_processing = CycleInterval::read_from(source)?;
+        let epilogue = CycleInterval::read_from(source)?;
+        let auth_procedure = CycleInterval::read_from(source)?;
+
+        Ok(Self {
+            prologue,
+            notes_processing,
+            note_execution,
+            tx_script_processing,
+            epilogue,
+            auth_procedure,
+        })
+    }
+}
+
+// CYCLE INTERVAL
+// ================================================================================================
+
 /// Stores the cycles corresponding to the start and the end of an interval.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
 pub struct CycleInterval {
     start: Option<RowIndex>,
     end: Option<RowIndex>,
@@ -210,4 +330,72 @@ impl CycleInterval {
 
         0
     }
+
+    /// Returns `true` if the interval is not closed or spans no cycles.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Serializable for CycleInterval {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.start.map(u32::from).write_into(target);
+        self.end.map(u32::from).write_into(target);
+    }
+}
+
+impl Deserializable for CycleInterval {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let start = Option::<u32>::read_from(source)?.map(RowIndex::from);
+        let end = Option::<u32>::read_from(source)?.map(RowIndex::from);
+
+        Ok(Self { start, end })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_protocol::Word;
+    use miden_protocol::note::NoteId;
+    use miden_protocol::utils::serde::{Deserializable, Serializable};
+
+    use super::{RowIndex, TransactionProgress, TxProgressSnapshot};
+
+    #[test]
+    fn snapshot_reflects_executed_notes() {
+        let note_id_1 = NoteId::new(Word::from([1, 2, 3, 4u32]), Word::empty());
+        let note_id_2 = NoteId::new(Word::from([5, 6, 7, 8u32]), Word::empty());
+
+        let mut tx_progress = TransactionProgress::new();
+        tx_progress.start_prologue(RowIndex::from(0u32));
+        tx_progress.end_prologue(RowIndex::from(100u32));
+        tx_progress.start_notes_processing(RowIndex::from(100u32));
+        tx_progress.start_note_execution(RowIndex::from(110u32), note_id_1);
+        tx_progress.end_note_execution(RowIndex::from(200u32));
+        tx_progress.start_note_execution(RowIndex::from(210u32), note_id_2);
+
+        // the second note is still being executed
+        let snapshot = tx_progress.snapshot();
+        assert_eq!(snapshot.note_execution().len(), 2);
+        assert_eq!(snapshot.num_executed_notes(), 1);
+        assert_eq!(snapshot.prologue().len(), 100);
+        assert_eq!(snapshot.notes_processing().end(), None);
+
+        tx_progress.end_note_execution(RowIndex::from(300u32));
+        tx_progress.end_notes_processing(RowIndex::from(310u32));
+
+        // taking a new snapshot does not affect the previous one
+        let new_snapshot = tx_progress.snapshot();
+        assert_eq!(snapshot.num_executed_notes(), 1);
+        assert_eq!(new_snapshot.num_executed_notes(), 2);
+        assert_eq!(new_snapshot.note_execution()[1].0, note_id_2);
+        assert_eq!(new_snapshot.note_execution()[1].1.len(), 90);
+        assert_eq!(new_snapshot.notes_processing().len(), 210);
+
+        let deserialized = TxProgressSnapshot::read_from_bytes(&new_snapshot.to_bytes()).unwrap();
+        assert_eq!(deserialized, new_snapshot);
+    }
 }
@@ -8,8 +8,11 @@ extern crate std;
 
 mod executor;
 pub use executor::{
+    AccountInputsExt,
+    AccountMutation,
     DataStore,
     ExecutionOptions,
+    ExecutionPreset,
     FailedNote,
     MAX_NUM_CHECKER_NOTES,
     MastForestStore,
@@ -17,10 +20,19 @@ pub use executor::{
     NoteConsumptionInfo,
     TransactionExecutor,
     TransactionExecutorHost,
+    TxRequest,
 };
 
 mod host;
-pub use host::{AccountProcedureIndexMap, LinkMap, MemoryViewer, ScriptMastForestStore};
+pub use host::{
+    AccountProcedureIndexMap,
+    CycleInterval,
+    ExecutionContext,
+    LinkMap,
+    MemoryViewer,
+    ScriptMastForestStore,
+    TxProgressSnapshot,
+};
 
 mod prover;
 pub use prover::{
@@ -30,6 +42,9 @@ pub use prover::{
     TransactionProverHost,
 };
 
+mod replay;
+pub use replay::{ReplayBundleExt, ReplayDivergence, ReplayDivergenceReport};
+
 mod verifier;
 pub use verifier::TransactionVerifier;
 
@@ -41,6 +56,7 @@ pub use errors::{
     TransactionExecutorError,
     TransactionKernelError,
     TransactionProverError,
+    TransactionReplayError,
     TransactionVerifierError,
 };
 
@@ -2,7 +2,7 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use miden_protocol::account::delta::AccountUpdateDetails;
-use miden_protocol::account::{AccountDelta, PartialAccount};
+use miden_protocol::account::{AccountCode, AccountDelta, PartialAccount};
 use miden_protocol::asset::Asset;
 use miden_protocol::block::BlockNumber;
 use miden_protocol::transaction::{
@@ -14,9 +14,11 @@ use miden_protocol::transaction::{
     TransactionInputs,
     TransactionKernel,
     TransactionOutputs,
+    TransactionScript,
+    TransactionWitness,
 };
 pub use miden_prover::ProvingOptions;
-use miden_prover::{ExecutionProof, Word, prove};
+use miden_prover::{AdviceInputs, ExecutionProof, StackInputs, StackOutputs, Word, prove};
 
 use super::TransactionProverError;
 use crate::host::{AccountProcedureIndexMap, ScriptMastForestStore};
@@ -94,6 +96,7 @@ impl LocalTransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionBuildFailed)
     }
 
+    /// Executes and proves the transaction described by the provided inputs.
     pub fn prove(
         &self,
         tx_inputs: impl Into<TransactionInputs>,
@@ -101,31 +104,96 @@ impl LocalTransactionProver {
         let tx_inputs = tx_inputs.into();
         let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(&tx_inputs);
 
-        self.mast_store.load_account_code(tx_inputs.account().code());
-        for account_code in tx_inputs.foreign_account_code() {
-            self.mast_store.load_account_code(account_code);
+        let tx_script = tx_inputs.tx_script().cloned();
+        let foreign_account_code = tx_inputs.foreign_account_code().to_vec();
+        let (partial_account, ref_block, _, input_notes, _) = tx_inputs.into_parts();
+
+        self.prove_inner(
+            partial_account,
+            input_notes,
+            tx_script.as_ref(),
+            &foreign_account_code,
+            ref_block.block_num(),
+            ref_block.commitment(),
+            stack_inputs,
+            advice_inputs.into_advice_inputs(),
+            None,
+        )
+    }
+
+    /// Proves the transaction described by the provided [`TransactionWitness`].
+    ///
+    /// The account, the input notes and the code executed by the transaction are read from the
+    /// advice inputs of the witness. The stack outputs of the proven execution are checked against
+    /// the outputs expected by the witness, so that a witness with mismatching outputs is rejected
+    /// before the proven transaction is built.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The witness was created for a transaction kernel program different from the current one.
+    /// - The inputs of the transaction host cannot be read from the witness.
+    /// - The execution of the transaction kernel program fails.
+    /// - The stack outputs of the transaction kernel program do not match the outputs expected by
+    ///   the witness.
+    pub fn prove_witness(
+        &self,
+        witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let kernel_program = TransactionKernel::main();
+        if witness.program_hash() != kernel_program.hash() {
+            return Err(TransactionProverError::WitnessKernelProgramHashMismatch {
+                expected: witness.program_hash(),
+                actual: kernel_program.hash(),
+            });
         }
 
-        let script_mast_store = ScriptMastForestStore::new(
-            tx_inputs.tx_script(),
-            tx_inputs.input_notes().iter().map(|n| n.note().script()),
-        );
+        let host_inputs = witness
+            .read_host_inputs()
+            .map_err(TransactionProverError::WitnessHostInputsReadFailed)?;
 
-        let account_procedure_index_map = AccountProcedureIndexMap::new(
-            tx_inputs.foreign_account_code().iter().chain([tx_inputs.account().code()]),
-        );
+        self.prove_inner(
+            host_inputs.account,
+            host_inputs.input_notes,
+            host_inputs.tx_script.as_ref(),
+            &host_inputs.foreign_account_code,
+            host_inputs.ref_block_num,
+            host_inputs.ref_block_commitment,
+            witness.stack_inputs().clone(),
+            witness.advice_inputs().clone(),
+            Some(witness.expected_stack_outputs()),
+        )
+    }
 
-        let (partial_account, ref_block, _, input_notes, _) = tx_inputs.into_parts();
-        let mut host = TransactionProverHost::new(
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Proves the transaction kernel program against the provided inputs and returns the resulting
+    /// proven transaction.
+    ///
+    /// If `expected_stack_outputs` is provided, the stack outputs of the program are checked
+    /// against them before the proven transaction is built.
+    #[allow(clippy::too_many_arguments)]
+    fn prove_inner(
+        &self,
+        partial_account: PartialAccount,
+        input_notes: InputNotes<InputNote>,
+        tx_script: Option<&TransactionScript>,
+        foreign_account_code: &[AccountCode],
+        ref_block_num: BlockNumber,
+        ref_block_commitment: Word,
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        expected_stack_outputs: Option<&StackOutputs>,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let mut host = build_host(
+            self.mast_store.as_ref(),
             &partial_account,
             input_notes,
-            self.mast_store.as_ref(),
-            script_mast_store,
-            account_procedure_index_map,
+            tx_script,
+            foreign_account_code,
         );
 
-        let advice_inputs = advice_inputs.into_advice_inputs();
-
         let (stack_outputs, proof) = prove(
             &TransactionKernel::main(),
             stack_inputs,
@@ -135,6 +203,10 @@ impl LocalTransactionProver {
         )
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
+        if expected_stack_outputs.is_some_and(|expected| expected != &stack_outputs) {
+            return Err(TransactionProverError::WitnessStackOutputsMismatch);
+        }
+
         // Extract transaction outputs and process transaction data.
         // Note that the account delta does not contain the removed transaction fee, so it is the
         // "pre-fee" delta of the transaction.
@@ -148,8 +220,8 @@ impl LocalTransactionProver {
             tx_outputs,
             pre_fee_account_delta,
             partial_account,
-            ref_block.block_num(),
-            ref_block.commitment(),
+            ref_block_num,
+            ref_block_commitment,
             proof,
         )
     }
@@ -164,6 +236,38 @@ impl Default for LocalTransactionProver {
     }
 }
 
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Loads the code of the provided accounts into the MAST store and returns a host for executing
+/// the transaction kernel program against the provided inputs.
+pub(crate) fn build_host<'store>(
+    mast_store: &'store TransactionMastStore,
+    partial_account: &PartialAccount,
+    input_notes: InputNotes<InputNote>,
+    tx_script: Option<&TransactionScript>,
+    foreign_account_code: &[AccountCode],
+) -> TransactionProverHost<'store, TransactionMastStore> {
+    mast_store.load_account_code(partial_account.code());
+    for account_code in foreign_account_code {
+        mast_store.load_account_code(account_code);
+    }
+
+    let script_mast_store =
+        ScriptMastForestStore::new(tx_script, input_notes.iter().map(|n| n.note().script()));
+
+    let account_procedure_index_map =
+        AccountProcedureIndexMap::new(foreign_account_code.iter().chain([partial_account.code()]));
+
+    TransactionProverHost::new(
+        partial_account,
+        input_notes,
+        mast_store,
+        script_mast_store,
+        account_procedure_index_map,
+    )
+}
+
 #[cfg(any(feature = "testing", test))]
 impl LocalTransactionProver {
     pub fn prove_dummy(
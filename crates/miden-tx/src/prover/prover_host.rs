@@ -3,8 +3,10 @@ use alloc::vec::Vec;
 
 use miden_processor::{
     AdviceMutation,
+    AsyncHost,
     BaseHost,
     EventError,
+    FutureMaybeSend,
     MastForest,
     MastForestStore,
     ProcessState,
@@ -16,11 +18,20 @@ use miden_protocol::assembly::debuginfo::Location;
 use miden_protocol::assembly::{SourceFile, SourceSpan};
 use miden_protocol::transaction::{InputNote, InputNotes, OutputNote};
 
-use crate::host::{RecipientData, ScriptMastForestStore, TransactionBaseHost, TransactionEvent};
+use crate::host::{
+    ExecutionContext,
+    RecipientData,
+    ScriptMastForestStore,
+    TransactionBaseHost,
+    TransactionEvent,
+};
 use crate::{AccountProcedureIndexMap, TransactionKernelError};
 
 /// The transaction prover host is responsible for handling [`SyncHost`] requests made by the
 /// transaction kernel during proving.
+///
+/// It also implements [`AsyncHost`] so that the same host can be used to re-execute a transaction
+/// from its recorded inputs with the fast processor.
 pub struct TransactionProverHost<'store, STORE>
 where
     STORE: MastForestStore,
@@ -103,17 +114,19 @@ where
             return Ok(Vec::new());
         };
 
+        // The execution context is not tracked at proving time, since the transaction has already
+        // been executed successfully and errors are not expected.
         let result = match tx_event {
             // Foreign account data and witnesses should be in the advice provider at
             // proving time, so there is nothing to do.
             TransactionEvent::AccountBeforeForeignLoad { .. } => Ok(Vec::new()),
 
-            TransactionEvent::AccountVaultAfterRemoveAsset { asset } => {
-                self.base_host.on_account_vault_after_remove_asset(asset)
-            },
-            TransactionEvent::AccountVaultAfterAddAsset { asset } => {
-                self.base_host.on_account_vault_after_add_asset(asset)
-            },
+            TransactionEvent::AccountVaultAfterRemoveAsset { asset } => self
+                .base_host
+                .on_account_vault_after_remove_asset(asset, ExecutionContext::default()),
+            TransactionEvent::AccountVaultAfterAddAsset { asset } => self
+                .base_host
+                .on_account_vault_after_add_asset(asset, ExecutionContext::default()),
 
             TransactionEvent::AccountStorageAfterSetItem { slot_name, new_value } => {
                 self.base_host.on_account_storage_after_set_item(slot_name, new_value)
@@ -154,9 +167,10 @@ where
                 }
             },
 
-            TransactionEvent::NoteBeforeAddAsset { note_idx, asset } => {
-                self.base_host.on_note_before_add_asset(note_idx, asset).map(|_| Vec::new())
-            },
+            TransactionEvent::NoteBeforeAddAsset { note_idx, asset } => self
+                .base_host
+                .on_note_before_add_asset(note_idx, asset, ExecutionContext::default())
+                .map(|_| Vec::new()),
 
             TransactionEvent::NoteBeforeSetAttachment { note_idx, attachment } => self
                 .base_host
@@ -195,3 +209,21 @@ where
         result.map_err(EventError::from)
     }
 }
+
+impl<STORE> AsyncHost for TransactionProverHost<'_, STORE>
+where
+    STORE: MastForestStore,
+{
+    fn get_mast_forest(&self, node_digest: &Word) -> impl FutureMaybeSend<Option<Arc<MastForest>>> {
+        let mast_forest = <Self as SyncHost>::get_mast_forest(self, node_digest);
+        async move { mast_forest }
+    }
+
+    fn on_event(
+        &mut self,
+        process: &ProcessState,
+    ) -> impl FutureMaybeSend<Result<Vec<AdviceMutation>, EventError>> {
+        let result = <Self as SyncHost>::on_event(self, process);
+        async move { result }
+    }
+}
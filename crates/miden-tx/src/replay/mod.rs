@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use miden_processor::fast::FastProcessor;
+use miden_processor::{FutureMaybeSend, StackInputs};
+use miden_protocol::Word;
+use miden_protocol::asset::FungibleAsset;
+use miden_protocol::block::BlockNumber;
+use miden_protocol::transaction::{ReplayBundle, TransactionKernel, TransactionOutputs};
+
+use crate::errors::TransactionReplayError;
+use crate::prover::{TransactionMastStore, build_host};
+
+// REPLAY BUNDLE EXTENSION
+// ================================================================================================
+
+/// An extension trait for [`ReplayBundle`] which allows re-executing the recorded transaction.
+pub trait ReplayBundleExt {
+    /// Re-executes the transaction recorded in this bundle and returns the outputs of the
+    /// re-execution.
+    ///
+    /// The transaction kernel is executed without a data store or an authenticator. All data
+    /// requested by the kernel (including signatures) is expected to be present in the advice
+    /// inputs of the bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The kernel program hash recorded in the bundle does not match the current transaction
+    ///   kernel.
+    /// - The execution of the transaction kernel program fails.
+    /// - The transaction outputs cannot be constructed from the results of the execution.
+    /// - The outputs of the re-execution diverge from the outputs recorded in the bundle.
+    fn execute(&self) -> impl FutureMaybeSend<Result<TransactionOutputs, TransactionReplayError>>;
+}
+
+impl ReplayBundleExt for ReplayBundle {
+    fn execute(&self) -> impl FutureMaybeSend<Result<TransactionOutputs, TransactionReplayError>> {
+        async move {
+            let kernel_program = TransactionKernel::main();
+            if kernel_program.hash() != self.kernel_program_hash() {
+                return Err(TransactionReplayError::KernelProgramHashMismatch {
+                    expected: self.kernel_program_hash(),
+                    actual: kernel_program.hash(),
+                });
+            }
+
+            let tx_inputs = self.tx_inputs();
+            let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(tx_inputs);
+            let advice_inputs = advice_inputs.into_advice_inputs();
+
+            // The fast processor expects the stack inputs in reverse order, see
+            // `TransactionExecutor::prepare_transaction`.
+            let stack_inputs = StackInputs::new(stack_inputs.iter().copied().collect())
+                .expect("stack inputs of the transaction kernel should be valid");
+
+            let mast_store = TransactionMastStore::new();
+            let mut host = build_host(
+                &mast_store,
+                tx_inputs.account(),
+                tx_inputs.input_notes().clone(),
+                tx_inputs.tx_script(),
+                tx_inputs.foreign_account_code(),
+            );
+
+            let processor = FastProcessor::new_with_advice_inputs(
+                stack_inputs.as_slice(),
+                advice_inputs.clone(),
+            );
+            let output = processor
+                .execute(&kernel_program, &mut host)
+                .await
+                .map_err(TransactionReplayError::TransactionProgramExecutionFailed)?;
+
+            let (_, _, output_notes) = host.into_parts();
+            let tx_outputs = TransactionKernel::from_transaction_parts(
+                &output.stack,
+                &advice_inputs,
+                output_notes,
+            )
+            .map_err(TransactionReplayError::TransactionOutputConstructionFailed)?;
+
+            let report = ReplayDivergenceReport::new(self.tx_outputs(), &tx_outputs);
+            if !report.is_empty() {
+                return Err(TransactionReplayError::OutputsDiverged(report));
+            }
+
+            Ok(tx_outputs)
+        }
+    }
+}
+
+// REPLAY DIVERGENCE
+// ================================================================================================
+
+/// A single difference between the recorded and the replayed outputs of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayDivergence {
+    FinalAccountCommitment {
+        recorded: Word,
+        replayed: Word,
+    },
+    AccountDeltaCommitment {
+        recorded: Word,
+        replayed: Word,
+    },
+    OutputNotesCommitment {
+        recorded: Word,
+        replayed: Word,
+    },
+    Fee {
+        recorded: FungibleAsset,
+        replayed: FungibleAsset,
+    },
+    ExpirationBlockNum {
+        recorded: BlockNumber,
+        replayed: BlockNumber,
+    },
+}
+
+impl fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FinalAccountCommitment { recorded, replayed } => {
+                write!(f, "final account commitment was {recorded} but replay produced {replayed}")
+            },
+            Self::AccountDeltaCommitment { recorded, replayed } => {
+                write!(f, "account delta commitment was {recorded} but replay produced {replayed}")
+            },
+            Self::OutputNotesCommitment { recorded, replayed } => {
+                write!(f, "output notes commitment was {recorded} but replay produced {replayed}")
+            },
+            Self::Fee { recorded, replayed } => {
+                write!(f, "fee was {recorded:?} but replay produced {replayed:?}")
+            },
+            Self::ExpirationBlockNum { recorded, replayed } => {
+                write!(f, "expiration block number was {recorded} but replay produced {replayed}")
+            },
+        }
+    }
+}
+
+/// Describes all differences between the recorded and the replayed outputs of a transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayDivergenceReport {
+    divergences: Vec<ReplayDivergence>,
+}
+
+impl ReplayDivergenceReport {
+    /// Returns a new [`ReplayDivergenceReport`] listing all differences between the `recorded` and
+    /// the `replayed` transaction outputs.
+    pub fn new(recorded: &TransactionOutputs, replayed: &TransactionOutputs) -> Self {
+        let mut divergences = Vec::new();
+
+        if recorded.account.commitment() != replayed.account.commitment() {
+            divergences.push(ReplayDivergence::FinalAccountCommitment {
+                recorded: recorded.account.commitment(),
+                replayed: replayed.account.commitment(),
+            });
+        }
+
+        if recorded.account_delta_commitment != replayed.account_delta_commitment {
+            divergences.push(ReplayDivergence::AccountDeltaCommitment {
+                recorded: recorded.account_delta_commitment,
+                replayed: replayed.account_delta_commitment,
+            });
+        }
+
+        if recorded.output_notes.commitment() != replayed.output_notes.commitment() {
+            divergences.push(ReplayDivergence::OutputNotesCommitment {
+                recorded: recorded.output_notes.commitment(),
+                replayed: replayed.output_notes.commitment(),
+            });
+        }
+
+        if recorded.fee != replayed.fee {
+            divergences.push(ReplayDivergence::Fee {
+                recorded: recorded.fee,
+                replayed: replayed.fee,
+            });
+        }
+
+        if recorded.expiration_block_num != replayed.expiration_block_num {
+            divergences.push(ReplayDivergence::ExpirationBlockNum {
+                recorded: recorded.expiration_block_num,
+                replayed: replayed.expiration_block_num,
+            });
+        }
+
+        Self { divergences }
+    }
+
+    /// Returns the list of differences between the recorded and the replayed outputs.
+    pub fn divergences(&self) -> &[ReplayDivergence] {
+        &self.divergences
+    }
+
+    /// Returns `true` if the recorded and the replayed outputs are identical, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+impl fmt::Display for ReplayDivergenceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, divergence) in self.divergences.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{divergence}")?;
+        }
+
+        Ok(())
+    }
+}
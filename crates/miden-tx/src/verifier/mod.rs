@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use miden_protocol::CoreLibrary;
 use miden_protocol::transaction::{ProvenTransaction, TransactionKernel};
 use miden_protocol::vm::ProgramInfo;
@@ -13,8 +15,14 @@ use super::TransactionVerifierError;
 /// The [TransactionVerifier] contains a [ProgramInfo] object which is associated with the
 /// transaction kernel program.  The `proof_security_level` specifies the minimum security
 /// level that the transaction proof must have in order to be considered valid.
+///
+/// The verifier can additionally be configured with the [ProgramInfo] of other known transaction
+/// kernels, e.g. of previous kernel versions. These are only used to diagnose failed
+/// verifications: a proof which does not verify against the expected kernel but verifies against
+/// a known kernel is reported as a kernel mismatch rather than as an invalid proof.
 pub struct TransactionVerifier {
     tx_program_info: ProgramInfo,
+    known_tx_program_infos: Vec<ProgramInfo>,
     proof_security_level: u32,
 }
 
@@ -22,14 +30,42 @@ impl TransactionVerifier {
     /// Returns a new [TransactionVerifier] instantiated with the specified security level.
     pub fn new(proof_security_level: u32) -> Self {
         let tx_program_info = TransactionKernel::program_info();
-        Self { tx_program_info, proof_security_level }
+        Self {
+            tx_program_info,
+            known_tx_program_infos: Vec::new(),
+            proof_security_level,
+        }
+    }
+
+    /// Sets the transaction kernel program against which transactions are verified.
+    ///
+    /// By default, transactions are verified against [`TransactionKernel::program_info`].
+    pub fn with_kernel_program(mut self, tx_program_info: ProgramInfo) -> Self {
+        self.tx_program_info = tx_program_info;
+        self
+    }
+
+    /// Adds the provided transaction kernel programs to the known kernels of this verifier.
+    ///
+    /// If a proof fails to verify against the expected kernel, it is verified against the known
+    /// kernels to determine whether it was produced against one of them, in which case
+    /// [`TransactionVerifierError::KernelProgramMismatch`] is returned.
+    pub fn with_known_kernel_programs(
+        mut self,
+        tx_program_infos: impl IntoIterator<Item = ProgramInfo>,
+    ) -> Self {
+        self.known_tx_program_infos.extend(tx_program_infos);
+        self
     }
 
     /// Verifies the provided [`ProvenTransaction`] against the transaction kernel.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - Transaction verification fails.
+    /// - The transaction proof was produced against one of the known transaction kernels instead of
+    ///   the expected one.
+    /// - The transaction proof is invalid, including if it was produced against a different
+    ///   transaction kernel which is not known to this verifier.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: &ProvenTransaction) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
@@ -50,21 +86,45 @@ impl TransactionVerifier {
 
         // verify transaction proof
         let precompile_verifiers = CoreLibrary::default().verifier_registry();
-        let proof_security_level = verify_with_precompiles(
-            self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
-            transaction.proof().clone(),
-            &precompile_verifiers,
-        )
-        .map_err(TransactionVerifierError::TransactionVerificationFailed)?
-        .0;
+        let verify = |program_info: &ProgramInfo| {
+            verify_with_precompiles(
+                program_info.clone(),
+                stack_inputs.clone(),
+                stack_outputs.clone(),
+                transaction.proof().clone(),
+                &precompile_verifiers,
+            )
+        };
+
+        let proof_security_level = match verify(&self.tx_program_info) {
+            Ok((proof_security_level, _)) => proof_security_level,
+            Err(source) => {
+                // check whether the proof was produced against another known kernel
+                let expected = self.tx_program_info.program_hash();
+                let actual = self
+                    .known_tx_program_infos
+                    .iter()
+                    .filter(|program_info| program_info.program_hash() != expected)
+                    .find(|program_info| verify(program_info).is_ok());
+
+                return Err(match actual {
+                    Some(actual) => TransactionVerifierError::KernelProgramMismatch {
+                        expected: *expected,
+                        actual: *actual.program_hash(),
+                    },
+                    None => TransactionVerifierError::ProofVerificationFailed {
+                        proof_security_level: self.proof_security_level,
+                        source,
+                    },
+                });
+            },
+        };
 
         // check security level
         if proof_security_level < self.proof_security_level {
-            return Err(TransactionVerifierError::InsufficientProofSecurityLevel {
+            return Err(TransactionVerifierError::InsufficientSecurityLevel {
+                required: self.proof_security_level,
                 actual: proof_security_level,
-                expected_minimum: self.proof_security_level,
             });
         }
 